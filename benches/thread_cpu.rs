@@ -0,0 +1,34 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use howlong::criterion::{ProcessCpuMeasurement, ThreadCpuMeasurement};
+
+fn fib(n: u64) -> u64 {
+    (0..n)
+        .fold((0u64, 1u64), |(a, b), _| (b, a.wrapping_add(b)))
+        .0
+}
+
+fn bench_thread_cpu(c: &mut Criterion<ThreadCpuMeasurement>) {
+    c.bench_function("fib thread cpu", |b| {
+        b.iter(|| fib(criterion::black_box(10_000)))
+    });
+}
+
+fn bench_process_cpu(c: &mut Criterion<ProcessCpuMeasurement>) {
+    c.bench_function("fib process cpu", |b| {
+        b.iter(|| fib(criterion::black_box(10_000)))
+    });
+}
+
+criterion_group! {
+    name = thread_cpu;
+    config = Criterion::default().with_measurement(ThreadCpuMeasurement);
+    targets = bench_thread_cpu
+}
+
+criterion_group! {
+    name = process_cpu;
+    config = Criterion::default().with_measurement(ProcessCpuMeasurement::default());
+    targets = bench_process_cpu
+}
+
+criterion_main!(thread_cpu, process_cpu);
@@ -1,30 +1,3 @@
-#[cfg(unix)]
-fn is_defined(m: &'static str) -> bool {
-    let file = format!("{}/test_{}.c", std::env::var("OUT_DIR").unwrap(), m);
-    std::fs::write(
-        &file,
-        format!(
-            r#"
-            #include <time.h>
-
-            #ifdef {}
-            MACRO_IS_DEFINED
-            #endif
-            "#,
-            m
-        ),
-    )
-    .unwrap();
-    let check = cc::Build::new().file(&file).expand();
-    let check = String::from_utf8(check).unwrap();
-    check.contains("MACRO_IS_DEFINED")
-}
-
-#[cfg(not(unix))]
-fn is_defined(_m: &'static str) -> bool {
-    false
-}
-
 #[cfg(any(target_os = "macos", target_os = "ios"))]
 fn gen_darwin_binding() {
     use std::path::PathBuf;
@@ -33,14 +6,21 @@ fn gen_darwin_binding() {
 
     let bindings = bindgen::Builder::default()
         .header("src/clock/darwin_wrapper.h")
+        .allowlist_function("host_statistics")
         .allowlist_function("mach_absolute_time")
+        .allowlist_function("mach_continuous_time")
+        .allowlist_function("mach_host_self")
         .allowlist_function("mach_timebase_info")
         .allowlist_function("pthread_mach_thread_np")
         .allowlist_function("pthread_self")
         .allowlist_function("thread_info")
+        .allowlist_type("host_cpu_load_info_data_t")
         .allowlist_type("mach_timebase_info_data_t")
         .allowlist_type("thread_basic_info_data_t")
+        .allowlist_var("CPU_STATE_.*")
+        .allowlist_var("HOST_CPU_LOAD_INFO")
         .allowlist_var("THREAD_BASIC_INFO")
+        .allowlist_var("__HOST_CPU_LOAD_INFO_COUNT")
         .allowlist_var("__THREAD_BASIC_INFO_COUNT")
         .parse_callbacks(Box::new(bindgen::CargoCallbacks))
         .layout_tests(false)
@@ -53,16 +33,111 @@ fn gen_darwin_binding() {
 }
 
 fn main() {
-    let have_steady_clock = if cfg!(any(target_os = "macos", target_os = "ios", windows)) {
-        true
-    } else {
-        is_defined("CLOCK_MONOTONIC")
-    };
-    if have_steady_clock {
+    println!("cargo:rustc-check-cfg=cfg(have_steady_clock)");
+    println!("cargo:rustc-check-cfg=cfg(have_clock_thread_cputime_id)");
+    println!("cargo:rustc-check-cfg=cfg(have_clock_process_cputime_id)");
+    println!("cargo:rustc-check-cfg=cfg(have_pthread_getcpuclockid)");
+    println!("cargo:rustc-check-cfg=cfg(have_suspend_aware_clock)");
+    println!("cargo:rustc-check-cfg=cfg(have_coarse_clock)");
+    println!("cargo:rustc-check-cfg=cfg(have_clock_tai)");
+    println!("cargo:rustc-check-cfg=cfg(have_clock_nanosleep)");
+    println!("cargo:rustc-check-cfg=cfg(howlong_unsupported)");
+
+    // Without the `std` feature, the backends of the operating systems are not compiled, and
+    // every clock returns `Error::Unsupported` as on the other platforms.
+    if std::env::var_os("CARGO_FEATURE_STD").is_none() {
+        println!("cargo:rustc-cfg=howlong_unsupported");
+        return;
+    }
+
+    // Fuchsia, Haiku, and WASI have their own backends with a steady clock.
+    let target_os = std::env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    if (target_os == "fuchsia" || target_os == "haiku" || target_os == "wasi")
+        && std::env::var_os("CARGO_CFG_HOWLONG_UNSUPPORTED").is_none()
+    {
+        println!("cargo:rustc-cfg=have_steady_clock");
+        return;
+    }
+
+    // `wasm32-unknown-unknown` has its own backend with the `wasm` feature.
+    let target_arch = std::env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    if target_arch == "wasm32"
+        && target_os == "unknown"
+        && std::env::var_os("CARGO_FEATURE_WASM").is_some()
+        && std::env::var_os("CARGO_CFG_HOWLONG_UNSUPPORTED").is_none()
+    {
         println!("cargo:rustc-cfg=have_steady_clock");
+        return;
     }
-    if cfg!(unix) && is_defined("CLOCK_THREAD_CPUTIME_ID") {
+
+    // Fall back to the clocks returning `Error::Unsupported` on the other platforms, or if it
+    // is forced by `--cfg howlong_unsupported`.
+    let target_family = std::env::var("CARGO_CFG_TARGET_FAMILY").unwrap_or_default();
+    let is_supported = target_family
+        .split(',')
+        .any(|f| f == "unix" || f == "windows");
+    if !is_supported {
+        println!("cargo:rustc-cfg=howlong_unsupported");
+    }
+    if !is_supported || std::env::var_os("CARGO_CFG_HOWLONG_UNSUPPORTED").is_some() {
+        return;
+    }
+
+    // The clocks are decided by the target, as defined by the `libc` crate, rather than by
+    // probing the C headers, which needs a C compiler for the target when cross-compiling. A
+    // clock rejected by the running kernel is reported at runtime instead.
+    let is_darwin = matches!(target_os.as_str(), "macos" | "ios");
+    let is_windows = target_family.split(',').any(|f| f == "windows");
+    let have_clock_cputime_id = matches!(
+        target_os.as_str(),
+        "linux"
+            | "android"
+            | "emscripten"
+            | "l4re"
+            | "freebsd"
+            | "dragonfly"
+            | "netbsd"
+            | "openbsd"
+            | "solaris"
+            | "illumos"
+            | "aix"
+            | "hurd"
+            | "cygwin"
+            | "nto"
+    );
+
+    // `CLOCK_MONOTONIC` is defined on every Unix target.
+    println!("cargo:rustc-cfg=have_steady_clock");
+    if have_clock_cputime_id {
         println!("cargo:rustc-cfg=have_clock_thread_cputime_id");
+        println!("cargo:rustc-cfg=have_clock_process_cputime_id");
+    }
+    if have_clock_cputime_id && target_os != "openbsd" {
+        println!("cargo:rustc-cfg=have_pthread_getcpuclockid");
+    }
+    if is_darwin
+        || is_windows
+        || matches!(
+            target_os.as_str(),
+            "linux" | "android" | "emscripten" | "l4re" | "freebsd" | "openbsd" | "cygwin"
+        )
+    {
+        println!("cargo:rustc-cfg=have_suspend_aware_clock");
+    }
+    if is_darwin
+        || is_windows
+        || matches!(
+            target_os.as_str(),
+            "linux" | "android" | "emscripten" | "l4re" | "freebsd" | "hurd" | "cygwin"
+        )
+    {
+        println!("cargo:rustc-cfg=have_coarse_clock");
+    }
+    if target_os == "linux" {
+        println!("cargo:rustc-cfg=have_clock_tai");
+    }
+    if matches!(target_os.as_str(), "linux" | "android") {
+        println!("cargo:rustc-cfg=have_clock_nanosleep");
     }
 
     #[cfg(any(target_os = "macos", target_os = "ios"))]
@@ -0,0 +1,85 @@
+use crate::{clock::SteadyClock, Clock, TimePoint};
+use std::time::Instant;
+
+impl SteadyClock {
+    /// Return the current timepoint of [`SteadyClock`] together with the current
+    /// [`Instant`], read back-to-back.
+    ///
+    /// The two readings are taken one after the other, so they differ by the cost of a single
+    /// clock read, typically tens of nanoseconds.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn now_with_instant() -> (TimePoint, Instant) {
+        let t = Self::now();
+        (t, Instant::now())
+    }
+}
+
+/// A pair of a [`SteadyClock`] timepoint and an [`Instant`] taken at the same time, which
+/// converts between the two.
+///
+/// Both are steady clocks, so they advance at the same rate and the conversion is a constant
+/// offset. The pair is read back-to-back, using the middle of two [`SteadyClock`] readings
+/// around the [`Instant`], so the conversions are skewed by up to half the cost of a clock
+/// read, and by the difference of the underlying clocks on the platforms where
+/// [`SteadyClock`] and [`Instant`] are not the same.
+///
+/// ```
+/// use howlong::*;
+///
+/// let anchor = ClockAnchor::new();
+/// let instant = anchor.to_instant(SteadyClock::now());
+/// println!("{:?}", instant.elapsed());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ClockAnchor {
+    time_point: TimePoint,
+    instant: Instant,
+}
+
+impl ClockAnchor {
+    /// Record the current timepoint of [`SteadyClock`] and the current [`Instant`].
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn new() -> Self {
+        let before = SteadyClock::now();
+        let instant = Instant::now();
+        let after = SteadyClock::now();
+        let time_point = TimePoint(before.0 + after.saturating_sub(before) / 2);
+        ClockAnchor {
+            time_point,
+            instant,
+        }
+    }
+
+    /// Convert a timepoint of [`SteadyClock`] to an [`Instant`].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the result cannot be represented by [`Instant`].
+    pub fn to_instant(&self, time_point: TimePoint) -> Instant {
+        match time_point.checked_sub(self.time_point) {
+            Some(d) => self.instant + d,
+            None => self.instant - (self.time_point - time_point),
+        }
+    }
+
+    /// Convert an [`Instant`] to a timepoint of [`SteadyClock`]. An instant before the epoch
+    /// of [`SteadyClock`] is converted to the epoch.
+    pub fn to_timepoint(&self, instant: Instant) -> TimePoint {
+        match instant.checked_duration_since(self.instant) {
+            Some(d) => TimePoint(self.time_point.0 + d),
+            None => TimePoint(self.time_point.0.saturating_sub(self.instant - instant)),
+        }
+    }
+}
+
+impl Default for ClockAnchor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,383 @@
+//! Persist the timings of a run as a baseline, and detect the regressions of later runs.
+//!
+//! A [`Baseline`] is saved as the CSV written by [`CsvWriter`], one row per entry sorted by
+//! name, so that it can be kept under version control and read by scripts.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use howlong::{baseline::Baseline, ProcessCPUTimer};
+//!
+//! let mut current = Baseline::new();
+//! let timer = ProcessCPUTimer::new();
+//! // parse
+//! current.record("parse", timer.elapsed());
+//!
+//! let baseline = Baseline::load("baseline.csv").unwrap_or_default();
+//! let comparison = baseline.comparison(&current, 0.1);
+//! println!("{}", comparison);
+//! if comparison.regressions().is_empty() {
+//!     current.save("baseline.csv").unwrap();
+//! }
+//! ```
+
+use crate::{
+    fmt::compact,
+    report::{write_table, CsvWriter},
+    Duration, Error, ProcessDuration, Result,
+};
+use core::convert::TryFrom;
+use core::iter::FromIterator;
+use std::collections::{btree_map, BTreeMap};
+use std::io;
+use std::path::Path;
+
+/// Named [`ProcessDuration`]s of a run, compared against another run with
+/// [`compare`](Baseline::compare).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Baseline {
+    entries: BTreeMap<String, ProcessDuration>,
+}
+
+impl Baseline {
+    /// Create an empty baseline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record `d` as the entry `name`, replacing the previous one with the same name.
+    pub fn record(&mut self, name: impl Into<String>, d: ProcessDuration) {
+        self.entries.insert(name.into(), d);
+    }
+
+    /// Remove and return the entry `name`.
+    pub fn remove(&mut self, name: &str) -> Option<ProcessDuration> {
+        self.entries.remove(name)
+    }
+
+    /// Return the entry `name`.
+    pub fn get(&self, name: &str) -> Option<ProcessDuration> {
+        self.entries.get(name).copied()
+    }
+
+    /// Return an iterator over the entries sorted by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, ProcessDuration)> {
+        self.entries.iter().map(|(name, d)| (name.as_str(), *d))
+    }
+
+    /// Return the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Return true if there is no entry.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Return the entries as CSV written by [`CsvWriter`], e.g.
+    /// ```text
+    /// label,real_ns,user_ns,system_ns,cpu_pct
+    /// parse,3200000000,3100000000,50000000,98.4
+    /// ```
+    pub fn to_csv(&self) -> String {
+        let mut writer = CsvWriter::new(Vec::new());
+        writer.write_header().expect("writing to a Vec never fails");
+        for (name, d) in &self.entries {
+            writer
+                .write_row(name, d)
+                .expect("writing to a Vec never fails");
+        }
+        String::from_utf8(writer.into_inner()).expect("the CSV is valid UTF-8")
+    }
+
+    /// Parse the output of [`to_csv`](Baseline::to_csv). The `cpu_pct` column is ignored.
+    ///
+    /// # Errors
+    ///
+    /// This function will return [`Error::ParseError`] if `s` is malformed, or contains an
+    /// entry twice.
+    pub fn from_csv(s: &str) -> Result<Self> {
+        let mut rows = parse_csv(s)?.into_iter();
+        match rows.next() {
+            Some(header) if header == HEADER => {}
+            header => {
+                return Err(Error::ParseError(format!(
+                    "expected the header `{}`, found `{}`",
+                    HEADER.join(","),
+                    header.unwrap_or_default().join(",")
+                )))
+            }
+        }
+        let mut baseline = Baseline::new();
+        for row in rows.filter(|row| row != &[""]) {
+            if row.len() != HEADER.len() {
+                return Err(Error::ParseError(format!(
+                    "expected {} fields, found {} in `{}`",
+                    HEADER.len(),
+                    row.len(),
+                    row.join(",")
+                )));
+            }
+            let d = ProcessDuration::new(nanos(&row[1])?, nanos(&row[2])?, nanos(&row[3])?);
+            match baseline.entries.entry(row[0].clone()) {
+                btree_map::Entry::Vacant(entry) => {
+                    entry.insert(d);
+                }
+                btree_map::Entry::Occupied(entry) => {
+                    return Err(Error::ParseError(format!(
+                        "duplicate entry `{}`",
+                        entry.key()
+                    )))
+                }
+            }
+        }
+        Ok(baseline)
+    }
+
+    /// Write the baseline to the file `path` as [`to_csv`](Baseline::to_csv), replacing it if
+    /// it exists.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        std::fs::write(path, self.to_csv())
+    }
+
+    /// Read a baseline saved by [`save`](Baseline::save) from the file `path`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if reading the file failed, or an error of the kind
+    /// [`io::ErrorKind::InvalidData`] if it is malformed, see
+    /// [`from_csv`](Baseline::from_csv).
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let csv = std::fs::read_to_string(path)?;
+        Self::from_csv(&csv).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Compare `current` against `self`, and return the entries which became slower by more
+    /// than `threshold`, e.g. `0.1` for 10%, and the entries which were added or removed,
+    /// sorted by name. See [`Regression::ratio`].
+    pub fn compare(&self, current: &Baseline, threshold: f64) -> Vec<Regression> {
+        self.comparison(current, threshold).regressions()
+    }
+
+    /// Compare `current` against `self` like [`compare`](Baseline::compare), and return the
+    /// comparison of all the entries, which may be printed as a table.
+    pub fn comparison<'a>(&'a self, current: &'a Baseline, threshold: f64) -> Comparison<'a> {
+        Comparison {
+            old: self,
+            new: current,
+            threshold,
+        }
+    }
+}
+
+impl<S: Into<String>> FromIterator<(S, ProcessDuration)> for Baseline {
+    fn from_iter<I: IntoIterator<Item = (S, ProcessDuration)>>(iter: I) -> Self {
+        let mut baseline = Baseline::new();
+        for (name, d) in iter {
+            baseline.record(name, d);
+        }
+        baseline
+    }
+}
+
+const HEADER: [&str; 5] = ["label", "real_ns", "user_ns", "system_ns", "cpu_pct"];
+
+fn nanos(field: &str) -> Result<Duration> {
+    let nanos: u128 = field
+        .parse()
+        .map_err(|_| Error::ParseError(format!("invalid nanoseconds `{}`", field)))?;
+    let secs = u64::try_from(nanos / 1_000_000_000)
+        .map_err(|_| Error::ParseError(format!("too large nanoseconds `{}`", field)))?;
+    Ok(Duration::new(secs, (nanos % 1_000_000_000) as u32))
+}
+
+/// Split `s` into rows of fields. A field may be quoted, with `""` standing for a double
+/// quote, to contain commas, double quotes, and line breaks.
+fn parse_csv(s: &str) -> Result<Vec<Vec<String>>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if field.is_empty() => loop {
+                match chars.next() {
+                    Some('"') if chars.peek() == Some(&'"') => {
+                        chars.next();
+                        field.push('"');
+                    }
+                    Some('"') => break,
+                    Some(c) => field.push(c),
+                    None => return Err(Error::ParseError("unterminated quoted field".to_owned())),
+                }
+            },
+            ',' => row.push(core::mem::take(&mut field)),
+            '\n' => {
+                row.push(core::mem::take(&mut field));
+                rows.push(core::mem::take(&mut row));
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            c => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+/// An entry of a [`Baseline`] which became slower, was added, or was removed, returned by
+/// [`Baseline::compare`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Regression {
+    /// The name of the entry.
+    pub name: String,
+    /// The entry in the baseline, or `None` if it was added.
+    pub old: Option<ProcessDuration>,
+    /// The entry in the current run, or `None` if it was removed.
+    pub new: Option<ProcessDuration>,
+    /// The greater of the ratios `new / old` of the real times and of the CPU times, or
+    /// `None` if the entry was added or removed. A ratio is 1 if both times are zero, and
+    /// infinity if only the old one is.
+    pub ratio: Option<f64>,
+}
+
+impl Regression {
+    fn new(name: &str, old: Option<ProcessDuration>, new: Option<ProcessDuration>) -> Self {
+        let ratio = match (old, new) {
+            (Some(old), Some(new)) => {
+                Some(ratio(old.real, new.real).max(ratio(old.cpu_time(), new.cpu_time())))
+            }
+            _ => None,
+        };
+        Regression {
+            name: name.to_owned(),
+            old,
+            new,
+            ratio,
+        }
+    }
+
+    /// Return true if the entry is only in the current run.
+    pub fn is_added(&self) -> bool {
+        self.old.is_none()
+    }
+
+    /// Return true if the entry is only in the baseline.
+    pub fn is_removed(&self) -> bool {
+        self.new.is_none()
+    }
+
+    /// Return true if the entry is in both runs and became slower by more than `threshold`.
+    pub fn exceeds(&self, threshold: f64) -> bool {
+        matches!(self.ratio, Some(ratio) if ratio > 1.0 + threshold)
+    }
+}
+
+fn ratio(old: Duration, new: Duration) -> f64 {
+    match (old.is_zero(), new.is_zero()) {
+        (true, true) => 1.0,
+        (true, false) => f64::INFINITY,
+        _ => new.as_nanos() as f64 / old.as_nanos() as f64,
+    }
+}
+
+/// The comparison of all the entries of two [`Baseline`]s, returned by
+/// [`Baseline::comparison`].
+///
+/// Its [`Display`](core::fmt::Display) prints a table of the entries sorted by name, whose
+/// last column flags the regressions beyond the threshold, and the added and removed entries.
+/// It will look something like this:
+/// ```text
+/// entry   old wall  new wall  old cpu  new cpu  ratio
+/// encode     100ms     130ms    100ms    110ms  1.30×  REGRESSED
+/// legacy     3.00s         -    2.90s        -      -  removed
+/// parse      3.20s     3.10s    3.15s    3.05s  0.97×
+/// render         -    12.0ms        -   11.5ms      -  added
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Comparison<'a> {
+    old: &'a Baseline,
+    new: &'a Baseline,
+    threshold: f64,
+}
+
+impl Comparison<'_> {
+    /// Return the threshold of the comparison.
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Return the comparisons of all the entries of both baselines, sorted by name.
+    pub fn entries(&self) -> Vec<Regression> {
+        let mut names: Vec<&str> = self
+            .old
+            .entries
+            .keys()
+            .chain(self.new.entries.keys())
+            .map(String::as_str)
+            .collect();
+        names.sort_unstable();
+        names.dedup();
+        names
+            .into_iter()
+            .map(|name| Regression::new(name, self.old.get(name), self.new.get(name)))
+            .collect()
+    }
+
+    /// Return the entries which became slower by more than the threshold, and the entries
+    /// which were added or removed, sorted by name, see [`Baseline::compare`].
+    pub fn regressions(&self) -> Vec<Regression> {
+        let mut entries = self.entries();
+        entries.retain(|r| r.ratio.is_none() || r.exceeds(self.threshold));
+        entries
+    }
+}
+
+impl core::fmt::Display for Comparison<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let entries = self.entries();
+        if entries.is_empty() {
+            return f.write_str("no entries recorded");
+        }
+        let time = |d: Option<ProcessDuration>, cpu: bool| match d {
+            Some(d) if cpu => compact(d.cpu_time()).to_string(),
+            Some(d) => compact(d.real).to_string(),
+            None => "-".to_owned(),
+        };
+        let rows: Vec<_> = entries
+            .iter()
+            .map(|r| {
+                let status = if r.is_added() {
+                    "added"
+                } else if r.is_removed() {
+                    "removed"
+                } else if r.exceeds(self.threshold) {
+                    "REGRESSED"
+                } else {
+                    ""
+                };
+                let cells = [
+                    r.name.clone(),
+                    time(r.old, false),
+                    time(r.new, false),
+                    time(r.old, true),
+                    time(r.new, true),
+                    r.ratio
+                        .map_or_else(|| "-".to_owned(), |ratio| format!("{:.2}×", ratio)),
+                ];
+                (cells, status)
+            })
+            .collect();
+        write_table(
+            f,
+            [
+                "entry", "old wall", "new wall", "old cpu", "new cpu", "ratio",
+            ],
+            &rows,
+            None,
+        )
+    }
+}
@@ -0,0 +1,226 @@
+//! Helpers for quick microbenchmark-style measurements.
+//!
+//! # Examples
+//!
+//! ```
+//! use howlong::bench;
+//!
+//! let best = bench::best_of(10, || {
+//!     bench::black_box((0..1000u64).sum::<u64>());
+//! })
+//! .unwrap();
+//! println!("best of 10: {:?}", best);
+//!
+//! let stats = bench::run(100, || {
+//!     bench::black_box((0..1000u64).sum::<u64>());
+//! })
+//! .unwrap();
+//! println!("{}", stats); // 100 iterations in 1.2ms: min 10µs, max 20µs, mean 12µs ± 1µs, median 11µs
+//! ```
+
+use crate::{clock::*, Clock, Duration, Error, ProcessDuration, Result, StreamingStats};
+
+/// An identity function that hints the compiler to be maximally pessimistic about what
+/// `black_box` could do, so that the computation of `input` is not optimized away.
+#[inline(never)]
+pub fn black_box<T>(input: T) -> T {
+    unsafe {
+        let ret = core::ptr::read_volatile(&input);
+        core::mem::forget(input);
+        ret
+    }
+}
+
+/// Run `f` for `n` times and return the shortest [`Duration`] of a single run measured by
+/// [`HighResolutionClock`].
+///
+/// # Errors
+///
+/// This function will return [`Error::ZeroIterations`] if `n` is zero, or an error if
+/// accessing to the underlying clock failed.
+pub fn best_of<F: FnMut()>(n: usize, mut f: F) -> Result<Duration> {
+    best_of_with_result(n, &mut f).map(|(best, _)| best)
+}
+
+/// Like [`best_of`] but also return the result of the last run of `f`.
+///
+/// # Errors
+///
+/// This function will return [`Error::ZeroIterations`] if `n` is zero, or an error if
+/// accessing to the underlying clock failed.
+pub fn best_of_with_result<T, F: FnMut() -> T>(n: usize, mut f: F) -> Result<(Duration, T)> {
+    let mut best: Option<(Duration, T)> = None;
+    for _ in 0..n {
+        let start = HighResolutionClock::try_now()?;
+        let result = black_box(f());
+        let elapsed = HighResolutionClock::try_now()?.saturating_sub(start);
+        best = Some(match best {
+            Some((d, _)) => (d.min(elapsed), result),
+            None => (elapsed, result),
+        });
+    }
+    best.ok_or(Error::ZeroIterations)
+}
+
+/// Run `f` for `n` times and return the [`ProcessDuration`] measured by [`ProcessCPUClock`]
+/// of the run with the shortest wall time.
+///
+/// # Errors
+///
+/// This function will return [`Error::ZeroIterations`] if `n` is zero, or an error if
+/// accessing to the underlying clock failed.
+pub fn best_of_process_cpu<F: FnMut()>(n: usize, mut f: F) -> Result<ProcessDuration> {
+    let mut best: Option<ProcessDuration> = None;
+    for _ in 0..n {
+        let start = ProcessCPUClock::try_now()?;
+        f();
+        let elapsed = ProcessCPUClock::try_now()?.saturating_sub(start);
+        best = Some(match best {
+            Some(d) if d.real <= elapsed.real => d,
+            _ => elapsed,
+        });
+    }
+    best.ok_or(Error::ZeroIterations)
+}
+
+/// The maximum number of samples kept to estimate the median in [`run`].
+const RESERVOIR_SIZE: usize = 1024;
+
+/// Summary statistics of the iterations measured by [`run`], see [`StreamingStats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// The number of measured iterations.
+    pub iterations: u64,
+    /// The total time of all iterations.
+    pub total: Duration,
+    /// The shortest iteration.
+    pub min: Duration,
+    /// The longest iteration.
+    pub max: Duration,
+    /// The arithmetic mean of all iterations.
+    pub mean: Duration,
+    /// The median of all iterations. It is exact for up to 1024 iterations and estimated from
+    /// a uniform random sample of 1024 iterations otherwise.
+    pub median: Duration,
+    /// The standard deviation of all iterations.
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Serialize the statistics as a JSON object of integer nanoseconds, e.g.
+    /// ```text
+    /// {"iterations":100,"total_ns":1200000,"min_ns":10000,"max_ns":20000,"mean_ns":12000,"median_ns":11000,"stddev_ns":1000}
+    /// ```
+    ///
+    /// It does not require the `serde` feature.
+    pub fn to_json(&self) -> String {
+        format!(
+            concat!(
+                r#"{{"iterations":{},"total_ns":{},"min_ns":{},"max_ns":{},"#,
+                r#""mean_ns":{},"median_ns":{},"stddev_ns":{}}}"#,
+            ),
+            self.iterations,
+            self.total.as_nanos(),
+            self.min.as_nanos(),
+            self.max.as_nanos(),
+            self.mean.as_nanos(),
+            self.median.as_nanos(),
+            self.stddev.as_nanos(),
+        )
+    }
+}
+
+impl core::fmt::Display for Stats {
+    /// Formats the [`Stats`]. It will look something like this:
+    /// ```text
+    /// 100 iterations in 1.2ms: min 10µs, max 20µs, mean 12µs ± 1µs, median 11µs
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} iterations in {:?}: min {:?}, max {:?}, mean {:?} ± {:?}, median {:?}",
+            self.iterations, self.total, self.min, self.max, self.mean, self.stddev, self.median,
+        )
+    }
+}
+
+/// Run `f` once as warmup and then for `iterations` times, measuring each iteration with
+/// [`HighResolutionClock`].
+///
+/// The statistics are computed in a streaming fashion, so the memory used does not grow with
+/// `iterations`.
+///
+/// # Errors
+///
+/// This function will return [`Error::ZeroIterations`] if `iterations` is zero, or an error
+/// if accessing to the underlying clock failed.
+pub fn run<F: FnMut()>(iterations: u64, f: F) -> Result<Stats> {
+    run_with_warmup(1, iterations, f)
+}
+
+/// Like [`run`] but run `f` for `warmup` times before the measurement.
+///
+/// # Errors
+///
+/// This function will return [`Error::ZeroIterations`] if `iterations` is zero, or an error
+/// if accessing to the underlying clock failed.
+pub fn run_with_warmup<F: FnMut()>(warmup: u64, iterations: u64, mut f: F) -> Result<Stats> {
+    if iterations == 0 {
+        return Err(Error::ZeroIterations);
+    }
+    for _ in 0..warmup {
+        f();
+    }
+
+    let mut reservoir = Vec::with_capacity(RESERVOIR_SIZE.min(iterations as usize));
+    let mut rng = XorShift64(0x2545_f491_4f6c_dd1d);
+    let mut total = Duration::ZERO;
+    let mut stats = StreamingStats::new();
+    for i in 0..iterations {
+        let start = HighResolutionClock::try_now()?;
+        f();
+        let elapsed = HighResolutionClock::try_now()?.saturating_sub(start);
+
+        total += elapsed;
+        stats.record(elapsed);
+
+        // Algorithm R reservoir sampling.
+        if reservoir.len() < RESERVOIR_SIZE {
+            reservoir.push(elapsed);
+        } else {
+            let j = rng.next() % (i + 1);
+            if (j as usize) < RESERVOIR_SIZE {
+                reservoir[j as usize] = elapsed;
+            }
+        }
+    }
+
+    reservoir.sort_unstable();
+    let mid = reservoir.len() / 2;
+    let median = if reservoir.len() % 2 == 0 {
+        (reservoir[mid - 1] + reservoir[mid]) / 2
+    } else {
+        reservoir[mid]
+    };
+    Ok(Stats {
+        iterations,
+        total,
+        min: stats.min(),
+        max: stats.max(),
+        mean: stats.mean(),
+        median,
+        stddev: stats.stddev(),
+    })
+}
+
+/// A tiny pseudo random number generator for reservoir sampling.
+struct XorShift64(u64);
+
+impl XorShift64 {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
@@ -0,0 +1,111 @@
+//! Run a command and report how long it takes, like `time(1)`.
+//!
+//! ```text
+//! howlong [-o FILE] [-f FORMAT] [--] COMMAND [ARGS...]
+//! ```
+//!
+//! The report is written to stderr, or appended to `FILE` if `-o` is given. `FORMAT` is
+//! interpreted by `howlong::ProcessDuration::format`. The exit status of `COMMAND` is
+//! forwarded.
+
+use howlong::command::TimedCommand;
+use std::ffi::OsString;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::process::{exit, Command, ExitStatus};
+
+const USAGE: &str = "usage: howlong [-o FILE] [-f FORMAT] [--] COMMAND [ARGS...]";
+
+struct Args {
+    output: Option<OsString>,
+    format: Option<String>,
+    command: Vec<OsString>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = std::env::args_os().skip(1);
+    let mut output = None;
+    let mut format = None;
+    let mut command = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.to_str() {
+            Some("-o") => output = Some(args.next().ok_or("missing argument for -o")?),
+            Some("-f") => {
+                let f = args.next().ok_or("missing argument for -f")?;
+                format = Some(f.into_string().map_err(|_| "invalid format string")?);
+            }
+            Some("-h") | Some("--help") => return Err(USAGE.to_owned()),
+            Some("--") => {
+                command.extend(args);
+                break;
+            }
+            Some(a) if a.starts_with('-') => return Err(format!("unknown option: {}", a)),
+            _ => {
+                command.push(arg);
+                command.extend(args);
+                break;
+            }
+        }
+    }
+    if command.is_empty() {
+        return Err(USAGE.to_owned());
+    }
+    Ok(Args {
+        output,
+        format,
+        command,
+    })
+}
+
+#[cfg(unix)]
+fn exit_code(status: ExitStatus) -> i32 {
+    use std::os::unix::process::ExitStatusExt;
+    status
+        .code()
+        .or_else(|| status.signal().map(|s| 128 + s))
+        .unwrap_or(1)
+}
+
+#[cfg(not(unix))]
+fn exit_code(status: ExitStatus) -> i32 {
+    status.code().unwrap_or(1)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(msg) => {
+            eprintln!("{}", msg);
+            exit(2);
+        }
+    };
+
+    let mut command = Command::new(&args.command[0]);
+    command.args(&args.command[1..]);
+    let (status, elapsed) = match command.timed_status() {
+        Ok(result) => result,
+        Err(e) => {
+            eprintln!("howlong: {}: {}", args.command[0].to_string_lossy(), e);
+            exit(127);
+        }
+    };
+
+    let report = match args.format {
+        Some(ref f) => elapsed.format(f),
+        None => elapsed.to_string(),
+    };
+    match args.output {
+        Some(ref path) => {
+            let written = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .and_then(|mut f| writeln!(f, "{}", report));
+            if let Err(e) = written {
+                eprintln!("howlong: {}: {}", path.to_string_lossy(), e);
+            }
+        }
+        None => eprintln!("{}", report),
+    }
+    exit(exit_code(status));
+}
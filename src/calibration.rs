@@ -0,0 +1,162 @@
+use crate::{bench::black_box, timer::Timer, Clock, Duration, TimePoint};
+use core::any::TypeId;
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+/// The number of samples taken by [`calibrate`].
+const CALIBRATION_SAMPLES: usize = 4096;
+
+fn cache() -> &'static RwLock<HashMap<TypeId, Duration>> {
+    static CACHE: OnceLock<RwLock<HashMap<TypeId, Duration>>> = OnceLock::new();
+    CACHE.get_or_init(Default::default)
+}
+
+fn measure<ClockType: Clock<Output = TimePoint>>() -> Duration {
+    let mut samples = Vec::with_capacity(CALIBRATION_SAMPLES);
+    for _ in 0..CALIBRATION_SAMPLES {
+        let start = black_box(<ClockType>::now());
+        let end = black_box(<ClockType>::now());
+        samples.push(end.saturating_sub(start));
+    }
+    samples.sort_unstable();
+    samples[CALIBRATION_SAMPLES / 2]
+}
+
+/// Return the median cost of a `ClockType::now()` call.
+///
+/// The calibration is performed on the first call for each clock and cached afterwards. Use
+/// [`recalibrate`] to measure it again.
+///
+/// The result is approximate. It might be zero for a clock whose resolution is coarser than
+/// the cost of reading it.
+///
+/// # Panics
+///
+/// This function might panic when acessing to the underlying clock failed.
+pub fn calibrate<ClockType: Clock<Output = TimePoint> + 'static>() -> Duration {
+    let id = TypeId::of::<ClockType>();
+    if let Some(overhead) = cache().read().unwrap().get(&id) {
+        return *overhead;
+    }
+    // Measure without holding the lock, so that the other clocks are not blocked meanwhile.
+    // If several threads race, the first stored value is kept.
+    let overhead = measure::<ClockType>();
+    *cache().write().unwrap().entry(id).or_insert(overhead)
+}
+
+/// Measure the cost of a `ClockType::now()` call again and update the cached value returned
+/// by [`calibrate`].
+///
+/// # Panics
+///
+/// This function might panic when acessing to the underlying clock failed.
+pub fn recalibrate<ClockType: Clock<Output = TimePoint> + 'static>() -> Duration {
+    let overhead = measure::<ClockType>();
+    cache()
+        .write()
+        .unwrap()
+        .insert(TypeId::of::<ClockType>(), overhead);
+    overhead
+}
+
+/// A timer which subtracts the overhead of reading the clock from the elapsed time.
+///
+/// The overhead is estimated by [`calibrate`], which is approximate, and subtracted once for
+/// each interval the timer has run, i.e. for the start and every resume. Use
+/// [`elapsed_raw()`](#method.elapsed_raw) to get the uncorrected value.
+pub struct CalibratedTimer<ClockType: Clock<Output = TimePoint> + 'static> {
+    inner: Timer<ClockType, TimePoint, Duration>,
+    /// The number of intervals since the last start.
+    intervals: u32,
+}
+
+impl<ClockType: Clock<Output = TimePoint> + 'static> CalibratedTimer<ClockType> {
+    /// Construct a timer and start it. The clock is calibrated first if it is not yet.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        calibrate::<ClockType>();
+        CalibratedTimer {
+            inner: Timer::new(),
+            intervals: 1,
+        }
+    }
+
+    /// Return true if the timer is running.
+    #[inline(always)]
+    pub fn is_running(&self) -> bool {
+        self.inner.is_running()
+    }
+
+    /// Return true if the timer is not running.
+    #[inline(always)]
+    pub fn is_stopped(&self) -> bool {
+        self.inner.is_stopped()
+    }
+
+    /// Like [`elapsed_raw()`](#method.elapsed_raw) but subtract the calibrated overhead of
+    /// reading the clock once per interval, saturating at zero.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    #[inline(always)]
+    pub fn elapsed(&self) -> Duration {
+        let overhead = calibrate::<ClockType>()
+            .checked_mul(self.intervals)
+            .unwrap_or(Duration::MAX);
+        self.elapsed_raw().saturating_sub(overhead)
+    }
+
+    /// Return the accumulated elapsed times as of the previous [`stop()`](#method.stop)
+    /// if the timer is stopped. Otherwise, the elapsed times accumulated between the most
+    /// recent call to [`start()`](#method.start) or [`resume()`](#method.resume) and the
+    /// current time values.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    #[inline(always)]
+    pub fn elapsed_raw(&self) -> Duration {
+        self.inner.elapsed()
+    }
+
+    /// If the timer is not running, reset and start the timer.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    #[inline(always)]
+    pub fn start(&mut self) {
+        if self.inner.is_stopped() {
+            self.intervals = 1;
+        }
+        self.inner.start();
+    }
+
+    /// Stop the timer.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    #[inline(always)]
+    pub fn stop(&mut self) {
+        self.inner.stop();
+    }
+
+    /// Resume the timer, accumulating additional elapsed time.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    #[inline(always)]
+    pub fn resume(&mut self) {
+        if self.inner.is_stopped() {
+            self.intervals = self.intervals.saturating_add(1);
+        }
+        self.inner.resume();
+    }
+}
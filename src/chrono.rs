@@ -0,0 +1,81 @@
+//! Convert the timepoints of [`SystemClock`] and durations to and from
+//! [`chrono`](https://docs.rs/chrono) types.
+//!
+//! # Examples
+//!
+//! ```
+//! use howlong::{clock::SystemClock, Duration};
+//!
+//! let now = SystemClock::now_datetime().unwrap();
+//! println!("{}", now.to_rfc3339());
+//!
+//! let d = howlong::chrono::to_chrono(Duration::from_millis(1500)).unwrap();
+//! assert_eq!(d.num_milliseconds(), 1500);
+//! ```
+
+use crate::{
+    clock::{SystemClock, UNIX_EPOCH_OFFSET},
+    Clock, Duration, Error, Result, Stamped, TimePoint,
+};
+use ::chrono::{DateTime, TimeDelta, Utc};
+
+/// Convert `d` to [`chrono::Duration`].
+///
+/// # Errors
+///
+/// This function will return [`Error::OutOfRange`] if `d` is longer than
+/// [`chrono::Duration::MAX`](::chrono::Duration::MAX).
+pub fn to_chrono(d: Duration) -> Result<TimeDelta> {
+    TimeDelta::from_std(d).map_err(|_| Error::OutOfRange)
+}
+
+/// Convert `d` from [`chrono::Duration`].
+///
+/// # Errors
+///
+/// This function will return [`Error::OutOfRange`] if `d` is negative.
+pub fn from_chrono(d: TimeDelta) -> Result<Duration> {
+    d.to_std().map_err(|_| Error::OutOfRange)
+}
+
+impl SystemClock {
+    /// Return the current time as [`DateTime<Utc>`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the underlying system calls failed,
+    /// or [`Error::OutOfRange`] if the time cannot be represented by [`DateTime<Utc>`].
+    pub fn now_datetime() -> Result<DateTime<Utc>> {
+        Self::try_now()?.to_datetime_utc().ok_or(Error::OutOfRange)
+    }
+}
+
+impl TimePoint {
+    /// Convert a timepoint of [`SystemClock`] to [`DateTime<Utc>`], or return `None` if it
+    /// cannot be represented. The timepoints of the other clocks do not have a meaningful
+    /// epoch, so the result is only valid for [`SystemClock`]. Use
+    /// [`Stamped<SystemClock>`](Stamped) to enforce that at compile time.
+    pub fn to_datetime_utc(&self) -> Option<DateTime<Utc>> {
+        let (secs, nanos) = match self.0.checked_sub(UNIX_EPOCH_OFFSET) {
+            Some(d) => (d.as_secs(), d.subsec_nanos()),
+            // Before the Unix epoch, which is only possible on Windows.
+            None => {
+                let d = UNIX_EPOCH_OFFSET - self.0;
+                return DateTime::from_timestamp(-(d.as_secs() as i64), 0)?
+                    .checked_sub_signed(TimeDelta::nanoseconds(d.subsec_nanos().into()));
+            }
+        };
+        if secs > i64::MAX as u64 {
+            return None;
+        }
+        DateTime::from_timestamp(secs as i64, nanos)
+    }
+}
+
+impl Stamped<SystemClock> {
+    /// Convert the timepoint to [`DateTime<Utc>`], or return `None` if it cannot be
+    /// represented. See [`TimePoint::to_datetime_utc`].
+    pub fn to_datetime_utc(&self) -> Option<DateTime<Utc>> {
+        self.into_inner().to_datetime_utc()
+    }
+}
@@ -0,0 +1,214 @@
+use crate::clock::{tick_factor, HighResolutionClock};
+use crate::{Clock, Duration, Error, ProcessDuration, ProcessTimePoint, Result};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// The CPU accounting files of the cgroup of the calling process.
+#[derive(Clone, Debug)]
+enum CgroupSource {
+    /// The directory of a cgroup v2, containing `cpu.stat`.
+    V2(PathBuf),
+    /// The directory of a cgroup v1 in the `cpuacct` hierarchy, containing `cpuacct.usage`.
+    V1(PathBuf),
+}
+
+impl CgroupSource {
+    fn dir(&self) -> &Path {
+        match self {
+            CgroupSource::V2(dir) | CgroupSource::V1(dir) => dir,
+        }
+    }
+}
+
+/// A clock to report the CPU usage of the cgroup of the calling process, as accounted by the
+/// cgroup CPU controller. It is what CPU throttling in containers is based on.
+///
+/// It reads `cpu.stat` of cgroup v2, or falls back to the `cpuacct` controller of cgroup v1.
+/// The cgroup of the calling process is resolved from `/proc/self/cgroup` and
+/// `/proc/self/mountinfo` at first use, and cached afterwards.
+///
+/// The CPU usage includes all the processes in the cgroup, not only the calling one. The real
+/// time is reported by [`HighResolutionClock`].
+pub struct CgroupCPUClock;
+
+impl CgroupCPUClock {
+    /// Return the directory of the cgroup of the calling process, which contains the CPU
+    /// accounting files.
+    ///
+    /// # Errors
+    ///
+    /// This function will return [`Error::CgroupUnavailable`] if the cgroup CPU accounting
+    /// files cannot be found.
+    pub fn path() -> Result<PathBuf> {
+        Ok(source()?.dir().to_path_buf())
+    }
+
+    /// Return the total CPU usage of the cgroup, i.e. `usage_usec` of `cpu.stat` or
+    /// `cpuacct.usage`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return [`Error::CgroupUnavailable`] if the cgroup CPU accounting
+    /// files cannot be found, or an error if reading them failed.
+    pub fn usage() -> Result<Duration> {
+        match source()? {
+            CgroupSource::V2(dir) => {
+                let stat = read_file(&dir.join("cpu.stat"))?;
+                Ok(Duration::from_micros(stat_value(&stat, "usage_usec")?))
+            }
+            CgroupSource::V1(dir) => {
+                Ok(Duration::from_nanos(read_u64(&dir.join("cpuacct.usage"))?))
+            }
+        }
+    }
+}
+
+impl Clock for CgroupCPUClock {
+    type Output = ProcessTimePoint;
+    type Duration = ProcessDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        let (user, system) = match source()? {
+            CgroupSource::V2(dir) => {
+                let stat = read_file(&dir.join("cpu.stat"))?;
+                (
+                    Duration::from_micros(stat_value(&stat, "user_usec")?),
+                    Duration::from_micros(stat_value(&stat, "system_usec")?),
+                )
+            }
+            CgroupSource::V1(dir) => match read_u64(&dir.join("cpuacct.usage_user")) {
+                Ok(user) => (
+                    Duration::from_nanos(user),
+                    Duration::from_nanos(read_u64(&dir.join("cpuacct.usage_sys"))?),
+                ),
+                // `cpuacct.usage_user` is only available since Linux 4.7, fall back to
+                // `cpuacct.stat` in clock ticks.
+                Err(_) => {
+                    let stat = read_file(&dir.join("cpuacct.stat"))?;
+                    let factor = tick_factor()?;
+                    (
+                        Duration::from_nanos(stat_value(&stat, "user")? * factor),
+                        Duration::from_nanos(stat_value(&stat, "system")? * factor),
+                    )
+                }
+            },
+        };
+        Ok(ProcessTimePoint {
+            real: HighResolutionClock::try_now()?.0,
+            user,
+            system,
+        })
+    }
+
+    fn resolution() -> Result<Duration> {
+        match source()? {
+            CgroupSource::V2(_) => Ok(Duration::from_micros(1)),
+            CgroupSource::V1(dir) => {
+                if dir.join("cpuacct.usage_user").exists() {
+                    Ok(Duration::from_nanos(1))
+                } else {
+                    Ok(Duration::from_nanos(tick_factor()?))
+                }
+            }
+        }
+    }
+}
+
+/// Return the cached CPU accounting files of the cgroup of the calling process.
+fn source() -> Result<&'static CgroupSource> {
+    static SOURCE: OnceLock<CgroupSource> = OnceLock::new();
+    if let Some(source) = SOURCE.get() {
+        return Ok(source);
+    }
+    let source = resolve()?;
+    Ok(SOURCE.get_or_init(|| source))
+}
+
+fn resolve() -> Result<CgroupSource> {
+    let cgroup = read_file(Path::new("/proc/self/cgroup"))?;
+    let mountinfo = read_file(Path::new("/proc/self/mountinfo"))?;
+    let mut v1 = None;
+    // Each line is `hierarchy-ID:controller-list:cgroup-path`.
+    for line in cgroup.lines() {
+        let mut fields = line.splitn(3, ':');
+        let (id, controllers, path) = match (fields.next(), fields.next(), fields.next()) {
+            (Some(id), Some(controllers), Some(path)) => (id, controllers, path),
+            _ => continue,
+        };
+        if id == "0" && controllers.is_empty() {
+            if let Some(dir) = cgroup_dir(&mountinfo, path, "cgroup2", None) {
+                if dir.join("cpu.stat").exists() {
+                    return Ok(CgroupSource::V2(dir));
+                }
+            }
+        } else if controllers.split(',').any(|c| c == "cpuacct") {
+            if let Some(dir) = cgroup_dir(&mountinfo, path, "cgroup", Some("cpuacct")) {
+                if dir.join("cpuacct.usage").exists() {
+                    v1 = Some(CgroupSource::V1(dir));
+                }
+            }
+        }
+    }
+    v1.ok_or(Error::CgroupUnavailable)
+}
+
+/// Return the directory of the cgroup `path` in the hierarchy mounted with the file system type
+/// `fstype`, and for cgroup v1, with the super option `controller`.
+fn cgroup_dir(
+    mountinfo: &str,
+    path: &str,
+    fstype: &str,
+    controller: Option<&str>,
+) -> Option<PathBuf> {
+    // Each line is `id parent major:minor root mount-point options ... - fstype source super`.
+    for line in mountinfo.lines() {
+        let (mount, fs) = match line.split_once(" - ") {
+            Some(fields) => fields,
+            None => continue,
+        };
+        let mount: Vec<&str> = mount.split(' ').collect();
+        let fs: Vec<&str> = fs.split(' ').collect();
+        if mount.len() < 5 || fs.len() < 3 || fs[0] != fstype {
+            continue;
+        }
+        if let Some(controller) = controller {
+            if !fs[2].split(',').any(|option| option == controller) {
+                continue;
+            }
+        }
+        // The root of the mount is not `/` if only a sub-hierarchy is mounted, e.g. in a
+        // container without a cgroup namespace.
+        let relative = match path.strip_prefix(mount[3]) {
+            Some(relative) => relative,
+            None => continue,
+        };
+        return Some(Path::new(mount[4]).join(relative.trim_start_matches('/')));
+    }
+    None
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path).map_err(|err| match err.kind() {
+        io::ErrorKind::NotFound | io::ErrorKind::PermissionDenied => Error::CgroupUnavailable,
+        _ => Error::SystemError("read", err.raw_os_error().unwrap_or(0)),
+    })
+}
+
+fn read_u64(path: &Path) -> Result<u64> {
+    read_file(path)?
+        .trim()
+        .parse()
+        .map_err(|_| Error::CgroupUnavailable)
+}
+
+/// Return the value of `key` in a file of `key value` lines, e.g. `cpu.stat`.
+fn stat_value(stat: &str, key: &str) -> Result<u64> {
+    stat.lines()
+        .filter_map(|line| line.split_once(' '))
+        .find(|(k, _)| *k == key)
+        .and_then(|(_, v)| v.trim().parse().ok())
+        .ok_or(Error::CgroupUnavailable)
+}
@@ -1,7 +1,13 @@
 // Ref: https://github.com/boostorg/chrono/tree/develop/include/boost/chrono/detail/inlined/mac
 
-use crate::{Clock, Duration, Error, Result, TimePoint};
+use crate::{
+    Clock, Duration, Error, ProcessTimePoint, RawInstant, Result, ThreadDuration, ThreadTimePoint,
+    TimePoint,
+};
 use core::mem;
+use std::os::unix::thread::JoinHandleExt;
+use std::sync::{Mutex, OnceLock};
+use std::thread::JoinHandle;
 
 #[allow(dead_code)]
 #[path = "./posix.rs"]
@@ -16,80 +22,636 @@ mod mach {
     include!(concat!(env!("OUT_DIR"), "/darwin_bindings.rs"));
 }
 
+#[inline(always)]
+fn gettimeofday() -> Result<Duration> {
+    let mut tv = libc::timeval {
+        tv_sec: 0,
+        tv_usec: 0,
+    };
+    let ret = unsafe { libc::gettimeofday(&mut tv, core::ptr::null_mut()) };
+    if ret != 0 {
+        return Err(Error::SystemError("gettimeofday", posix::errno()));
+    }
+    Ok(Duration::from_secs(tv.tv_sec as u64) + Duration::from_micros(tv.tv_usec as u64))
+}
+
 /// A system clock.
-// `gettimeofday` is the most precise "system time" available on macOS.
+// It is based on `clock_gettime(CLOCK_REALTIME)`, which reports nanoseconds, unlike
+// `gettimeofday`.
 pub struct SystemClock;
 
 impl Clock for SystemClock {
     type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = false;
+    const IS_CPU_TIME: bool = false;
 
     fn try_now() -> Result<Self::Output> {
-        let mut tv = libc::timeval {
+        let mut ts = libc::timespec {
             tv_sec: 0,
-            tv_usec: 0,
+            tv_nsec: 0,
         };
-        let ret = unsafe { libc::gettimeofday(&mut tv, core::ptr::null_mut()) };
+        let ret = unsafe { libc::clock_gettime(libc::CLOCK_REALTIME, &mut ts) };
         if ret != 0 {
-            return Err(Error::SystemError("gettimeofday", posix::errno()));
+            return Err(Error::SystemError("clock_gettime", posix::errno()));
         }
-        let d = Duration::from_secs(tv.tv_sec as u64) + Duration::from_micros(tv.tv_usec as u64);
+        let d = Duration::from_secs(ts.tv_sec as u64) + Duration::from_nanos(ts.tv_nsec as u64);
         Ok(TimePoint(d))
     }
+
+    fn resolution() -> Result<Duration> {
+        let mut ts = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        let ret = unsafe { libc::clock_getres(libc::CLOCK_REALTIME, &mut ts) };
+        if ret != 0 {
+            return Err(Error::SystemError("clock_getres", posix::errno()));
+        }
+        Ok(Duration::from_secs(ts.tv_sec as u64) + Duration::from_nanos(ts.tv_nsec as u64))
+    }
 }
 
 /// A steady clock.
-// On macOS, it is based on `mach_absolute_time`.
+// On macOS, it is based on `clock_gettime_nsec_np(CLOCK_UPTIME_RAW)`, which is available since
+// macOS 10.12 and returns the nanoseconds since the computer booted up in a single call.
+// On older systems, it falls back to `mach_absolute_time`, where
 // `mach_absolute_time() * MachInfo.numer / MachInfo.denom` is the number of
 // nanoseconds since the computer booted up.
 pub struct SteadyClock;
 
 impl Clock for SteadyClock {
     type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        let d = match clock_gettime_nsec_np() {
+            Some(func) => {
+                let nanos = unsafe { func(libc::CLOCK_UPTIME_RAW) };
+                if nanos == 0 {
+                    return Err(Error::SystemError("clock_gettime_nsec_np", posix::errno()));
+                }
+                Duration::from_nanos(nanos)
+            }
+            None => mach_ticks_to_duration(unsafe { mach::mach_absolute_time() })?,
+        };
+        Ok(TimePoint(d))
+    }
+
+    fn resolution() -> Result<Duration> {
+        mach_resolution()
+    }
+}
+
+impl SteadyClock {
+    /// Return the raw reading of the clock, i.e. the ticks of `mach_absolute_time`, to be
+    /// converted later by [`SteadyClock::convert`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `mach_timebase_info` failed, which is needed to
+    /// convert the ticks.
+    #[inline(always)]
+    pub fn raw_now() -> Result<RawInstant> {
+        mach_timebase()?;
+        Ok(RawInstant(unsafe { mach::mach_absolute_time() }))
+    }
+}
+
+/// Convert the raw ticks of [`SteadyClock`] to [`Duration`] using the timebase cached by
+/// [`SteadyClock::raw_now`].
+#[inline(always)]
+pub(crate) fn raw_ticks_to_duration(ticks: u64) -> Duration {
+    mach_ticks_to_duration(ticks).unwrap_or_default()
+}
+
+/// A steady clock which keeps counting while the system is suspended.
+// On macOS, it is based on `mach_continuous_time`, which is available since macOS 10.12.
+// Unlike `mach_absolute_time`, it does not stop while the system is asleep.
+pub struct SuspendAwareClock;
+
+impl Clock for SuspendAwareClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        let ticks = unsafe { mach::mach_continuous_time() };
+        Ok(TimePoint(mach_ticks_to_duration(ticks)?))
+    }
+
+    fn resolution() -> Result<Duration> {
+        mach_resolution()
+    }
+}
+
+/// A clock to report the time since the system booted.
+// On macOS, it is based on `mach_continuous_time`, like `SuspendAwareClock`.
+pub struct UptimeClock;
+
+impl UptimeClock {
+    /// Return the time since the system booted.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the underlying system calls failed.
+    pub fn uptime() -> Result<Duration> {
+        Ok(Self::try_now()?.0)
+    }
+}
+
+impl Clock for UptimeClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        SuspendAwareClock::try_now()
+    }
+
+    fn resolution() -> Result<Duration> {
+        SuspendAwareClock::resolution()
+    }
+}
+
+/// A clock to report the time since the calling process started.
+// The start time reported by `proc_pidinfo(PROC_PIDTBSDINFO)` is compared to `gettimeofday`
+// once, to locate it on `SuspendAwareClock`, which is then read afterwards.
+pub struct ProcessUptimeClock;
+
+impl ProcessUptimeClock {
+    /// Return the time since the calling process started.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the underlying system calls failed.
+    pub fn uptime() -> Result<Duration> {
+        Ok(Self::try_now()?.0)
+    }
+}
+
+impl Clock for ProcessUptimeClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        let start = process_start_time()?;
+        Ok(TimePoint(
+            SuspendAwareClock::try_now()?.0.saturating_sub(start),
+        ))
+    }
+
+    fn resolution() -> Result<Duration> {
+        SuspendAwareClock::resolution()
+    }
+}
+
+/// Return the cached time when the calling process started, on [`SuspendAwareClock`].
+fn process_start_time() -> Result<Duration> {
+    static START: OnceLock<Duration> = OnceLock::new();
+    if let Some(start) = START.get() {
+        return Ok(*start);
+    }
+    let mut info: libc::proc_bsdinfo = unsafe { mem::zeroed() };
+    let size = mem::size_of::<libc::proc_bsdinfo>() as libc::c_int;
+    let ret = unsafe {
+        libc::proc_pidinfo(
+            libc::getpid(),
+            libc::PROC_PIDTBSDINFO,
+            0,
+            &mut info as *mut libc::proc_bsdinfo as *mut libc::c_void,
+            size,
+        )
+    };
+    if ret != size {
+        return Err(Error::SystemError("proc_pidinfo", posix::errno()));
+    }
+    let now = SuspendAwareClock::try_now()?.0;
+    let started =
+        Duration::from_secs(info.pbi_start_tvsec) + Duration::from_micros(info.pbi_start_tvusec);
+    let age = gettimeofday()?.saturating_sub(started);
+    Ok(*START.get_or_init(|| now.saturating_sub(age)))
+}
+
+/// A system clock which is faster to read but less precise than `SystemClock`.
+// On macOS, it is based on `gettimeofday`, which is cheap to read but only reports microseconds.
+pub struct CoarseSystemClock;
+
+impl Clock for CoarseSystemClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = false;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(TimePoint(gettimeofday()?))
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(Duration::from_micros(1))
+    }
+}
+
+/// A steady clock which is faster to read but less precise than `SteadyClock`.
+// On macOS, it is based on `clock_gettime_nsec_np(CLOCK_MONOTONIC_RAW_APPROX)`, which is
+// available since macOS 10.12.
+pub struct CoarseSteadyClock;
+
+impl Clock for CoarseSteadyClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
 
     fn try_now() -> Result<Self::Output> {
-        let mut info: mach::mach_timebase_info_data_t = unsafe { mem::zeroed() };
-        let ret = unsafe { mach::mach_timebase_info(&mut info) };
+        let func = clock_gettime_nsec_np().ok_or(Error::Unsupported("clock_gettime_nsec_np"))?;
+        let nanos = unsafe { func(libc::CLOCK_MONOTONIC_RAW_APPROX) };
+        if nanos == 0 {
+            return Err(Error::SystemError("clock_gettime_nsec_np", posix::errno()));
+        }
+        Ok(TimePoint(Duration::from_nanos(nanos)))
+    }
+
+    fn resolution() -> Result<Duration> {
+        let mut ts = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        let ret = unsafe { libc::clock_getres(libc::CLOCK_MONOTONIC_RAW_APPROX, &mut ts) };
         if ret != 0 {
-            return Err(Error::SystemError("mach_timebase_info", ret));
+            return Err(Error::SystemError("clock_getres", posix::errno()));
         }
-        let absolute_time = unsafe { mach::mach_absolute_time() };
-        let d = if info.numer == info.denom {
-            Duration::from_nanos(absolute_time)
+        Ok(Duration::from_secs(ts.tv_sec as u64) + Duration::from_nanos(ts.tv_nsec as u64))
+    }
+}
+
+pub use posix::{
+    ChildrenCPUClock, ProcessCPUClock, ProcessRealCPUClock, ProcessSystemCPUClock,
+    ProcessUserCPUClock, ProcessWithChildrenCPUClock, RusageCPUClock, RusageChildrenCPUClock,
+    RusageSystemCPUClock, RusageUserCPUClock,
+};
+
+pub(crate) use posix::wait_child;
+
+type ClockGettimeNsecNp = unsafe extern "C" fn(libc::clockid_t) -> u64;
+
+/// Look up `clock_gettime_nsec_np`, which is only available since macOS 10.12.
+fn clock_gettime_nsec_np() -> Option<ClockGettimeNsecNp> {
+    static FUNC: OnceLock<Option<ClockGettimeNsecNp>> = OnceLock::new();
+    *FUNC.get_or_init(|| {
+        let name = b"clock_gettime_nsec_np\0".as_ptr() as *const libc::c_char;
+        let func = unsafe { libc::dlsym(libc::RTLD_DEFAULT, name) };
+        if func.is_null() {
+            None
         } else {
-            let factor = (info.numer as f64) / (info.denom as f64);
-            Duration::from_nanos(absolute_time * (factor as u64))
-        };
-        Ok(TimePoint(d))
+            Some(unsafe { mem::transmute::<*mut libc::c_void, ClockGettimeNsecNp>(func) })
+        }
+    })
+}
+
+/// Return the cached `(numer, denom)` of `mach_timebase_info`.
+fn mach_timebase() -> Result<(u32, u32)> {
+    static TIMEBASE: OnceLock<(u32, u32)> = OnceLock::new();
+    if let Some(timebase) = TIMEBASE.get() {
+        return Ok(*timebase);
+    }
+    let mut info: mach::mach_timebase_info_data_t = unsafe { mem::zeroed() };
+    let ret = unsafe { mach::mach_timebase_info(&mut info) };
+    if ret != 0 {
+        return Err(Error::SystemError("mach_timebase_info", ret));
     }
+    Ok(*TIMEBASE.get_or_init(|| (info.numer, info.denom)))
+}
+
+/// Scale `ticks` by the timebase `numer / denom` to nanoseconds, e.g. 125/3 on Apple Silicon,
+/// without losing the fractional part of the ratio.
+#[inline(always)]
+fn scale_mach_ticks(ticks: u64, numer: u32, denom: u32) -> Duration {
+    super::scale_ticks(ticks, numer as u64, denom as u64)
 }
 
-pub use posix::{ProcessCPUClock, ProcessRealCPUClock, ProcessSystemCPUClock, ProcessUserCPUClock};
+/// Convert `ticks` of `mach_absolute_time` or `mach_continuous_time` to [`Duration`].
+fn mach_ticks_to_duration(ticks: u64) -> Result<Duration> {
+    let (numer, denom) = mach_timebase()?;
+    Ok(scale_mach_ticks(ticks, numer, denom))
+}
+
+/// Return the duration of a single tick of `mach_absolute_time` or `mach_continuous_time`.
+fn mach_resolution() -> Result<Duration> {
+    Ok(mach_ticks_to_duration(1)?.max(Duration::from_nanos(1)))
+}
+
+/// A clock to report real, user-CPU, and system-CPU clocks of another process.
+///
+/// It is obtained by [`ProcessCPUClock::for_pid`]. The CPU times are read by
+/// `proc_pid_rusage`.
+pub struct PidCpuClock {
+    pid: u32,
+}
+
+impl PidCpuClock {
+    pub(crate) fn open(pid: u32) -> Result<Self> {
+        let clock = PidCpuClock { pid };
+        clock.try_now()?;
+        Ok(clock)
+    }
+
+    /// Return the process id.
+    pub fn pid(&self) -> u32 {
+        self.pid
+    }
+
+    /// Return the current timepoint.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the process does not exist anymore, or if
+    /// acessing to the underlying system calls failed.
+    pub fn try_now(&self) -> Result<ProcessTimePoint> {
+        let mut info: libc::rusage_info_v2 = unsafe { mem::zeroed() };
+        let ret = unsafe {
+            libc::proc_pid_rusage(
+                self.pid as libc::c_int,
+                libc::RUSAGE_INFO_V2,
+                &mut info as *mut libc::rusage_info_v2 as *mut libc::rusage_info_t,
+            )
+        };
+        if ret != 0 {
+            return Err(Error::SystemError("proc_pid_rusage", posix::errno()));
+        }
+        Ok(ProcessTimePoint {
+            real: SteadyClock::try_now()?.0,
+            user: mach_ticks_to_duration(info.ri_user_time)?,
+            system: mach_ticks_to_duration(info.ri_system_time)?,
+        })
+    }
+
+    /// Return the current timepoint.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying system calls failed.
+    /// Use [`try_now`](#method.try_now) if you want to handle the error.
+    pub fn now(&self) -> ProcessTimePoint {
+        self.try_now().expect("Failed to access the clock.")
+    }
+}
 
 /// A clock to report the real thread wall-clock.
 pub struct ThreadClock;
 
+#[inline(always)]
+fn get_thread_times(port: mach::mach_port_t) -> Result<(Duration, Duration)> {
+    let mut info: mach::thread_basic_info_data_t = unsafe { mem::zeroed() };
+    let mut count: mach::mach_msg_type_number_t = mach::__THREAD_BASIC_INFO_COUNT;
+    let ret = unsafe {
+        mach::thread_info(
+            port,
+            mach::THREAD_BASIC_INFO,
+            &mut info as *mut mach::thread_basic_info as *mut i32,
+            &mut count,
+        )
+    };
+    if ret != 0 {
+        return Err(Error::SystemError("thread_info", ret));
+    }
+    let user = Duration::from_secs(info.user_time.seconds as u64)
+        + Duration::from_micros(info.user_time.microseconds as u64);
+    let system = Duration::from_secs(info.system_time.seconds as u64)
+        + Duration::from_micros(info.system_time.microseconds as u64);
+    Ok((user, system))
+}
+
+#[inline(always)]
+fn get_thread_time(port: mach::mach_port_t) -> Result<Duration> {
+    let (user, system) = get_thread_times(port)?;
+    Ok(user + system)
+}
+
 impl Clock for ThreadClock {
     type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
 
     fn try_now() -> Result<Self::Output> {
         let port = unsafe { mach::pthread_mach_thread_np(mach::pthread_self()) };
-        let mut info: mach::thread_basic_info_data_t = unsafe { mem::zeroed() };
-        let mut count: mach::mach_msg_type_number_t = mach::__THREAD_BASIC_INFO_COUNT;
-        let ret = unsafe {
-            mach::thread_info(
-                port,
-                mach::THREAD_BASIC_INFO,
-                &mut info as *mut mach::thread_basic_info as *mut i32,
-                &mut count,
-            )
+        Ok(TimePoint(get_thread_time(port)?))
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(Duration::from_micros(1))
+    }
+}
+
+/// A clock to report the user-CPU and system-CPU clocks of the calling thread.
+pub struct ThreadCPUClock;
+
+impl Clock for ThreadCPUClock {
+    type Output = ThreadTimePoint;
+    type Duration = ThreadDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        let port = unsafe { mach::pthread_mach_thread_np(mach::pthread_self()) };
+        let (user, system) = get_thread_times(port)?;
+        Ok(ThreadTimePoint { user, system })
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(Duration::from_micros(1))
+    }
+}
+
+/// A clock to report the user-CPU, system-CPU, and idle times of all the processors of the
+/// host by `host_statistics(HOST_CPU_LOAD_INFO)`.
+///
+/// The kernel counts the times in 32-bit clock ticks, which wrap around after a few weeks on a
+/// large machine. The wraparounds are accumulated between readings, so the clock should be
+/// read at least once per wraparound. The `nice` time is counted as user.
+pub struct HostCPUClock;
+
+impl Clock for HostCPUClock {
+    type Output = crate::HostTimePoint;
+    type Duration = crate::HostDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        // The last raw ticks and their accumulated total.
+        static TICKS: Mutex<Option<([u32; 3], [u64; 3])>> = Mutex::new(None);
+        let now = host_cpu_ticks()?;
+        let total = {
+            let mut ticks = TICKS.lock().unwrap_or_else(|e| e.into_inner());
+            let total = match *ticks {
+                Some((prev, mut total)) => {
+                    for ((total, now), prev) in total.iter_mut().zip(now).zip(prev) {
+                        *total += u64::from(now.wrapping_sub(prev));
+                    }
+                    total
+                }
+                None => [u64::from(now[0]), u64::from(now[1]), u64::from(now[2])],
+            };
+            *ticks = Some((now, total));
+            total
         };
-        if ret != 0 {
-            return Err(Error::SystemError("thread_info", ret));
+        let factor = posix::tick_factor()?;
+        Ok(crate::HostTimePoint {
+            user: Duration::from_nanos(total[0] * factor),
+            system: Duration::from_nanos(total[1] * factor),
+            idle: Duration::from_nanos(total[2] * factor),
+        })
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(Duration::from_nanos(posix::tick_factor()?))
+    }
+}
+
+/// Return the user, system, and idle ticks of all the processors of the host.
+fn host_cpu_ticks() -> Result<[u32; 3]> {
+    // `mach_host_self` returns a new send right each time, so it is only called once.
+    static HOST: OnceLock<mach::host_t> = OnceLock::new();
+    let host = *HOST.get_or_init(|| unsafe { mach::mach_host_self() });
+    let mut info: mach::host_cpu_load_info_data_t = unsafe { mem::zeroed() };
+    let mut count: mach::mach_msg_type_number_t = mach::__HOST_CPU_LOAD_INFO_COUNT;
+    let ret = unsafe {
+        mach::host_statistics(
+            host,
+            mach::HOST_CPU_LOAD_INFO as mach::host_flavor_t,
+            &mut info as *mut mach::host_cpu_load_info_data_t as *mut mach::integer_t,
+            &mut count,
+        )
+    };
+    if ret != 0 {
+        return Err(Error::SystemError("host_statistics", ret));
+    }
+    let ticks = info.cpu_ticks;
+    Ok([
+        ticks[mach::CPU_STATE_USER as usize].wrapping_add(ticks[mach::CPU_STATE_NICE as usize]),
+        ticks[mach::CPU_STATE_SYSTEM as usize],
+        ticks[mach::CPU_STATE_IDLE as usize],
+    ])
+}
+
+/// A handle to read the CPU-time clock of another thread.
+///
+/// It is based on the mach thread port returned by `pthread_mach_thread_np`, which the handle
+/// does not own. The port only refers to the thread while it is running. Once the thread has
+/// exited, the name of the port may be reused, so reading the handle may return an error or
+/// the CPU time of another thread, and it must not be read at all after the thread has been
+/// joined or detached.
+pub struct ThreadCpuHandle {
+    port: mach::mach_port_t,
+}
+
+impl ThreadCpuHandle {
+    /// Construct a handle for the thread of `handle`. It must not be read after `handle` is
+    /// joined, see [`ThreadCpuHandle`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the thread port cannot be obtained.
+    pub fn for_join_handle<T>(handle: &JoinHandle<T>) -> Result<Self> {
+        unsafe { Self::from_pthread(handle.as_pthread_t()) }
+    }
+
+    /// Construct a handle for the calling thread, e.g. to be read by other threads while it
+    /// runs.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the thread port cannot be obtained.
+    pub fn current() -> Result<Self> {
+        unsafe { Self::from_pthread(libc::pthread_self()) }
+    }
+
+    /// Construct a handle for the thread `thread`, e.g. a thread created by a C library.
+    ///
+    /// # Safety
+    ///
+    /// `thread` must refer to a thread which has not been joined or detached yet.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the thread port cannot be obtained.
+    pub unsafe fn from_pthread(thread: libc::pthread_t) -> Result<Self> {
+        let port = mach::pthread_mach_thread_np(thread as mach::pthread_t);
+        if port == 0 {
+            return Err(Error::SystemError("pthread_mach_thread_np", libc::ESRCH));
         }
-        let user = Duration::from_secs(info.user_time.seconds as u64)
-            + Duration::from_micros(info.user_time.microseconds as u64);
-        let system = Duration::from_secs(info.system_time.seconds as u64)
-            + Duration::from_micros(info.system_time.microseconds as u64);
-        Ok(TimePoint(user + system))
+        Ok(ThreadCpuHandle { port })
+    }
+
+    /// Return the CPU time of the thread.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the thread has exited, or if acessing to the
+    /// underlying system calls failed.
+    pub fn try_now(&self) -> Result<TimePoint> {
+        Ok(TimePoint(get_thread_time(self.port)?))
+    }
+
+    /// Return the CPU time of the thread.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying system calls failed.
+    /// Use [`try_now`](#method.try_now) if you want to handle the error.
+    pub fn now(&self) -> TimePoint {
+        self.try_now().expect("Failed to access the clock.")
+    }
+
+    /// Return the CPU time spent by the thread since `start`, which is a timepoint returned by
+    /// this handle.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying system calls failed.
+    pub fn elapsed_since(&self, start: &TimePoint) -> Duration {
+        self.now().saturating_sub(*start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scale_mach_ticks() {
+        // Apple Silicon
+        assert_eq!(scale_mach_ticks(0, 125, 3), Duration::ZERO);
+        assert_eq!(scale_mach_ticks(1, 125, 3), Duration::from_nanos(41));
+        assert_eq!(scale_mach_ticks(3, 125, 3), Duration::from_nanos(125));
+        assert_eq!(scale_mach_ticks(24_000_000, 125, 3), Duration::from_secs(1));
+        assert_eq!(
+            scale_mach_ticks(24_000_000 * 365 * 24 * 3600, 125, 3),
+            Duration::from_secs(365 * 24 * 3600)
+        );
+
+        // Intel
+        assert_eq!(scale_mach_ticks(1, 1, 1), Duration::from_nanos(1));
+        assert_eq!(
+            scale_mach_ticks(1_000_000_000, 1, 1),
+            Duration::from_secs(1)
+        );
+        assert_eq!(
+            scale_mach_ticks(u64::MAX, 1, 1),
+            Duration::from_nanos(u64::MAX)
+        );
+
+        // The counter near `u64::MAX` does not wrap around.
+        assert_eq!(
+            scale_mach_ticks(u64::MAX, 125, 3),
+            Duration::new(768_614_336_404, 564_650_625)
+        );
     }
 }
@@ -1,5 +1,7 @@
+#include <mach/mach.h>
 #include <mach/mach_time.h>
 #include <mach/thread_act.h>
 #include <pthread.h>
 
 const mach_msg_type_number_t __THREAD_BASIC_INFO_COUNT = THREAD_BASIC_INFO_COUNT;
+const mach_msg_type_number_t __HOST_CPU_LOAD_INFO_COUNT = HOST_CPU_LOAD_INFO_COUNT;
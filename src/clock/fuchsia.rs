@@ -0,0 +1,313 @@
+// Ref: https://fuchsia.dev/reference/syscalls
+//
+// Fuchsia does not split the CPU time into user and system times, so the whole CPU time is
+// reported as user-CPU time.
+
+use crate::{
+    Clock, Duration, Error, ProcessDuration, ProcessTimePoint, RawInstant, Result, ThreadDuration,
+    ThreadTimePoint, TimePoint,
+};
+use core::mem;
+
+#[allow(dead_code)]
+#[path = "./unsupported.rs"]
+mod unsupported;
+
+#[allow(non_camel_case_types)]
+mod zx {
+    pub type zx_handle_t = u32;
+    pub type zx_status_t = i32;
+    pub type zx_time_t = i64;
+    pub type zx_duration_t = i64;
+
+    pub const ZX_HANDLE_INVALID: zx_handle_t = 0;
+    pub const ZX_OK: zx_status_t = 0;
+    // The topics of zircon/syscalls/object.h, where `__ZX_INFO_TOPIC` stores the version of
+    // the record in the top 4 bits.
+    pub const fn info_topic(topic: u32, version: u32) -> u32 {
+        (version << 28) | topic
+    }
+    pub const ZX_INFO_THREAD_STATS: u32 = 15;
+    pub const ZX_INFO_TASK_RUNTIME_V1: u32 = info_topic(30, 0);
+
+    #[repr(C)]
+    #[derive(Default)]
+    pub struct zx_info_thread_stats_t {
+        pub total_runtime: zx_duration_t,
+        pub last_scheduled_cpu: u32,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    pub struct zx_info_task_runtime_v1_t {
+        pub cpu_time: zx_duration_t,
+        pub queue_time: zx_duration_t,
+    }
+
+    #[link(name = "zircon")]
+    extern "C" {
+        pub fn zx_clock_get_monotonic() -> zx_time_t;
+        pub fn zx_clock_read(handle: zx_handle_t, now: *mut zx_time_t) -> zx_status_t;
+        pub fn zx_object_get_info(
+            handle: zx_handle_t,
+            topic: u32,
+            buffer: *mut core::ffi::c_void,
+            buffer_size: usize,
+            actual: *mut usize,
+            avail: *mut usize,
+        ) -> zx_status_t;
+    }
+
+    // Provided by the C library.
+    extern "C" {
+        pub fn zx_process_self() -> zx_handle_t;
+        pub fn zx_thread_self() -> zx_handle_t;
+        pub fn zx_utc_reference_get() -> zx_handle_t;
+    }
+}
+
+/// Convert a `zx_time_t` or a `zx_duration_t` in nanoseconds to a [`Duration`], where the
+/// negative values are clamped to zero.
+fn from_zx_nanos(ns: i64) -> Duration {
+    Duration::from_nanos(ns.max(0) as u64)
+}
+
+/// Return the record of the information `topic` of the kernel object `handle`.
+fn get_info<T: Default>(handle: zx::zx_handle_t, topic: u32) -> Result<T> {
+    let mut info = T::default();
+    let mut actual = 0;
+    let mut avail = 0;
+    let status = unsafe {
+        zx::zx_object_get_info(
+            handle,
+            topic,
+            &mut info as *mut T as *mut core::ffi::c_void,
+            mem::size_of::<T>(),
+            &mut actual,
+            &mut avail,
+        )
+    };
+    if status != zx::ZX_OK {
+        return Err(Error::SystemError("zx_object_get_info", status));
+    }
+    Ok(info)
+}
+
+fn monotonic_time() -> Duration {
+    from_zx_nanos(unsafe { zx::zx_clock_get_monotonic() })
+}
+
+/// A system clock.
+///
+/// It reads the UTC clock of the calling process.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = false;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        let handle = unsafe { zx::zx_utc_reference_get() };
+        if handle == zx::ZX_HANDLE_INVALID {
+            return Err(Error::Unsupported("zx_utc_reference_get"));
+        }
+        let mut now = 0;
+        let status = unsafe { zx::zx_clock_read(handle, &mut now) };
+        if status != zx::ZX_OK {
+            return Err(Error::SystemError("zx_clock_read", status));
+        }
+        Ok(TimePoint(from_zx_nanos(now)))
+    }
+}
+
+/// A steady clock.
+pub struct SteadyClock;
+
+impl Clock for SteadyClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(TimePoint(monotonic_time()))
+    }
+}
+
+impl SteadyClock {
+    /// Return the raw reading of the clock, i.e. the nanoseconds of `zx_clock_get_monotonic`,
+    /// to be converted later by [`SteadyClock::convert`].
+    ///
+    /// # Errors
+    ///
+    /// This function never fails on Fuchsia.
+    #[inline(always)]
+    pub fn raw_now() -> Result<RawInstant> {
+        Ok(RawInstant(
+            unsafe { zx::zx_clock_get_monotonic() }.max(0) as u64
+        ))
+    }
+}
+
+/// Convert the raw ticks of [`SteadyClock`], which are already nanoseconds, to [`Duration`].
+#[inline(always)]
+pub(crate) fn raw_ticks_to_duration(ticks: u64) -> Duration {
+    Duration::from_nanos(ticks)
+}
+
+/// A clock to report the real process wall-clock.
+pub struct ProcessRealCPUClock;
+
+impl Clock for ProcessRealCPUClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(TimePoint(monotonic_time()))
+    }
+}
+
+/// A clock to report the user cpu-clock.
+///
+/// It reports the whole CPU time of the calling process by `ZX_INFO_TASK_RUNTIME`.
+pub struct ProcessUserCPUClock;
+
+impl Clock for ProcessUserCPUClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(TimePoint(ProcessCPUClock::total()?))
+    }
+}
+
+/// A clock to report the system cpu-clock.
+///
+/// The whole CPU time is reported as user-CPU time, so it is always zero, like the system-CPU
+/// time of [`ProcessCPUClock`].
+pub struct ProcessSystemCPUClock;
+
+impl Clock for ProcessSystemCPUClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(TimePoint(Duration::ZERO))
+    }
+}
+
+/// A clock to report real, user-CPU, and system-CPU clocks.
+///
+/// The whole CPU time is reported as user-CPU time, and the system-CPU time is always zero.
+pub struct ProcessCPUClock;
+
+impl ProcessCPUClock {
+    /// Return the total, i.e. user plus system, CPU time of the calling process.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the underlying system calls failed.
+    pub fn total() -> Result<Duration> {
+        let info: zx::zx_info_task_runtime_v1_t = get_info(
+            unsafe { zx::zx_process_self() },
+            zx::ZX_INFO_TASK_RUNTIME_V1,
+        )?;
+        Ok(from_zx_nanos(info.cpu_time))
+    }
+}
+
+impl Clock for ProcessCPUClock {
+    type Output = ProcessTimePoint;
+    type Duration = ProcessDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(ProcessTimePoint {
+            real: monotonic_time(),
+            user: Self::total()?,
+            system: Duration::ZERO,
+        })
+    }
+}
+
+/// A clock to report the real thread wall-clock.
+///
+/// It reports the total runtime of the calling thread by `ZX_INFO_THREAD_STATS`.
+pub struct ThreadClock;
+
+impl Clock for ThreadClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        let info: zx::zx_info_thread_stats_t =
+            get_info(unsafe { zx::zx_thread_self() }, zx::ZX_INFO_THREAD_STATS)?;
+        Ok(TimePoint(from_zx_nanos(info.total_runtime)))
+    }
+}
+
+/// A clock to report the user-CPU and system-CPU clocks of the calling thread.
+///
+/// The whole CPU time of [`ThreadClock`] is reported as user-CPU time, and the system-CPU time
+/// is always zero.
+pub struct ThreadCPUClock;
+
+impl Clock for ThreadCPUClock {
+    type Output = ThreadTimePoint;
+    type Duration = ThreadDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(ThreadTimePoint {
+            user: ThreadClock::try_now()?.0,
+            system: Duration::ZERO,
+        })
+    }
+}
+
+pub use unsupported::{
+    ChildrenCPUClock, HostCPUClock, PidCpuClock, ProcessUptimeClock, ProcessWithChildrenCPUClock,
+    ThreadCpuHandle, UptimeClock,
+};
+
+pub(crate) use unsupported::wait_child;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_zx_nanos() {
+        assert_eq!(from_zx_nanos(0), Duration::ZERO);
+        assert_eq!(from_zx_nanos(1_500_000_000), Duration::from_millis(1500));
+        assert_eq!(
+            from_zx_nanos(i64::MAX),
+            Duration::from_nanos(i64::MAX as u64)
+        );
+        assert_eq!(from_zx_nanos(-1), Duration::ZERO);
+        // `ZX_TIME_INFINITE_PAST`
+        assert_eq!(from_zx_nanos(i64::MIN), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_info_layout() {
+        assert_eq!(zx::ZX_INFO_THREAD_STATS, 15);
+        assert_eq!(zx::ZX_INFO_TASK_RUNTIME_V1, 30);
+        // `ZX_INFO_TASK_RUNTIME` is the newer version with a larger record.
+        assert_eq!(zx::info_topic(30, 1), 0x1000_001e);
+        assert_eq!(mem::size_of::<zx::zx_info_thread_stats_t>(), 16);
+        assert_eq!(mem::size_of::<zx::zx_info_task_runtime_v1_t>(), 16);
+    }
+}
@@ -0,0 +1,328 @@
+// Ref: https://www.haiku-os.org/docs/api/OS_8h.html
+
+use crate::{
+    Clock, Duration, Error, ProcessDuration, ProcessTimePoint, RawInstant, Result, ThreadDuration,
+    ThreadTimePoint, TimePoint,
+};
+use core::mem;
+
+#[allow(dead_code)]
+#[path = "./unsupported.rs"]
+mod unsupported;
+
+/// Convert a `bigtime_t` in microseconds to a [`Duration`], where the negative values are
+/// clamped to zero.
+fn from_bigtime(us: libc::bigtime_t) -> Duration {
+    Duration::from_micros(us.max(0) as u64)
+}
+
+/// Return the user-CPU and system-CPU times of the calling team, or its terminated children if
+/// `who` is `B_TEAM_USAGE_CHILDREN`.
+fn get_team_usage(who: i32) -> Result<(Duration, Duration)> {
+    let mut info: libc::team_usage_info = unsafe { mem::zeroed() };
+    let ret = unsafe { libc::get_team_usage_info(libc::B_CURRENT_TEAM, who, &mut info) };
+    if ret != libc::B_OK {
+        return Err(Error::SystemError("get_team_usage_info", ret));
+    }
+    Ok((from_bigtime(info.user_time), from_bigtime(info.kernel_time)))
+}
+
+/// Return the user-CPU and system-CPU times of the calling thread.
+fn get_thread_times() -> Result<(Duration, Duration)> {
+    let mut info: libc::thread_info = unsafe { mem::zeroed() };
+    let ret = unsafe { libc::get_thread_info(libc::find_thread(core::ptr::null()), &mut info) };
+    if ret != libc::B_OK {
+        return Err(Error::SystemError("get_thread_info", ret));
+    }
+    Ok((from_bigtime(info.user_time), from_bigtime(info.kernel_time)))
+}
+
+fn system_time() -> Duration {
+    from_bigtime(unsafe { libc::system_time() })
+}
+
+/// A system clock.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = false;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(TimePoint(from_bigtime(unsafe {
+            libc::real_time_clock_usecs()
+        })))
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(Duration::from_micros(1))
+    }
+}
+
+/// A steady clock.
+pub struct SteadyClock;
+
+impl Clock for SteadyClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(TimePoint(system_time()))
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(Duration::from_micros(1))
+    }
+}
+
+impl SteadyClock {
+    /// Return the raw reading of the clock, i.e. the microseconds of `system_time`, to be
+    /// converted later by [`SteadyClock::convert`].
+    ///
+    /// # Errors
+    ///
+    /// This function never fails on Haiku.
+    #[inline(always)]
+    pub fn raw_now() -> Result<RawInstant> {
+        Ok(RawInstant(unsafe { libc::system_time() }.max(0) as u64))
+    }
+}
+
+/// Convert the raw ticks of [`SteadyClock`], which are microseconds, to [`Duration`].
+#[inline(always)]
+pub(crate) fn raw_ticks_to_duration(ticks: u64) -> Duration {
+    Duration::from_micros(ticks)
+}
+
+/// A clock to report the time since the system booted.
+pub struct UptimeClock;
+
+impl UptimeClock {
+    /// Return the time since the system booted.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the underlying system calls failed.
+    pub fn uptime() -> Result<Duration> {
+        Ok(Self::try_now()?.0)
+    }
+}
+
+impl Clock for UptimeClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(TimePoint(system_time()))
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(Duration::from_micros(1))
+    }
+}
+
+/// A clock to report the real process wall-clock.
+pub struct ProcessRealCPUClock;
+
+impl Clock for ProcessRealCPUClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(TimePoint(system_time()))
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(Duration::from_micros(1))
+    }
+}
+
+/// A clock to report the user cpu-clock.
+pub struct ProcessUserCPUClock;
+
+impl Clock for ProcessUserCPUClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        let (user, _) = get_team_usage(libc::B_TEAM_USAGE_SELF)?;
+        Ok(TimePoint(user))
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(Duration::from_micros(1))
+    }
+}
+
+/// A clock to report the system cpu-clock.
+pub struct ProcessSystemCPUClock;
+
+impl Clock for ProcessSystemCPUClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        let (_, system) = get_team_usage(libc::B_TEAM_USAGE_SELF)?;
+        Ok(TimePoint(system))
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(Duration::from_micros(1))
+    }
+}
+
+/// A clock to report real, user-CPU, and system-CPU clocks.
+///
+/// It does not include the terminated child processes, see [`ChildrenCPUClock`].
+pub struct ProcessCPUClock;
+
+impl ProcessCPUClock {
+    /// Return the total, i.e. user plus system, CPU time of the calling process.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the underlying system calls failed.
+    pub fn total() -> Result<Duration> {
+        let (user, system) = get_team_usage(libc::B_TEAM_USAGE_SELF)?;
+        Ok(user + system)
+    }
+}
+
+impl Clock for ProcessCPUClock {
+    type Output = ProcessTimePoint;
+    type Duration = ProcessDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        let (user, system) = get_team_usage(libc::B_TEAM_USAGE_SELF)?;
+        Ok(ProcessTimePoint {
+            real: system_time(),
+            user,
+            system,
+        })
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(Duration::from_micros(1))
+    }
+}
+
+/// A clock to report the user and system CPU time of the terminated child processes, together
+/// with the wall-clock time.
+pub struct ChildrenCPUClock;
+
+impl Clock for ChildrenCPUClock {
+    type Output = ProcessTimePoint;
+    type Duration = ProcessDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        let (user, system) = get_team_usage(libc::B_TEAM_USAGE_CHILDREN)?;
+        Ok(ProcessTimePoint {
+            real: system_time(),
+            user,
+            system,
+        })
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(Duration::from_micros(1))
+    }
+}
+
+/// A clock to report real, user-CPU, and system-CPU clocks of the calling process, including
+/// the terminated child processes.
+pub struct ProcessWithChildrenCPUClock;
+
+impl Clock for ProcessWithChildrenCPUClock {
+    type Output = ProcessTimePoint;
+    type Duration = ProcessDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        let (user, system) = get_team_usage(libc::B_TEAM_USAGE_SELF)?;
+        let (children_user, children_system) = get_team_usage(libc::B_TEAM_USAGE_CHILDREN)?;
+        Ok(ProcessTimePoint {
+            real: system_time(),
+            user: user + children_user,
+            system: system + children_system,
+        })
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(Duration::from_micros(1))
+    }
+}
+
+/// A clock to report the real thread wall-clock.
+pub struct ThreadClock;
+
+impl Clock for ThreadClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        let (user, system) = get_thread_times()?;
+        Ok(TimePoint(user + system))
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(Duration::from_micros(1))
+    }
+}
+
+/// A clock to report the user-CPU and system-CPU clocks of the calling thread.
+pub struct ThreadCPUClock;
+
+impl Clock for ThreadCPUClock {
+    type Output = ThreadTimePoint;
+    type Duration = ThreadDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        let (user, system) = get_thread_times()?;
+        Ok(ThreadTimePoint { user, system })
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(Duration::from_micros(1))
+    }
+}
+
+pub use unsupported::{HostCPUClock, PidCpuClock, ProcessUptimeClock, ThreadCpuHandle};
+
+pub(crate) use unsupported::wait_child;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_bigtime() {
+        assert_eq!(from_bigtime(0), Duration::ZERO);
+        assert_eq!(from_bigtime(1_500_000), Duration::from_millis(1500));
+        assert_eq!(from_bigtime(-1), Duration::ZERO);
+        assert_eq!(
+            from_bigtime(i64::MAX),
+            Duration::from_micros(i64::MAX as u64)
+        );
+    }
+}
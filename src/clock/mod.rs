@@ -5,8 +5,22 @@
 //!   real-time clock.
 //! * [`SteadyClock`]: It provides access to system-wide steady clock. There is no fixed
 //!   relationship between values returned by `SteadyClock::now()` and wall-clock time
-//! * [`HighResolutionClock`]: Default to [`SteadyClock`] if available, otherwise fallback to
-//!   [`SystemClock`].
+//! * [`SuspendAwareClock`]: Like [`SteadyClock`] but it keeps counting while the system is
+//!   suspended. Use `SuspendAwareClock::is_available()` to check whether the running kernel
+//!   supports it.
+//! * [`CoarseSystemClock`], [`CoarseSteadyClock`]: Like [`SystemClock`] and [`SteadyClock`]
+//!   but faster to read at the cost of a lower resolution, typically a few milliseconds.
+//! * [`TaiClock`]: It provides access to the International Atomic Time on Linux. It differs
+//!   from [`SystemClock`] only if the TAI-UTC offset has been configured by the administrator,
+//!   e.g. through a PTP or NTP daemon.
+//! * `TscClock`: It provides access to the timestamp counter of x86 processors, calibrated
+//!   against [`SteadyClock`]. It requires the `tsc` feature.
+//! * [`UptimeClock`]: It provides access to the time since the system booted. Use
+//!   `UptimeClock::uptime()` to get it as a [`Duration`](crate::Duration).
+//! * [`ProcessUptimeClock`]: It provides access to the time since the calling process started.
+//!   Use `ProcessUptimeClock::uptime()` to get it as a [`Duration`](crate::Duration).
+//! * [`HighResolutionClock`]: Default to [`SteadyClock`] if available and working at runtime,
+//!   otherwise fallback to [`SystemClock`]. See `HighResolutionClock::backend()`.
 //! * [`ProcessRealCPUClock`]: It provides access to the real process wall-clock steady clock,
 //!   i.e. the real CPU-time clock of the calling process.
 //! * [`ProcessUserCPUClock`]: It provides access to the user CPU-time steady clock of the
@@ -15,8 +29,26 @@
 //!   the calling process.
 //! * [`ProcessCPUClock`]: It provides access to real, user-CPU, and system-CPU clocks at
 //!   the same time.
+//! * [`ProcessSelfCPUClock`]: The same as [`ProcessCPUClock`], to make explicit that the child
+//!   processes are not included.
+//! * [`ProcessWithChildrenCPUClock`]: Like [`ProcessCPUClock`] but the user-CPU and system-CPU
+//!   times include those of the child processes reported by [`ChildrenCPUClock`].
+//! * `ProcStatClock`: Like [`ProcessCPUClock`] but based on `/proc/self/stat` on Linux, which
+//!   also reports the CPU times of the children separately.
+//! * `CgroupCPUClock`: It provides access to the user-CPU and system-CPU clocks of the cgroup
+//!   of the calling process on Linux, as accounted by the cgroup CPU controller.
+//! * [`ChildrenCPUClock`]: It provides access to the user-CPU and system-CPU clocks of the
+//!   child processes, i.e. the terminated and waited-for children on Posix, and the children
+//!   assigned to `ChildrenJob` on Windows, together with the real clock.
+//! * [`HostCPUClock`]: It provides access to the user-CPU, system-CPU, and idle clocks of the
+//!   whole host, summed over all the CPUs.
 //! * [`ThreadClock`]: It provides access to the real thread wall-clock, i.e. the real CPU-time
 //!   clock of the calling thread.
+//! * [`ThreadCPUClock`]: It provides access to the user-CPU and system-CPU clocks of the
+//!   calling thread.
+//! * `ThreadCycleClock`, `ProcessCycleClock`: They provide access to the CPU cycles spent by
+//!   the calling thread and process on Windows, which are much more precise than
+//!   [`ThreadClock`] and [`ProcessCPUClock`] there.
 //!
 //! # Implementations
 //!
@@ -26,13 +58,59 @@
 //!
 //! | Clock | Posix | Darwin | Windows |
 //! |-------|-------|--------|---------|
-//! | [`SystemClock`] | `clock_gettime(CLOCK_REALTIME)` | `gettimeofday` | `GetSystemTimeAsFileTime` |
+//! | [`SystemClock`] | `clock_gettime(CLOCK_REALTIME)` | `clock_gettime(CLOCK_REALTIME)` | `GetSystemTimePreciseAsFileTime`, or `GetSystemTimeAsFileTime` before Windows 8 |
 //! | [`SteadyClock`] | `clock_gettime(CLOCK_MONOTONIC)` | `mach_timebase_info`, `mach_absolute_time` | `QueryPerformanceCounter`, `QueryPerformanceFrequency` |
-//! | [`ProcessRealCPUClock`] | `times` | `times` | `QueryPerformanceCounter`, `QueryPerformanceFrequency` |
-//! | [`ProcessUserCPUClock`] | `times` | `times` | `GetProcessTimes` |
-//! | [`ProcessSystemCPUClock`] | `times` | `times` | `GetProcessTimes` |
-//! | [`ProcessCPUClock`] | `times` | `times` | `GetProcessTimes`, `QueryPerformanceCounter`, `QueryPerformanceFrequency` |
+//! | [`SuspendAwareClock`] | `clock_gettime(CLOCK_BOOTTIME)` | `mach_timebase_info`, `mach_continuous_time` | `QueryInterruptTimePrecise`, or `GetTickCount64` before Windows 10 |
+//! | [`CoarseSystemClock`] | `clock_gettime(CLOCK_REALTIME_COARSE)` | `gettimeofday` | `GetSystemTimeAsFileTime` |
+//! | [`CoarseSteadyClock`] | `clock_gettime(CLOCK_MONOTONIC_COARSE)` | `clock_gettime_nsec_np(CLOCK_MONOTONIC_RAW_APPROX)` | `GetTickCount64` |
+//! | [`TaiClock`] | `clock_gettime(CLOCK_TAI)` on Linux | N/A | N/A |
+//! | `TscClock` | `rdtsc` | `rdtsc` | `rdtsc` |
+//! | [`UptimeClock`] | `clock_gettime(CLOCK_BOOTTIME)`, or `/proc/uptime` | `mach_timebase_info`, `mach_continuous_time` | [`SuspendAwareClock`] |
+//! | [`ProcessUptimeClock`] | `/proc/self/stat` and [`UptimeClock`] on Linux | `proc_pidinfo(PROC_PIDTBSDINFO)`, `gettimeofday`, and [`SuspendAwareClock`] | `GetProcessTimes`, `GetSystemTimePreciseAsFileTime`, and [`SuspendAwareClock`] |
+//! | [`ProcessRealCPUClock`] | `clock_gettime(CLOCK_MONOTONIC)`, or `times` | `clock_gettime(CLOCK_MONOTONIC)` | `QueryPerformanceCounter`, `QueryPerformanceFrequency` |
+//! | [`ProcessUserCPUClock`] | `getrusage(RUSAGE_SELF)`, or `times` | `getrusage(RUSAGE_SELF)` | `GetProcessTimes` |
+//! | [`ProcessSystemCPUClock`] | `getrusage(RUSAGE_SELF)`, or `times` | `getrusage(RUSAGE_SELF)` | `GetProcessTimes` |
+//! | [`ProcessCPUClock`] | `getrusage(RUSAGE_SELF)`, or `times` | `getrusage(RUSAGE_SELF)` | `GetProcessTimes`, `QueryPerformanceCounter`, `QueryPerformanceFrequency` |
+//! | [`ProcessWithChildrenCPUClock`] | `getrusage(RUSAGE_SELF)`, `getrusage(RUSAGE_CHILDREN)`, or `times` | `getrusage(RUSAGE_SELF)`, `getrusage(RUSAGE_CHILDREN)` | `GetProcessTimes`, `QueryInformationJobObject` |
+//! | `RusageUserCPUClock`, `RusageSystemCPUClock`, `RusageCPUClock` | `getrusage(RUSAGE_SELF)` | `getrusage(RUSAGE_SELF)` | N/A |
+//! | [`PidCpuClock`] | `/proc/<pid>/stat` on Linux | `proc_pid_rusage` | `OpenProcess`, `GetProcessTimes` |
+//! | `ProcStatClock` | `/proc/self/stat` on Linux | N/A | N/A |
+//! | `CgroupCPUClock` | `cpu.stat` of cgroup v2, or `cpuacct.usage_user`, `cpuacct.usage_sys` of cgroup v1 on Linux | N/A | N/A |
+//! | [`ChildrenCPUClock`] | `getrusage(RUSAGE_CHILDREN)` | `getrusage(RUSAGE_CHILDREN)` | `QueryInformationJobObject` |
+//! | [`HostCPUClock`] | `/proc/stat` on Linux | `host_statistics(HOST_CPU_LOAD_INFO)` | `GetSystemTimes` |
 //! | [`ThreadClock`] | `clock_gettime(pthread_getcpuclockid)` | `thread_info` | `GetThreadTimes` |
+//! | [`ThreadCPUClock`] | `getrusage(RUSAGE_THREAD)` on Linux, otherwise `clock_gettime(CLOCK_THREAD_CPUTIME_ID)` as user-CPU time | `thread_info` | `GetThreadTimes` |
+//! | `ThreadCycleClock` | N/A | N/A | `QueryThreadCycleTime` |
+//! | `ProcessCycleClock` | N/A | N/A | `QueryProcessCycleTime` |
+//! | [`ThreadCpuHandle`] | `clock_gettime(pthread_getcpuclockid)`, `/proc/self/task/<tid>/stat` | `pthread_mach_thread_np`, `thread_info` | `DuplicateHandle` or `OpenThread`, `GetThreadTimes` |
+//!
+//! On `wasm32-unknown-unknown` with the `wasm` feature, [`SystemClock`] uses `Date.now()`, and
+//! [`SteadyClock`] and [`ProcessRealCPUClock`] use `performance.now()`. The other clocks return
+//! [`Error::Unsupported`](crate::Error::Unsupported).
+//!
+//! On Fuchsia, [`SteadyClock`] uses `zx_clock_get_monotonic`, [`SystemClock`] reads the UTC
+//! clock, [`ThreadClock`] uses `ZX_INFO_THREAD_STATS`, and [`ProcessCPUClock`] uses
+//! `ZX_INFO_TASK_RUNTIME`. The whole CPU time is reported as user-CPU time.
+//!
+//! On Haiku, [`SteadyClock`] uses `system_time`, [`SystemClock`] uses `real_time_clock_usecs`,
+//! [`ThreadClock`] uses `get_thread_info`, and the process clocks use `get_team_usage_info`.
+//!
+//! On WASI, [`SystemClock`], [`SteadyClock`], [`ProcessCPUClock`], and [`ThreadClock`] use
+//! `clock_time_get` with `CLOCK_REALTIME`, `CLOCK_MONOTONIC`, `CLOCK_PROCESS_CPUTIME_ID`, and
+//! `CLOCK_THREAD_CPUTIME_ID`. The whole CPU time is reported as user-CPU time. The clocks not
+//! implemented by the runtime return [`Error::Unsupported`](crate::Error::Unsupported).
+//!
+//! On the other platforms, or without the `std` feature, the crate still compiles but every
+//! clock returns [`Error::Unsupported`](crate::Error::Unsupported). Use [`is_supported`] to
+//! check it.
+//!
+//! On Posix, the process CPU clocks use `getrusage(RUSAGE_SELF)` with a microsecond resolution,
+//! and only fall back to `times`, whose resolution is a clock tick, if it is not available. Like
+//! on Windows, they do not include the CPU times of the terminated child processes, which are
+//! reported by [`ChildrenCPUClock`] instead. Use [`ProcessWithChildrenCPUClock`] to include
+//! them. On Windows, only the children assigned to `ChildrenJob` are included, see
+//! [`ChildrenCPUClock`]. `ProcessCPUClock::total()` reports the total CPU
+//! time using `clock_gettime(CLOCK_PROCESS_CPUTIME_ID)` if available.
 //!
 //! `HighResolutionClock`, `ProcessRealCPUClock`, and the `real` field in the result of `ProcessCPUClock` *all* semantically provide "real" (or "wall clock") time but differ in their internal implementation across operating systems as shown above.
 //!
@@ -56,9 +134,64 @@
 //! ```
 //!
 //! Note that, above, `elapsed.real` from `ProcessCPUClock` would be identical to `elapsed` from `ProcessRealCPUClock` and is semantically identical to `elapsed` from `HighResolutionClock`.
+//!
+//! # Resolution
+//!
+//! Use [`Clock::resolution`](crate::Clock::resolution) to find out the smallest difference
+//! between two timepoints a clock can report.
+//!
+//! ```
+//! use howlong::*;
+//!
+//! println!("{:?}", SystemClock::resolution());
+//! println!("{:?}", ProcessCPUClock::resolution());
+//! ```
+//!
+//! # Metadata
+//!
+//! Use [`Clock::IS_STEADY`](crate::Clock::IS_STEADY) and
+//! [`Clock::IS_CPU_TIME`](crate::Clock::IS_CPU_TIME) to tell the kinds of clocks apart in
+//! generic code, and [`Clock::info`](crate::Clock::info) to describe a clock at runtime.
+//!
+//! ```
+//! use howlong::*;
+//!
+//! assert!(!SystemClock::IS_STEADY);
+//! assert!(ThreadClock::IS_CPU_TIME);
+//! println!("{:?}", HighResolutionClock::info());
+//! ```
+//!
+//! # Overhead
+//!
+//! Reading some clocks is much more expensive than others. Use [`overhead`] or
+//! [`overhead_report`] to find out how expensive they are on your system.
+//!
+//! ```
+//! for (clock, overhead) in howlong::clock::overhead_report().unwrap() {
+//!     println!("{}: {:?}", clock, overhead);
+//! }
+//! ```
+//!
+//! # Raw ticks
+//!
+//! In very hot code, use `SteadyClock::raw_now()` to read the raw counter of [`SteadyClock`]
+//! as a [`RawInstant`](crate::RawInstant), and convert it only afterwards with
+//! `SteadyClock::convert()` or `RawInstant::delta()`.
+//!
+//! ```
+//! use howlong::*;
+//!
+//! let events: Vec<RawInstant> = (0..1000).map(|_| SteadyClock::raw_now().unwrap()).collect();
+//! let total = RawInstant::delta(events[0], events[999]);
+//! println!("{:?} {:?}", SteadyClock::convert(events[0]), total);
+//! ```
 
 cfg_if::cfg_if! {
-    if #[cfg(any(target_os = "macos", target_os = "ios"))] {
+    if #[cfg(howlong_unsupported)] {
+        // Fallback, which can also be forced by `--cfg howlong_unsupported`
+        mod unsupported;
+        pub use unsupported::*;
+    } else if #[cfg(any(target_os = "macos", target_os = "ios"))] {
         // Darwin
         mod darwin;
         pub use darwin::*;
@@ -66,21 +199,388 @@ cfg_if::cfg_if! {
         // Windows
         mod win;
         pub use win::*;
+    } else if #[cfg(target_os = "fuchsia")] {
+        // Fuchsia
+        mod fuchsia;
+        pub use fuchsia::*;
+    } else if #[cfg(target_os = "haiku")] {
+        // Haiku
+        mod haiku;
+        pub use haiku::*;
     } else if #[cfg(unix)] {
         // Posix
         mod posix;
         pub use posix::*;
+    } else if #[cfg(target_os = "wasi")] {
+        // WASI
+        mod wasi;
+        pub use self::wasi::*;
+    } else if #[cfg(all(target_arch = "wasm32", target_os = "unknown", feature = "wasm"))] {
+        // WebAssembly in the browsers
+        mod wasm;
+        pub use wasm::*;
     } else {
-        compile_error!("unsupported platform.");
+        // Fallback
+        mod unsupported;
+        pub use unsupported::*;
+    }
+}
+
+/// Scale `ticks` by `numer / denom` to a [`Duration`](crate::Duration) of nanoseconds in 128-bit
+/// arithmetic, so that neither the multiplication wraps around nor the fraction of the ratio is
+/// lost. The result saturates at `Duration::MAX`.
+// Only used by the backends with a counter of their own, i.e. Darwin, Windows, and the TSC.
+#[allow(dead_code)]
+#[inline(always)]
+pub(crate) fn scale_ticks(ticks: u64, numer: u64, denom: u64) -> crate::Duration {
+    let nanos = ticks as u128 * numer as u128 / denom as u128;
+    match core::convert::TryFrom::try_from(nanos / 1_000_000_000) {
+        Ok(secs) => crate::Duration::new(secs, (nanos % 1_000_000_000) as u32),
+        Err(_) => crate::Duration::MAX,
+    }
+}
+
+/// Return true if the clocks are supported on this platform. Otherwise, every clock returns
+/// [`Error::Unsupported`](crate::Error::Unsupported).
+pub const fn is_supported() -> bool {
+    !cfg!(howlong_unsupported)
+}
+
+/// The clock backing [`HighResolutionClock`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BackendKind {
+    /// [`SteadyClock`].
+    Steady,
+    /// [`SystemClock`].
+    System,
+}
+
+const BACKEND_UNDECIDED: u8 = 0;
+const BACKEND_STEADY: u8 = 1;
+const BACKEND_SYSTEM: u8 = 2;
+
+static HIGH_RESOLUTION_BACKEND: core::sync::atomic::AtomicU8 =
+    core::sync::atomic::AtomicU8::new(BACKEND_UNDECIDED);
+
+/// A high resolution clock.
+///
+/// At first use, it reads [`SteadyClock`], and falls back to [`SystemClock`] if that fails,
+/// e.g. `CLOCK_MONOTONIC` is rejected by the running kernel. The decision is kept for the rest
+/// of the process, so that the timepoints read by it can always be compared with each other.
+///
+/// As the fallback is not monotonic, [`IS_STEADY`](crate::Clock::IS_STEADY) is false. Use
+/// [`is_steady()`](HighResolutionClock::is_steady) or [`info()`](crate::Clock::info) to know
+/// whether the decided backend is.
+pub struct HighResolutionClock;
+
+impl HighResolutionClock {
+    /// Return the clock backing [`HighResolutionClock`], deciding it if not decided yet.
+    pub fn backend() -> BackendKind {
+        match HIGH_RESOLUTION_BACKEND.load(core::sync::atomic::Ordering::Relaxed) {
+            BACKEND_STEADY => BackendKind::Steady,
+            BACKEND_SYSTEM => BackendKind::System,
+            _ => {
+                let _ = Self::decide();
+                Self::backend()
+            }
+        }
+    }
+
+    /// Return true if the clock backing [`HighResolutionClock`] is monotonic, deciding it if
+    /// not decided yet.
+    pub fn is_steady() -> bool {
+        Self::backend() == BackendKind::Steady
+    }
+
+    /// Force the clock backing [`HighResolutionClock`], e.g. for debugging.
+    ///
+    /// It must be called before the clock is first read, so that the timepoints of different
+    /// backends are never mixed.
+    ///
+    /// # Errors
+    ///
+    /// This function will return the decided backend if it is not `backend`, i.e. the clock
+    /// has already been read, or another backend has been forced.
+    pub fn force_backend(backend: BackendKind) -> core::result::Result<(), BackendKind> {
+        let value = match backend {
+            BackendKind::Steady => BACKEND_STEADY,
+            BackendKind::System => BACKEND_SYSTEM,
+        };
+        match HIGH_RESOLUTION_BACKEND.compare_exchange(
+            BACKEND_UNDECIDED,
+            value,
+            core::sync::atomic::Ordering::Relaxed,
+            core::sync::atomic::Ordering::Relaxed,
+        ) {
+            Ok(_) => Ok(()),
+            Err(decided) if decided == value => Ok(()),
+            Err(_) => Err(Self::backend()),
+        }
+    }
+
+    /// Decide the backend by reading [`SteadyClock`], and return the timepoint read by the
+    /// decided backend.
+    #[cold]
+    fn decide() -> crate::Result<crate::TimePoint> {
+        let (backend, now) = match steady_now() {
+            Ok(now) => (BACKEND_STEADY, Ok(now)),
+            Err(_) => (BACKEND_SYSTEM, <SystemClock as crate::Clock>::try_now()),
+        };
+        match HIGH_RESOLUTION_BACKEND.compare_exchange(
+            BACKEND_UNDECIDED,
+            backend,
+            core::sync::atomic::Ordering::Relaxed,
+            core::sync::atomic::Ordering::Relaxed,
+        ) {
+            Ok(_) => now,
+            // Another thread has decided or forced the backend in the meantime.
+            Err(_) => <Self as crate::Clock>::try_now(),
+        }
+    }
+}
+
+#[cfg(have_steady_clock)]
+impl HighResolutionClock {
+    /// Return the raw reading of [`SteadyClock`], see [`SteadyClock::raw_now`], to be
+    /// converted later by [`HighResolutionClock::convert`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return [`Error::Unsupported`](crate::Error::Unsupported) if the
+    /// clock backing [`HighResolutionClock`] is [`SystemClock`], or an error like
+    /// [`SteadyClock::raw_now`].
+    #[inline(always)]
+    pub fn raw_now() -> crate::Result<crate::RawInstant> {
+        match Self::backend() {
+            BackendKind::Steady => SteadyClock::raw_now(),
+            BackendKind::System => Err(crate::Error::Unsupported("SteadyClock")),
+        }
+    }
+
+    /// Convert a raw instant read by [`HighResolutionClock::raw_now`] to a timepoint, which is
+    /// the same as [`HighResolutionClock::now`](crate::Clock::now) would have returned at that
+    /// instant, see [`SteadyClock::convert`].
+    #[inline(always)]
+    pub fn convert(raw: crate::RawInstant) -> crate::TimePoint {
+        SteadyClock::convert(raw)
+    }
+}
+
+impl crate::Clock for HighResolutionClock {
+    type Output = crate::TimePoint;
+    type Duration = crate::Duration;
+    const IS_STEADY: bool = false;
+    const IS_CPU_TIME: bool = false;
+
+    #[inline(always)]
+    fn try_now() -> crate::Result<Self::Output> {
+        match HIGH_RESOLUTION_BACKEND.load(core::sync::atomic::Ordering::Relaxed) {
+            BACKEND_STEADY => steady_now(),
+            BACKEND_SYSTEM => <SystemClock as crate::Clock>::try_now(),
+            _ => Self::decide(),
+        }
+    }
+
+    fn resolution() -> crate::Result<crate::Duration> {
+        match Self::backend() {
+            BackendKind::Steady => steady_resolution(),
+            BackendKind::System => <SystemClock as crate::Clock>::resolution(),
+        }
+    }
+
+    fn info() -> crate::ClockInfo {
+        match Self::backend() {
+            #[cfg(have_steady_clock)]
+            BackendKind::Steady => <SteadyClock as crate::Clock>::info(),
+            _ => <SystemClock as crate::Clock>::info(),
+        }
     }
 }
 
 cfg_if::cfg_if! {
     if #[cfg(have_steady_clock)] {
-        /// A high resolution clock.
-        pub type HighResolutionClock = SteadyClock;
+        #[inline(always)]
+        fn steady_now() -> crate::Result<crate::TimePoint> {
+            <SteadyClock as crate::Clock>::try_now()
+        }
+
+        fn steady_resolution() -> crate::Result<crate::Duration> {
+            <SteadyClock as crate::Clock>::resolution()
+        }
     } else {
-        /// A high resolution clock.
-        pub type HighResolutionClock = SystemClock;
+        #[inline(always)]
+        fn steady_now() -> crate::Result<crate::TimePoint> {
+            Err(crate::Error::Unsupported("SteadyClock"))
+        }
+
+        fn steady_resolution() -> crate::Result<crate::Duration> {
+            Err(crate::Error::Unsupported("SteadyClock"))
+        }
+    }
+}
+
+#[cfg(have_steady_clock)]
+impl SteadyClock {
+    /// Convert a raw instant read by `SteadyClock::raw_now()` to a timepoint, which is the same
+    /// as `SteadyClock::now()` would have returned at that instant.
+    #[inline(always)]
+    pub fn convert(raw: crate::RawInstant) -> crate::TimePoint {
+        crate::TimePoint(raw_ticks_to_duration(raw.0))
+    }
+}
+
+#[cfg(have_steady_clock)]
+impl crate::RawInstant {
+    /// Return the [`Duration`](crate::Duration) elapsed from `start` to `end`, or zero if
+    /// `start` is later than `end`.
+    #[inline(always)]
+    pub fn delta(start: Self, end: Self) -> crate::Duration {
+        raw_ticks_to_duration(end.0.saturating_sub(start.0))
+    }
+}
+
+/// The time from the epoch of [`SystemClock`] to the Unix epoch, i.e. from 1601-01-01 of
+/// `FILETIME` on Windows, and zero on the other platforms.
+#[cfg(all(windows, not(howlong_unsupported)))]
+pub(crate) const UNIX_EPOCH_OFFSET: crate::Duration = crate::Duration::from_secs(11_644_473_600);
+#[cfg(not(all(windows, not(howlong_unsupported))))]
+pub(crate) const UNIX_EPOCH_OFFSET: crate::Duration = crate::Duration::ZERO;
+
+impl SystemClock {
+    /// Convert `time` to a timepoint of [`SystemClock`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return [`Error::BeforeEpoch`](crate::Error::BeforeEpoch) if `time`
+    /// is before the epoch of the clock, i.e. the Unix epoch, or 1601-01-01 on Windows.
+    #[cfg(feature = "std")]
+    pub fn from_system_time(time: std::time::SystemTime) -> crate::Result<crate::TimePoint> {
+        let d = match time.duration_since(std::time::UNIX_EPOCH) {
+            Ok(d) => UNIX_EPOCH_OFFSET + d,
+            Err(e) => UNIX_EPOCH_OFFSET
+                .checked_sub(e.duration())
+                .ok_or(crate::Error::BeforeEpoch)?,
+        };
+        Ok(crate::TimePoint(d))
+    }
+
+    /// Return the current time as the nanoseconds since the Unix epoch.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the underlying system calls failed.
+    pub fn unix_nanos() -> crate::Result<u128> {
+        let now = <Self as crate::Clock>::try_now()?;
+        Ok(now.0.saturating_sub(UNIX_EPOCH_OFFSET).as_nanos())
+    }
+}
+
+#[cfg(feature = "std")]
+impl crate::TimePoint {
+    /// Convert a timepoint of [`SystemClock`] to [`SystemTime`](std::time::SystemTime). The
+    /// timepoints of the other clocks do not have a meaningful epoch.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the timepoint cannot be represented by `SystemTime`.
+    pub fn to_system_time(&self) -> std::time::SystemTime {
+        match self.0.checked_sub(UNIX_EPOCH_OFFSET) {
+            Some(d) => std::time::UNIX_EPOCH + d,
+            None => std::time::UNIX_EPOCH - (UNIX_EPOCH_OFFSET - self.0),
+        }
+    }
+}
+
+/// A clock to report real, user-CPU, and system-CPU clocks of the calling process, excluding
+/// the child processes. It is the same as [`ProcessCPUClock`].
+pub type ProcessSelfCPUClock = ProcessCPUClock;
+
+impl ProcessCPUClock {
+    /// Return a clock to report real, user-CPU, and system-CPU clocks of the process `pid`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the process does not exist or cannot be
+    /// accessed.
+    pub fn for_pid(pid: u32) -> crate::Result<PidCpuClock> {
+        PidCpuClock::open(pid)
+    }
+}
+
+impl ThreadClock {
+    /// Return a handle to read the CPU-time clock of the thread `thread`, see
+    /// [`ThreadCpuHandle::from_pthread`].
+    ///
+    /// # Safety
+    ///
+    /// `thread` must refer to a thread which has not been joined or detached yet.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the CPU-time clock of the thread cannot be
+    /// obtained.
+    #[cfg(all(
+        unix,
+        not(any(target_os = "fuchsia", target_os = "haiku")),
+        not(howlong_unsupported)
+    ))]
+    pub unsafe fn for_native_id(thread: libc::pthread_t) -> crate::Result<ThreadCpuHandle> {
+        ThreadCpuHandle::from_pthread(thread)
+    }
+
+    /// Return a handle to read the CPU-time clock of the thread of the thread id `id`, see
+    /// [`ThreadCpuHandle::from_thread_id`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the thread does not exist or cannot be accessed.
+    #[cfg(all(windows, not(howlong_unsupported)))]
+    pub fn for_native_id(id: u32) -> crate::Result<ThreadCpuHandle> {
+        ThreadCpuHandle::from_thread_id(id)
+    }
+}
+
+#[cfg(feature = "std")]
+mod overhead;
+#[cfg(feature = "std")]
+pub use overhead::*;
+
+#[cfg(all(target_os = "linux", not(howlong_unsupported)))]
+mod cgroup;
+#[cfg(all(target_os = "linux", not(howlong_unsupported)))]
+pub use cgroup::*;
+
+#[cfg(all(feature = "tsc", any(target_arch = "x86", target_arch = "x86_64")))]
+mod tsc;
+#[cfg(all(feature = "tsc", any(target_arch = "x86", target_arch = "x86_64")))]
+pub use tsc::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Duration;
+
+    #[test]
+    fn test_scale_ticks() {
+        assert_eq!(scale_ticks(0, 125, 3), Duration::ZERO);
+        assert_eq!(scale_ticks(3, 125, 3), Duration::from_nanos(125));
+        assert_eq!(
+            scale_ticks(10_000_000, 1_000_000_000, 10_000_000),
+            Duration::from_secs(1)
+        );
+
+        // Near `u64::MAX`, the multiplication would wrap around in 64-bit arithmetic.
+        assert_eq!(
+            scale_ticks(u64::MAX, 125, 3),
+            Duration::new(768_614_336_404, 564_650_625)
+        );
+        assert_eq!(
+            scale_ticks(u64::MAX - 1, 1_000_000_000, 10_000_000),
+            Duration::new(1_844_674_407_370, 955_161_400)
+        );
+        assert_eq!(scale_ticks(u64::MAX, 1, 1), Duration::from_nanos(u64::MAX));
+
+        // The result does not fit in a `Duration`.
+        assert_eq!(scale_ticks(u64::MAX, u64::MAX, 1), Duration::MAX);
     }
 }
@@ -0,0 +1,88 @@
+use super::*;
+use crate::{bench::black_box, Clock, Duration, Result};
+use std::collections::BTreeMap;
+
+/// The number of batches measured by [`overhead`].
+const OVERHEAD_BATCHES: u32 = 100;
+/// The number of clock calls in each batch measured by [`overhead`].
+const OVERHEAD_BATCH_SIZE: u32 = 100;
+
+/// Return the median cost of a `ClockType::try_now()` call.
+///
+/// The calls are made in batches timed by [`HighResolutionClock`] and the median per-call
+/// cost among the batches is returned.
+///
+/// # Errors
+///
+/// This function will return an error if acessing to the underlying clocks failed.
+pub fn overhead<ClockType: Clock>() -> Result<Duration> {
+    let mut samples = Vec::with_capacity(OVERHEAD_BATCHES as usize);
+    for _ in 0..OVERHEAD_BATCHES {
+        let start = HighResolutionClock::try_now()?;
+        for _ in 0..OVERHEAD_BATCH_SIZE {
+            black_box(<ClockType>::try_now()?);
+        }
+        let elapsed = HighResolutionClock::try_now()?.saturating_sub(start);
+        samples.push(elapsed / OVERHEAD_BATCH_SIZE);
+    }
+    samples.sort_unstable();
+    Ok(samples[samples.len() / 2])
+}
+
+/// Return the [`overhead`] of all the clocks available on this system, keyed by their names.
+///
+/// # Errors
+///
+/// This function will return an error if acessing to the underlying clocks failed.
+pub fn overhead_report() -> Result<BTreeMap<&'static str, Duration>> {
+    let mut report = BTreeMap::new();
+    report.insert("SystemClock", overhead::<SystemClock>()?);
+    #[cfg(have_steady_clock)]
+    report.insert("SteadyClock", overhead::<SteadyClock>()?);
+    #[cfg(have_suspend_aware_clock)]
+    if SuspendAwareClock::is_available() {
+        report.insert("SuspendAwareClock", overhead::<SuspendAwareClock>()?);
+    }
+    #[cfg(have_coarse_clock)]
+    if CoarseSystemClock::is_available() {
+        report.insert("CoarseSystemClock", overhead::<CoarseSystemClock>()?);
+    }
+    #[cfg(have_coarse_clock)]
+    if CoarseSteadyClock::is_available() {
+        report.insert("CoarseSteadyClock", overhead::<CoarseSteadyClock>()?);
+    }
+    #[cfg(have_clock_tai)]
+    if TaiClock::is_available() {
+        report.insert("TaiClock", overhead::<TaiClock>()?);
+    }
+    #[cfg(all(feature = "tsc", any(target_arch = "x86", target_arch = "x86_64")))]
+    if TscClock::is_available() {
+        report.insert("TscClock", overhead::<TscClock>()?);
+    }
+    report.insert("UptimeClock", overhead::<UptimeClock>()?);
+    report.insert("ProcessRealCPUClock", overhead::<ProcessRealCPUClock>()?);
+    report.insert("ProcessUserCPUClock", overhead::<ProcessUserCPUClock>()?);
+    report.insert(
+        "ProcessSystemCPUClock",
+        overhead::<ProcessSystemCPUClock>()?,
+    );
+    report.insert("ProcessCPUClock", overhead::<ProcessCPUClock>()?);
+    report.insert(
+        "ProcessWithChildrenCPUClock",
+        overhead::<ProcessWithChildrenCPUClock>()?,
+    );
+    report.insert("ChildrenCPUClock", overhead::<ChildrenCPUClock>()?);
+    #[cfg(all(target_os = "linux", not(howlong_unsupported)))]
+    report.insert("ProcStatClock", overhead::<ProcStatClock>()?);
+    #[cfg(all(target_os = "linux", not(howlong_unsupported)))]
+    if CgroupCPUClock::is_available() {
+        report.insert("CgroupCPUClock", overhead::<CgroupCPUClock>()?);
+    }
+    report.insert("ThreadClock", overhead::<ThreadClock>()?);
+    report.insert("ThreadCPUClock", overhead::<ThreadCPUClock>()?);
+    #[cfg(all(windows, not(howlong_unsupported)))]
+    report.insert("ThreadCycleClock", overhead::<ThreadCycleClock>()?);
+    #[cfg(all(windows, not(howlong_unsupported)))]
+    report.insert("ProcessCycleClock", overhead::<ProcessCycleClock>()?);
+    Ok(report)
+}
@@ -1,6 +1,17 @@
 // Ref: https://github.com/boostorg/chrono/tree/develop/include/boost/chrono/detail/inlined/posix
 
-use crate::{Clock, Duration, Error, ProcessTimePoint, Result, TimePoint};
+use crate::{
+    Clock, Duration, Error, ProcessDuration, ProcessTimePoint, Result, ThreadDuration,
+    ThreadTimePoint, TimePoint,
+};
+use core::mem;
+use std::fs::File;
+use std::os::unix::fs::FileExt;
+use std::os::unix::process::ExitStatusExt;
+use std::os::unix::thread::JoinHandleExt;
+use std::process::{Child, ExitStatus};
+use std::sync::OnceLock;
+use std::thread::JoinHandle;
 
 pub(crate) fn errno() -> i32 {
     errno::errno().into()
@@ -11,6 +22,9 @@ pub struct SystemClock;
 
 impl Clock for SystemClock {
     type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = false;
+    const IS_CPU_TIME: bool = false;
 
     fn try_now() -> Result<Self::Output> {
         let mut ts = libc::timespec {
@@ -24,6 +38,10 @@ impl Clock for SystemClock {
         let d = Duration::from_secs(ts.tv_sec as u64) + Duration::from_nanos(ts.tv_nsec as u64);
         Ok(TimePoint(d))
     }
+
+    fn resolution() -> Result<Duration> {
+        get_clock_res(libc::CLOCK_REALTIME)
+    }
 }
 
 #[cfg(have_steady_clock)]
@@ -33,25 +51,295 @@ pub struct SteadyClock;
 #[cfg(have_steady_clock)]
 impl Clock for SteadyClock {
     type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    /// Return the current timepoint.
+    ///
+    /// # Errors
+    ///
+    /// This function will return [`Error::Unsupported`] if the running kernel rejects
+    /// `CLOCK_MONOTONIC`, or an error if acessing to the underlying system calls failed.
+    fn try_now() -> Result<Self::Output> {
+        match get_clock_time(libc::CLOCK_MONOTONIC) {
+            Err(Error::SystemError(_, libc::EINVAL)) => Err(Error::Unsupported("CLOCK_MONOTONIC")),
+            t => Ok(TimePoint(t?)),
+        }
+    }
+
+    fn resolution() -> Result<Duration> {
+        match get_clock_res(libc::CLOCK_MONOTONIC) {
+            Err(Error::SystemError(_, libc::EINVAL)) => Err(Error::Unsupported("CLOCK_MONOTONIC")),
+            res => res,
+        }
+    }
+}
+
+#[cfg(have_steady_clock)]
+impl SteadyClock {
+    /// Return the raw reading of the clock, i.e. the nanoseconds of `CLOCK_MONOTONIC`, to be
+    /// converted later by [`SteadyClock::convert`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error like [`SteadyClock::try_now`].
+    #[inline(always)]
+    pub fn raw_now() -> Result<crate::RawInstant> {
+        Ok(crate::RawInstant(Self::try_now()?.0.as_nanos() as u64))
+    }
+}
+
+/// Convert the raw ticks of [`SteadyClock`], which are already nanoseconds, to [`Duration`].
+#[cfg(have_steady_clock)]
+#[inline(always)]
+pub(crate) fn raw_ticks_to_duration(ticks: u64) -> Duration {
+    Duration::from_nanos(ticks)
+}
+
+/// Block until `CLOCK_MONOTONIC`, i.e. [`SteadyClock`], reaches `target` by
+/// `clock_nanosleep(TIMER_ABSTIME)`, which is re-issued if interrupted by a signal.
+#[cfg(all(have_steady_clock, have_clock_nanosleep))]
+pub(crate) fn monotonic_sleep_until(target: TimePoint) -> Result<()> {
+    use core::convert::TryInto;
+
+    let ts = libc::timespec {
+        // Saturate at the 32-bit maximum, which every `time_t` can hold and no monotonic clock
+        // reaches.
+        tv_sec: target.0.as_secs().try_into().unwrap_or(i32::MAX.into()),
+        tv_nsec: target.0.subsec_nanos() as _,
+    };
+    loop {
+        let ret = unsafe {
+            libc::clock_nanosleep(
+                libc::CLOCK_MONOTONIC,
+                libc::TIMER_ABSTIME,
+                &ts,
+                core::ptr::null_mut(),
+            )
+        };
+        match ret {
+            0 => return Ok(()),
+            libc::EINTR => continue,
+            // Unlike most system calls, `clock_nanosleep` returns the error number.
+            e => return Err(Error::SystemError("clock_nanosleep", e)),
+        }
+    }
+}
+
+#[cfg(have_suspend_aware_clock)]
+#[doc = "A steady clock which keeps counting while the system is suspended."]
+// It is based on `clock_gettime(CLOCK_BOOTTIME)`, which is available since Linux 2.6.39.
+pub struct SuspendAwareClock;
+
+#[cfg(have_suspend_aware_clock)]
+impl Clock for SuspendAwareClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
 
     fn try_now() -> Result<Self::Output> {
         let mut ts = libc::timespec {
             tv_sec: 0,
             tv_nsec: 0,
         };
-        let ret = unsafe { libc::clock_gettime(libc::CLOCK_MONOTONIC, &mut ts) };
+        let ret = unsafe { libc::clock_gettime(libc::CLOCK_BOOTTIME, &mut ts) };
         if ret != 0 {
             return Err(Error::SystemError("clock_gettime", errno()));
         }
         let d = Duration::from_secs(ts.tv_sec as u64) + Duration::from_nanos(ts.tv_nsec as u64);
         Ok(TimePoint(d))
     }
+
+    fn resolution() -> Result<Duration> {
+        get_clock_res(libc::CLOCK_BOOTTIME)
+    }
+}
+
+#[cfg(have_coarse_clock)]
+#[doc = "A system clock which is faster to read but less precise than `SystemClock`."]
+// It is based on `clock_gettime(CLOCK_REALTIME_COARSE)`, which is available since Linux 2.6.32.
+pub struct CoarseSystemClock;
+
+#[cfg(have_coarse_clock)]
+impl Clock for CoarseSystemClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = false;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(TimePoint(get_clock_time(libc::CLOCK_REALTIME_COARSE)?))
+    }
+
+    fn resolution() -> Result<Duration> {
+        get_clock_res(libc::CLOCK_REALTIME_COARSE)
+    }
+}
+
+#[cfg(have_coarse_clock)]
+#[doc = "A steady clock which is faster to read but less precise than `SteadyClock`."]
+// It is based on `clock_gettime(CLOCK_MONOTONIC_COARSE)`, which is available since Linux 2.6.32.
+pub struct CoarseSteadyClock;
+
+#[cfg(have_coarse_clock)]
+impl Clock for CoarseSteadyClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(TimePoint(get_clock_time(libc::CLOCK_MONOTONIC_COARSE)?))
+    }
+
+    fn resolution() -> Result<Duration> {
+        get_clock_res(libc::CLOCK_MONOTONIC_COARSE)
+    }
+}
+
+#[cfg(have_clock_tai)]
+#[doc = "A clock to report the International Atomic Time (TAI)."]
+#[doc = ""]
+#[doc = "It differs from `SystemClock` by the TAI-UTC offset, i.e. the accumulated leap seconds,"]
+#[doc = "only if the offset has been configured in the kernel, e.g. by a PTP or NTP daemon."]
+#[doc = "Otherwise, it reports the same value as `SystemClock`."]
+// It is based on `clock_gettime(CLOCK_TAI)`, which is available since Linux 3.10.
+pub struct TaiClock;
+
+#[cfg(have_clock_tai)]
+impl TaiClock {
+    /// Return the offset between TAI and UTC, computed from a paired read of [`TaiClock`] and
+    /// [`SystemClock`] and rounded to whole seconds.
+    ///
+    /// # Errors
+    ///
+    /// This function will return [`Error::Unsupported`] if the running kernel does not support
+    /// `CLOCK_TAI`, or an error if acessing to the underlying system calls failed.
+    pub fn tai_utc_offset() -> Result<Duration> {
+        let before = SystemClock::try_now()?;
+        let tai = Self::try_now()?.0;
+        let after = SystemClock::try_now()?;
+        let utc = before.0 + after.saturating_duration_since(before) / 2;
+        let offset = tai.checked_sub(utc).unwrap_or_default();
+        let secs = offset.as_secs() + u64::from(offset.subsec_nanos() >= 500_000_000);
+        Ok(Duration::from_secs(secs))
+    }
+}
+
+#[cfg(have_clock_tai)]
+impl Clock for TaiClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = false;
+    const IS_CPU_TIME: bool = false;
+
+    /// Return the current timepoint.
+    ///
+    /// # Errors
+    ///
+    /// This function will return [`Error::Unsupported`] if the running kernel does not support
+    /// `CLOCK_TAI`, so that the caller can fall back to [`SystemClock`].
+    fn try_now() -> Result<Self::Output> {
+        match get_clock_time(libc::CLOCK_TAI) {
+            Err(Error::SystemError(_, libc::EINVAL)) => Err(Error::Unsupported("CLOCK_TAI")),
+            t => Ok(TimePoint(t?)),
+        }
+    }
+
+    fn resolution() -> Result<Duration> {
+        match get_clock_res(libc::CLOCK_TAI) {
+            Err(Error::SystemError(_, libc::EINVAL)) => Err(Error::Unsupported("CLOCK_TAI")),
+            res => res,
+        }
+    }
+}
+
+/// A clock to report the time since the system booted.
+// It is based on `clock_gettime(CLOCK_BOOTTIME)` if available, otherwise `/proc/uptime`.
+pub struct UptimeClock;
+
+impl UptimeClock {
+    /// Return the time since the system booted.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the underlying system calls failed.
+    pub fn uptime() -> Result<Duration> {
+        Ok(Self::try_now()?.0)
+    }
+}
+
+impl Clock for UptimeClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        #[cfg(have_suspend_aware_clock)]
+        if let Ok(t) = SuspendAwareClock::try_now() {
+            return Ok(t);
+        }
+        let uptime = std::fs::read("/proc/uptime").map_err(|e| {
+            Error::SystemError("read(/proc/uptime)", e.raw_os_error().unwrap_or_default())
+        })?;
+        let d = parse_proc_uptime(&uptime)
+            .ok_or(Error::SystemError("parse(/proc/uptime)", libc::EINVAL))?;
+        Ok(TimePoint(d))
+    }
+
+    fn resolution() -> Result<Duration> {
+        #[cfg(have_suspend_aware_clock)]
+        if let Ok(res) = SuspendAwareClock::resolution() {
+            return Ok(res);
+        }
+        // `/proc/uptime` reports the uptime in centiseconds.
+        Ok(Duration::from_millis(10))
+    }
+}
+
+/// Parse the first field, i.e. the uptime in seconds, of `/proc/uptime`.
+pub(crate) fn parse_proc_uptime(uptime: &[u8]) -> Option<Duration> {
+    let field = core::str::from_utf8(uptime)
+        .ok()?
+        .split_whitespace()
+        .next()?;
+    let (secs, frac) = field.split_once('.').unwrap_or((field, ""));
+    if frac.len() > 9 || !frac.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let nanos = if frac.is_empty() {
+        0
+    } else {
+        frac.parse::<u32>().ok()? * 10u32.pow(9 - frac.len() as u32)
+    };
+    Some(Duration::new(secs.parse().ok()?, nanos))
 }
 
-fn tick_factor() -> Result<u64> {
-    let factor = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+#[cfg(test)]
+static SYSCONF_CLK_TCK_CALLS: std::sync::atomic::AtomicUsize =
+    std::sync::atomic::AtomicUsize::new(0);
+
+/// Return the cached result of `sysconf(_SC_CLK_TCK)` together with its errno, since the
+/// number of clock ticks per second cannot change during the life of the process. A failure is
+/// cached as well, so that it is not retried on every call.
+#[inline(always)]
+fn clk_tck() -> (libc::c_long, i32) {
+    static CLK_TCK: OnceLock<(libc::c_long, i32)> = OnceLock::new();
+    *CLK_TCK.get_or_init(|| {
+        #[cfg(test)]
+        SYSCONF_CLK_TCK_CALLS.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let factor = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+        (factor, if factor <= 0 { errno() } else { 0 })
+    })
+}
+
+pub(crate) fn tick_factor() -> Result<u64> {
+    let (factor, err) = clk_tck();
     if factor <= 0 {
-        return Err(Error::SystemError("sysconf(_SC_CLK_TCK)", errno()));
+        return Err(Error::SystemError("sysconf(_SC_CLK_TCK)", err));
     }
     if factor > 1_000_000_000 {
         return Err(Error::ClkFreqTooHigh);
@@ -74,69 +362,658 @@ fn times() -> Result<(libc::clock_t, libc::tms)> {
     Ok((ret, tm))
 }
 
+/// The source of the process CPU clocks, chosen once at the first use.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ProcessCPUSource {
+    /// `getrusage(RUSAGE_SELF)` with a microsecond resolution.
+    Rusage,
+    /// `times` with a resolution of a clock tick.
+    Times,
+}
+
+fn process_cpu_source() -> ProcessCPUSource {
+    static SOURCE: OnceLock<ProcessCPUSource> = OnceLock::new();
+    *SOURCE.get_or_init(|| {
+        if getrusage(libc::RUSAGE_SELF).is_ok() {
+            ProcessCPUSource::Rusage
+        } else {
+            ProcessCPUSource::Times
+        }
+    })
+}
+
+#[inline(always)]
+fn getrusage(who: libc::c_int) -> Result<(Duration, Duration)> {
+    let mut usage: libc::rusage = unsafe { mem::zeroed() };
+    let ret = unsafe { libc::getrusage(who, &mut usage) };
+    if ret != 0 {
+        return Err(Error::SystemError("getrusage", errno()));
+    }
+    Ok((
+        timeval_to_duration(usage.ru_utime),
+        timeval_to_duration(usage.ru_stime),
+    ))
+}
+
+/// Return the user-CPU and system-CPU times of the calling process, excluding its children.
+#[inline(always)]
+fn process_cpu_times() -> Result<(Duration, Duration)> {
+    match process_cpu_source() {
+        ProcessCPUSource::Rusage => getrusage(libc::RUSAGE_SELF),
+        ProcessCPUSource::Times => {
+            let (_, tm) = times()?;
+            let factor = tick_factor()?;
+            Ok((
+                Duration::from_nanos((tm.tms_utime as u64) * factor),
+                Duration::from_nanos((tm.tms_stime as u64) * factor),
+            ))
+        }
+    }
+}
+
+#[inline(always)]
+fn process_cpu_resolution() -> Result<Duration> {
+    match process_cpu_source() {
+        ProcessCPUSource::Rusage => Ok(Duration::from_micros(1)),
+        ProcessCPUSource::Times => Ok(Duration::from_nanos(tick_factor()?)),
+    }
+}
+
+/// Return the real process wall-clock.
+#[inline(always)]
+fn process_real_time() -> Result<Duration> {
+    Ok(SteadyClock::try_now()?.0)
+}
+
+#[inline(always)]
+fn process_real_resolution() -> Result<Duration> {
+    SteadyClock::resolution()
+}
+
 /// A clock to report the real process wall-clock.
+// It is based on `clock_gettime(CLOCK_MONOTONIC)` if available, otherwise `times`.
 pub struct ProcessRealCPUClock;
 
 impl Clock for ProcessRealCPUClock {
     type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
 
     fn try_now() -> Result<Self::Output> {
-        let (c, _) = times()?;
-        let factor = tick_factor()?;
-        let d = Duration::from_nanos((c as u64) * factor);
-        Ok(TimePoint(d))
+        Ok(TimePoint(process_real_time()?))
+    }
+
+    fn resolution() -> Result<Duration> {
+        process_real_resolution()
     }
 }
 
 /// A clock to report the user cpu-clock.
+///
+/// It does not include the terminated child processes, see [`ChildrenCPUClock`].
+// It is based on `getrusage(RUSAGE_SELF)` if available, otherwise `times`.
 pub struct ProcessUserCPUClock;
 
 impl Clock for ProcessUserCPUClock {
     type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
 
     fn try_now() -> Result<Self::Output> {
-        let (_, tm) = times()?;
-        let factor = tick_factor()?;
-        let d = Duration::from_nanos(((tm.tms_utime + tm.tms_cutime) as u64) * factor);
-        Ok(TimePoint(d))
+        let (user, _) = process_cpu_times()?;
+        Ok(TimePoint(user))
+    }
+
+    fn resolution() -> Result<Duration> {
+        process_cpu_resolution()
     }
 }
 
 /// A clock to report the system cpu-clock.
+///
+/// It does not include the terminated child processes, see [`ChildrenCPUClock`].
+// It is based on `getrusage(RUSAGE_SELF)` if available, otherwise `times`.
 pub struct ProcessSystemCPUClock;
 
 impl Clock for ProcessSystemCPUClock {
     type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
 
     fn try_now() -> Result<Self::Output> {
-        let (_, tm) = times()?;
-        let factor = tick_factor()?;
-        let d = Duration::from_nanos(((tm.tms_stime + tm.tms_cstime) as u64) * factor);
-        Ok(TimePoint(d))
+        let (_, system) = process_cpu_times()?;
+        Ok(TimePoint(system))
+    }
+
+    fn resolution() -> Result<Duration> {
+        process_cpu_resolution()
     }
 }
 
 /// A clock to report real, user-CPU, and system-CPU clocks.
+///
+/// It does not include the terminated child processes, see [`ChildrenCPUClock`].
+// It is based on `getrusage(RUSAGE_SELF)` if available, otherwise `times`.
 pub struct ProcessCPUClock;
 
+impl ProcessCPUClock {
+    /// Return the total, i.e. user plus system, CPU time of the calling process.
+    ///
+    /// It is based on `clock_gettime(CLOCK_PROCESS_CPUTIME_ID)` if available, which has a
+    /// better resolution than the user-CPU and system-CPU clocks.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the underlying system calls failed.
+    pub fn total() -> Result<Duration> {
+        #[cfg(have_clock_process_cputime_id)]
+        if let Ok(total) = get_clock_time(libc::CLOCK_PROCESS_CPUTIME_ID) {
+            return Ok(total);
+        }
+        let (user, system) = process_cpu_times()?;
+        Ok(user + system)
+    }
+}
+
 impl Clock for ProcessCPUClock {
     type Output = ProcessTimePoint;
+    type Duration = ProcessDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
 
     fn try_now() -> Result<Self::Output> {
-        let (c, tm) = times()?;
+        let (user, system) = process_cpu_times()?;
+        Ok(ProcessTimePoint {
+            real: process_real_time()?,
+            user,
+            system,
+        })
+    }
+
+    fn resolution() -> Result<Duration> {
+        process_cpu_resolution()
+    }
+}
+
+/// A clock to report the user cpu-clock of the calling process by `getrusage(RUSAGE_SELF)`.
+pub struct RusageUserCPUClock;
+
+impl Clock for RusageUserCPUClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        let (user, _) = getrusage(libc::RUSAGE_SELF)?;
+        Ok(TimePoint(user))
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(Duration::from_micros(1))
+    }
+}
+
+/// A clock to report the system cpu-clock of the calling process by `getrusage(RUSAGE_SELF)`.
+pub struct RusageSystemCPUClock;
+
+impl Clock for RusageSystemCPUClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        let (_, system) = getrusage(libc::RUSAGE_SELF)?;
+        Ok(TimePoint(system))
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(Duration::from_micros(1))
+    }
+}
+
+/// A clock to report real, user-CPU, and system-CPU clocks of the calling process by
+/// `getrusage(RUSAGE_SELF)`. The real clock is read from [`HighResolutionClock`].
+///
+/// [`HighResolutionClock`]: crate::clock::HighResolutionClock
+pub struct RusageCPUClock;
+
+impl Clock for RusageCPUClock {
+    type Output = ProcessTimePoint;
+    type Duration = ProcessDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        let (user, system) = getrusage(libc::RUSAGE_SELF)?;
+        Ok(ProcessTimePoint {
+            real: crate::clock::HighResolutionClock::try_now()?.0,
+            user,
+            system,
+        })
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(Duration::from_micros(1))
+    }
+}
+
+/// A clock to report real, user-CPU, and system-CPU clocks of the terminated and waited-for
+/// child processes by `getrusage(RUSAGE_CHILDREN)`, i.e. [`ChildrenCPUClock`].
+pub type RusageChildrenCPUClock = ChildrenCPUClock;
+
+/// The CPU times of a process read from `/proc/<pid>/stat`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProcStat {
+    /// The user-CPU time of the process, i.e. `utime`.
+    pub user: Duration,
+    /// The system-CPU time of the process, i.e. `stime`.
+    pub system: Duration,
+    /// The user-CPU time of the terminated and waited-for children, i.e. `cutime`.
+    pub children_user: Duration,
+    /// The system-CPU time of the terminated and waited-for children, i.e. `cstime`.
+    pub children_system: Duration,
+}
+
+impl ProcStat {
+    /// Parse the content of `/proc/<pid>/stat` or `/proc/<pid>/task/<tid>/stat`.
+    ///
+    /// The executable name in the second field may contain spaces and parentheses, e.g.
+    /// `1234 ((my prog) (x)) R ...`, so the fields are counted from its last `)`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the content is malformed, or if
+    /// `sysconf(_SC_CLK_TCK)` failed.
+    pub fn parse(stat: &[u8]) -> Result<Self> {
+        let [utime, stime, cutime, cstime] =
+            parse_proc_stat(stat).ok_or(Error::SystemError("parse(/proc/*/stat)", libc::EINVAL))?;
         let factor = tick_factor()?;
+        Ok(ProcStat {
+            user: Duration::from_nanos(utime * factor),
+            system: Duration::from_nanos(stime * factor),
+            children_user: Duration::from_nanos(cutime * factor),
+            children_system: Duration::from_nanos(cstime * factor),
+        })
+    }
+}
+
+/// Parse the `utime`, `stime`, `cutime`, and `cstime` fields, in clock ticks, from the content
+/// of `/proc/<pid>/stat` or `/proc/<pid>/task/<tid>/stat`.
+pub(crate) fn parse_proc_stat(stat: &[u8]) -> Option<[u64; 4]> {
+    // The second field is the executable name in parentheses, which may contain spaces.
+    let end = stat.iter().rposition(|&c| c == b')')?;
+    let stat = core::str::from_utf8(stat.get(end + 1..)?).ok()?;
+    // `utime`, `stime`, `cutime`, and `cstime` are the 14th to 17th fields, counting from the
+    // 3rd field.
+    let mut fields = stat.split_ascii_whitespace().skip(11);
+    let mut times = [0; 4];
+    for time in times.iter_mut() {
+        *time = fields.next()?.parse().ok()?;
+    }
+    Some(times)
+}
+
+/// Parse the `starttime` field, in clock ticks since the system booted, from the content of
+/// `/proc/<pid>/stat`.
+#[cfg_attr(not(any(target_os = "linux", target_os = "android")), allow(dead_code))]
+pub(crate) fn parse_proc_start_time(stat: &[u8]) -> Option<u64> {
+    let end = stat.iter().rposition(|&c| c == b')')?;
+    let stat = core::str::from_utf8(stat.get(end + 1..)?).ok()?;
+    // `starttime` is the 22nd field, counting from the 3rd field.
+    stat.split_ascii_whitespace().nth(19)?.parse().ok()
+}
+
+/// Read a `stat` file under `/proc`.
+pub(crate) fn read_proc_stat(file: &File) -> Result<ProcStat> {
+    let mut buf = [0u8; 2048];
+    let n = file.read_at(&mut buf, 0).map_err(|e| {
+        Error::SystemError("read(/proc/*/stat)", e.raw_os_error().unwrap_or_default())
+    })?;
+    ProcStat::parse(&buf[..n])
+}
+
+/// A clock to report real, user-CPU, and system-CPU clocks of the calling process by parsing
+/// `/proc/self/stat`, which also reports the children separately, see
+/// [`children`](#method.children).
+///
+/// The file is opened at first use and kept open, and it is read with `pread` afterwards.
+/// Its resolution is a clock tick.
+#[cfg(target_os = "linux")]
+pub struct ProcStatClock;
+
+#[cfg(target_os = "linux")]
+impl ProcStatClock {
+    /// Return the CPU times of the calling process and its terminated and waited-for children.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to `/proc/self/stat` failed.
+    pub fn stat() -> Result<ProcStat> {
+        static STAT: OnceLock<File> = OnceLock::new();
+        let file = match STAT.get() {
+            Some(file) => file,
+            None => {
+                let file = File::open("/proc/self/stat").map_err(|e| {
+                    Error::SystemError(
+                        "open(/proc/self/stat)",
+                        e.raw_os_error().unwrap_or_default(),
+                    )
+                })?;
+                STAT.get_or_init(|| file)
+            }
+        };
+        read_proc_stat(file)
+    }
+
+    /// Return the real, user-CPU, and system-CPU clocks of the terminated and waited-for
+    /// children of the calling process, i.e. `cutime` and `cstime`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to `/proc/self/stat` failed.
+    pub fn children() -> Result<ProcessTimePoint> {
+        let stat = Self::stat()?;
+        Ok(ProcessTimePoint {
+            real: process_real_time()?,
+            user: stat.children_user,
+            system: stat.children_system,
+        })
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Clock for ProcStatClock {
+    type Output = ProcessTimePoint;
+    type Duration = ProcessDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        let stat = Self::stat()?;
+        Ok(ProcessTimePoint {
+            real: process_real_time()?,
+            user: stat.user,
+            system: stat.system,
+        })
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(Duration::from_nanos(tick_factor()?))
+    }
+}
+
+/// A clock to report the time since the calling process started.
+///
+/// On Linux and Android, it is the [`UptimeClock`] minus the `starttime` of
+/// `/proc/self/stat`, so its accuracy is a clock tick. It returns [`Error::Unsupported`] on
+/// the other platforms.
+pub struct ProcessUptimeClock;
+
+impl ProcessUptimeClock {
+    /// Return the time since the calling process started.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the underlying system calls failed.
+    pub fn uptime() -> Result<Duration> {
+        Ok(Self::try_now()?.0)
+    }
+}
+
+impl Clock for ProcessUptimeClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        let start = process_start_time()?;
+        Ok(TimePoint(UptimeClock::try_now()?.0.saturating_sub(start)))
+    }
+
+    fn resolution() -> Result<Duration> {
+        process_start_time()?;
+        UptimeClock::resolution()
+    }
+}
+
+/// Return the cached time when the calling process started, since the system booted.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn process_start_time() -> Result<Duration> {
+    static START: OnceLock<Duration> = OnceLock::new();
+    if let Some(start) = START.get() {
+        return Ok(*start);
+    }
+    let stat = std::fs::read("/proc/self/stat").map_err(|e| {
+        Error::SystemError(
+            "read(/proc/self/stat)",
+            e.raw_os_error().unwrap_or_default(),
+        )
+    })?;
+    let ticks = parse_proc_start_time(&stat)
+        .ok_or(Error::SystemError("parse(/proc/self/stat)", libc::EINVAL))?;
+    let start = Duration::from_nanos(ticks * tick_factor()?);
+    Ok(*START.get_or_init(|| start))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn process_start_time() -> Result<Duration> {
+    Err(Error::Unsupported("ProcessUptimeClock"))
+}
+
+/// A clock to report the user-CPU, system-CPU, and idle times of all the processors of the
+/// host by parsing the aggregate `cpu` line of `/proc/stat` on Linux and Android.
+///
+/// The `nice` time is counted as user, the `irq` and `softirq` times as system, and the
+/// `iowait` time as idle. The time stolen by the hypervisor is not counted. Its resolution is a
+/// clock tick. It returns [`Error::Unsupported`] on the other platforms.
+pub struct HostCPUClock;
+
+impl Clock for HostCPUClock {
+    type Output = crate::HostTimePoint;
+    type Duration = crate::HostDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        host_cpu_times()
+    }
+
+    fn resolution() -> Result<Duration> {
+        if cfg!(any(target_os = "linux", target_os = "android")) {
+            Ok(Duration::from_nanos(tick_factor()?))
+        } else {
+            Err(Error::Unsupported("HostCPUClock"))
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn host_cpu_times() -> Result<crate::HostTimePoint> {
+    static STAT: OnceLock<File> = OnceLock::new();
+    let file = match STAT.get() {
+        Some(file) => file,
+        None => {
+            let file = File::open("/proc/stat").map_err(|e| {
+                Error::SystemError("open(/proc/stat)", e.raw_os_error().unwrap_or_default())
+            })?;
+            STAT.get_or_init(|| file)
+        }
+    };
+    // The aggregate `cpu` line comes first.
+    let mut buf = [0u8; 1024];
+    let n = file.read_at(&mut buf, 0).map_err(|e| {
+        Error::SystemError("read(/proc/stat)", e.raw_os_error().unwrap_or_default())
+    })?;
+    let [user, system, idle] =
+        parse_host_stat(&buf[..n]).ok_or(Error::SystemError("parse(/proc/stat)", libc::EINVAL))?;
+    let factor = tick_factor()?;
+    Ok(crate::HostTimePoint {
+        user: Duration::from_nanos(user * factor),
+        system: Duration::from_nanos(system * factor),
+        idle: Duration::from_nanos(idle * factor),
+    })
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn host_cpu_times() -> Result<crate::HostTimePoint> {
+    Err(Error::Unsupported("HostCPUClock"))
+}
+
+/// Parse the user, system, and idle times, in clock ticks, from the aggregate `cpu` line of
+/// `/proc/stat`, i.e. `cpu user nice system idle iowait irq softirq steal ...`. The fields
+/// after `idle` are missing on old kernels.
+#[cfg_attr(not(any(target_os = "linux", target_os = "android")), allow(dead_code))]
+pub(crate) fn parse_host_stat(stat: &[u8]) -> Option<[u64; 3]> {
+    let line = stat.split(|&c| c == b'\n').next()?;
+    let line = core::str::from_utf8(line).ok()?;
+    let mut fields = line.split_ascii_whitespace();
+    if fields.next()? != "cpu" {
+        return None;
+    }
+    let mut times = [0u64; 7];
+    for (i, time) in times.iter_mut().enumerate() {
+        match fields.next() {
+            Some(field) => *time = field.parse().ok()?,
+            None if i >= 4 => break,
+            None => return None,
+        }
+    }
+    let [user, nice, system, idle, iowait, irq, softirq] = times;
+    Some([user + nice, system + irq + softirq, idle + iowait])
+}
+
+/// A clock to report real, user-CPU, and system-CPU clocks of another process.
+///
+/// It is obtained by [`ProcessCPUClock::for_pid`](crate::clock::ProcessCPUClock::for_pid).
+/// The CPU times are read from `/proc/<pid>/stat`, which is kept open across readings. It is
+/// only supported on Linux and Android, since the other systems lay out the file differently,
+/// if they have it at all.
+pub struct PidCpuClock {
+    pid: u32,
+    stat: File,
+}
+
+impl PidCpuClock {
+    pub(crate) fn open(pid: u32) -> Result<Self> {
+        if !cfg!(any(target_os = "linux", target_os = "android")) {
+            return Err(Error::Unsupported("PidCpuClock"));
+        }
+        let stat = File::open(format!("/proc/{}/stat", pid)).map_err(|e| {
+            Error::SystemError(
+                "open(/proc/<pid>/stat)",
+                e.raw_os_error().unwrap_or_default(),
+            )
+        })?;
+        Ok(PidCpuClock { pid, stat })
+    }
+
+    /// Return the process id.
+    pub fn pid(&self) -> u32 {
+        self.pid
+    }
+
+    /// Return the current timepoint.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the process does not exist anymore, or if
+    /// acessing to the underlying system calls failed.
+    pub fn try_now(&self) -> Result<ProcessTimePoint> {
+        let stat = read_proc_stat(&self.stat)?;
+        Ok(ProcessTimePoint {
+            real: crate::clock::HighResolutionClock::try_now()?.0,
+            user: stat.user,
+            system: stat.system,
+        })
+    }
+
+    /// Return the current timepoint.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying system calls failed.
+    /// Use [`try_now`](#method.try_now) if you want to handle the error.
+    pub fn now(&self) -> ProcessTimePoint {
+        self.try_now().expect("Failed to access the clock.")
+    }
+}
+
+/// A clock to report the user and system CPU time of the terminated and waited-for children,
+/// together with the wall-clock time.
+pub struct ChildrenCPUClock;
+
+impl Clock for ChildrenCPUClock {
+    type Output = ProcessTimePoint;
+    type Duration = ProcessDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        let (user, system) = getrusage(libc::RUSAGE_CHILDREN)?;
+        Ok(ProcessTimePoint {
+            real: crate::clock::HighResolutionClock::try_now()?.0,
+            user,
+            system,
+        })
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(Duration::from_micros(1))
+    }
+}
+
+/// A clock to report real, user-CPU, and system-CPU clocks of the calling process, including
+/// the terminated and waited-for child processes.
+// It is based on `getrusage(RUSAGE_SELF)` and `getrusage(RUSAGE_CHILDREN)` if available,
+// otherwise `times`.
+pub struct ProcessWithChildrenCPUClock;
+
+impl Clock for ProcessWithChildrenCPUClock {
+    type Output = ProcessTimePoint;
+    type Duration = ProcessDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        let (user, system, children_user, children_system) = match process_cpu_source() {
+            ProcessCPUSource::Rusage => {
+                let (user, system) = getrusage(libc::RUSAGE_SELF)?;
+                let (children_user, children_system) = getrusage(libc::RUSAGE_CHILDREN)?;
+                (user, system, children_user, children_system)
+            }
+            ProcessCPUSource::Times => {
+                let (_, tm) = times()?;
+                let factor = tick_factor()?;
+                (
+                    Duration::from_nanos((tm.tms_utime as u64) * factor),
+                    Duration::from_nanos((tm.tms_stime as u64) * factor),
+                    Duration::from_nanos((tm.tms_cutime as u64) * factor),
+                    Duration::from_nanos((tm.tms_cstime as u64) * factor),
+                )
+            }
+        };
         Ok(ProcessTimePoint {
-            real: Duration::from_nanos((c as u64) * factor),
-            user: Duration::from_nanos(((tm.tms_utime + tm.tms_cutime) as u64) * factor),
-            system: Duration::from_nanos(((tm.tms_stime + tm.tms_cstime) as u64) * factor),
+            real: process_real_time()?,
+            user: user + children_user,
+            system: system + children_system,
         })
     }
+
+    fn resolution() -> Result<Duration> {
+        process_cpu_resolution()
+    }
 }
 
 /// A clock to report the real thread wall-clock.
 pub struct ThreadClock;
 
-#[cfg(not(have_clock_thread_cputime_id))]
+#[cfg(have_pthread_getcpuclockid)]
 extern "C" {
     fn pthread_getcpuclockid(
         thread_id: libc::pthread_t,
@@ -144,34 +1021,306 @@ extern "C" {
     ) -> libc::c_int;
 }
 
-#[cfg(not(have_clock_thread_cputime_id))]
+/// Return the CPU-time clock id of the thread `thread`.
+///
+/// It is based on `pthread_getcpuclockid` if available. Otherwise, only the calling thread is
+/// supported on OpenBSD, and an [`Error::Unsupported`] is returned on the other systems.
+#[cfg(have_pthread_getcpuclockid)]
 #[inline(always)]
-fn get_thread_clock_id() -> Result<libc::clockid_t> {
+fn get_thread_clock_id(thread: libc::pthread_t) -> Result<libc::clockid_t> {
     let mut clock_id: libc::clockid_t = 0;
-    let ret = unsafe { pthread_getcpuclockid(libc::pthread_self(), &mut clock_id) };
+    let ret = unsafe { pthread_getcpuclockid(thread, &mut clock_id) };
     if ret != 0 {
-        return Err(Error::SystemError("pthread_getcpuclockid", errno()));
+        return Err(Error::SystemError("pthread_getcpuclockid", ret));
     }
     Ok(clock_id)
 }
 
+#[cfg(all(not(have_pthread_getcpuclockid), target_os = "openbsd"))]
+fn get_thread_clock_id(thread: libc::pthread_t) -> Result<libc::clockid_t> {
+    if unsafe { libc::pthread_equal(thread, libc::pthread_self()) } == 0 {
+        return Err(Error::Unsupported("pthread_getcpuclockid"));
+    }
+    // `__CLOCK_THREAD_CPUTIME_ID(tid)` of `<sys/_time.h>`
+    let tid = unsafe { libc::getthrid() };
+    Ok(libc::CLOCK_THREAD_CPUTIME_ID | (tid << 12))
+}
+
+#[cfg(all(not(have_pthread_getcpuclockid), not(target_os = "openbsd")))]
+fn get_thread_clock_id(_thread: libc::pthread_t) -> Result<libc::clockid_t> {
+    Err(Error::Unsupported("pthread_getcpuclockid"))
+}
+
+#[inline(always)]
+fn get_clock_time(clock_id: libc::clockid_t) -> Result<Duration> {
+    let mut ts = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    let ret = unsafe { libc::clock_gettime(clock_id, &mut ts) };
+    if ret != 0 {
+        return Err(Error::SystemError("clock_gettime", errno()));
+    }
+    Ok(Duration::from_secs(ts.tv_sec as u64) + Duration::from_nanos(ts.tv_nsec as u64))
+}
+
+#[inline(always)]
+fn get_clock_res(clock_id: libc::clockid_t) -> Result<Duration> {
+    let mut ts = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    let ret = unsafe { libc::clock_getres(clock_id, &mut ts) };
+    if ret != 0 {
+        return Err(Error::SystemError("clock_getres", errno()));
+    }
+    Ok(Duration::from_secs(ts.tv_sec as u64) + Duration::from_nanos(ts.tv_nsec as u64))
+}
+
 impl Clock for ThreadClock {
     type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
 
     fn try_now() -> Result<Self::Output> {
-        let mut ts = libc::timespec {
-            tv_sec: 0,
-            tv_nsec: 0,
-        };
+        // Fall back to `pthread_getcpuclockid` if the running kernel rejects the clock id.
         #[cfg(have_clock_thread_cputime_id)]
-        let clock_id = libc::CLOCK_THREAD_CPUTIME_ID;
-        #[cfg(not(have_clock_thread_cputime_id))]
-        let clock_id = get_thread_clock_id()?;
-        let ret = unsafe { libc::clock_gettime(clock_id, &mut ts) };
-        if ret != 0 {
-            return Err(Error::SystemError("clock_gettime", errno()));
+        match get_clock_time(libc::CLOCK_THREAD_CPUTIME_ID) {
+            Err(Error::SystemError(_, libc::EINVAL)) => {}
+            t => return Ok(TimePoint(t?)),
         }
-        let d = Duration::from_secs(ts.tv_sec as u64) + Duration::from_nanos(ts.tv_nsec as u64);
-        Ok(TimePoint(d))
+        let clock_id = get_thread_clock_id(unsafe { libc::pthread_self() })?;
+        Ok(TimePoint(get_clock_time(clock_id)?))
+    }
+
+    fn resolution() -> Result<Duration> {
+        #[cfg(have_clock_thread_cputime_id)]
+        match get_clock_res(libc::CLOCK_THREAD_CPUTIME_ID) {
+            Err(Error::SystemError(_, libc::EINVAL)) => {}
+            res => return res,
+        }
+        let clock_id = get_thread_clock_id(unsafe { libc::pthread_self() })?;
+        get_clock_res(clock_id)
+    }
+}
+
+/// A clock to report the user-CPU and system-CPU clocks of the calling thread.
+///
+/// On Linux, it is based on `getrusage(RUSAGE_THREAD)` with a microsecond resolution. Other
+/// Posix systems do not report a split per thread, so the whole CPU time of [`ThreadClock`] is
+/// reported as user-CPU time and the system-CPU time is always zero.
+pub struct ThreadCPUClock;
+
+impl Clock for ThreadCPUClock {
+    type Output = ThreadTimePoint;
+    type Duration = ThreadDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        #[cfg(target_os = "linux")]
+        let (user, system) = getrusage(libc::RUSAGE_THREAD)?;
+        #[cfg(not(target_os = "linux"))]
+        let (user, system) = (ThreadClock::try_now()?.0, Duration::ZERO);
+        Ok(ThreadTimePoint { user, system })
+    }
+
+    fn resolution() -> Result<Duration> {
+        #[cfg(target_os = "linux")]
+        return Ok(Duration::from_micros(1));
+        #[cfg(not(target_os = "linux"))]
+        return ThreadClock::resolution();
+    }
+}
+
+/// A handle to read the CPU-time clock of another thread.
+///
+/// It is based on the CPU-time clock id returned by `pthread_getcpuclockid`, or on
+/// `/proc/self/task/<tid>/stat` for a handle constructed by [`from_tid`](#method.from_tid).
+///
+/// The clock id only refers to the thread while it is running. Once the thread has exited, its
+/// id may be reused, so reading the handle may return an error or the CPU time of another
+/// thread, and it must not be read at all after the thread has been joined or detached. A
+/// handle constructed by [`from_tid`](#method.from_tid) keeps the file open and returns an
+/// error once the thread has exited.
+///
+/// Without `pthread_getcpuclockid`, e.g. on OpenBSD, [`from_pthread`](#method.from_pthread)
+/// returns [`Error::Unsupported`] except for the calling thread on OpenBSD.
+pub struct ThreadCpuHandle {
+    source: ThreadCpuSource,
+}
+
+enum ThreadCpuSource {
+    ClockId(libc::clockid_t),
+    #[cfg(target_os = "linux")]
+    Stat(File),
+}
+
+impl ThreadCpuHandle {
+    /// Construct a handle for the thread of `handle`. It must not be read after `handle` is
+    /// joined, see [`ThreadCpuHandle`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the CPU-time clock of the thread cannot be
+    /// obtained, e.g. the thread has exited.
+    pub fn for_join_handle<T>(handle: &JoinHandle<T>) -> Result<Self> {
+        // `RawPthread` is an integer, while `pthread_t` is a pointer on e.g. musl.
+        #[allow(clippy::unnecessary_cast)]
+        unsafe {
+            Self::from_pthread(handle.as_pthread_t() as usize as libc::pthread_t)
+        }
+    }
+
+    /// Construct a handle for the calling thread, e.g. to be read by other threads while it
+    /// runs.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the CPU-time clock of the thread cannot be
+    /// obtained.
+    pub fn current() -> Result<Self> {
+        unsafe { Self::from_pthread(libc::pthread_self()) }
+    }
+
+    /// Construct a handle for the thread `thread`, e.g. a thread created by a C library.
+    ///
+    /// # Safety
+    ///
+    /// `thread` must refer to a thread which has not been joined or detached yet. Passing a
+    /// `pthread_t` of a thread that has been joined is undefined behavior in most `libc`s.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the CPU-time clock of the thread cannot be
+    /// obtained.
+    pub unsafe fn from_pthread(thread: libc::pthread_t) -> Result<Self> {
+        Ok(ThreadCpuHandle {
+            source: ThreadCpuSource::ClockId(get_thread_clock_id(thread)?),
+        })
+    }
+
+    /// Construct a handle for the thread of the kernel thread id `tid` in the current process.
+    ///
+    /// The CPU times are read from `/proc/self/task/<tid>/stat`, which is kept open across
+    /// readings. Its resolution is limited to the clock ticks of the system, which are usually
+    /// 10ms. Once the thread has exited, the readings return an error.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the thread does not exist in the current process.
+    #[cfg(target_os = "linux")]
+    pub fn from_tid(tid: u32) -> Result<Self> {
+        let stat = File::open(format!("/proc/self/task/{}/stat", tid)).map_err(|e| {
+            Error::SystemError(
+                "open(/proc/self/task/<tid>/stat)",
+                e.raw_os_error().unwrap_or_default(),
+            )
+        })?;
+        Ok(ThreadCpuHandle {
+            source: ThreadCpuSource::Stat(stat),
+        })
+    }
+
+    /// Return the CPU time of the thread.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the thread has exited, or if acessing to the
+    /// underlying system calls failed.
+    pub fn try_now(&self) -> Result<TimePoint> {
+        match &self.source {
+            ThreadCpuSource::ClockId(clock_id) => Ok(TimePoint(get_clock_time(*clock_id)?)),
+            #[cfg(target_os = "linux")]
+            ThreadCpuSource::Stat(stat) => {
+                let stat = read_proc_stat(stat)?;
+                Ok(TimePoint(stat.user + stat.system))
+            }
+        }
+    }
+
+    /// Return the CPU time of the thread.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying system calls failed.
+    /// Use [`try_now`](#method.try_now) if you want to handle the error.
+    pub fn now(&self) -> TimePoint {
+        self.try_now().expect("Failed to access the clock.")
+    }
+
+    /// Return the CPU time spent by the thread since `start`, which is a timepoint returned by
+    /// this handle.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying system calls failed.
+    pub fn elapsed_since(&self, start: &TimePoint) -> Duration {
+        self.now().saturating_sub(*start)
+    }
+}
+
+#[inline(always)]
+fn timeval_to_duration(tv: libc::timeval) -> Duration {
+    Duration::from_secs(tv.tv_sec as u64) + Duration::from_micros(tv.tv_usec as u64)
+}
+
+/// Wait for `child` to exit, and return its exit status, user CPU time, and system CPU time.
+///
+/// The child is reaped by `wait4`, so it must not have been waited for before.
+pub(crate) fn wait_child(child: &mut Child) -> Result<(ExitStatus, Duration, Duration)> {
+    let mut status = 0;
+    let mut usage: libc::rusage = unsafe { mem::zeroed() };
+    loop {
+        let ret = unsafe { libc::wait4(child.id() as libc::pid_t, &mut status, 0, &mut usage) };
+        if ret != -1 {
+            break;
+        }
+        let err = errno();
+        if err != libc::EINTR {
+            return Err(Error::SystemError("wait4", err));
+        }
+    }
+    Ok((
+        ExitStatus::from_raw(status),
+        timeval_to_duration(usage.ru_utime),
+        timeval_to_duration(usage.ru_stime),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::Ordering;
+
+    #[test]
+    fn test_tick_factor_cached() {
+        let factor = tick_factor().unwrap();
+        for _ in 0..1000 {
+            assert_eq!(tick_factor().unwrap(), factor);
+        }
+        assert_eq!(SYSCONF_CLK_TCK_CALLS.load(Ordering::Relaxed), 1);
+        let ticks = unsafe { libc::sysconf(libc::_SC_CLK_TCK) };
+        assert_eq!(factor, 1_000_000_000 / ticks as u64);
+    }
+
+    #[test]
+    fn test_parse_proc_start_time() {
+        let stat = b"42 (a) b)) S 1 42 42 0 -1 4194560 100 0 0 0 7 3 1 2 20 0 1 0 12345 0 0\n";
+        assert_eq!(parse_proc_start_time(stat), Some(12345));
+        assert_eq!(parse_proc_start_time(b"42 (a) S 1 42"), None);
+    }
+
+    #[test]
+    fn test_parse_host_stat() {
+        let stat = b"cpu  10 1 20 300 4 2 3 5 0 0\ncpu0 5 0 10 150 2 1 1 2 0 0\nintr 1 2 3\n";
+        assert_eq!(parse_host_stat(stat), Some([11, 25, 304]));
+        // Linux 2.4 only reports the first four fields.
+        assert_eq!(parse_host_stat(b"cpu 10 1 20 300\n"), Some([11, 20, 300]));
+        assert_eq!(parse_host_stat(b"cpu 10 1 20\n"), None);
+        assert_eq!(parse_host_stat(b"cpu0 10 1 20 300\n"), None);
+        assert_eq!(parse_host_stat(b"cpu 10 x 20 300\n"), None);
+        assert_eq!(parse_host_stat(b""), None);
     }
 }
@@ -0,0 +1,172 @@
+#[cfg(have_steady_clock)]
+use crate::clock::SteadyClock;
+use crate::{Clock, Duration, Error, Result, TimePoint};
+use std::sync::atomic::{AtomicPtr, Ordering};
+use std::sync::OnceLock;
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::{__cpuid, _rdtsc};
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::{__cpuid, _rdtsc};
+
+/// The duration over which the frequency of the timestamp counter is calibrated.
+#[cfg(have_steady_clock)]
+const CALIBRATION_PERIOD: Duration = Duration::from_millis(10);
+
+/// The current calibration of the timestamp counter, or null if not calibrated yet. A
+/// calibration is never freed once published, since a reader might still hold it.
+static CALIBRATION: AtomicPtr<Calibration> = AtomicPtr::new(core::ptr::null_mut());
+
+/// Convert the ticks after `base_ticks` to the time after `base`, at `frequency` ticks per
+/// second.
+struct Calibration {
+    base_ticks: u64,
+    base: Duration,
+    frequency: u64,
+}
+
+impl Calibration {
+    fn to_duration(&self, ticks: u64) -> Duration {
+        let elapsed = ticks.saturating_sub(self.base_ticks);
+        self.base
+            .saturating_add(super::scale_ticks(elapsed, 1_000_000_000, self.frequency))
+    }
+
+    fn get() -> Result<&'static Calibration> {
+        // SAFETY: A published calibration is never freed.
+        match unsafe { CALIBRATION.load(Ordering::Acquire).as_ref() } {
+            Some(calibration) => Ok(calibration),
+            None => Self::update(false),
+        }
+    }
+
+    /// Calibrate the timestamp counter, and publish it if `replace` is true or there is no
+    /// calibration yet. The new base is never earlier than the time of the current
+    /// calibration, so that the timepoints do not go backwards.
+    fn update(replace: bool) -> Result<&'static Calibration> {
+        if !has_invariant_tsc() {
+            return Err(Error::Unsupported("TscClock"));
+        }
+        let (frequency, base_ticks, now) = calibrate()?;
+        let mut current = CALIBRATION.load(Ordering::Acquire);
+        loop {
+            // SAFETY: A published calibration is never freed.
+            let base = match unsafe { current.as_ref() } {
+                Some(calibration) if !replace => return Ok(calibration),
+                Some(calibration) => now.max(calibration.to_duration(base_ticks)),
+                None => now,
+            };
+            let new = Box::into_raw(Box::new(Calibration {
+                base_ticks,
+                base,
+                frequency,
+            }));
+            match CALIBRATION.compare_exchange(current, new, Ordering::AcqRel, Ordering::Acquire) {
+                // SAFETY: `new` was just published, and it is never freed.
+                Ok(_) => return Ok(unsafe { &*new }),
+                Err(actual) => {
+                    // SAFETY: `new` was not published.
+                    drop(unsafe { Box::from_raw(new) });
+                    current = actual;
+                }
+            }
+        }
+    }
+}
+
+/// Measure the frequency of the timestamp counter against [`SteadyClock`], and return it in Hz
+/// with the ticks and the time at the end of the measurement.
+#[cfg(have_steady_clock)]
+fn calibrate() -> Result<(u64, u64, Duration)> {
+    let (start, start_ticks) = (SteadyClock::try_now()?, rdtsc());
+    std::thread::sleep(CALIBRATION_PERIOD);
+    let (end, end_ticks) = (SteadyClock::try_now()?, rdtsc());
+    let nanos = end.saturating_sub(start).as_nanos();
+    let ticks = end_ticks.wrapping_sub(start_ticks) as u128;
+    if nanos == 0 || ticks == 0 {
+        return Err(Error::Unsupported("TscClock"));
+    }
+    let frequency = (ticks * 1_000_000_000 / nanos) as u64;
+    Ok((frequency, end_ticks, end.0))
+}
+
+#[cfg(not(have_steady_clock))]
+fn calibrate() -> Result<(u64, u64, Duration)> {
+    Err(Error::Unsupported("TscClock"))
+}
+
+/// A clock based on the timestamp counter (TSC) of x86 processors.
+///
+/// Reading it is a single `rdtsc` instruction, which is much cheaper than a system call or
+/// even a vDSO call. The ticks are converted to nanoseconds using a frequency calibrated
+/// against [`SteadyClock`] for about 10ms at first use, which can be repeated by
+/// [`recalibrate`](TscClock::recalibrate), before the clock is read for measurements.
+///
+/// It is only available on processors with an invariant TSC, i.e. one that ticks at a constant
+/// rate regardless of the power state. Even then, be aware that:
+/// * The counters of different sockets might not be synchronized, so timepoints read on
+///   different CPUs, e.g. before and after a thread migration, might not be comparable.
+/// * Hypervisors might not expose a stable TSC to virtual machines, or might emulate `rdtsc`,
+///   which makes it slower and less accurate.
+pub struct TscClock;
+
+impl TscClock {
+    /// Return the calibrated frequency of the timestamp counter in Hz. The clock is calibrated
+    /// first if it is not yet.
+    ///
+    /// # Errors
+    ///
+    /// This function will return [`Error::Unsupported`] if the processor does not have an
+    /// invariant TSC, or an error if acessing to the underlying clock failed.
+    pub fn frequency() -> Result<u64> {
+        Calibration::get().map(|calibration| calibration.frequency)
+    }
+
+    /// Calibrate the frequency of the timestamp counter again, and return it in Hz.
+    ///
+    /// The new frequency replaces the one of the whole process. It only applies to the ticks
+    /// counted after the calibration, so that the timepoints read before and after stay
+    /// comparable and do not go backwards, but a duration spanning it is measured partly with
+    /// each of the frequencies.
+    ///
+    /// # Errors
+    ///
+    /// This function will return [`Error::Unsupported`] if the processor does not have an
+    /// invariant TSC, or an error if acessing to the underlying clock failed.
+    pub fn recalibrate() -> Result<u64> {
+        Calibration::update(true).map(|calibration| calibration.frequency)
+    }
+}
+
+/// Return true if the processor has an invariant TSC.
+fn has_invariant_tsc() -> bool {
+    static INVARIANT_TSC: OnceLock<bool> = OnceLock::new();
+    // `__cpuid` is a safe function in recent Rust versions.
+    #[allow(unused_unsafe)]
+    *INVARIANT_TSC.get_or_init(|| unsafe {
+        // CPUID.80000007H:EDX[8] indicates the invariant TSC.
+        __cpuid(0x8000_0000).eax >= 0x8000_0007 && __cpuid(0x8000_0007).edx & (1 << 8) != 0
+    })
+}
+
+#[inline(always)]
+fn rdtsc() -> u64 {
+    unsafe { _rdtsc() }
+}
+
+impl Clock for TscClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        let calibration = Calibration::get()?;
+        Ok(TimePoint(calibration.to_duration(rdtsc())))
+    }
+
+    fn resolution() -> Result<Duration> {
+        let frequency = Self::frequency()?;
+        Ok(Duration::from_nanos((1_000_000_000 / frequency).max(1)))
+    }
+}
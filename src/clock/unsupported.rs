@@ -0,0 +1,309 @@
+// A fallback for the platforms without a supported backend. It compiles everywhere, and every
+// clock returns `Error::Unsupported` at runtime.
+
+use crate::{
+    Clock, Duration, Error, ProcessDuration, ProcessTimePoint, Result, ThreadDuration,
+    ThreadTimePoint, TimePoint,
+};
+#[cfg(feature = "std")]
+use std::process::{Child, ExitStatus};
+#[cfg(feature = "std")]
+use std::thread::JoinHandle;
+
+/// A system clock.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = false;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        Err(Error::Unsupported("SystemClock"))
+    }
+}
+
+/// A clock to report the time since the system booted.
+pub struct UptimeClock;
+
+impl UptimeClock {
+    /// Return the time since the system booted.
+    ///
+    /// # Errors
+    ///
+    /// This function always returns [`Error::Unsupported`] on this platform.
+    pub fn uptime() -> Result<Duration> {
+        Ok(Self::try_now()?.0)
+    }
+}
+
+impl Clock for UptimeClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        Err(Error::Unsupported("UptimeClock"))
+    }
+}
+
+/// A clock to report the time since the calling process started.
+pub struct ProcessUptimeClock;
+
+impl ProcessUptimeClock {
+    /// Return the time since the calling process started.
+    ///
+    /// # Errors
+    ///
+    /// This function always returns [`Error::Unsupported`] on this platform.
+    pub fn uptime() -> Result<Duration> {
+        Ok(Self::try_now()?.0)
+    }
+}
+
+impl Clock for ProcessUptimeClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        Err(Error::Unsupported("ProcessUptimeClock"))
+    }
+}
+
+/// A clock to report the real process wall-clock.
+pub struct ProcessRealCPUClock;
+
+impl Clock for ProcessRealCPUClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        Err(Error::Unsupported("ProcessRealCPUClock"))
+    }
+}
+
+/// A clock to report the user cpu-clock.
+pub struct ProcessUserCPUClock;
+
+impl Clock for ProcessUserCPUClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        Err(Error::Unsupported("ProcessUserCPUClock"))
+    }
+}
+
+/// A clock to report the system cpu-clock.
+pub struct ProcessSystemCPUClock;
+
+impl Clock for ProcessSystemCPUClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        Err(Error::Unsupported("ProcessSystemCPUClock"))
+    }
+}
+
+/// A clock to report real, user-CPU, and system-CPU clocks.
+pub struct ProcessCPUClock;
+
+impl ProcessCPUClock {
+    /// Return the total, i.e. user plus system, CPU time of the calling process.
+    ///
+    /// # Errors
+    ///
+    /// This function always returns [`Error::Unsupported`] on this platform.
+    pub fn total() -> Result<Duration> {
+        Err(Error::Unsupported("ProcessCPUClock"))
+    }
+}
+
+impl Clock for ProcessCPUClock {
+    type Output = ProcessTimePoint;
+    type Duration = ProcessDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        Err(Error::Unsupported("ProcessCPUClock"))
+    }
+}
+
+/// A clock to report real, user-CPU, and system-CPU clocks of another process.
+pub struct PidCpuClock {
+    pid: u32,
+}
+
+impl PidCpuClock {
+    pub(crate) fn open(_pid: u32) -> Result<Self> {
+        Err(Error::Unsupported("PidCpuClock"))
+    }
+
+    /// Return the process id.
+    pub fn pid(&self) -> u32 {
+        self.pid
+    }
+
+    /// Return the current timepoint.
+    ///
+    /// # Errors
+    ///
+    /// This function always returns [`Error::Unsupported`] on this platform.
+    pub fn try_now(&self) -> Result<ProcessTimePoint> {
+        Err(Error::Unsupported("PidCpuClock"))
+    }
+
+    /// Return the current timepoint.
+    ///
+    /// # Panics
+    ///
+    /// This function always panics on this platform.
+    pub fn now(&self) -> ProcessTimePoint {
+        self.try_now().expect("Failed to access the clock.")
+    }
+}
+
+/// A clock to report the user and system CPU time of the child processes, together with the
+/// wall-clock time.
+pub struct ChildrenCPUClock;
+
+impl Clock for ChildrenCPUClock {
+    type Output = ProcessTimePoint;
+    type Duration = ProcessDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        Err(Error::Unsupported("ChildrenCPUClock"))
+    }
+}
+
+/// A clock to report real, user-CPU, and system-CPU clocks of the calling process, including
+/// the child processes.
+pub struct ProcessWithChildrenCPUClock;
+
+impl Clock for ProcessWithChildrenCPUClock {
+    type Output = ProcessTimePoint;
+    type Duration = ProcessDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        Err(Error::Unsupported("ProcessWithChildrenCPUClock"))
+    }
+}
+
+/// A clock to report the real thread wall-clock.
+pub struct ThreadClock;
+
+impl Clock for ThreadClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        Err(Error::Unsupported("ThreadClock"))
+    }
+}
+
+/// A clock to report the user-CPU and system-CPU clocks of the calling thread.
+pub struct ThreadCPUClock;
+
+impl Clock for ThreadCPUClock {
+    type Output = ThreadTimePoint;
+    type Duration = ThreadDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        Err(Error::Unsupported("ThreadCPUClock"))
+    }
+}
+
+/// A clock to report the user-CPU, system-CPU, and idle times of all the processors of the
+/// host.
+pub struct HostCPUClock;
+
+impl Clock for HostCPUClock {
+    type Output = crate::HostTimePoint;
+    type Duration = crate::HostDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        Err(Error::Unsupported("HostCPUClock"))
+    }
+}
+
+/// A handle to read the CPU-time clock of another thread.
+pub struct ThreadCpuHandle {
+    _private: (),
+}
+
+impl ThreadCpuHandle {
+    /// Construct a handle for the thread of `handle`.
+    ///
+    /// # Errors
+    ///
+    /// This function always returns [`Error::Unsupported`] on this platform.
+    #[cfg(feature = "std")]
+    pub fn for_join_handle<T>(_handle: &JoinHandle<T>) -> Result<Self> {
+        Err(Error::Unsupported("ThreadCpuHandle"))
+    }
+
+    /// Construct a handle for the calling thread.
+    ///
+    /// # Errors
+    ///
+    /// This function always returns [`Error::Unsupported`] on this platform.
+    pub fn current() -> Result<Self> {
+        Err(Error::Unsupported("ThreadCpuHandle"))
+    }
+
+    /// Return the CPU time of the thread.
+    ///
+    /// # Errors
+    ///
+    /// This function always returns [`Error::Unsupported`] on this platform.
+    pub fn try_now(&self) -> Result<TimePoint> {
+        Err(Error::Unsupported("ThreadCpuHandle"))
+    }
+
+    /// Return the CPU time of the thread.
+    ///
+    /// # Panics
+    ///
+    /// This function always panics on this platform.
+    pub fn now(&self) -> TimePoint {
+        self.try_now().expect("Failed to access the clock.")
+    }
+
+    /// Return the CPU time spent by the thread since `start`, which is a timepoint returned by
+    /// this handle.
+    ///
+    /// # Panics
+    ///
+    /// This function always panics on this platform.
+    pub fn elapsed_since(&self, start: &TimePoint) -> Duration {
+        self.now().saturating_sub(*start)
+    }
+}
+
+/// Wait for `child` to exit, and return its exit status, user CPU time, and system CPU time.
+#[cfg(feature = "std")]
+pub(crate) fn wait_child(_child: &mut Child) -> Result<(ExitStatus, Duration, Duration)> {
+    Err(Error::Unsupported("wait_child"))
+}
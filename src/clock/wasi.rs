@@ -0,0 +1,247 @@
+// A backend for WASI using `clock_time_get` and `clock_res_get`. WASI does not split the CPU
+// time into user and system times, so the whole CPU time is reported as user-CPU time.
+
+use crate::{
+    Clock, Duration, Error, ProcessDuration, ProcessTimePoint, RawInstant, Result, ThreadDuration,
+    ThreadTimePoint, TimePoint,
+};
+
+#[allow(dead_code)]
+#[path = "./unsupported.rs"]
+mod unsupported;
+
+/// Return the name of the WASI clock `clock_id` for the error messages.
+fn clock_name(clock_id: ::wasi::Clockid) -> &'static str {
+    match clock_id {
+        ::wasi::CLOCKID_REALTIME => "CLOCK_REALTIME",
+        ::wasi::CLOCKID_MONOTONIC => "CLOCK_MONOTONIC",
+        ::wasi::CLOCKID_PROCESS_CPUTIME_ID => "CLOCK_PROCESS_CPUTIME_ID",
+        ::wasi::CLOCKID_THREAD_CPUTIME_ID => "CLOCK_THREAD_CPUTIME_ID",
+        _ => "clock_time_get",
+    }
+}
+
+/// Map the error of a WASI call, where the clocks not implemented by the runtime are reported
+/// as [`Error::Unsupported`]. The runtimes disagree on the errno of an unknown clock, e.g.
+/// wasmtime returns `ERRNO_BADF` for the CPU-time clocks.
+fn map_errno(func: &'static str, clock_id: ::wasi::Clockid, errno: ::wasi::Errno) -> Error {
+    if errno == ::wasi::ERRNO_NOTSUP || errno == ::wasi::ERRNO_INVAL || errno == ::wasi::ERRNO_BADF
+    {
+        Error::Unsupported(clock_name(clock_id))
+    } else {
+        Error::SystemError(func, errno.raw() as i32)
+    }
+}
+
+fn get_clock_time(clock_id: ::wasi::Clockid) -> Result<Duration> {
+    let ns = unsafe { ::wasi::clock_time_get(clock_id, 1) }
+        .map_err(|errno| map_errno("clock_time_get", clock_id, errno))?;
+    Ok(Duration::from_nanos(ns))
+}
+
+fn get_clock_res(clock_id: ::wasi::Clockid) -> Result<Duration> {
+    let ns = unsafe { ::wasi::clock_res_get(clock_id) }
+        .map_err(|errno| map_errno("clock_res_get", clock_id, errno))?;
+    Ok(Duration::from_nanos(ns))
+}
+
+/// A system clock.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = false;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(TimePoint(get_clock_time(::wasi::CLOCKID_REALTIME)?))
+    }
+
+    fn resolution() -> Result<Duration> {
+        get_clock_res(::wasi::CLOCKID_REALTIME)
+    }
+}
+
+/// A steady clock.
+pub struct SteadyClock;
+
+impl Clock for SteadyClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(TimePoint(get_clock_time(::wasi::CLOCKID_MONOTONIC)?))
+    }
+
+    fn resolution() -> Result<Duration> {
+        get_clock_res(::wasi::CLOCKID_MONOTONIC)
+    }
+}
+
+impl SteadyClock {
+    /// Return the raw reading of the clock, i.e. the nanoseconds of `CLOCK_MONOTONIC`, to be
+    /// converted later by [`SteadyClock::convert`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error like [`SteadyClock::try_now`].
+    #[inline(always)]
+    pub fn raw_now() -> Result<RawInstant> {
+        Ok(RawInstant(Self::try_now()?.0.as_nanos() as u64))
+    }
+}
+
+/// Convert the raw ticks of [`SteadyClock`], which are already nanoseconds, to [`Duration`].
+#[inline(always)]
+pub(crate) fn raw_ticks_to_duration(ticks: u64) -> Duration {
+    Duration::from_nanos(ticks)
+}
+
+/// A clock to report the real process wall-clock.
+pub struct ProcessRealCPUClock;
+
+impl Clock for ProcessRealCPUClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        SteadyClock::try_now()
+    }
+
+    fn resolution() -> Result<Duration> {
+        SteadyClock::resolution()
+    }
+}
+
+/// A clock to report the user cpu-clock.
+///
+/// It reports the whole CPU time of the calling process by `CLOCK_PROCESS_CPUTIME_ID`.
+pub struct ProcessUserCPUClock;
+
+impl Clock for ProcessUserCPUClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(TimePoint(ProcessCPUClock::total()?))
+    }
+
+    fn resolution() -> Result<Duration> {
+        get_clock_res(::wasi::CLOCKID_PROCESS_CPUTIME_ID)
+    }
+}
+
+/// A clock to report the system cpu-clock.
+///
+/// The whole CPU time is reported as user-CPU time, so it is always zero, like the system-CPU
+/// time of [`ProcessCPUClock`].
+pub struct ProcessSystemCPUClock;
+
+impl Clock for ProcessSystemCPUClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(TimePoint(Duration::ZERO))
+    }
+
+    fn resolution() -> Result<Duration> {
+        ProcessUserCPUClock::resolution()
+    }
+}
+
+/// A clock to report real, user-CPU, and system-CPU clocks.
+///
+/// The whole CPU time is reported as user-CPU time, and the system-CPU time is always zero.
+pub struct ProcessCPUClock;
+
+impl ProcessCPUClock {
+    /// Return the total, i.e. user plus system, CPU time of the calling process.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the underlying system calls failed,
+    /// or [`Error::Unsupported`] if the runtime does not implement `CLOCK_PROCESS_CPUTIME_ID`.
+    pub fn total() -> Result<Duration> {
+        get_clock_time(::wasi::CLOCKID_PROCESS_CPUTIME_ID)
+    }
+}
+
+impl Clock for ProcessCPUClock {
+    type Output = ProcessTimePoint;
+    type Duration = ProcessDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(ProcessTimePoint {
+            real: SteadyClock::try_now()?.0,
+            user: Self::total()?,
+            system: Duration::ZERO,
+        })
+    }
+
+    fn resolution() -> Result<Duration> {
+        get_clock_res(::wasi::CLOCKID_PROCESS_CPUTIME_ID)
+    }
+}
+
+/// A clock to report the real thread wall-clock.
+pub struct ThreadClock;
+
+impl Clock for ThreadClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(TimePoint(get_clock_time(
+            ::wasi::CLOCKID_THREAD_CPUTIME_ID,
+        )?))
+    }
+
+    fn resolution() -> Result<Duration> {
+        get_clock_res(::wasi::CLOCKID_THREAD_CPUTIME_ID)
+    }
+}
+
+/// A clock to report the user-CPU and system-CPU clocks of the calling thread.
+///
+/// The whole CPU time of [`ThreadClock`] is reported as user-CPU time, and the system-CPU time
+/// is always zero.
+pub struct ThreadCPUClock;
+
+impl Clock for ThreadCPUClock {
+    type Output = ThreadTimePoint;
+    type Duration = ThreadDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(ThreadTimePoint {
+            user: ThreadClock::try_now()?.0,
+            system: Duration::ZERO,
+        })
+    }
+
+    fn resolution() -> Result<Duration> {
+        ThreadClock::resolution()
+    }
+}
+
+pub use unsupported::{
+    ChildrenCPUClock, HostCPUClock, PidCpuClock, ProcessUptimeClock, ProcessWithChildrenCPUClock,
+    ThreadCpuHandle, UptimeClock,
+};
+
+pub(crate) use unsupported::wait_child;
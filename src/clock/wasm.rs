@@ -0,0 +1,99 @@
+// A backend for `wasm32-unknown-unknown` using the JavaScript `Date` and `performance` APIs.
+// There is no way to read CPU times, so these clocks are taken from the fallback.
+
+use crate::{Clock, Duration, Error, RawInstant, Result, TimePoint};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::Performance;
+
+#[allow(dead_code)]
+#[path = "./unsupported.rs"]
+mod unsupported;
+
+/// Return `performance.now()` of the global object, which is the window or a worker.
+fn performance_now() -> Result<Duration> {
+    thread_local! {
+        static PERFORMANCE: Option<Performance> =
+            js_sys::Reflect::get(&js_sys::global(), &JsValue::from_str("performance"))
+                .ok()
+                .and_then(|performance| performance.dyn_into().ok());
+    }
+    let ms = PERFORMANCE
+        .with(|performance| performance.as_ref().map(Performance::now))
+        .ok_or(Error::Unsupported("performance.now"))?;
+    Ok(Duration::from_nanos((ms * 1_000_000.0) as u64))
+}
+
+/// A system clock.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = false;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(TimePoint(Duration::from_millis(js_sys::Date::now() as u64)))
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(Duration::from_millis(1))
+    }
+}
+
+/// A steady clock.
+///
+/// The resolution of `performance.now()` is coarsened by the browsers, and is not reported.
+pub struct SteadyClock;
+
+impl Clock for SteadyClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(TimePoint(performance_now()?))
+    }
+}
+
+impl SteadyClock {
+    /// Return the raw reading of the clock, i.e. `performance.now()` in nanoseconds, to be
+    /// converted later by [`SteadyClock::convert`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error like [`SteadyClock::try_now`].
+    #[inline(always)]
+    pub fn raw_now() -> Result<RawInstant> {
+        Ok(RawInstant(performance_now()?.as_nanos() as u64))
+    }
+}
+
+/// Convert the raw ticks of [`SteadyClock`], which are already nanoseconds, to [`Duration`].
+#[inline(always)]
+pub(crate) fn raw_ticks_to_duration(ticks: u64) -> Duration {
+    Duration::from_nanos(ticks)
+}
+
+/// A clock to report the real process wall-clock.
+pub struct ProcessRealCPUClock;
+
+impl Clock for ProcessRealCPUClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(TimePoint(performance_now()?))
+    }
+}
+
+pub use unsupported::{
+    ChildrenCPUClock, HostCPUClock, PidCpuClock, ProcessCPUClock, ProcessSystemCPUClock,
+    ProcessUptimeClock, ProcessUserCPUClock, ProcessWithChildrenCPUClock, ThreadCPUClock,
+    ThreadClock, ThreadCpuHandle, UptimeClock,
+};
+
+pub(crate) use unsupported::wait_child;
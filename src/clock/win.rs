@@ -1,16 +1,36 @@
 // Ref: https://github.com/boostorg/chrono/tree/develop/include/boost/chrono/detail/inlined/win
 
-use core::mem;
-use winapi::shared::minwindef::FILETIME;
+use core::{mem, ptr};
+use std::os::windows::io::AsRawHandle;
+use std::process::{Child, ExitStatus};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+use std::thread::JoinHandle;
+use winapi::shared::minwindef::{BOOL, DWORD, FALSE, FARPROC, FILETIME};
+use winapi::shared::ntdef::ULONGLONG;
 use winapi::um::{
     errhandlingapi::GetLastError,
-    processthreadsapi::{GetCurrentProcess, GetCurrentThread, GetProcessTimes, GetThreadTimes},
+    handleapi::{CloseHandle, DuplicateHandle},
+    jobapi2::{AssignProcessToJobObject, CreateJobObjectW, QueryInformationJobObject},
+    libloaderapi::{GetModuleHandleA, GetProcAddress},
+    processthreadsapi::{
+        GetCurrentProcess, GetCurrentThread, GetCurrentThreadId, GetProcessTimes, GetSystemTimes,
+        GetThreadTimes, OpenProcess, OpenThread,
+    },
     profileapi::{QueryPerformanceCounter, QueryPerformanceFrequency},
-    sysinfoapi::GetSystemTimeAsFileTime,
-    winnt::LARGE_INTEGER,
+    realtimeapiset::{QueryProcessCycleTime, QueryThreadCycleTime},
+    sysinfoapi::{GetSystemTimeAdjustment, GetSystemTimeAsFileTime, GetTickCount64},
+    winnt::{
+        JobObjectBasicAccountingInformation, DUPLICATE_SAME_ACCESS, HANDLE,
+        JOBOBJECT_BASIC_ACCOUNTING_INFORMATION, LARGE_INTEGER, PROCESS_QUERY_LIMITED_INFORMATION,
+        THREAD_QUERY_LIMITED_INFORMATION,
+    },
 };
 
-use crate::{Clock, Duration, Error, ProcessTimePoint, Result, TimePoint};
+use crate::{
+    Clock, CycleTimePoint, Cycles, Duration, Error, ProcessDuration, ProcessTimePoint, RawInstant,
+    Result, ThreadDuration, ThreadTimePoint, TimePoint,
+};
 
 fn errno() -> i32 {
     unsafe { GetLastError() as i32 }
@@ -21,20 +41,104 @@ fn filetime_to_duration(ft: FILETIME) -> Duration {
     Duration::from_nanos((((ft.dwHighDateTime as u64) << 32) | (ft.dwLowDateTime as u64)) * 100)
 }
 
+/// Return the interval between the clock interrupts, which is the resolution of the clocks
+/// updated by them, e.g. `GetSystemTimeAsFileTime`, `GetTickCount64`, and `GetProcessTimes`.
+fn time_increment() -> Result<Duration> {
+    let mut adjustment: DWORD = 0;
+    let mut increment: DWORD = 0;
+    let mut disabled: BOOL = 0;
+    let ret = unsafe { GetSystemTimeAdjustment(&mut adjustment, &mut increment, &mut disabled) };
+    if ret == 0 {
+        return Err(Error::SystemError("GetSystemTimeAdjustment", errno()));
+    }
+    Ok(Duration::from_nanos(increment as u64 * 100))
+}
+
+/// Look up the function `name` exported by the loaded module `module`. Both names must be
+/// nul-terminated.
+fn get_proc_address(module: &[u8], name: &[u8]) -> Option<FARPROC> {
+    let module = unsafe { GetModuleHandleA(module.as_ptr() as *const i8) };
+    if module.is_null() {
+        return None;
+    }
+    let func = unsafe { GetProcAddress(module, name.as_ptr() as *const i8) };
+    if func.is_null() {
+        None
+    } else {
+        Some(func)
+    }
+}
+
+type GetSystemTimePreciseAsFileTime = unsafe extern "system" fn(*mut FILETIME);
+
+fn get_system_time_precise_as_file_time() -> Option<GetSystemTimePreciseAsFileTime> {
+    static FUNC: OnceLock<Option<GetSystemTimePreciseAsFileTime>> = OnceLock::new();
+    *FUNC.get_or_init(|| {
+        let func = get_proc_address(b"kernel32.dll\0", b"GetSystemTimePreciseAsFileTime\0")?;
+        Some(unsafe { mem::transmute::<FARPROC, GetSystemTimePreciseAsFileTime>(func) })
+    })
+}
+
 /// A system clock.
+// It is based on `GetSystemTimePreciseAsFileTime`, which is available since Windows 8. On
+// older systems, it falls back to `GetSystemTimeAsFileTime`, which only ticks at the clock
+// interrupts, i.e. every 15.6ms by default.
 pub struct SystemClock;
 
 impl Clock for SystemClock {
     type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = false;
+    const IS_CPU_TIME: bool = false;
 
     fn try_now() -> Result<Self::Output> {
         let mut ft = FILETIME {
             dwLowDateTime: 0,
             dwHighDateTime: 0,
         };
-        unsafe { GetSystemTimeAsFileTime(&mut ft) };
+        match get_system_time_precise_as_file_time() {
+            Some(func) => unsafe { func(&mut ft) },
+            None => unsafe { GetSystemTimeAsFileTime(&mut ft) },
+        }
         Ok(TimePoint(filetime_to_duration(ft)))
     }
+
+    fn resolution() -> Result<Duration> {
+        match get_system_time_precise_as_file_time() {
+            Some(_) => Ok(Duration::from_nanos(100)),
+            None => time_increment(),
+        }
+    }
+}
+
+/// Return the cached frequency of `QueryPerformanceCounter` in Hz, which is fixed at boot.
+fn qpc_frequency() -> Result<u64> {
+    static FREQUENCY: OnceLock<u64> = OnceLock::new();
+    if let Some(frequency) = FREQUENCY.get() {
+        return Ok(*frequency);
+    }
+    let mut freq: LARGE_INTEGER = unsafe { mem::zeroed() };
+    let ret = unsafe { QueryPerformanceFrequency(&mut freq) };
+    if ret == 0 {
+        return Err(Error::SystemError("QueryPerformanceFrequency", errno()));
+    }
+    Ok(*FREQUENCY.get_or_init(|| unsafe { *freq.QuadPart() } as u64))
+}
+
+#[inline(always)]
+fn qpc() -> Result<u64> {
+    let mut cnt: LARGE_INTEGER = unsafe { mem::zeroed() };
+    let ret = unsafe { QueryPerformanceCounter(&mut cnt) };
+    if ret == 0 {
+        return Err(Error::SystemError("QueryPerformanceCounter", errno()));
+    }
+    Ok(unsafe { *cnt.QuadPart() } as u64)
+}
+
+/// Convert `ticks` of `QueryPerformanceCounter` ticking at `frequency` to [`Duration`].
+#[inline(always)]
+fn qpc_ticks_to_duration(ticks: u64, frequency: u64) -> Duration {
+    super::scale_ticks(ticks, 1_000_000_000, frequency)
 }
 
 /// A steady clock.
@@ -42,22 +146,226 @@ pub struct SteadyClock;
 
 impl Clock for SteadyClock {
     type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
 
     fn try_now() -> Result<Self::Output> {
-        let mut freq: LARGE_INTEGER = unsafe { mem::zeroed() };
-        let ret = unsafe { QueryPerformanceFrequency(&mut freq) };
-        if ret == 0 {
-            return Err(Error::SystemError("QueryPerformanceFrequency", errno()));
-        }
-        let factor = (1_000_000_000 / unsafe { *freq.QuadPart() }) as u64;
-        let mut cnt: LARGE_INTEGER = unsafe { mem::zeroed() };
-        let ret = unsafe { QueryPerformanceCounter(&mut cnt) };
-        if ret == 0 {
-            return Err(Error::SystemError("QueryPerformanceCounter", errno()));
-        }
-        let d = Duration::from_nanos(factor * unsafe { *cnt.QuadPart() as u64 });
+        let frequency = qpc_frequency()?;
+        Ok(TimePoint(qpc_ticks_to_duration(qpc()?, frequency)))
+    }
+
+    fn resolution() -> Result<Duration> {
+        let ticks = qpc_ticks_to_duration(1, qpc_frequency()?);
+        Ok(ticks.max(Duration::from_nanos(1)))
+    }
+}
+
+impl SteadyClock {
+    /// Return the raw reading of the clock, i.e. the ticks of `QueryPerformanceCounter`, to be
+    /// converted later by [`SteadyClock::convert`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the underlying system calls failed.
+    #[inline(always)]
+    pub fn raw_now() -> Result<RawInstant> {
+        qpc_frequency()?;
+        Ok(RawInstant(qpc()?))
+    }
+}
+
+/// Convert the raw ticks of [`SteadyClock`] to [`Duration`] using the frequency cached by
+/// [`SteadyClock::raw_now`].
+#[inline(always)]
+pub(crate) fn raw_ticks_to_duration(ticks: u64) -> Duration {
+    qpc_frequency().map_or(Duration::ZERO, |frequency| {
+        qpc_ticks_to_duration(ticks, frequency)
+    })
+}
+
+/// A system clock which is faster to read but less precise than `SystemClock`.
+// `GetSystemTimeAsFileTime` is already cheap to read and ticks at the clock interrupts.
+pub struct CoarseSystemClock;
+
+impl Clock for CoarseSystemClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = false;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        let mut ft = FILETIME {
+            dwLowDateTime: 0,
+            dwHighDateTime: 0,
+        };
+        unsafe { GetSystemTimeAsFileTime(&mut ft) };
+        Ok(TimePoint(filetime_to_duration(ft)))
+    }
+
+    fn resolution() -> Result<Duration> {
+        time_increment()
+    }
+}
+
+/// A steady clock which is faster to read but less precise than `SteadyClock`.
+// It is based on `GetTickCount64`, which ticks at the clock interrupts.
+pub struct CoarseSteadyClock;
+
+impl Clock for CoarseSteadyClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(TimePoint(Duration::from_millis(unsafe {
+            GetTickCount64()
+        })))
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(time_increment()?.max(Duration::from_millis(1)))
+    }
+}
+
+/// A steady clock which keeps counting while the system is suspended.
+// It is based on `QueryInterruptTimePrecise`, which is available since Windows 10. Note that
+// `QueryUnbiasedInterruptTime` and `QueryPerformanceCounter` exclude the time the system spends
+// asleep. On older systems, it falls back to `GetTickCount64` with a millisecond resolution.
+pub struct SuspendAwareClock;
+
+type QueryInterruptTimePrecise = unsafe extern "system" fn(*mut ULONGLONG);
+
+fn query_interrupt_time_precise() -> Option<QueryInterruptTimePrecise> {
+    static FUNC: OnceLock<Option<QueryInterruptTimePrecise>> = OnceLock::new();
+    *FUNC.get_or_init(|| {
+        let func = get_proc_address(b"kernelbase.dll\0", b"QueryInterruptTimePrecise\0")?;
+        Some(unsafe { mem::transmute::<FARPROC, QueryInterruptTimePrecise>(func) })
+    })
+}
+
+impl Clock for SuspendAwareClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        let d = match query_interrupt_time_precise() {
+            Some(func) => {
+                let mut time: ULONGLONG = 0;
+                unsafe { func(&mut time) };
+                Duration::from_nanos(time * 100)
+            }
+            None => Duration::from_millis(unsafe { GetTickCount64() }),
+        };
         Ok(TimePoint(d))
     }
+
+    fn resolution() -> Result<Duration> {
+        match query_interrupt_time_precise() {
+            Some(_) => Ok(Duration::from_nanos(100)),
+            None => time_increment(),
+        }
+    }
+}
+
+/// A clock to report the time since the system booted.
+// The interrupt time read by `SuspendAwareClock` counts from the boot and includes the time
+// the system spends asleep, like `CLOCK_BOOTTIME` on Linux, unlike `QueryUnbiasedInterruptTime`.
+pub struct UptimeClock;
+
+impl UptimeClock {
+    /// Return the time since the system booted.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the underlying system calls failed.
+    pub fn uptime() -> Result<Duration> {
+        Ok(Self::try_now()?.0)
+    }
+}
+
+impl Clock for UptimeClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        SuspendAwareClock::try_now()
+    }
+
+    fn resolution() -> Result<Duration> {
+        SuspendAwareClock::resolution()
+    }
+}
+
+/// A clock to report the time since the calling process started.
+// The creation time reported by `GetProcessTimes` is compared to the system clock once, to
+// locate it on `SuspendAwareClock`, which is then read afterwards.
+pub struct ProcessUptimeClock;
+
+impl ProcessUptimeClock {
+    /// Return the time since the calling process started.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the underlying system calls failed.
+    pub fn uptime() -> Result<Duration> {
+        Ok(Self::try_now()?.0)
+    }
+}
+
+impl Clock for ProcessUptimeClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        let start = process_start_time()?;
+        Ok(TimePoint(
+            SuspendAwareClock::try_now()?.0.saturating_sub(start),
+        ))
+    }
+
+    fn resolution() -> Result<Duration> {
+        SuspendAwareClock::resolution()
+    }
+}
+
+/// Return the cached time when the calling process started, on [`SuspendAwareClock`].
+fn process_start_time() -> Result<Duration> {
+    static START: OnceLock<Duration> = OnceLock::new();
+    if let Some(start) = START.get() {
+        return Ok(*start);
+    }
+    let mut creation = FILETIME {
+        dwLowDateTime: 0,
+        dwHighDateTime: 0,
+    };
+    let mut exit = creation;
+    let mut system_time = creation;
+    let mut user_time = creation;
+    let ret = unsafe {
+        GetProcessTimes(
+            GetCurrentProcess(),
+            &mut creation,
+            &mut exit,
+            &mut system_time,
+            &mut user_time,
+        )
+    };
+    if ret == 0 {
+        return Err(Error::SystemError("GetProcessTimes", errno()));
+    }
+    let now = SuspendAwareClock::try_now()?.0;
+    let age = SystemClock::try_now()?
+        .0
+        .saturating_sub(filetime_to_duration(creation));
+    Ok(*START.get_or_init(|| now.saturating_sub(age)))
 }
 
 /// A clock to report the real process wall-clock.
@@ -65,14 +373,21 @@ pub struct ProcessRealCPUClock;
 
 impl Clock for ProcessRealCPUClock {
     type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
 
     fn try_now() -> Result<Self::Output> {
         SteadyClock::try_now()
     }
+
+    fn resolution() -> Result<Duration> {
+        SteadyClock::resolution()
+    }
 }
 
 #[inline(always)]
-fn get_process_times() -> Result<(FILETIME, FILETIME)> {
+fn get_process_times(process: HANDLE) -> Result<(FILETIME, FILETIME)> {
     let mut creation = FILETIME {
         dwLowDateTime: 0,
         dwHighDateTime: 0,
@@ -91,7 +406,7 @@ fn get_process_times() -> Result<(FILETIME, FILETIME)> {
     };
     let ret = unsafe {
         GetProcessTimes(
-            GetCurrentProcess(),
+            process,
             &mut creation,
             &mut exit,
             &mut system_time,
@@ -109,11 +424,18 @@ pub struct ProcessUserCPUClock;
 
 impl Clock for ProcessUserCPUClock {
     type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
 
     fn try_now() -> Result<Self::Output> {
-        let (user_time, _) = get_process_times()?;
+        let (user_time, _) = get_process_times(unsafe { GetCurrentProcess() })?;
         Ok(TimePoint(filetime_to_duration(user_time)))
     }
+
+    fn resolution() -> Result<Duration> {
+        time_increment()
+    }
 }
 
 /// A clock to report the system cpu-clock.
@@ -121,66 +443,642 @@ pub struct ProcessSystemCPUClock;
 
 impl Clock for ProcessSystemCPUClock {
     type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
 
     fn try_now() -> Result<Self::Output> {
-        let (_, system_time) = get_process_times()?;
+        let (_, system_time) = get_process_times(unsafe { GetCurrentProcess() })?;
         Ok(TimePoint(filetime_to_duration(system_time)))
     }
+
+    fn resolution() -> Result<Duration> {
+        time_increment()
+    }
 }
 
 /// A clock to report real, user-CPU, and system-CPU clocks.
 pub struct ProcessCPUClock;
 
+impl ProcessCPUClock {
+    /// Return the total, i.e. user plus system, CPU time of the calling process.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the underlying system calls failed.
+    pub fn total() -> Result<Duration> {
+        let (user_time, system_time) = get_process_times(unsafe { GetCurrentProcess() })?;
+        Ok(filetime_to_duration(user_time) + filetime_to_duration(system_time))
+    }
+}
+
 impl Clock for ProcessCPUClock {
     type Output = ProcessTimePoint;
+    type Duration = ProcessDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
 
     fn try_now() -> Result<Self::Output> {
-        let (user_time, system_time) = get_process_times()?;
+        let (user_time, system_time) = get_process_times(unsafe { GetCurrentProcess() })?;
         Ok(ProcessTimePoint {
             real: SteadyClock::try_now()?.0,
             user: filetime_to_duration(user_time),
             system: filetime_to_duration(system_time),
         })
     }
+
+    fn resolution() -> Result<Duration> {
+        time_increment()
+    }
 }
 
-/// A clock to report the real thread wall-clock.
-pub struct ThreadClock;
+/// A clock to report the user-CPU, system-CPU, and idle times of all the processors of the
+/// host by `GetSystemTimes`.
+pub struct HostCPUClock;
 
-impl Clock for ThreadClock {
-    type Output = TimePoint;
+impl Clock for HostCPUClock {
+    type Output = crate::HostTimePoint;
+    type Duration = crate::HostDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
 
     fn try_now() -> Result<Self::Output> {
-        let mut creation = FILETIME {
+        let mut idle = FILETIME {
             dwLowDateTime: 0,
             dwHighDateTime: 0,
         };
-        let mut exit = FILETIME {
+        let mut kernel = FILETIME {
             dwLowDateTime: 0,
             dwHighDateTime: 0,
         };
-        let mut user_time = FILETIME {
+        let mut user = FILETIME {
             dwLowDateTime: 0,
             dwHighDateTime: 0,
         };
-        let mut system_time = FILETIME {
-            dwLowDateTime: 0,
-            dwHighDateTime: 0,
+        let ret = unsafe { GetSystemTimes(&mut idle, &mut kernel, &mut user) };
+        if ret == FALSE {
+            return Err(Error::SystemError("GetSystemTimes", errno()));
+        }
+        // The kernel time includes the idle time.
+        let idle = filetime_to_duration(idle);
+        Ok(crate::HostTimePoint {
+            user: filetime_to_duration(user),
+            system: filetime_to_duration(kernel).saturating_sub(idle),
+            idle,
+        })
+    }
+
+    fn resolution() -> Result<Duration> {
+        time_increment()
+    }
+}
+
+/// A clock to report real, user-CPU, and system-CPU clocks of another process.
+///
+/// It is obtained by [`ProcessCPUClock::for_pid`]. The process handle is kept open across
+/// readings, so the final CPU times are reported after the process exits.
+pub struct PidCpuClock {
+    pid: u32,
+    handle: HANDLE,
+}
+
+// The process handle can be used from any thread.
+unsafe impl Send for PidCpuClock {}
+unsafe impl Sync for PidCpuClock {}
+
+impl PidCpuClock {
+    pub(crate) fn open(pid: u32) -> Result<Self> {
+        let handle = unsafe { OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, pid) };
+        if handle.is_null() {
+            return Err(Error::SystemError("OpenProcess", errno()));
+        }
+        Ok(PidCpuClock { pid, handle })
+    }
+
+    /// Return the process id.
+    pub fn pid(&self) -> u32 {
+        self.pid
+    }
+
+    /// Return the current timepoint.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the underlying system calls failed.
+    pub fn try_now(&self) -> Result<ProcessTimePoint> {
+        let (user_time, system_time) = get_process_times(self.handle)?;
+        Ok(ProcessTimePoint {
+            real: SteadyClock::try_now()?.0,
+            user: filetime_to_duration(user_time),
+            system: filetime_to_duration(system_time),
+        })
+    }
+
+    /// Return the current timepoint.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying system calls failed.
+    /// Use [`try_now`](#method.try_now) if you want to handle the error.
+    pub fn now(&self) -> ProcessTimePoint {
+        self.try_now().expect("Failed to access the clock.")
+    }
+}
+
+impl Drop for PidCpuClock {
+    fn drop(&mut self) {
+        unsafe { CloseHandle(self.handle) };
+    }
+}
+
+/// A job object to which the child processes measured by [`ChildrenCPUClock`] are assigned.
+///
+/// Obtain it by [`ChildrenJob::get()`] or `ChildrenCPUTimer::job()`.
+pub struct ChildrenJob(HANDLE);
+
+// The job handle can be used from any thread.
+unsafe impl Send for ChildrenJob {}
+unsafe impl Sync for ChildrenJob {}
+
+impl ChildrenJob {
+    /// Return the job object of the current process, creating it on first use.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if creating the job object failed. The failure is
+    /// kept, so that the job object is only attempted to be created once.
+    pub fn get() -> Result<&'static ChildrenJob> {
+        // The job is created by the one thread which initializes it, so that no other handle is
+        // created by the threads racing with it.
+        static JOB: OnceLock<core::result::Result<ChildrenJob, i32>> = OnceLock::new();
+        let job = JOB.get_or_init(|| {
+            let handle = unsafe { CreateJobObjectW(ptr::null_mut(), ptr::null()) };
+            if handle.is_null() {
+                Err(errno())
+            } else {
+                Ok(ChildrenJob(handle))
+            }
+        });
+        job.as_ref()
+            .map_err(|&code| Error::SystemError("CreateJobObjectW", code))
+    }
+
+    /// Assign `child` to the job, so that its CPU time is reported by [`ChildrenCPUClock`].
+    ///
+    /// Only the CPU time spent after the assignment is accounted for. To account for all of
+    /// it, spawn the child as suspended using `CREATE_SUSPENDED` and resume it afterwards.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if assigning the process failed.
+    pub fn assign(&self, child: &Child) -> Result<()> {
+        let ret = unsafe { AssignProcessToJobObject(self.0, child.as_raw_handle() as HANDLE) };
+        if ret == 0 {
+            return Err(Error::SystemError("AssignProcessToJobObject", errno()));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for ChildrenJob {
+    fn drop(&mut self) {
+        unsafe { CloseHandle(self.0) };
+    }
+}
+
+/// A clock to report the user and system CPU time of the child processes assigned to
+/// [`ChildrenJob`], together with the wall-clock time.
+pub struct ChildrenCPUClock;
+
+impl Clock for ChildrenCPUClock {
+    type Output = ProcessTimePoint;
+    type Duration = ProcessDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        let job = ChildrenJob::get()?;
+        let mut info: JOBOBJECT_BASIC_ACCOUNTING_INFORMATION = unsafe { mem::zeroed() };
+        let ret = unsafe {
+            QueryInformationJobObject(
+                job.0,
+                JobObjectBasicAccountingInformation,
+                &mut info as *mut _ as *mut _,
+                mem::size_of::<JOBOBJECT_BASIC_ACCOUNTING_INFORMATION>() as DWORD,
+                ptr::null_mut(),
+            )
         };
+        if ret == 0 {
+            return Err(Error::SystemError("QueryInformationJobObject", errno()));
+        }
+        Ok(ProcessTimePoint {
+            real: SteadyClock::try_now()?.0,
+            user: Duration::from_nanos(unsafe { *info.TotalUserTime.QuadPart() } as u64 * 100),
+            system: Duration::from_nanos(unsafe { *info.TotalKernelTime.QuadPart() } as u64 * 100),
+        })
+    }
+
+    fn resolution() -> Result<Duration> {
+        time_increment()
+    }
+}
+
+/// A clock to report real, user-CPU, and system-CPU clocks of the calling process, including
+/// the child processes assigned to [`ChildrenJob`].
+pub struct ProcessWithChildrenCPUClock;
+
+impl Clock for ProcessWithChildrenCPUClock {
+    type Output = ProcessTimePoint;
+    type Duration = ProcessDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        let (user_time, system_time) = get_process_times(unsafe { GetCurrentProcess() })?;
+        let children = ChildrenCPUClock::try_now()?;
+        Ok(ProcessTimePoint {
+            real: children.real,
+            user: filetime_to_duration(user_time) + children.user,
+            system: filetime_to_duration(system_time) + children.system,
+        })
+    }
+
+    fn resolution() -> Result<Duration> {
+        time_increment()
+    }
+}
+
+/// A clock to report the real thread wall-clock.
+pub struct ThreadClock;
+
+#[inline(always)]
+fn get_thread_times(thread: HANDLE) -> Result<(Duration, Duration)> {
+    let mut creation = FILETIME {
+        dwLowDateTime: 0,
+        dwHighDateTime: 0,
+    };
+    let mut exit = FILETIME {
+        dwLowDateTime: 0,
+        dwHighDateTime: 0,
+    };
+    let mut user_time = FILETIME {
+        dwLowDateTime: 0,
+        dwHighDateTime: 0,
+    };
+    let mut system_time = FILETIME {
+        dwLowDateTime: 0,
+        dwHighDateTime: 0,
+    };
+    let ret = unsafe {
+        GetThreadTimes(
+            thread,
+            &mut creation,
+            &mut exit,
+            &mut system_time,
+            &mut user_time,
+        )
+    };
+    if ret == 0 {
+        return Err(Error::SystemError("GetThreadTimes", errno()));
+    }
+    let user = filetime_to_duration(user_time);
+    let system = filetime_to_duration(system_time);
+    Ok((user, system))
+}
+
+#[inline(always)]
+fn get_thread_time(thread: HANDLE) -> Result<Duration> {
+    let (user, system) = get_thread_times(thread)?;
+    Ok(user + system)
+}
+
+impl Clock for ThreadClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(TimePoint(get_thread_time(unsafe { GetCurrentThread() })?))
+    }
+
+    fn resolution() -> Result<Duration> {
+        time_increment()
+    }
+}
+
+/// A clock to report the user-CPU and system-CPU clocks of the calling thread.
+pub struct ThreadCPUClock;
+
+impl Clock for ThreadCPUClock {
+    type Output = ThreadTimePoint;
+    type Duration = ThreadDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        let (user, system) = get_thread_times(unsafe { GetCurrentThread() })?;
+        Ok(ThreadTimePoint { user, system })
+    }
+
+    fn resolution() -> Result<Duration> {
+        time_increment()
+    }
+}
+
+/// A handle to read the CPU-time clock of another thread.
+///
+/// It holds a handle to the thread, so reading it after the thread has exited returns the
+/// final CPU time of the thread.
+pub struct ThreadCpuHandle {
+    handle: HANDLE,
+}
+
+unsafe impl Send for ThreadCpuHandle {}
+unsafe impl Sync for ThreadCpuHandle {}
+
+impl ThreadCpuHandle {
+    /// Construct a handle for the thread of `handle`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the thread handle cannot be duplicated.
+    pub fn for_join_handle<T>(handle: &JoinHandle<T>) -> Result<Self> {
+        let mut dup: HANDLE = ptr::null_mut();
         let ret = unsafe {
-            GetThreadTimes(
-                GetCurrentThread(),
-                &mut creation,
-                &mut exit,
-                &mut system_time,
-                &mut user_time,
+            DuplicateHandle(
+                GetCurrentProcess(),
+                handle.as_raw_handle() as HANDLE,
+                GetCurrentProcess(),
+                &mut dup,
+                0,
+                FALSE,
+                DUPLICATE_SAME_ACCESS,
             )
         };
         if ret == 0 {
-            return Err(Error::SystemError("GetThreadTimes", errno()));
+            return Err(Error::SystemError("DuplicateHandle", errno()));
+        }
+        Ok(ThreadCpuHandle { handle: dup })
+    }
+
+    /// Construct a handle for the calling thread, e.g. to be read by other threads while it
+    /// runs.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the thread cannot be opened.
+    pub fn current() -> Result<Self> {
+        Self::from_thread_id(unsafe { GetCurrentThreadId() })
+    }
+
+    /// Construct a handle for the thread of the thread id `id`, e.g. a thread created by a C
+    /// library, with `OpenThread(THREAD_QUERY_LIMITED_INFORMATION)`.
+    ///
+    /// The opened handle keeps the thread object alive, so reading it after the thread has
+    /// exited returns the final CPU time of the thread. Note that the id of a thread which has
+    /// already exited might be reused by another thread.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the thread does not exist or cannot be accessed.
+    pub fn from_thread_id(id: u32) -> Result<Self> {
+        let handle = unsafe { OpenThread(THREAD_QUERY_LIMITED_INFORMATION, FALSE, id) };
+        if handle.is_null() {
+            return Err(Error::SystemError("OpenThread", errno()));
+        }
+        Ok(ThreadCpuHandle { handle })
+    }
+
+    /// Return the CPU time of the thread.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the underlying system calls failed.
+    pub fn try_now(&self) -> Result<TimePoint> {
+        Ok(TimePoint(get_thread_time(self.handle)?))
+    }
+
+    /// Return the CPU time of the thread.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying system calls failed.
+    /// Use [`try_now`](#method.try_now) if you want to handle the error.
+    pub fn now(&self) -> TimePoint {
+        self.try_now().expect("Failed to access the clock.")
+    }
+
+    /// Return the CPU time spent by the thread since `start`, which is a timepoint returned by
+    /// this handle.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying system calls failed.
+    pub fn elapsed_since(&self, start: &TimePoint) -> Duration {
+        self.now().saturating_sub(*start)
+    }
+}
+
+impl Drop for ThreadCpuHandle {
+    fn drop(&mut self) {
+        unsafe { CloseHandle(self.handle) };
+    }
+}
+
+/// The duration over which the cycle frequency is calibrated.
+const CYCLE_CALIBRATION_PERIOD: Duration = Duration::from_millis(10);
+
+/// The calibrated cycle frequency in Hz, or zero if not calibrated yet.
+static CYCLE_FREQUENCY: AtomicU64 = AtomicU64::new(0);
+
+#[inline(always)]
+fn get_thread_cycles() -> Result<u64> {
+    let mut cycles = 0;
+    let ret = unsafe { QueryThreadCycleTime(GetCurrentThread(), &mut cycles) };
+    if ret == 0 {
+        return Err(Error::SystemError("QueryThreadCycleTime", errno()));
+    }
+    Ok(cycles)
+}
+
+/// A clock to report the CPU cycles spent by the calling thread.
+///
+/// It is based on `QueryThreadCycleTime`, which is much more precise than the 15.6ms of
+/// `GetThreadTimes` used by [`ThreadClock`]. The cycles are not directly comparable across
+/// changes of the CPU frequency. Use [`Cycles::to_duration`](crate::Cycles::to_duration) to
+/// convert them to an approximate [`Duration`].
+pub struct ThreadCycleClock;
+
+impl ThreadCycleClock {
+    /// Return the cycle frequency in Hz, calibrated against [`SteadyClock`]. The frequency is
+    /// calibrated first if it is not yet.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the underlying clocks failed.
+    pub fn frequency() -> Result<u64> {
+        match CYCLE_FREQUENCY.load(Ordering::Relaxed) {
+            0 => Self::recalibrate(),
+            frequency => Ok(frequency),
+        }
+    }
+
+    /// Calibrate the cycle frequency again by spinning for about 10ms, and return it in Hz.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the underlying clocks failed.
+    pub fn recalibrate() -> Result<u64> {
+        let (start, start_cycles) = (SteadyClock::try_now()?, get_thread_cycles()?);
+        let (end, end_cycles) = loop {
+            let (now, cycles) = (SteadyClock::try_now()?, get_thread_cycles()?);
+            if now.saturating_sub(start) >= CYCLE_CALIBRATION_PERIOD {
+                break (now, cycles);
+            }
+        };
+        let nanos = (end - start).as_nanos();
+        let cycles = end_cycles.saturating_sub(start_cycles) as u128;
+        if nanos == 0 || cycles == 0 {
+            return Err(Error::Unsupported("QueryThreadCycleTime"));
+        }
+        let frequency = (cycles * 1_000_000_000 / nanos) as u64;
+        CYCLE_FREQUENCY.store(frequency, Ordering::Relaxed);
+        Ok(frequency)
+    }
+}
+
+impl Clock for ThreadCycleClock {
+    type Output = CycleTimePoint;
+    type Duration = Cycles;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(CycleTimePoint(get_thread_cycles()?))
+    }
+
+    fn resolution() -> Result<Duration> {
+        let frequency = Self::frequency()?;
+        Ok(Duration::from_nanos((1_000_000_000 / frequency).max(1)))
+    }
+}
+
+/// A clock to report the CPU cycles spent by all the threads of the calling process.
+///
+/// It is based on `QueryProcessCycleTime`. See [`ThreadCycleClock`] for the caveats.
+pub struct ProcessCycleClock;
+
+impl Clock for ProcessCycleClock {
+    type Output = CycleTimePoint;
+    type Duration = Cycles;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        let mut cycles = 0;
+        let ret = unsafe { QueryProcessCycleTime(GetCurrentProcess(), &mut cycles) };
+        if ret == 0 {
+            return Err(Error::SystemError("QueryProcessCycleTime", errno()));
         }
-        let user = filetime_to_duration(user_time);
-        let system = filetime_to_duration(system_time);
-        Ok(TimePoint(user + system))
+        Ok(CycleTimePoint(cycles))
+    }
+
+    fn resolution() -> Result<Duration> {
+        ThreadCycleClock::resolution()
+    }
+}
+
+/// Wait for `child` to exit, and return its exit status, user CPU time, and system CPU time.
+pub(crate) fn wait_child(child: &mut Child) -> Result<(ExitStatus, Duration, Duration)> {
+    let status = child.wait().map_err(|e| {
+        Error::SystemError("WaitForSingleObject", e.raw_os_error().unwrap_or_default())
+    })?;
+    let (user_time, system_time) = get_process_times(child.as_raw_handle() as HANDLE)?;
+    Ok((
+        status,
+        filetime_to_duration(user_time),
+        filetime_to_duration(system_time),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_qpc_ticks_to_duration() {
+        const YEAR: u64 = 365 * 24 * 3600;
+
+        // The common frequency on x86.
+        let frequency = 10_000_000;
+        assert_eq!(
+            qpc_ticks_to_duration(1, frequency),
+            Duration::from_nanos(100)
+        );
+        assert_eq!(
+            qpc_ticks_to_duration(frequency, frequency),
+            Duration::from_secs(1)
+        );
+        assert_eq!(
+            qpc_ticks_to_duration(frequency * YEAR, frequency),
+            Duration::from_secs(YEAR)
+        );
+
+        // The frequency on some ARM64 devices, which does not divide a second evenly.
+        let frequency = 24_000_000;
+        assert_eq!(
+            qpc_ticks_to_duration(1, frequency),
+            Duration::from_nanos(41)
+        );
+        assert_eq!(
+            qpc_ticks_to_duration(3, frequency),
+            Duration::from_nanos(125)
+        );
+        assert_eq!(
+            qpc_ticks_to_duration(frequency, frequency),
+            Duration::from_secs(1)
+        );
+        assert_eq!(
+            qpc_ticks_to_duration(frequency * YEAR, frequency),
+            Duration::from_secs(YEAR)
+        );
+
+        // The frequencies above 1 GHz.
+        let frequency = 3_500_000_000;
+        assert_eq!(qpc_ticks_to_duration(1, frequency), Duration::ZERO);
+        assert_eq!(qpc_ticks_to_duration(7, frequency), Duration::from_nanos(2));
+        assert_eq!(
+            qpc_ticks_to_duration(frequency, frequency),
+            Duration::from_secs(1)
+        );
+        assert_eq!(
+            qpc_ticks_to_duration(frequency * YEAR, frequency),
+            Duration::from_secs(YEAR)
+        );
+
+        let frequency = 1 << 32;
+        assert_eq!(
+            qpc_ticks_to_duration(1 << 31, frequency),
+            Duration::from_millis(500)
+        );
+        assert_eq!(
+            qpc_ticks_to_duration(frequency, frequency),
+            Duration::from_secs(1)
+        );
+        assert_eq!(
+            qpc_ticks_to_duration(frequency * YEAR, frequency),
+            Duration::from_secs(YEAR)
+        );
+
+        // The counter near `u64::MAX` does not wrap around.
+        assert_eq!(
+            qpc_ticks_to_duration(u64::MAX, 24_000_000),
+            Duration::new(768_614_336_404, 564_650_625)
+        );
+        assert_eq!(
+            qpc_ticks_to_duration(u64::MAX, 10_000_000),
+            Duration::new(1_844_674_407_370, 955_161_500)
+        );
     }
 }
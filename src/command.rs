@@ -0,0 +1,89 @@
+//! Measure how long a child process takes to execute.
+//!
+//! The wall time is measured by [`HighResolutionClock`] from spawning to reaping the child.
+//! The user and system CPU times of the child are reported by `wait4` on Posix and
+//! `GetProcessTimes` on Windows.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use howlong::command::TimedCommand;
+//! use std::process::Command;
+//!
+//! let (status, elapsed) = Command::new("ls").timed_status().unwrap();
+//! println!("{} exited with {}", elapsed, status);
+//! ```
+
+use crate::{clock::*, Clock, Error, ProcessDuration, Result};
+use std::io::{self, Read};
+use std::process::{Command, ExitStatus, Output, Stdio};
+use std::thread;
+
+#[inline(always)]
+fn io_error(name: &'static str, e: io::Error) -> Error {
+    Error::SystemError(name, e.raw_os_error().unwrap_or_default())
+}
+
+/// An extension trait for [`Command`] to measure the [`ProcessDuration`] of the child.
+pub trait TimedCommand {
+    /// Like [`Command::status`], but also return the [`ProcessDuration`] of the child.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if spawning or waiting for the child failed, or if
+    /// acessing to the underlying clock failed.
+    fn timed_status(&mut self) -> Result<(ExitStatus, ProcessDuration)>;
+
+    /// Like [`Command::output`], but also return the [`ProcessDuration`] of the child.
+    ///
+    /// The stdout and stderr of the child are captured. Unlike [`Command::output`], stdin is
+    /// inherited from the parent unless configured otherwise.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if spawning, reading from, or waiting for the child
+    /// failed, or if acessing to the underlying clock failed.
+    fn timed_output(&mut self) -> Result<(Output, ProcessDuration)>;
+}
+
+impl TimedCommand for Command {
+    fn timed_status(&mut self) -> Result<(ExitStatus, ProcessDuration)> {
+        let start = HighResolutionClock::try_now()?;
+        let mut child = self.spawn().map_err(|e| io_error("spawn", e))?;
+        let (status, user, system) = wait_child(&mut child)?;
+        let real = HighResolutionClock::try_now()?.saturating_sub(start);
+        Ok((status, ProcessDuration { real, user, system }))
+    }
+
+    fn timed_output(&mut self) -> Result<(Output, ProcessDuration)> {
+        let start = HighResolutionClock::try_now()?;
+        let mut child = self
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| io_error("spawn", e))?;
+        let mut child_stdout = child.stdout.take().expect("stdout is piped");
+        let mut child_stderr = child.stderr.take().expect("stderr is piped");
+        // Drain both pipes concurrently, so that the child does not block on a full pipe.
+        let stdout = thread::spawn(move || {
+            let mut buf = Vec::new();
+            child_stdout.read_to_end(&mut buf).map(|_| buf)
+        });
+        let mut stderr = Vec::new();
+        let stderr_result = child_stderr.read_to_end(&mut stderr);
+        let stdout_result = stdout.join().expect("failed to join the stdout reader");
+        // Always reap the child, even if reading from it failed.
+        let (status, user, system) = wait_child(&mut child)?;
+        let real = HighResolutionClock::try_now()?.saturating_sub(start);
+        let stdout = stdout_result.map_err(|e| io_error("read", e))?;
+        stderr_result.map_err(|e| io_error("read", e))?;
+        Ok((
+            Output {
+                status,
+                stdout,
+                stderr,
+            },
+            ProcessDuration { real, user, system },
+        ))
+    }
+}
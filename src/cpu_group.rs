@@ -0,0 +1,188 @@
+use crate::{
+    clock::{ThreadClock, ThreadCpuHandle},
+    Clock, Duration, Error, Result, TimePoint,
+};
+use core::cell::Cell;
+use core::convert::TryFrom;
+use core::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+thread_local! {
+    // Clippy falsely suggests `const` on the targets using the OS thread-local storage.
+    #[allow(clippy::missing_const_for_thread_local)]
+    static IN_GROUP: Cell<bool> = const { Cell::new(false) };
+}
+
+/// A logical group of threads whose CPU times, read from [`ThreadClock`], are added up.
+///
+/// A thread adds its CPU time to the group while it holds the guard returned by
+/// [`join`](CpuGroup::join). The CPU time of the thread is added to the total when the guard
+/// is dropped, and [`total`](CpuGroup::total) also reads the CPU time of the live members
+/// through a [`ThreadCpuHandle`] where the platform allows it.
+///
+/// A thread can be in one group at a time, so that its CPU time is never counted twice.
+///
+/// It is cheap to clone, and the clones refer to the same group.
+///
+/// # Examples
+///
+/// ```
+/// use howlong::CpuGroup;
+///
+/// let group = CpuGroup::new();
+/// let threads: Vec<_> = (0..4)
+///     .map(|_| {
+///         let group = group.clone();
+///         std::thread::spawn(move || {
+///             let _guard = group.join();
+///             // do some computations
+///         })
+///     })
+///     .collect();
+/// for thread in threads {
+///     thread.join().unwrap();
+/// }
+/// println!("{:?} of CPU time", group.total());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CpuGroup {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    /// The CPU time in nanoseconds of the members which have left.
+    finished: AtomicU64,
+    members: Mutex<Vec<Member>>,
+    next_id: AtomicU64,
+}
+
+struct Member {
+    id: u64,
+    start: TimePoint,
+    /// `None` if the platform cannot read the CPU time of other threads.
+    handle: Option<ThreadCpuHandle>,
+}
+
+impl core::fmt::Debug for Member {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Member")
+            .field("id", &self.id)
+            .field("start", &self.start)
+            .finish()
+    }
+}
+
+impl Inner {
+    fn members(&self) -> MutexGuard<'_, Vec<Member>> {
+        // A member is only removed or added while the lock is held, so a panic elsewhere
+        // cannot leave the list inconsistent.
+        self.members.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn nanos(d: Duration) -> u64 {
+    u64::try_from(d.as_nanos()).unwrap_or(u64::MAX)
+}
+
+impl CpuGroup {
+    /// Create an empty group.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the calling thread to the group until the returned guard is dropped.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the calling thread is already in a group, and might panic
+    /// when acessing to the underlying clock failed. Use [`try_join`](CpuGroup::try_join) if
+    /// you want to handle the errors.
+    pub fn join(&self) -> CpuGroupGuard {
+        match self.try_join() {
+            Err(Error::AlreadyInGroup) => panic!("The thread is already in a CPU group."),
+            guard => guard.expect("Failed to access the clock."),
+        }
+    }
+
+    /// Add the calling thread to the group until the returned guard is dropped.
+    ///
+    /// # Errors
+    ///
+    /// This function will return [`Error::AlreadyInGroup`] if the calling thread is already
+    /// in a group, or an error if acessing to the thread clock failed.
+    pub fn try_join(&self) -> Result<CpuGroupGuard> {
+        if IN_GROUP.with(Cell::get) {
+            return Err(Error::AlreadyInGroup);
+        }
+        let id = self.inner.next_id.fetch_add(1, Ordering::Relaxed);
+        let start = ThreadClock::try_now()?;
+        let handle = ThreadCpuHandle::current().ok();
+        self.inner.members().push(Member { id, start, handle });
+        IN_GROUP.with(|in_group| in_group.set(true));
+        Ok(CpuGroupGuard {
+            group: self.clone(),
+            id,
+            _not_send: PhantomData,
+        })
+    }
+
+    /// Return the total CPU time of the members, including the time so far of the live
+    /// members if the platform allows reading the CPU time of other threads, see
+    /// [`ThreadCpuHandle`]. Otherwise, the time of a member is only added when it leaves.
+    pub fn total(&self) -> Duration {
+        let members = self.inner.members();
+        let live: Duration = members
+            .iter()
+            .filter_map(|member| {
+                let now = member.handle.as_ref()?.try_now().ok()?;
+                Some(now.saturating_sub(member.start))
+            })
+            .sum();
+        Duration::from_nanos(self.inner.finished.load(Ordering::Relaxed)) + live
+    }
+
+    /// Return the number of the live members.
+    pub fn members(&self) -> usize {
+        self.inner.members().len()
+    }
+}
+
+/// A guard returned by [`CpuGroup::join`], which adds the CPU time of the thread to the group
+/// and leaves the group when dropped.
+///
+/// It must be dropped on the thread which joined the group, so it is neither [`Send`] nor
+/// [`Sync`].
+#[derive(Debug)]
+pub struct CpuGroupGuard {
+    group: CpuGroup,
+    id: u64,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl CpuGroupGuard {
+    /// Return the group.
+    pub fn group(&self) -> &CpuGroup {
+        &self.group
+    }
+}
+
+impl Drop for CpuGroupGuard {
+    fn drop(&mut self) {
+        let mut members = self.group.inner.members();
+        if let Some(i) = members.iter().position(|member| member.id == self.id) {
+            let member = members.swap_remove(i);
+            // The time is lost if the clock fails, rather than panicking in `drop`.
+            if let Ok(now) = ThreadClock::try_now() {
+                let elapsed = nanos(now.saturating_sub(member.start));
+                self.group
+                    .inner
+                    .finished
+                    .fetch_add(elapsed, Ordering::Relaxed);
+            }
+        }
+        drop(members);
+        IN_GROUP.with(|in_group| in_group.set(false));
+    }
+}
@@ -0,0 +1,237 @@
+//! [`criterion`](https://docs.rs/criterion) measurements based on the clocks of this crate.
+//!
+//! * [`SteadyMeasurement`]: wall time measured by [`HighResolutionClock`].
+//! * [`ProcessCpuMeasurement`]: user and/or system CPU time of the process measured by
+//!   [`ProcessCPUClock`].
+//! * [`ThreadCpuMeasurement`]: CPU time of the calling thread measured by [`ThreadClock`].
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use criterion::Criterion;
+//! use howlong::criterion::ThreadCpuMeasurement;
+//!
+//! let mut c = Criterion::default().with_measurement(ThreadCpuMeasurement);
+//! c.bench_function("sum", |b| b.iter(|| (0..1000u64).sum::<u64>()));
+//! ```
+
+use crate::{clock::*, Clock, Duration, ProcessTimePoint, TimePoint};
+use ::criterion::measurement::{Measurement, ValueFormatter};
+use ::criterion::Throughput;
+
+/// A [`ValueFormatter`] for values in nanoseconds.
+pub struct DurationFormatter;
+
+impl DurationFormatter {
+    fn per_second(
+        &self,
+        count: f64,
+        typical: f64,
+        values: &mut [f64],
+        base: f64,
+        units: [&'static str; 4],
+    ) -> &'static str {
+        let per_second = count * (1e9 / typical);
+        let (denominator, unit) = if per_second < base {
+            (1.0, units[0])
+        } else if per_second < base * base {
+            (base, units[1])
+        } else if per_second < base * base * base {
+            (base * base, units[2])
+        } else {
+            (base * base * base, units[3])
+        };
+        for val in values {
+            *val = count * (1e9 / *val) / denominator;
+        }
+        unit
+    }
+}
+
+impl ValueFormatter for DurationFormatter {
+    fn scale_values(&self, ns: f64, values: &mut [f64]) -> &'static str {
+        let (factor, unit) = if ns < 1e0 {
+            (1e3, "ps")
+        } else if ns < 1e3 {
+            (1e0, "ns")
+        } else if ns < 1e6 {
+            (1e-3, "µs")
+        } else if ns < 1e9 {
+            (1e-6, "ms")
+        } else {
+            (1e-9, "s")
+        };
+        for val in values {
+            *val *= factor;
+        }
+        unit
+    }
+
+    fn scale_throughputs(
+        &self,
+        typical: f64,
+        throughput: &Throughput,
+        values: &mut [f64],
+    ) -> &'static str {
+        match *throughput {
+            Throughput::Bytes(bytes) => self.per_second(
+                bytes as f64,
+                typical,
+                values,
+                1024.0,
+                ["  B/s", "KiB/s", "MiB/s", "GiB/s"],
+            ),
+            Throughput::BytesDecimal(bytes) => self.per_second(
+                bytes as f64,
+                typical,
+                values,
+                1000.0,
+                ["  B/s", "KB/s", "MB/s", "GB/s"],
+            ),
+            Throughput::Elements(elems) => self.per_second(
+                elems as f64,
+                typical,
+                values,
+                1000.0,
+                [" elem/s", "Kelem/s", "Melem/s", "Gelem/s"],
+            ),
+        }
+    }
+
+    fn scale_for_machines(&self, _values: &mut [f64]) -> &'static str {
+        "ns"
+    }
+}
+
+/// Measure the wall time using [`HighResolutionClock`].
+pub struct SteadyMeasurement;
+
+impl Measurement for SteadyMeasurement {
+    type Intermediate = TimePoint;
+    type Value = Duration;
+
+    fn start(&self) -> Self::Intermediate {
+        HighResolutionClock::now()
+    }
+
+    fn end(&self, i: Self::Intermediate) -> Self::Value {
+        HighResolutionClock::now().saturating_sub(i)
+    }
+
+    fn add(&self, v1: &Self::Value, v2: &Self::Value) -> Self::Value {
+        *v1 + *v2
+    }
+
+    fn zero(&self) -> Self::Value {
+        Duration::from_secs(0)
+    }
+
+    fn to_f64(&self, value: &Self::Value) -> f64 {
+        value.as_nanos() as f64
+    }
+
+    fn formatter(&self) -> &dyn ValueFormatter {
+        &DurationFormatter
+    }
+}
+
+/// The kind of CPU time reported by [`ProcessCpuMeasurement`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProcessCpuTime {
+    /// The user CPU time.
+    User,
+    /// The system CPU time.
+    System,
+    /// The sum of the user and system CPU time.
+    UserSystem,
+}
+
+/// Measure the CPU time of the process using [`ProcessCPUClock`].
+pub struct ProcessCpuMeasurement {
+    kind: ProcessCpuTime,
+}
+
+impl ProcessCpuMeasurement {
+    /// Construct a measurement reporting the given kind of CPU time.
+    pub fn new(kind: ProcessCpuTime) -> Self {
+        ProcessCpuMeasurement { kind }
+    }
+
+    /// Return the kind of CPU time reported.
+    pub fn kind(&self) -> ProcessCpuTime {
+        self.kind
+    }
+}
+
+impl Default for ProcessCpuMeasurement {
+    /// Construct a measurement reporting [`ProcessCpuTime::UserSystem`].
+    fn default() -> Self {
+        Self::new(ProcessCpuTime::UserSystem)
+    }
+}
+
+impl Measurement for ProcessCpuMeasurement {
+    type Intermediate = ProcessTimePoint;
+    type Value = Duration;
+
+    fn start(&self) -> Self::Intermediate {
+        ProcessCPUClock::now()
+    }
+
+    fn end(&self, i: Self::Intermediate) -> Self::Value {
+        let d = ProcessCPUClock::now().saturating_sub(i);
+        match self.kind {
+            ProcessCpuTime::User => d.user,
+            ProcessCpuTime::System => d.system,
+            ProcessCpuTime::UserSystem => d.cpu_time(),
+        }
+    }
+
+    fn add(&self, v1: &Self::Value, v2: &Self::Value) -> Self::Value {
+        *v1 + *v2
+    }
+
+    fn zero(&self) -> Self::Value {
+        Duration::from_secs(0)
+    }
+
+    fn to_f64(&self, value: &Self::Value) -> f64 {
+        value.as_nanos() as f64
+    }
+
+    fn formatter(&self) -> &dyn ValueFormatter {
+        &DurationFormatter
+    }
+}
+
+/// Measure the CPU time of the calling thread using [`ThreadClock`].
+pub struct ThreadCpuMeasurement;
+
+impl Measurement for ThreadCpuMeasurement {
+    type Intermediate = TimePoint;
+    type Value = Duration;
+
+    fn start(&self) -> Self::Intermediate {
+        ThreadClock::now()
+    }
+
+    fn end(&self, i: Self::Intermediate) -> Self::Value {
+        ThreadClock::now().saturating_sub(i)
+    }
+
+    fn add(&self, v1: &Self::Value, v2: &Self::Value) -> Self::Value {
+        *v1 + *v2
+    }
+
+    fn zero(&self) -> Self::Value {
+        Duration::from_secs(0)
+    }
+
+    fn to_f64(&self, value: &Self::Value) -> f64 {
+        value.as_nanos() as f64
+    }
+
+    fn formatter(&self) -> &dyn ValueFormatter {
+        &DurationFormatter
+    }
+}
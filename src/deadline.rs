@@ -0,0 +1,103 @@
+//! A point in time measured by [`SteadyClock`] by which something should be done.
+
+use crate::{clock::*, Clock, Duration, Result, TimePoint};
+
+/// A timepoint of [`SteadyClock`] by which something should be done.
+///
+/// # Examples
+///
+/// ```
+/// use howlong::{Deadline, Duration};
+///
+/// let deadline = Deadline::after(Duration::from_millis(10));
+/// while !deadline.is_expired() {
+///     // do some work
+///     # break;
+/// }
+/// deadline.sleep().unwrap();
+/// assert_eq!(deadline.remaining(), Duration::ZERO);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Deadline(TimePoint);
+
+impl Deadline {
+    /// Construct a deadline `timeout` from now.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the deadline overflows, or when acessing to the underlying
+    /// clock failed. Use [`checked_after()`](#method.checked_after) if the former is possible.
+    pub fn after(timeout: Duration) -> Self {
+        Self::checked_after(timeout).expect("The deadline overflows.")
+    }
+
+    /// Construct a deadline `timeout` from now, or return `None` if it overflows.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn checked_after(timeout: Duration) -> Option<Self> {
+        SteadyClock::now().checked_add(timeout).map(Deadline)
+    }
+
+    /// Construct a deadline at `point`, which should be a timepoint of [`SteadyClock`].
+    pub fn at(point: TimePoint) -> Self {
+        Deadline(point)
+    }
+
+    /// Return the timepoint of the deadline.
+    pub fn time_point(&self) -> TimePoint {
+        self.0
+    }
+
+    /// Return the time left until the deadline, or zero if it has expired.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn remaining(&self) -> Duration {
+        self.0.saturating_duration_since(SteadyClock::now())
+    }
+
+    /// Return true if the deadline has been reached.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn is_expired(&self) -> bool {
+        SteadyClock::now() >= self.0
+    }
+
+    /// Return the time passed since the deadline, or zero if it has not expired.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn elapsed_past(&self) -> Duration {
+        SteadyClock::now().saturating_duration_since(self.0)
+    }
+
+    /// Postpone the deadline by `d`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the deadline overflows.
+    pub fn extend(&mut self, d: Duration) {
+        self.0 += d;
+    }
+
+    /// Block the calling thread until the deadline, see [`sleep_until`](crate::sleep_until).
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the underlying system calls failed.
+    pub fn sleep(&self) -> Result<()> {
+        crate::sleep_until(self.0)
+    }
+}
+
+impl From<Deadline> for TimePoint {
+    fn from(d: Deadline) -> Self {
+        d.0
+    }
+}
@@ -0,0 +1,470 @@
+use crate::{
+    clock::*, Clock, Duration, Error, ProcessDuration, ProcessTimePoint, Result, TimePoint, Timer,
+    TimerClock,
+};
+use alloc::{boxed::Box, format, rc::Rc};
+use core::marker::PhantomData;
+
+/// An object-safe counterpart of [`Clock`] for clocks chosen at runtime.
+///
+/// It is implemented by [`DynClockAdapter`] for every clock whose timepoint is a [`TimePoint`].
+///
+/// ```
+/// use howlong::*;
+///
+/// let clocks: Vec<Box<dyn DynClock>> = vec![
+///     Box::new(DynClockAdapter::<SystemClock>::new()),
+///     <dyn DynClock>::by_name("thread").unwrap(),
+/// ];
+/// for clock in &clocks {
+///     println!("{}: {:?}", clock.name(), clock.now());
+/// }
+/// ```
+pub trait DynClock: Send + Sync {
+    /// Return the current timepoint, see [`Clock::try_now`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the underlying system calls failed.
+    fn try_now(&self) -> Result<TimePoint>;
+
+    /// Return the current timepoint, see [`Clock::now`].
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying system calls failed.
+    fn now(&self) -> TimePoint {
+        self.try_now().expect("Failed to access the clock.")
+    }
+
+    /// Return the name of the clock type, e.g. `SteadyClock`.
+    fn name(&self) -> &'static str;
+
+    /// Return true if the clock is monotonic, see [`Clock::IS_STEADY`].
+    fn is_steady(&self) -> bool;
+
+    /// Return true if the clock measures CPU time, see [`Clock::IS_CPU_TIME`].
+    fn is_cpu_time(&self) -> bool;
+
+    /// Return the resolution of the clock, see [`Clock::resolution`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the resolution cannot be obtained.
+    fn resolution(&self) -> Result<Duration>;
+}
+
+impl dyn DynClock {
+    /// Return the clock of the name `name`, see [`ClockKind`], or `None` if it is unknown or
+    /// not available on this system, see [`ClockKind::to_dyn_clock`].
+    pub fn by_name(name: &str) -> Option<Box<dyn DynClock>> {
+        name.parse::<ClockKind>().ok()?.to_dyn_clock()
+    }
+}
+
+/// A zero-sized adapter implementing [`DynClock`] for the clock `ClockType`.
+pub struct DynClockAdapter<ClockType: Clock<Output = TimePoint>> {
+    _clock: PhantomData<fn() -> ClockType>,
+}
+
+impl<ClockType: Clock<Output = TimePoint>> DynClockAdapter<ClockType> {
+    /// Construct the adapter.
+    pub const fn new() -> Self {
+        DynClockAdapter {
+            _clock: PhantomData,
+        }
+    }
+}
+
+impl<ClockType: Clock<Output = TimePoint>> Default for DynClockAdapter<ClockType> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<ClockType: Clock<Output = TimePoint>> DynClock for DynClockAdapter<ClockType> {
+    fn try_now(&self) -> Result<TimePoint> {
+        <ClockType>::try_now()
+    }
+
+    fn name(&self) -> &'static str {
+        <ClockType>::info().name
+    }
+
+    fn is_steady(&self) -> bool {
+        <ClockType>::IS_STEADY
+    }
+
+    fn is_cpu_time(&self) -> bool {
+        <ClockType>::IS_CPU_TIME
+    }
+
+    fn resolution(&self) -> Result<Duration> {
+        <ClockType>::resolution()
+    }
+}
+
+/// A timer driven by a clock chosen at runtime, see [`DynClock`].
+///
+/// The clock may be [`ThreadClock`], which must be read on the thread which started the
+/// timer, so it is neither [`Send`] nor [`Sync`], like [`ThreadTimer`](crate::ThreadTimer).
+pub struct DynTimer {
+    clock: Box<dyn DynClock>,
+    // The timepoints are read from `clock`, the clock type of `inner` is never used.
+    inner: Timer<SystemClock, TimePoint, Duration>,
+    // makes type non-sync and non-send
+    _no_sync: PhantomData<Rc<()>>,
+}
+
+impl DynTimer {
+    /// Construct a timer using `clock` and start it.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn new(clock: Box<dyn DynClock>) -> Self {
+        let inner = Timer::with_start(clock.now());
+        DynTimer {
+            clock,
+            inner,
+            _no_sync: PhantomData,
+        }
+    }
+
+    /// Return the underlying clock.
+    pub fn clock(&self) -> &dyn DynClock {
+        self.clock.as_ref()
+    }
+
+    /// Return true if the timer is running.
+    pub fn is_running(&self) -> bool {
+        self.inner.is_running()
+    }
+
+    /// Return true if the timer is not running.
+    pub fn is_stopped(&self) -> bool {
+        self.inner.is_stopped()
+    }
+
+    /// Return the accumulated elapsed times as of the previous [`stop()`](#method.stop)
+    /// if the timer is stopped. Otherwise, the elapsed times accumulated between the most
+    /// recent call to [`start()`](#method.start) or [`resume()`](#method.resume) and the
+    /// current time values.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn elapsed(&self) -> Duration {
+        self.inner.elapsed_with(|| self.clock.now())
+    }
+
+    /// If the timer is not running, reset and start the timer.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn start(&mut self) {
+        let clock = &self.clock;
+        self.inner.start_with(|| clock.now());
+    }
+
+    /// Stop the timer.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn stop(&mut self) {
+        let clock = &self.clock;
+        self.inner.stop_with(|| clock.now());
+    }
+
+    /// Resume the timer, accumulating additional elapsed time.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn resume(&mut self) {
+        let clock = &self.clock;
+        self.inner.resume_with(|| clock.now());
+    }
+}
+
+macro_rules! clock_kinds {
+    ($($(#[$attr:meta])* $kind:ident => $name:literal,)*) => {
+        /// The clocks which can be chosen at runtime, see [`timer_for`] and
+        /// [`to_dyn_clock`](ClockKind::to_dyn_clock).
+        ///
+        /// It is parsed from and formatted as its name, e.g. `"steady"`, so that a command-line
+        /// flag maps to it directly. [`<dyn DynClock>::by_name`] accepts the same names.
+        ///
+        /// [`<dyn DynClock>::by_name`]: trait.DynClock.html#method.by_name
+        #[non_exhaustive]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum ClockKind {
+            $($(#[$attr])* $kind,)*
+        }
+
+        impl ClockKind {
+            /// All the kinds, including those not available on this platform.
+            pub const ALL: &'static [ClockKind] = &[$(ClockKind::$kind,)*];
+
+            /// Return the name of the kind, e.g. `"steady"`.
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(ClockKind::$kind => $name,)*
+                }
+            }
+        }
+
+        impl core::str::FromStr for ClockKind {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self> {
+                match s {
+                    $($name => Ok(ClockKind::$kind),)*
+                    _ => Err(Error::ParseError(format!("unknown clock `{}`", s))),
+                }
+            }
+        }
+    };
+}
+
+clock_kinds! {
+    /// [`SystemClock`].
+    System => "system",
+    /// [`SteadyClock`].
+    Steady => "steady",
+    /// [`HighResolutionClock`].
+    HighResolution => "high_resolution",
+    /// `SuspendAwareClock`.
+    SuspendAware => "suspend_aware",
+    /// `CoarseSystemClock`.
+    CoarseSystem => "coarse_system",
+    /// `CoarseSteadyClock`.
+    CoarseSteady => "coarse_steady",
+    /// `TaiClock`.
+    Tai => "tai",
+    /// `TscClock`.
+    Tsc => "tsc",
+    /// [`UptimeClock`].
+    Uptime => "uptime",
+    /// [`ProcessRealCPUClock`].
+    ProcessReal => "process_real",
+    /// [`ProcessUserCPUClock`].
+    ProcessUser => "process_user",
+    /// [`ProcessSystemCPUClock`].
+    ProcessSystem => "process_system",
+    /// [`ProcessCPUClock`].
+    ProcessCPU => "process_cpu",
+    /// [`ProcessWithChildrenCPUClock`].
+    ProcessWithChildrenCPU => "process_with_children_cpu",
+    /// [`ChildrenCPUClock`].
+    ChildrenCPU => "children_cpu",
+    /// [`ThreadClock`].
+    Thread => "thread",
+}
+
+impl ClockKind {
+    /// Return the clock of the kind as a [`DynClock`], or `None` if it is not available on
+    /// this system, or if its timepoint is not a [`TimePoint`], i.e. for
+    /// [`ProcessCPU`](ClockKind::ProcessCPU),
+    /// [`ProcessWithChildrenCPU`](ClockKind::ProcessWithChildrenCPU), and
+    /// [`ChildrenCPU`](ClockKind::ChildrenCPU). Use [`timer_for`] to time those.
+    pub fn to_dyn_clock(self) -> Option<Box<dyn DynClock>> {
+        fn boxed<ClockType: Clock<Output = TimePoint> + 'static>() -> Option<Box<dyn DynClock>> {
+            Some(Box::new(DynClockAdapter::<ClockType>::new()))
+        }
+        match self {
+            ClockKind::System => boxed::<SystemClock>(),
+            #[cfg(have_steady_clock)]
+            ClockKind::Steady => boxed::<SteadyClock>(),
+            ClockKind::HighResolution => boxed::<HighResolutionClock>(),
+            #[cfg(have_suspend_aware_clock)]
+            ClockKind::SuspendAware if SuspendAwareClock::is_available() => {
+                boxed::<SuspendAwareClock>()
+            }
+            #[cfg(have_coarse_clock)]
+            ClockKind::CoarseSystem if CoarseSystemClock::is_available() => {
+                boxed::<CoarseSystemClock>()
+            }
+            #[cfg(have_coarse_clock)]
+            ClockKind::CoarseSteady if CoarseSteadyClock::is_available() => {
+                boxed::<CoarseSteadyClock>()
+            }
+            #[cfg(have_clock_tai)]
+            ClockKind::Tai if TaiClock::is_available() => boxed::<TaiClock>(),
+            #[cfg(all(feature = "tsc", any(target_arch = "x86", target_arch = "x86_64")))]
+            ClockKind::Tsc if TscClock::is_available() => boxed::<TscClock>(),
+            ClockKind::Uptime => boxed::<UptimeClock>(),
+            ClockKind::ProcessReal => boxed::<ProcessRealCPUClock>(),
+            ClockKind::ProcessUser => boxed::<ProcessUserCPUClock>(),
+            ClockKind::ProcessSystem => boxed::<ProcessSystemCPUClock>(),
+            ClockKind::Thread => boxed::<ThreadClock>(),
+            _ => None,
+        }
+    }
+}
+
+impl core::fmt::Display for ClockKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// An object-safe timer of a clock chosen at runtime, returned by [`timer_for`].
+///
+/// It is implemented by every [`Timer`] whose timepoint is a [`TimePoint`] or a
+/// [`ProcessTimePoint`].
+pub trait AnyTimer {
+    /// Return true if the timer is running, see [`Timer::is_running`].
+    fn is_running(&self) -> bool;
+
+    /// If the timer is not running, reset and start the timer, see [`Timer::start`].
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    fn start(&mut self);
+
+    /// Stop the timer, see [`Timer::stop`].
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    fn stop(&mut self);
+
+    /// Resume the timer, see [`Timer::resume`].
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    fn resume(&mut self);
+
+    /// Return the elapsed time, see [`Timer::elapsed`]. It is the real time for the process
+    /// clocks, e.g. [`ProcessCPUClock`].
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    fn elapsed(&self) -> Duration;
+
+    /// Return the elapsed real, user-CPU, and system-CPU times for the process clocks, e.g.
+    /// [`ProcessCPUClock`], or `None` for the other clocks.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    fn process_elapsed(&self) -> Option<ProcessDuration> {
+        None
+    }
+}
+
+impl<ClockType: Clock<Output = TimePoint>> AnyTimer for Timer<ClockType, TimePoint, Duration> {
+    fn is_running(&self) -> bool {
+        Timer::is_running(self)
+    }
+
+    fn start(&mut self) {
+        Timer::start(self)
+    }
+
+    fn stop(&mut self) {
+        Timer::stop(self)
+    }
+
+    fn resume(&mut self) {
+        Timer::resume(self)
+    }
+
+    fn elapsed(&self) -> Duration {
+        Timer::elapsed(self)
+    }
+}
+
+impl<ClockType: Clock<Output = ProcessTimePoint>> AnyTimer
+    for Timer<ClockType, ProcessTimePoint, ProcessDuration>
+{
+    fn is_running(&self) -> bool {
+        Timer::is_running(self)
+    }
+
+    fn start(&mut self) {
+        Timer::start(self)
+    }
+
+    fn stop(&mut self) {
+        Timer::stop(self)
+    }
+
+    fn resume(&mut self) {
+        Timer::resume(self)
+    }
+
+    fn elapsed(&self) -> Duration {
+        Timer::elapsed(self).real
+    }
+
+    fn process_elapsed(&self) -> Option<ProcessDuration> {
+        Some(Timer::elapsed(self))
+    }
+}
+
+/// Construct a timer of the clock `kind` and start it.
+///
+/// ```
+/// use howlong::{timer_for, ClockKind};
+///
+/// let kind: ClockKind = "process_cpu".parse().unwrap();
+/// let timer = timer_for(kind).unwrap();
+/// // do some computations
+/// println!("{:?}", timer.elapsed());
+/// println!("{}", timer.process_elapsed().unwrap());
+/// ```
+///
+/// # Errors
+///
+/// This function will return [`Error::Unsupported`] if the clock is not available on this
+/// platform or system, or an error if acessing to the underlying clock failed.
+pub fn timer_for(kind: ClockKind) -> Result<Box<dyn AnyTimer>> {
+    fn boxed<ClockType>() -> Result<Box<dyn AnyTimer>>
+    where
+        ClockType: TimerClock + 'static,
+        Timer<ClockType>: AnyTimer,
+    {
+        Ok(Box::new(Timer::<ClockType>::with_start(
+            <ClockType>::try_now()?,
+        )))
+    }
+    match kind {
+        ClockKind::System => boxed::<SystemClock>(),
+        #[cfg(have_steady_clock)]
+        ClockKind::Steady => boxed::<SteadyClock>(),
+        ClockKind::HighResolution => boxed::<HighResolutionClock>(),
+        #[cfg(have_suspend_aware_clock)]
+        ClockKind::SuspendAware if SuspendAwareClock::is_available() => {
+            boxed::<SuspendAwareClock>()
+        }
+        #[cfg(have_coarse_clock)]
+        ClockKind::CoarseSystem if CoarseSystemClock::is_available() => {
+            boxed::<CoarseSystemClock>()
+        }
+        #[cfg(have_coarse_clock)]
+        ClockKind::CoarseSteady if CoarseSteadyClock::is_available() => {
+            boxed::<CoarseSteadyClock>()
+        }
+        #[cfg(have_clock_tai)]
+        ClockKind::Tai if TaiClock::is_available() => boxed::<TaiClock>(),
+        #[cfg(all(feature = "tsc", any(target_arch = "x86", target_arch = "x86_64")))]
+        ClockKind::Tsc if TscClock::is_available() => boxed::<TscClock>(),
+        ClockKind::Uptime => boxed::<UptimeClock>(),
+        ClockKind::ProcessReal => boxed::<ProcessRealCPUClock>(),
+        ClockKind::ProcessUser => boxed::<ProcessUserCPUClock>(),
+        ClockKind::ProcessSystem => boxed::<ProcessSystemCPUClock>(),
+        ClockKind::ProcessCPU => boxed::<ProcessCPUClock>(),
+        ClockKind::ProcessWithChildrenCPU => boxed::<ProcessWithChildrenCPUClock>(),
+        ClockKind::ChildrenCPU => boxed::<ChildrenCPUClock>(),
+        ClockKind::Thread => boxed::<ThreadClock>(),
+        #[allow(unreachable_patterns)]
+        _ => Err(Error::Unsupported(kind.name())),
+    }
+}
@@ -0,0 +1,54 @@
+//! Extension traits to time code fluently.
+//!
+//! Import them with `use howlong::ext::*`.
+//!
+//! # Examples
+//!
+//! ```
+//! use howlong::{clock::ProcessCPUClock, ext::*};
+//!
+//! let (sum, d) = (|| (0..1000u64).sum::<u64>()).timed_hr();
+//! assert_eq!(sum, 499_500);
+//! println!("{:?}", d);
+//!
+//! let (v, d) = (|| vec![1, 2, 3]).timed::<ProcessCPUClock>();
+//! assert_eq!(v, [1, 2, 3]);
+//! println!("{}", d); // 5.71s wall, 5.70s user + 0ns system = 5.70s CPU (99.8%)
+//! ```
+
+use crate::{clock::HighResolutionClock, Clock, Duration};
+use core::ops::Sub;
+
+/// Time a closure with a chosen [`Clock`].
+///
+/// It is implemented for every `FnOnce() -> R`.
+pub trait ElapsedExt<R>: FnOnce() -> R + Sized {
+    /// Call the closure, and return its output and the duration of the call measured by the
+    /// clock `C`, in its native duration type, see [`Clock::Duration`].
+    ///
+    /// The clock is read immediately before and after the call. If the closure panics, the
+    /// panic propagates and nothing is measured.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed, or if the
+    /// clock went backwards during the call, see [`Clock::elapsed_since`].
+    #[inline]
+    fn timed<C: Clock>(self) -> (R, C::Duration)
+    where
+        C::Output: Sub<Output = C::Duration> + Copy,
+    {
+        let start = C::now();
+        let output = self();
+        let elapsed = C::elapsed_since(start);
+        (output, elapsed)
+    }
+
+    /// Like [`timed`](ElapsedExt::timed) with [`HighResolutionClock`].
+    #[inline]
+    fn timed_hr(self) -> (R, Duration) {
+        self.timed::<HighResolutionClock>()
+    }
+}
+
+impl<F: FnOnce() -> R, R> ElapsedExt<R> for F {}
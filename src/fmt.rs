@@ -0,0 +1,365 @@
+//! Human-friendly formatting of [`Duration`].
+//!
+//! The [`Debug`](core::fmt::Debug) format of [`Duration`], e.g. `7230.5s`, is hard to read
+//! for long runs. This module provides three alternatives:
+//!
+//! | Function    | `7230.5s`        | `1.234567ms`            |
+//! |-------------|------------------|-------------------------|
+//! | [`human`]   | `2h 0m 30.5s`    | `1.23ms`                |
+//! | [`full`]    | `0d 2h 0m 30.5s` | `0d 0h 0m 0.001234567s` |
+//! | [`compact`] | `7230s`          | `1.23ms`                |
+//!
+//! Use [`format_duration`] and [`parse_duration`] to write and read durations in the grammar
+//! of [humantime](https://docs.rs/humantime), e.g. `2h 30.5s` or `1h 30m 15s`, which is handy
+//! for configuration files.
+//!
+//! # Examples
+//!
+//! ```
+//! use howlong::fmt;
+//! use std::time::Duration;
+//!
+//! assert_eq!(fmt::human(Duration::from_secs(5400)).to_string(), "1h 30m");
+//! assert_eq!(fmt::compact(Duration::from_nanos(1_234_567)).to_string(), "1.23ms");
+//! assert_eq!(fmt::parse_duration("1h 30m").unwrap(), Duration::from_secs(5400));
+//! ```
+
+use crate::{Duration, Error, ProcessDuration};
+use alloc::{borrow::ToOwned, format, string::String};
+use core::fmt::{Display, Formatter, Result};
+
+const SECS_PER_MINUTE: u64 = 60;
+const SECS_PER_HOUR: u64 = 60 * SECS_PER_MINUTE;
+const SECS_PER_DAY: u64 = 24 * SECS_PER_HOUR;
+
+/// Format `d` in days, hours, minutes, and seconds, e.g. `2h 0m 30.5s`.
+///
+/// Only the two most significant non-zero components, and the zero components between them,
+/// are printed. The seconds are printed with at most one decimal place. Durations shorter
+/// than a minute are formatted by [`compact`] instead.
+///
+/// The dropped components are truncated rather than rounded, so the output never overstates
+/// `d`, e.g. `1d 23h 59m 59s` is printed as `1d 23h`.
+pub fn human(d: Duration) -> impl Display {
+    Human(d)
+}
+
+/// Format `d` with all of the days, hours, minutes, and seconds, e.g. `0d 2h 0m 30.5s`.
+///
+/// The seconds are printed with up to nine decimal places, so the output is exact.
+pub fn full(d: Duration) -> impl Display {
+    Full(d)
+}
+
+/// Format `d` with three significant figures in the unit among `ns`, `µs`, `ms`, and `s`
+/// that fits, e.g. `1.23ms`, like [criterion](https://docs.rs/criterion) does.
+///
+/// The value is rounded to the nearest, so e.g. `999.6µs` is printed as `1.00ms`. Durations
+/// of 1000 seconds or longer are printed as whole seconds, still rounded to three significant
+/// figures, e.g. `7230s`.
+pub fn compact(d: Duration) -> impl Display {
+    Compact(d)
+}
+
+/// Format `d` in the grammar of [humantime](https://docs.rs/humantime), e.g. `2h 37m 1ms` or
+/// `1day 1ns`.
+///
+/// Every non-zero component among days, hours, minutes, seconds, milliseconds, microseconds,
+/// and nanoseconds is printed, so the output can be parsed back by [`parse_duration`]. A zero
+/// duration is printed as `0s`. Unlike humantime, which prints months and years of average
+/// lengths, the durations of a month or longer are printed in days, e.g. `400days`.
+pub fn format_duration(d: Duration) -> impl Display {
+    Humantime(d)
+}
+
+/// The units accepted by [`parse_duration`], and their lengths in nanoseconds.
+const UNITS: &[(&[&str], u128)] = &[
+    (&["nanos", "nsec", "ns"], 1),
+    (&["usec", "us", "µs"], 1_000),
+    (&["millis", "msec", "ms"], 1_000_000),
+    (&["seconds", "second", "secs", "sec", "s"], 1_000_000_000),
+    (
+        &["minutes", "minute", "mins", "min", "m"],
+        60 * 1_000_000_000,
+    ),
+    (&["hours", "hour", "hrs", "hr", "h"], 3_600 * 1_000_000_000),
+    (&["days", "day", "d"], 86_400 * 1_000_000_000),
+    (&["weeks", "week", "w"], 7 * 86_400 * 1_000_000_000),
+];
+
+/// Parse a duration in the grammar of [humantime](https://docs.rs/humantime), e.g.
+/// `1h 30m 15s`, `2days 4h`, or `1.5s`.
+///
+/// The input is a sequence of numbers each immediately followed by a unit. The spaces between
+/// them are optional. The units are `ns`, `us` or `µs`, `ms`, `s`, `m`, `h`, `d`, and `w`, with
+/// the other forms of humantime, e.g. `secs` and `hours`. The numbers may have a fraction, e.g.
+/// `0.25s`, which is truncated to nanoseconds.
+///
+/// # Errors
+///
+/// This function will return [`Error::ParseError`] if `s` is empty or malformed, if a unit is
+/// missing or unknown, or if a unit appears twice, e.g. `1m 2m`. Months and years are
+/// rejected as their lengths are ambiguous.
+pub fn parse_duration(s: &str) -> crate::Result<Duration> {
+    let error = |msg: String| Error::ParseError(format!("{} in duration `{}`", msg, s));
+    let mut rest = s.trim_start();
+    if rest.is_empty() {
+        return Err(Error::ParseError("empty duration".to_owned()));
+    }
+
+    let mut seen = [false; 8];
+    let mut nanos = 0u128;
+    while !rest.is_empty() {
+        let end = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(rest.len());
+        let (number, tail) = rest.split_at(end);
+        if number.is_empty() {
+            return Err(error(format!("expected a number at `{}`", rest)));
+        }
+        let end = tail
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(end);
+        rest = tail.trim_start();
+
+        let (int, frac) = number.split_once('.').unwrap_or((number, ""));
+        if int.is_empty() || number.ends_with('.') || frac.contains('.') {
+            return Err(error(format!("invalid number `{}`", number)));
+        }
+        if unit.is_empty() {
+            return Err(error(format!("missing unit after `{}`", number)));
+        }
+        let (index, scale) = match UNITS.iter().position(|&(names, _)| names.contains(&unit)) {
+            Some(i) => (i, UNITS[i].1),
+            None => {
+                return Err(error(match unit {
+                    "months" | "month" | "M" | "years" | "year" | "y" => {
+                        format!("ambiguous unit `{}`", unit)
+                    }
+                    _ => format!("unknown unit `{}`", unit),
+                }))
+            }
+        };
+        if core::mem::replace(&mut seen[index], true) {
+            return Err(error(format!("repeated unit `{}`", unit)));
+        }
+
+        let overflow = || error("overflow".to_owned());
+        let int: u128 = int.parse().map_err(|_| overflow())?;
+        let mut frac_nanos = 0u128;
+        let mut place = scale;
+        for digit in frac.bytes() {
+            place /= 10;
+            frac_nanos += u128::from(digit - b'0') * place;
+        }
+        nanos = int
+            .checked_mul(scale)
+            .and_then(|n| n.checked_add(frac_nanos))
+            .and_then(|n| n.checked_add(nanos))
+            .ok_or_else(overflow)?;
+    }
+
+    if nanos / 1_000_000_000 > u64::MAX as u128 {
+        return Err(error("overflow".to_owned()));
+    }
+    Ok(Duration::new(
+        (nanos / 1_000_000_000) as u64,
+        (nanos % 1_000_000_000) as u32,
+    ))
+}
+
+struct Human(Duration);
+
+impl Display for Human {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let secs = self.0.as_secs();
+        if secs < SECS_PER_MINUTE {
+            return Compact(self.0).fmt(f);
+        }
+
+        let tenths = self.0.subsec_nanos() / 100_000_000;
+        let components = [
+            secs / SECS_PER_DAY,
+            secs % SECS_PER_DAY / SECS_PER_HOUR,
+            secs % SECS_PER_HOUR / SECS_PER_MINUTE,
+            secs % SECS_PER_MINUTE,
+        ];
+        let is_nonzero = |i: usize| components[i] != 0 || (i == 3 && tenths != 0);
+
+        // `secs` is at least a minute, so there is a non-zero component before the seconds.
+        let first = (0..3).find(|&i| is_nonzero(i)).unwrap();
+        let last = (first + 1..4).find(|&i| is_nonzero(i)).unwrap_or(first);
+        for i in first..=last.min(2) {
+            if i > first {
+                f.write_str(" ")?;
+            }
+            write!(f, "{}{}", components[i], ["d", "h", "m"][i])?;
+        }
+        match (last, tenths) {
+            (3, 0) => write!(f, " {}s", components[3]),
+            (3, _) => write!(f, " {}.{}s", components[3], tenths),
+            _ => Ok(()),
+        }
+    }
+}
+
+struct Full(Duration);
+
+impl Display for Full {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let secs = self.0.as_secs();
+        write!(
+            f,
+            "{}d {}h {}m {}",
+            secs / SECS_PER_DAY,
+            secs % SECS_PER_DAY / SECS_PER_HOUR,
+            secs % SECS_PER_HOUR / SECS_PER_MINUTE,
+            secs % SECS_PER_MINUTE,
+        )?;
+        let mut nanos = self.0.subsec_nanos();
+        if nanos != 0 {
+            let mut width = 9;
+            while nanos.is_multiple_of(10) {
+                nanos /= 10;
+                width -= 1;
+            }
+            write!(f, ".{:0width$}", nanos, width = width)?;
+        }
+        f.write_str("s")
+    }
+}
+
+struct Compact(Duration);
+
+impl Display for Compact {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let nanos = self.0.as_nanos();
+        if nanos < 1000 {
+            return write!(f, "{}ns", nanos);
+        }
+
+        // Round to three significant figures, which may carry into one more digit.
+        let scale = 10u128.pow(digits(nanos) - 3);
+        let nanos = (nanos + scale / 2) / scale * scale;
+        let (unit, name) = match digits(nanos) {
+            4..=6 => (1_000, "µs"),
+            7..=9 => (1_000_000, "ms"),
+            _ => (1_000_000_000, "s"),
+        };
+        let integral = nanos / unit;
+        let decimals = 3u32.saturating_sub(digits(integral));
+        if decimals == 0 {
+            write!(f, "{}{}", integral, name)
+        } else {
+            let fraction = nanos % unit / (unit / 10u128.pow(decimals));
+            write!(
+                f,
+                "{}.{:0width$}{}",
+                integral,
+                fraction,
+                name,
+                width = decimals as usize
+            )
+        }
+    }
+}
+
+struct Humantime(Duration);
+
+impl Display for Humantime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let secs = self.0.as_secs();
+        let nanos = self.0.subsec_nanos();
+        if secs == 0 && nanos == 0 {
+            return f.write_str("0s");
+        }
+
+        let days = secs / SECS_PER_DAY;
+        let components = [
+            (days, if days == 1 { "day" } else { "days" }),
+            (secs % SECS_PER_DAY / SECS_PER_HOUR, "h"),
+            (secs % SECS_PER_HOUR / SECS_PER_MINUTE, "m"),
+            (secs % SECS_PER_MINUTE, "s"),
+            (u64::from(nanos / 1_000_000), "ms"),
+            (u64::from(nanos / 1_000 % 1_000), "us"),
+            (u64::from(nanos % 1_000), "ns"),
+        ];
+        let mut first = true;
+        for &(value, unit) in components.iter().filter(|&&(value, _)| value != 0) {
+            if !first {
+                f.write_str(" ")?;
+            }
+            first = false;
+            write!(f, "{}{}", value, unit)?;
+        }
+        Ok(())
+    }
+}
+
+/// The number of decimal digits of `n`.
+fn digits(mut n: u128) -> u32 {
+    let mut digits = 1;
+    while n >= 10 {
+        n /= 10;
+        digits += 1;
+    }
+    digits
+}
+
+/// A wrapper of [`ProcessDuration`] whose [`Display`] formats the times by [`human`].
+///
+/// It is returned by [`ProcessDuration::human`], and will look something like this:
+/// ```text
+/// 2h 0m 30.5s wall, 1h 59m user + 12.3s system = 1h 59m CPU (99.1%)
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct HumanProcessDuration(pub(crate) ProcessDuration);
+
+impl Display for HumanProcessDuration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let d = &self.0;
+        write!(
+            f,
+            "{} wall, {} user + {} system = {} CPU (",
+            human(d.real),
+            human(d.user),
+            human(d.system),
+            human(d.cpu_time()),
+        )?;
+        match d.checked_cpu_usage() {
+            Some(usage) => write!(f, "{:.1}%)", usage * 100f64),
+            None => write!(f, "--%)"),
+        }
+    }
+}
+
+/// A wrapper of [`ProcessDuration`] whose [`Display`] formats the times by
+/// [`format_duration`].
+///
+/// It is returned by [`ProcessDuration::format_human`], and will look something like this:
+/// ```text
+/// 2h 30s 500ms wall, 1h 59m 10s user + 12s 300ms system = 1h 59m 22s 300ms CPU (99.1%)
+/// ```
+///
+/// The output can be parsed back by [`ProcessDuration::parse_human`].
+#[derive(Clone, Copy, Debug)]
+pub struct HumantimeProcessDuration(pub(crate) ProcessDuration);
+
+impl Display for HumantimeProcessDuration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let d = &self.0;
+        write!(
+            f,
+            "{} wall, {} user + {} system = {} CPU (",
+            format_duration(d.real),
+            format_duration(d.user),
+            format_duration(d.system),
+            format_duration(d.cpu_time()),
+        )?;
+        match d.checked_cpu_usage() {
+            Some(usage) => write!(f, "{:.1}%)", usage * 100f64),
+            None => write!(f, "--%)"),
+        }
+    }
+}
@@ -0,0 +1,269 @@
+use crate::{fmt::compact, Duration};
+use alloc::{vec, vec::Vec};
+use core::convert::TryFrom;
+
+/// A histogram of [`Duration`]s with logarithmically spaced buckets, to query percentiles such
+/// as the p50, p95, and p99 of latencies.
+///
+/// The buckets cover a range of durations with a given number of significant decimal digits,
+/// like [HdrHistogram](http://hdrhistogram.org/). The durations below the range are counted in
+/// the first bucket, and those above it in the last one. The buckets are allocated by the
+/// constructor, so [`record`](DurationHistogram::record) never allocates and the memory is
+/// bounded, e.g. about 36 KiB for the [default](DurationHistogram::default) range of 1ns to
+/// 1h with two significant digits.
+///
+/// # Examples
+///
+/// ```
+/// use howlong::{Duration, DurationHistogram};
+///
+/// let mut histogram = DurationHistogram::default();
+/// for us in 1..=1000 {
+///     histogram.record(Duration::from_micros(us));
+/// }
+/// let p99 = histogram.percentile(99.0);
+/// assert!(p99 >= Duration::from_micros(990) && p99 <= Duration::from_micros(1000));
+/// println!("{}", histogram); // 1000 samples: p50 502µs, p90 901µs, p95 950µs, p99 991µs, max 1.00ms
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DurationHistogram {
+    /// The length of a unit in nanoseconds, i.e. the lowest duration of the range.
+    unit: u64,
+    /// The highest duration of the range in units.
+    highest: u64,
+    /// The log2 of the number of linear buckets per power of two.
+    sub_bits: u32,
+    counts: Vec<u64>,
+    count: u64,
+    min: Duration,
+    max: Duration,
+}
+
+impl DurationHistogram {
+    /// Construct an empty histogram covering `lowest` to `highest` with `significant_digits`
+    /// decimal digits, i.e. the percentiles are reported with a relative error below
+    /// `10^-significant_digits`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `lowest` is zero or longer than `highest`, or if
+    /// `significant_digits` is not between 1 and 5.
+    pub fn new(lowest: Duration, highest: Duration, significant_digits: u32) -> Self {
+        assert!(
+            !lowest.is_zero() && lowest <= highest,
+            "the range of the histogram must not be empty and start above zero"
+        );
+        assert!(
+            (1..=5).contains(&significant_digits),
+            "the significant digits must be between 1 and 5"
+        );
+        let unit = u64::try_from(lowest.as_nanos()).unwrap_or(u64::MAX);
+        let highest = u64::try_from(highest.as_nanos() / u128::from(unit)).unwrap_or(u64::MAX);
+        let sub_bits = (2 * 10u64.pow(significant_digits))
+            .next_power_of_two()
+            .trailing_zeros();
+        let mut histogram = DurationHistogram {
+            unit,
+            highest,
+            sub_bits,
+            counts: Vec::new(),
+            count: 0,
+            min: Duration::ZERO,
+            max: Duration::ZERO,
+        };
+        histogram.counts = vec![0; histogram.index(highest) + 1];
+        histogram
+    }
+
+    /// The index of the bucket of `value` units.
+    fn index(&self, value: u64) -> usize {
+        let value = value.min(self.highest);
+        let sub_count = 1u64 << self.sub_bits;
+        if value < sub_count {
+            return value as usize;
+        }
+        // The magnitude is chosen so that `value >> magnitude` is in `[sub_count / 2, sub_count)`.
+        let magnitude = (63 - value.leading_zeros()) - (self.sub_bits - 1);
+        let half = sub_count / 2;
+        (sub_count + u64::from(magnitude - 1) * half + ((value >> magnitude) - half)) as usize
+    }
+
+    /// The highest value in units of the bucket `index`.
+    fn highest_equivalent(&self, index: usize) -> u64 {
+        let index = index as u64;
+        let sub_count = 1u64 << self.sub_bits;
+        if index < sub_count {
+            return index;
+        }
+        let half = sub_count / 2;
+        let magnitude = (index - sub_count) / half + 1;
+        let sub = (index - sub_count) % half + half;
+        (((sub + 1) as u128) << magnitude)
+            .saturating_sub(1)
+            .min(u64::MAX as u128) as u64
+    }
+
+    /// The highest duration of the bucket `index`, clamped to the recorded range.
+    fn value_at(&self, index: usize) -> Duration {
+        let nanos = u128::from(self.highest_equivalent(index)) * u128::from(self.unit);
+        let d = match u64::try_from(nanos / 1_000_000_000) {
+            Ok(secs) => Duration::new(secs, (nanos % 1_000_000_000) as u32),
+            Err(_) => Duration::MAX,
+        };
+        d.min(self.max).max(self.min)
+    }
+
+    /// Record the duration `d`.
+    pub fn record(&mut self, d: Duration) {
+        let value = u64::try_from(d.as_nanos() / u128::from(self.unit)).unwrap_or(u64::MAX);
+        let index = self.index(value);
+        self.counts[index] += 1;
+        if self.count == 0 {
+            self.min = d;
+            self.max = d;
+        } else {
+            self.min = self.min.min(d);
+            self.max = self.max.max(d);
+        }
+        self.count += 1;
+    }
+
+    /// Return the number of recorded durations.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Return the shortest recorded duration, or zero if nothing is recorded.
+    pub fn min(&self) -> Duration {
+        self.min
+    }
+
+    /// Return the longest recorded duration, or zero if nothing is recorded.
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+
+    /// Return the duration below or at which `percentile` percent of the recorded durations
+    /// fall, e.g. `percentile(99.0)` for the p99. It is the highest duration of the bucket, so
+    /// it might overstate the exact percentile by the precision of the histogram, but it never
+    /// exceeds [`max`](DurationHistogram::max). The lowest and the highest ranks are reported
+    /// exactly as [`min`](DurationHistogram::min) and [`max`](DurationHistogram::max). Zero is
+    /// returned if nothing is recorded.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `percentile` is not between 0 and 100.
+    pub fn percentile(&self, percentile: f64) -> Duration {
+        assert!(
+            (0f64..=100f64).contains(&percentile),
+            "the percentile must be between 0 and 100"
+        );
+        if self.count == 0 {
+            return Duration::ZERO;
+        }
+        let exact = percentile * self.count as f64 / 100f64;
+        let mut rank = exact as u64;
+        if (rank as f64) < exact {
+            rank += 1;
+        }
+        // The extremes are known exactly, even outside of the range of the buckets.
+        let rank = rank.clamp(1, self.count);
+        if rank == 1 {
+            return self.min;
+        } else if rank == self.count {
+            return self.max;
+        }
+        let mut seen = 0;
+        for (index, &count) in self.counts.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                return self.value_at(index);
+            }
+        }
+        self.max
+    }
+
+    /// Add the durations recorded by `other`, e.g. to combine the histograms of several threads.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `other` has a different range or precision.
+    pub fn merge(&mut self, other: &DurationHistogram) {
+        assert!(
+            self.unit == other.unit
+                && self.highest == other.highest
+                && self.sub_bits == other.sub_bits,
+            "the histograms must have the same range and precision"
+        );
+        if other.count == 0 {
+            return;
+        }
+        for (count, &other) in self.counts.iter_mut().zip(other.counts.iter()) {
+            *count += other;
+        }
+        if self.count == 0 {
+            self.min = other.min;
+            self.max = other.max;
+        } else {
+            self.min = self.min.min(other.min);
+            self.max = self.max.max(other.max);
+        }
+        self.count += other.count;
+    }
+
+    /// Remove all the recorded durations, keeping the buckets allocated.
+    pub fn clear(&mut self) {
+        self.counts.iter_mut().for_each(|count| *count = 0);
+        self.count = 0;
+        self.min = Duration::ZERO;
+        self.max = Duration::ZERO;
+    }
+}
+
+impl Default for DurationHistogram {
+    /// Construct an empty histogram covering 1ns to 1h with two significant digits.
+    fn default() -> Self {
+        DurationHistogram::new(Duration::from_nanos(1), Duration::from_secs(3600), 2)
+    }
+}
+
+impl core::fmt::Display for DurationHistogram {
+    /// Formats the key percentiles of the [`DurationHistogram`] by [`compact`]. It will look
+    /// something like this:
+    /// ```text
+    /// 1000 samples: p50 502µs, p90 901µs, p95 950µs, p99 991µs, max 1.00ms
+    /// ```
+    ///
+    /// The alternate form, i.e. `{:#}`, prints the highest duration and the count of every
+    /// non-empty bucket instead, one per line, e.g.
+    /// ```text
+    /// ≤ 1.00µs: 1
+    /// ≤ 1.99µs: 1
+    /// ```
+    ///
+    /// It is printed as `no samples` if nothing is recorded.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.count == 0 {
+            return f.write_str("no samples");
+        }
+        if f.alternate() {
+            let mut first = true;
+            for (index, &count) in self.counts.iter().enumerate() {
+                if count == 0 {
+                    continue;
+                }
+                if !first {
+                    f.write_str("\n")?;
+                }
+                first = false;
+                write!(f, "≤ {}: {}", compact(self.value_at(index)), count)?;
+            }
+            return Ok(());
+        }
+        write!(f, "{} samples:", self.count)?;
+        for &p in [50f64, 90f64, 95f64, 99f64].iter() {
+            write!(f, " p{} {},", p, compact(self.percentile(p)))?;
+        }
+        write!(f, " max {}", compact(self.max))
+    }
+}
@@ -0,0 +1,152 @@
+//! A drop-in replacement of [`std::time::Instant`].
+
+use crate::{clock::*, Clock, Duration, TimePoint};
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// A measurement of a clock, with the same methods and operators as [`std::time::Instant`], so
+/// that migrating to it only takes changing the import.
+///
+/// It is read from [`HighResolutionClock`], i.e. [`SteadyClock`] if it is available, and
+/// [`SystemClock`] otherwise. Unlike [`std::time::Instant`], it is only monotonically
+/// nondecreasing on the former: with the fallback, or if [`SystemClock`] is forced with
+/// [`HighResolutionClock::force_backend`], it follows the adjustments of the system time and
+/// may go backwards. Check it with [`HighResolutionClock::is_steady`]. Convert it to and from
+/// a [`TimePoint`] of [`HighResolutionClock`] with [`From`].
+///
+/// # Examples
+///
+/// ```
+/// use howlong::{Duration, Instant};
+///
+/// let now = Instant::now();
+/// // do some computations
+/// let elapsed = now.elapsed();
+/// assert!(Instant::now() >= now + elapsed);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Instant(TimePoint);
+
+impl Instant {
+    /// Return an instant corresponding to "now".
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    #[inline(always)]
+    pub fn now() -> Instant {
+        Instant(HighResolutionClock::now())
+    }
+
+    /// Return the amount of time elapsed from `earlier` to `self`, or zero if `earlier` is
+    /// later than `self`, like [`std::time::Instant::duration_since`].
+    #[inline(always)]
+    pub fn duration_since(&self, earlier: Instant) -> Duration {
+        self.saturating_duration_since(earlier)
+    }
+
+    /// Return the amount of time elapsed from `earlier` to `self`, or `None` if `earlier` is
+    /// later than `self`.
+    #[inline(always)]
+    pub fn checked_duration_since(&self, earlier: Instant) -> Option<Duration> {
+        self.0.checked_duration_since(earlier.0)
+    }
+
+    /// Return the amount of time elapsed from `earlier` to `self`, or zero if `earlier` is
+    /// later than `self`.
+    #[inline(always)]
+    pub fn saturating_duration_since(&self, earlier: Instant) -> Duration {
+        self.0.saturating_duration_since(earlier.0)
+    }
+
+    /// Return the amount of time elapsed since this instant.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    #[inline(always)]
+    pub fn elapsed(&self) -> Duration {
+        Instant::now().duration_since(*self)
+    }
+
+    /// Return the instant `duration` after `self`, or `None` if it overflows.
+    #[inline(always)]
+    pub fn checked_add(&self, duration: Duration) -> Option<Instant> {
+        self.0.checked_add(duration).map(Instant)
+    }
+
+    /// Return the instant `duration` before `self`, or `None` if it is before the epoch of the
+    /// clock.
+    #[inline(always)]
+    pub fn checked_sub(&self, duration: Duration) -> Option<Instant> {
+        self.0.checked_sub_duration(duration).map(Instant)
+    }
+}
+
+impl Add<Duration> for Instant {
+    type Output = Instant;
+
+    /// # Panics
+    ///
+    /// This function will panic if the result overflows. Use [`Instant::checked_add`] instead
+    /// if that is possible.
+    #[inline(always)]
+    fn add(self, rhs: Duration) -> Instant {
+        self.checked_add(rhs)
+            .expect("overflow when adding duration to instant")
+    }
+}
+
+impl AddAssign<Duration> for Instant {
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Duration> for Instant {
+    type Output = Instant;
+
+    /// # Panics
+    ///
+    /// This function will panic if the result is before the epoch of the clock. Use
+    /// [`Instant::checked_sub`] instead if that is possible.
+    #[inline(always)]
+    fn sub(self, rhs: Duration) -> Instant {
+        self.checked_sub(rhs)
+            .expect("overflow when subtracting duration from instant")
+    }
+}
+
+impl SubAssign<Duration> for Instant {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sub<Instant> for Instant {
+    type Output = Duration;
+
+    /// Return the amount of time elapsed from `other` to `self`, or zero if `other` is later
+    /// than `self`, like [`Instant::duration_since`].
+    #[inline(always)]
+    fn sub(self, other: Instant) -> Duration {
+        self.duration_since(other)
+    }
+}
+
+impl From<TimePoint> for Instant {
+    /// Convert a timepoint of [`HighResolutionClock`] to an instant.
+    #[inline(always)]
+    fn from(point: TimePoint) -> Self {
+        Instant(point)
+    }
+}
+
+impl From<Instant> for TimePoint {
+    /// Convert an instant to a timepoint of [`HighResolutionClock`].
+    #[inline(always)]
+    fn from(instant: Instant) -> Self {
+        instant.0
+    }
+}
@@ -6,14 +6,57 @@
 //!
 //! * [`SystemClock`], [`SystemTimer`]
 //! * [`SteadyClock`], [`SteadyTimer`] if supported by the system.
+//! * [`SuspendAwareClock`], [`SuspendAwareTimer`] if supported by the system.
+//! * [`CoarseSystemClock`], [`CoarseSystemTimer`] if supported by the system.
+//! * [`CoarseSteadyClock`], [`CoarseSteadyTimer`] if supported by the system.
+//! * [`TaiClock`], [`TaiTimer`] on Linux.
+//! * `TscClock`, `TscTimer` on x86 with the `tsc` feature.
+//! * [`UptimeClock`]
+//! * [`ProcessUptimeClock`]
 //! * [`HighResolutionClock`], [`HighResolutionTimer`]
 //! * [`ProcessRealCPUClock`], [`ProcessRealCPUTimer`]
 //! * [`ProcessUserCPUClock`], [`ProcessUserCPUTimer`]
 //! * [`ProcessSystemCPUClock`], [`ProcessSystemCPUTimer`]
 //! * [`ProcessCPUClock`], [`ProcessCPUTimer`]
+//! * [`ProcessSelfCPUClock`], [`ProcessSelfCPUTimer`]
+//! * [`ProcessWithChildrenCPUClock`], [`ProcessWithChildrenCPUTimer`]
+//! * [`ChildrenCPUClock`], [`ChildrenCPUTimer`]
+//! * [`HostCPUClock`], [`HostCPUTimer`]
+//! * `ProcStatClock`, `ProcStatTimer` on Linux.
+//! * `CgroupCPUClock`, `CgroupCPUTimer` on Linux.
+//! * `RusageUserCPUClock`, `RusageSystemCPUClock`, `RusageCPUClock`, `RusageChildrenCPUClock`,
+//!   and their timers on Unix except Fuchsia and Haiku.
 //! * [`ThreadClock`], [`ThreadTimer`]
+//! * [`ThreadCPUClock`], [`ThreadCPUTimer`]
+//! * `ThreadCycleClock`, `ThreadCycleTimer`, `ProcessCycleClock`, `ProcessCycleTimer` on
+//!   Windows.
 //!
-//! See [`crate::clock`] to read more about their differences.
+//! See [`crate::clock`] to read more about their differences. Use [`DynClock`] and
+//! [`DynTimer`], or [`timer_for`] with a [`ClockKind`], to choose a clock at runtime. Use
+//! `ClockAnchor` to convert the timepoints of [`SteadyClock`] to and from
+//! [`std::time::Instant`]. Use [`mock::MockClock`] and
+//! [`mock::MockProcessClock`] to test timing code deterministically.
+//! Use [`ext::ElapsedExt`] to time a closure, e.g. `(|| work()).timed::<ThreadClock>()`.
+//! Use [`Instant`] as a drop-in replacement of [`std::time::Instant`].
+//! Use [`Stamped`] and [`StampedTimer`] to tag timepoints with their clock, so that mixing up
+//! clocks is a compile error.
+//! Use [`compare_durations`] and [`ProcessDuration::speedup_over`] to compare runs before and
+//! after an optimization, and [`baseline`] to save the timings of a run and detect the
+//! regressions of later runs.
+//! Use [`ProcessStats`] to aggregate the durations of repeated runs,
+//! [`StreamingStats`] to track the mean and deviation of durations in constant memory, and
+//! [`DurationHistogram`] to query the percentiles of latencies.
+//! Use [`CpuUsageMonitor`] to sample the CPU usage of the process in the background, and
+//! [`CpuGroup`] to add up the CPU times of the threads working for the same task.
+//! Use `Ticker` to run periodic work at a fixed rate, and `sleep_until` to sleep until a
+//! timepoint of [`SteadyClock`] or a `Deadline`.
+//! Use `RateLimiter` and `SharedRateLimiter` to throttle work.
+//! Use [`fmt`] to format durations for humans, e.g. `2h 0m 30.5s`, and [`report`] to write
+//! them as CSV for scripts. Use [`parse_duration`] and [`format_duration`] to read and write
+//! durations like `1h 30m 15s`.
+//! Use [`reporting`] to silence the guards which report when dropped, e.g. with
+//! `HOWLONG_REPORT=off`. Use [`testing`] to assert duration budgets in tests, e.g.
+//! `assert_elapsed_under!(timer, Duration::from_millis(200))`.
 //!
 //! # Usage
 //!
@@ -34,9 +77,44 @@
 //! // do other computations
 //! println!("{}", timer.elapsed()); // 5.71s wall, 5.70s user + 0ns system = 5.70s CPU (99.8%)
 //! ```
+//!
+//! # Features
+//!
+//! * `std` (default): Implement the clocks of the operating systems, and everything built on
+//!   them, using the standard library. Without it, the crate is `#![no_std]` but requires
+//!   `alloc`. The generic [`Timer`], [`Clock`], and the timepoint and duration types remain
+//!   available to be used with your own clock, while the clocks of this crate return
+//!   [`Error::Unsupported`]. All the other features require `std`.
+//! * `serde`: Implement `Serialize` and `Deserialize` for [`ProcessDuration`],
+//!   [`ThreadDuration`], [`Cycles`], [`PersistedTimer`], and [`ProcessStats`].
+//! * `log`: Emit elapsed times through the [`log`](https://docs.rs/log) facade, e.g.
+//!   `Timer::log_elapsed` and `LoggedScope`.
+//! * `tracing`: Record elapsed times on [`tracing`](https://docs.rs/tracing) spans using
+//!   `Timer::record_in_span`, and time spans using `HowlongLayer`.
+//! * `metrics`: Record durations as histograms through the
+//!   [`metrics`](https://docs.rs/metrics) facade. See `howlong::metrics`.
+//! * `criterion`: Implement [`criterion`](https://docs.rs/criterion) measurements for the CPU
+//!   and thread clocks. See `howlong::criterion`.
+//! * `chrono`: Convert the timepoints of [`SystemClock`] and durations to and from
+//!   [`chrono`](https://docs.rs/chrono) types. See `howlong::chrono`.
+//! * `time`: Convert the timepoints of [`SystemClock`] and durations to and from
+//!   [`time`](https://docs.rs/time) types. See `howlong::time`.
+//! * `proptest`: Implement [`proptest`](https://docs.rs/proptest) `Arbitrary` for
+//!   [`ProcessDuration`], [`ProcessTimePoint`], and [`TimePoint`], and provide strategies
+//!   like `process_duration_within`. See `howlong::proptest`.
+//! * `rayon`: Implement `PoolCpuTimer` to measure the CPU time of the workers of a
+//!   [`rayon`](https://docs.rs/rayon) thread pool. See `howlong::rayon`.
+//! * `tsc`: Implement `TscClock` and `TscTimer` based on the timestamp counter of x86
+//!   processors.
+//! * `cli`: Build the `howlong` binary, which times a command like `time(1)`.
+//! * `wasm`: Implement [`SystemClock`] and [`SteadyClock`] on `wasm32-unknown-unknown` using
+//!   `Date.now()` and `performance.now()`. The CPU clocks return [`Error::Unsupported`].
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::upper_case_acronyms)]
 
+extern crate alloc;
+
 mod types;
 pub use types::*;
 
@@ -45,3 +123,142 @@ pub use clock::*;
 
 pub mod timer;
 pub use timer::*;
+
+pub mod ext;
+
+mod instant;
+pub use instant::*;
+
+#[cfg(feature = "std")]
+pub mod bench;
+
+pub mod fmt;
+pub use fmt::{format_duration, parse_duration};
+
+#[cfg(feature = "std")]
+pub mod report;
+
+#[cfg(feature = "std")]
+pub mod mock;
+
+#[cfg(feature = "std")]
+mod calibration;
+#[cfg(feature = "std")]
+pub use calibration::*;
+
+mod speedup;
+pub use speedup::*;
+
+mod stats;
+pub use stats::*;
+
+mod histogram;
+pub use histogram::*;
+
+mod dyn_clock;
+pub use dyn_clock::*;
+
+#[cfg(feature = "std")]
+mod monitor;
+#[cfg(feature = "std")]
+pub use monitor::*;
+
+#[cfg(feature = "std")]
+mod cpu_group;
+#[cfg(feature = "std")]
+pub use cpu_group::*;
+
+#[cfg(all(
+    have_steady_clock,
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+mod sleep;
+#[cfg(all(
+    have_steady_clock,
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+pub use sleep::*;
+
+#[cfg(all(
+    have_steady_clock,
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+mod deadline;
+#[cfg(all(
+    have_steady_clock,
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+pub use deadline::*;
+
+#[cfg(all(
+    have_steady_clock,
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+mod rate_limiter;
+#[cfg(all(
+    have_steady_clock,
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+pub use rate_limiter::*;
+
+#[cfg(all(
+    have_steady_clock,
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+mod ticker;
+#[cfg(all(
+    have_steady_clock,
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+pub use ticker::*;
+
+#[cfg(all(
+    have_steady_clock,
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+mod anchor;
+#[cfg(all(
+    have_steady_clock,
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+pub use anchor::*;
+
+#[cfg(feature = "std")]
+pub mod command;
+
+#[cfg(feature = "std")]
+pub mod reporting;
+
+#[cfg(feature = "std")]
+pub mod baseline;
+
+#[cfg(feature = "std")]
+pub mod testing;
+
+#[cfg(feature = "log")]
+mod logging;
+#[cfg(feature = "log")]
+pub use logging::*;
+
+#[cfg(feature = "tracing")]
+mod trace;
+#[cfg(feature = "tracing")]
+pub use trace::*;
+
+#[cfg(feature = "metrics")]
+pub mod metrics;
+
+#[cfg(feature = "criterion")]
+pub mod criterion;
+
+#[cfg(feature = "chrono")]
+pub mod chrono;
+
+#[cfg(feature = "time")]
+pub mod time;
+
+#[cfg(feature = "proptest")]
+pub mod proptest;
+
+#[cfg(feature = "rayon")]
+pub mod rayon;
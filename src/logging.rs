@@ -0,0 +1,106 @@
+use crate::{timer::*, Clock, Duration, ProcessDuration, ProcessTimePoint, TimePoint};
+use log::Level;
+
+impl<ClockType> Timer<ClockType, TimePoint, Duration>
+where
+    ClockType: Clock<Output = TimePoint>,
+{
+    /// Emit the elapsed time through the [`log`] facade as `"{msg}: {elapsed:?}"`.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn log_elapsed(&self, level: Level, target: &str, msg: &str) {
+        if log::log_enabled!(target: target, level) {
+            log::log!(target: target, level, "{}: {:?}", msg, self.elapsed());
+        }
+    }
+}
+
+impl<ClockType> Timer<ClockType, ProcessTimePoint, ProcessDuration>
+where
+    ClockType: Clock<Output = ProcessTimePoint>,
+{
+    /// Emit the elapsed time through the [`log`] facade as `"{msg}: {elapsed}"`.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn log_elapsed(&self, level: Level, target: &str, msg: &str) {
+        if log::log_enabled!(target: target, level) {
+            log::log!(target: target, level, "{}: {}", msg, self.elapsed());
+        }
+    }
+}
+
+impl ThreadTimer {
+    /// Emit the elapsed time through the [`log`] facade as `"{msg}: {elapsed:?}"`.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn log_elapsed(&self, level: Level, target: &str, msg: &str) {
+        if log::log_enabled!(target: target, level) {
+            log::log!(target: target, level, "{}: {:?}", msg, self.elapsed());
+        }
+    }
+}
+
+/// A guard which emits the time elapsed since its construction through the [`log`] facade
+/// when dropped.
+///
+/// # Examples
+///
+/// ```
+/// {
+///     let _scope = howlong::LoggedScope::new(log::Level::Info, "my_app", "computation");
+///     // do some computations
+/// } // logs "computation: 12.4ms"
+/// ```
+pub struct LoggedScope<'a> {
+    /// `None` if the reporting was disabled at the construction.
+    timer: Option<HighResolutionTimer>,
+    level: Level,
+    target: &'a str,
+    label: &'a str,
+    forced: bool,
+}
+
+impl<'a> LoggedScope<'a> {
+    /// Construct a guard and start timing.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn new(level: Level, target: &'a str, label: &'a str) -> Self {
+        LoggedScope {
+            timer: crate::reporting::is_enabled().then(HighResolutionTimer::new),
+            level,
+            target,
+            label,
+            forced: false,
+        }
+    }
+
+    /// Log when dropped even if the reporting is disabled, see [`crate::reporting`]. If it
+    /// was disabled at the construction, the timing starts now.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn force(mut self) -> Self {
+        self.timer.get_or_insert_with(HighResolutionTimer::new);
+        self.forced = true;
+        self
+    }
+}
+
+impl Drop for LoggedScope<'_> {
+    fn drop(&mut self) {
+        if let Some(timer) = &self.timer {
+            if self.forced || crate::reporting::is_enabled() {
+                timer.log_elapsed(self.level, self.target, self.label);
+            }
+        }
+    }
+}
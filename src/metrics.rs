@@ -0,0 +1,117 @@
+//! Record durations through the [`metrics`](https://docs.rs/metrics) facade.
+//!
+//! Durations are recorded as histograms in seconds. A [`ProcessDuration`] is recorded as
+//! three histograms named `<name>_wall_seconds`, `<name>_user_seconds`, and
+//! `<name>_system_seconds`.
+//!
+//! # Examples
+//!
+//! ```
+//! {
+//!     let _scope = howlong::metrics::MeteredScope::new("computation");
+//!     // do some computations
+//! } // records computation_{wall,user,system}_seconds
+//! ```
+
+use crate::{timer::ProcessCPUTimer, Duration, ProcessDuration};
+use ::metrics::Label;
+
+fn labels(labels: &[(&str, String)]) -> Vec<Label> {
+    labels
+        .iter()
+        .map(|(key, value)| Label::new(key.to_string(), value.clone()))
+        .collect()
+}
+
+fn record(name: String, d: Duration, labels: Vec<Label>) {
+    ::metrics::histogram!(name, labels).record(d.as_secs_f64());
+}
+
+fn record_process(name: &str, d: ProcessDuration, labels: Vec<Label>) {
+    record(format!("{}_wall_seconds", name), d.real, labels.clone());
+    record(format!("{}_user_seconds", name), d.user, labels.clone());
+    record(format!("{}_system_seconds", name), d.system, labels);
+}
+
+/// Record `d` in seconds as the histogram `<name>_seconds`.
+pub fn record_duration(name: &str, d: Duration) {
+    record_duration_with_labels(name, d, &[]);
+}
+
+/// Like [`record_duration`] but attach `labels` to the histogram.
+pub fn record_duration_with_labels(name: &str, d: Duration, labels: &[(&str, String)]) {
+    record(format!("{}_seconds", name), d, self::labels(labels));
+}
+
+/// Record `d` in seconds as the histograms `<name>_wall_seconds`, `<name>_user_seconds`, and
+/// `<name>_system_seconds`.
+pub fn record_process_duration(name: &str, d: ProcessDuration) {
+    record_process_duration_with_labels(name, d, &[]);
+}
+
+/// Like [`record_process_duration`] but attach `labels` to the histograms.
+pub fn record_process_duration_with_labels(
+    name: &str,
+    d: ProcessDuration,
+    labels: &[(&str, String)],
+) {
+    record_process(name, d, self::labels(labels));
+}
+
+/// A guard which records the [`ProcessDuration`] elapsed since its construction using
+/// [`record_process_duration`] when dropped.
+pub struct MeteredScope {
+    /// `None` if the reporting was disabled at the construction.
+    timer: Option<ProcessCPUTimer>,
+    name: String,
+    labels: Vec<Label>,
+    forced: bool,
+}
+
+impl MeteredScope {
+    /// Construct a guard and start timing.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn new(name: &str) -> Self {
+        Self::with_labels(name, &[])
+    }
+
+    /// Like [`new()`](#method.new) but attach `labels` to the histograms.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn with_labels(name: &str, labels: &[(&str, String)]) -> Self {
+        MeteredScope {
+            timer: crate::reporting::is_enabled().then(ProcessCPUTimer::new),
+            name: name.to_owned(),
+            labels: self::labels(labels),
+            forced: false,
+        }
+    }
+
+    /// Record when dropped even if the reporting is disabled, see [`crate::reporting`]. If
+    /// it was disabled at the construction, the timing starts now.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn force(mut self) -> Self {
+        self.timer.get_or_insert_with(ProcessCPUTimer::new);
+        self.forced = true;
+        self
+    }
+}
+
+impl Drop for MeteredScope {
+    fn drop(&mut self) {
+        if let Some(timer) = &self.timer {
+            if self.forced || crate::reporting::is_enabled() {
+                let labels = core::mem::take(&mut self.labels);
+                record_process(&self.name, timer.elapsed(), labels);
+            }
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! Clocks controlled by the caller, for deterministic tests of timing code.
+//!
+//! The current values of [`MockClock`] and [`MockProcessClock`] are thread-local and start at
+//! zero. They only change when [`set()`](MockClock::set), [`advance()`](MockClock::advance),
+//! or [`reset()`](MockClock::reset) is called on the same thread.
+//!
+//! # Examples
+//!
+//! ```
+//! use howlong::mock::{MockClock, MockTimer};
+//! use howlong::Duration;
+//!
+//! MockClock::reset();
+//! let mut timer = MockTimer::new();
+//! MockClock::advance(Duration::from_secs(2));
+//! timer.stop();
+//! MockClock::advance(Duration::from_secs(5));
+//! assert_eq!(timer.elapsed(), Duration::from_secs(2));
+//! ```
+
+use crate::{Clock, Duration, ProcessDuration, ProcessTimePoint, Result, TimePoint, Timer};
+use std::cell::Cell;
+
+thread_local! {
+    // Clippy falsely suggests `const` on the targets using the OS thread-local storage.
+    #[allow(clippy::missing_const_for_thread_local)]
+    static MOCK_NOW: Cell<Duration> = const { Cell::new(Duration::ZERO) };
+    #[allow(clippy::missing_const_for_thread_local)]
+    static MOCK_PROCESS_NOW: Cell<ProcessDuration> = const { Cell::new(ProcessDuration::ZERO) };
+}
+
+/// A clock whose current time is set by the caller.
+pub struct MockClock;
+
+impl MockClock {
+    /// Set the current time of the calling thread to `now`.
+    pub fn set(now: Duration) {
+        MOCK_NOW.with(|cell| cell.set(now));
+    }
+
+    /// Advance the current time of the calling thread by `d`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the current time overflows.
+    pub fn advance(d: Duration) {
+        MOCK_NOW.with(|cell| cell.set(cell.get() + d));
+    }
+
+    /// Reset the current time of the calling thread to zero.
+    pub fn reset() {
+        Self::set(Duration::ZERO);
+    }
+}
+
+impl Clock for MockClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(TimePoint(MOCK_NOW.with(Cell::get)))
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(Duration::from_nanos(1))
+    }
+}
+
+/// A process clock whose real, user-CPU, and system-CPU times are set by the caller.
+pub struct MockProcessClock;
+
+impl MockProcessClock {
+    /// Set the current times of the calling thread to `now`.
+    pub fn set(now: ProcessDuration) {
+        MOCK_PROCESS_NOW.with(|cell| cell.set(now));
+    }
+
+    /// Advance each of the current times of the calling thread by the matching field of `d`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if any of the current times overflows.
+    pub fn advance(d: ProcessDuration) {
+        MOCK_PROCESS_NOW.with(|cell| cell.set(cell.get() + d));
+    }
+
+    /// Reset the current times of the calling thread to zero.
+    pub fn reset() {
+        Self::set(ProcessDuration::ZERO);
+    }
+}
+
+impl Clock for MockProcessClock {
+    type Output = ProcessTimePoint;
+    type Duration = ProcessDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<Self::Output> {
+        Ok(MOCK_PROCESS_NOW.with(Cell::get).into())
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(Duration::from_nanos(1))
+    }
+}
+
+/// A timer using mock clock.
+pub type MockTimer = Timer<MockClock, TimePoint, Duration>;
+
+/// A timer using mock process clock.
+pub type MockProcessTimer = Timer<MockProcessClock, ProcessTimePoint, ProcessDuration>;
@@ -0,0 +1,148 @@
+//! Sample the CPU usage of the calling process periodically in a background thread.
+
+use crate::{clock::*, Clock, Duration, ProcessDuration, ProcessTimePoint, Result};
+use std::sync::mpsc::{self, Receiver, SyncSender, TryIter};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::{self, JoinHandle};
+
+/// The maximum number of samples pending in the channel. Further samples are dropped until
+/// some are received.
+const CHANNEL_CAPACITY: usize = 1024;
+
+/// A background sampler of [`ProcessCPUClock`].
+///
+/// Every `interval`, it reads [`ProcessCPUClock`] and publishes the [`ProcessDuration`]
+/// elapsed since the previous reading, whose [`cpu_usage()`](ProcessDuration::cpu_usage) is
+/// the CPU usage of the process during the interval. The first reading is only used as the
+/// starting point, so no sample is published for it.
+///
+/// The sampler is stopped when [`stop()`](#method.stop) is called or the monitor is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use howlong::{CpuUsageMonitor, Duration};
+///
+/// let monitor = CpuUsageMonitor::start(Duration::from_millis(10));
+/// for sample in monitor.iter().take(3) {
+///     println!("{:.1}%", sample.unwrap().cpu_usage() * 100.0);
+/// }
+/// ```
+pub struct CpuUsageMonitor {
+    stopped: Arc<(Mutex<bool>, Condvar)>,
+    latest: Arc<Mutex<Option<ProcessDuration>>>,
+    samples: Receiver<Result<ProcessDuration>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl CpuUsageMonitor {
+    /// Spawn a thread to sample [`ProcessCPUClock`] every `interval`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the thread cannot be spawned.
+    pub fn start(interval: Duration) -> Self {
+        let stopped = Arc::new((Mutex::new(false), Condvar::new()));
+        let latest = Arc::new(Mutex::new(None));
+        let (sender, samples) = mpsc::sync_channel(CHANNEL_CAPACITY);
+        let handle = {
+            let stopped = stopped.clone();
+            let latest = latest.clone();
+            thread::Builder::new()
+                .name("howlong-cpu-usage".to_owned())
+                .spawn(move || sample(interval, &stopped, &latest, &sender))
+                .expect("Failed to spawn the sampler thread.")
+        };
+        CpuUsageMonitor {
+            stopped,
+            latest,
+            samples,
+            handle: Some(handle),
+        }
+    }
+
+    /// Return the most recent sample, or `None` if no sample has been taken yet.
+    pub fn latest(&self) -> Option<ProcessDuration> {
+        *lock(&self.latest)
+    }
+
+    /// Block until the next sample is available. Return `None` if the monitor is stopped and
+    /// all the samples have been received.
+    ///
+    /// An error is returned as a sample if acessing to the underlying clock failed. The
+    /// sampler keeps running in that case.
+    pub fn recv(&self) -> Option<Result<ProcessDuration>> {
+        self.samples.recv().ok()
+    }
+
+    /// Return a blocking iterator over the samples, see [`recv()`](#method.recv).
+    pub fn iter(&self) -> impl Iterator<Item = Result<ProcessDuration>> + '_ {
+        self.samples.iter()
+    }
+
+    /// Return an iterator over the samples which are already available, without blocking.
+    pub fn try_iter(&self) -> TryIter<'_, Result<ProcessDuration>> {
+        self.samples.try_iter()
+    }
+
+    /// Stop the sampler and wait for its thread to exit. The samples taken before are still
+    /// available. It does nothing if the monitor is already stopped.
+    pub fn stop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            let (stopped, condvar) = &*self.stopped;
+            *lock(stopped) = true;
+            condvar.notify_all();
+            let _ = handle.join();
+        }
+    }
+
+    /// Return true if the monitor is stopped.
+    pub fn is_stopped(&self) -> bool {
+        self.handle.is_none()
+    }
+}
+
+impl Drop for CpuUsageMonitor {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Lock `mutex`, ignoring poisoning since the protected values are always consistent.
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// The body of the sampler thread.
+fn sample(
+    interval: Duration,
+    stopped: &(Mutex<bool>, Condvar),
+    latest: &Mutex<Option<ProcessDuration>>,
+    sender: &SyncSender<Result<ProcessDuration>>,
+) {
+    let (stopped, condvar) = stopped;
+    let mut prev: Option<ProcessTimePoint> = None;
+    loop {
+        match ProcessCPUClock::try_now() {
+            Ok(now) => {
+                if let Some(prev) = prev {
+                    let delta = now.saturating_sub(prev);
+                    *lock(latest) = Some(delta);
+                    let _ = sender.try_send(Ok(delta));
+                }
+                prev = Some(now);
+            }
+            Err(e) => {
+                let _ = sender.try_send(Err(e));
+            }
+        }
+
+        let guard = lock(stopped);
+        let (guard, _) = condvar
+            .wait_timeout_while(guard, interval, |stopped| !*stopped)
+            .unwrap_or_else(|e| e.into_inner());
+        if *guard {
+            return;
+        }
+    }
+}
@@ -0,0 +1,122 @@
+//! [`proptest`](https://docs.rs/proptest) strategies for the timepoint and duration types.
+//!
+//! [`ProcessDuration`], [`ProcessTimePoint`], and [`TimePoint`] implement [`Arbitrary`] with the
+//! times up to [`DEFAULT_MAX`]. Use the strategies of this module to choose another bound.
+//!
+//! The generated times spread over the nanoseconds to days, and are occasionally zero. They
+//! shrink toward zero.
+//!
+//! # Examples
+//!
+//! ```
+//! use howlong::{proptest::process_duration_within, Duration, ProcessDuration};
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn test_idle_time(d in any::<ProcessDuration>()) {
+//!         prop_assert!(d.idle_time() <= d.real);
+//!     }
+//!
+//!     fn test_sum(d in process_duration_within(Duration::from_secs(1))) {
+//!         prop_assert_eq!([d, d].iter().sum::<ProcessDuration>(), d * 2);
+//!     }
+//! }
+//! # test_idle_time();
+//! # test_sum();
+//! ```
+
+use crate::{Duration, ProcessDuration, ProcessTimePoint, TimePoint};
+use ::proptest::{
+    arbitrary::Arbitrary,
+    strategy::{BoxedStrategy, Just, Strategy},
+};
+use core::convert::TryFrom;
+
+/// The bound of the times generated by [`Arbitrary`], i.e. 30 days.
+pub const DEFAULT_MAX: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+/// The maximal number of threads whose CPU times are added up by
+/// [`process_duration_within`].
+const MAX_THREADS: u32 = 16;
+
+const PPM: u32 = 1_000_000;
+
+/// A strategy generating durations up to `max`.
+///
+/// The durations are uniform below a microsecond, a millisecond, a second, and `max` with
+/// similar weights, so that every scale is covered, and zero with a lower weight.
+pub fn duration_within(max: Duration) -> impl Strategy<Value = Duration> + Clone {
+    let max = u64::try_from(max.as_nanos()).unwrap_or(u64::MAX);
+    ::proptest::prop_oneof![
+        1 => Just(0),
+        2 => 0..=max.min(1_000),
+        2 => 0..=max.min(1_000_000),
+        2 => 0..=max.min(1_000_000_000),
+        3 => 0..=max,
+    ]
+    .prop_map(Duration::from_nanos)
+}
+
+/// A strategy generating process durations whose times are each up to `max`.
+///
+/// Most of the durations model a single thread, i.e. the CPU time `user + system` does not
+/// exceed the real time. The others model up to 16 threads, so that the CPU time may exceed
+/// the real time.
+pub fn process_duration_within(max: Duration) -> impl Strategy<Value = ProcessDuration> + Clone {
+    let threads = ::proptest::prop_oneof![3 => Just(1), 1 => 2..=MAX_THREADS];
+    let ppm = || ::proptest::prop_oneof![1 => Just(0), 4 => 0..=PPM];
+    (duration_within(max), threads, ppm(), ppm()).prop_map(
+        move |(real, threads, user_ppm, system_ppm)| {
+            // The user-CPU time takes a part of the real time of each thread, and the
+            // system-CPU time a part of the rest.
+            let real_nanos = real.as_nanos();
+            let user = real_nanos * u128::from(user_ppm) / u128::from(PPM);
+            let system = (real_nanos - user) * u128::from(system_ppm) / u128::from(PPM);
+            let scale = |nanos: u128| {
+                let nanos = nanos * u128::from(threads);
+                u64::try_from(nanos)
+                    .map_or(max, Duration::from_nanos)
+                    .min(max)
+            };
+            ProcessDuration::new(real, scale(user), scale(system))
+        },
+    )
+}
+
+/// A strategy generating timepoints up to `max` after the epoch of the clock.
+pub fn time_point_within(max: Duration) -> impl Strategy<Value = TimePoint> + Clone {
+    duration_within(max).prop_map(TimePoint::from)
+}
+
+/// A strategy generating process timepoints whose times are each up to `max` after the epoch
+/// of the clock, like [`process_duration_within`].
+pub fn process_time_point_within(max: Duration) -> impl Strategy<Value = ProcessTimePoint> + Clone {
+    process_duration_within(max).prop_map(ProcessTimePoint::from)
+}
+
+impl Arbitrary for ProcessDuration {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        process_duration_within(DEFAULT_MAX).boxed()
+    }
+}
+
+impl Arbitrary for ProcessTimePoint {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        process_time_point_within(DEFAULT_MAX).boxed()
+    }
+}
+
+impl Arbitrary for TimePoint {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        time_point_within(DEFAULT_MAX).boxed()
+    }
+}
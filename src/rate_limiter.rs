@@ -0,0 +1,182 @@
+//! Token-bucket rate limiters measured by [`SteadyClock`].
+
+use crate::{clock::*, Clock, Duration, TimePoint};
+use std::sync::{Arc, Mutex};
+
+/// The units of credit in one token. Since the rate is scaled by 1e9 to an integer credit per
+/// nanosecond, it is exact to 1e-9 tokens per second.
+const TOKEN: u128 = 1_000_000_000_000_000_000;
+
+/// A token-bucket rate limiter measured by [`SteadyClock`].
+///
+/// The bucket holds up to `burst` tokens and is refilled at `rate` tokens per second. It
+/// starts full. The tokens are accounted as integer credit accrued every nanosecond, with the
+/// rate exact to 1e-9 tokens per second, so that no error accumulates over long runtimes.
+///
+/// # Examples
+///
+/// ```
+/// use howlong::RateLimiter;
+///
+/// let mut limiter = RateLimiter::new(10.0, 2);
+/// assert!(limiter.check());
+/// assert!(limiter.check());
+/// assert!(!limiter.check());
+/// ```
+#[derive(Debug)]
+pub struct RateLimiter {
+    /// The credit accrued per nanosecond, i.e. the rate in tokens per second scaled by 1e9.
+    rate: u128,
+    /// The maximum credit, i.e. `burst * TOKEN`.
+    capacity: u128,
+    /// The accrued credit as of `last`.
+    credit: u128,
+    last: TimePoint,
+}
+
+impl RateLimiter {
+    /// Construct a full rate limiter refilled at `rate` tokens per second and holding up to
+    /// `burst` tokens.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rate` is not positive, or `burst` is zero, or when
+    /// acessing to the underlying clock failed.
+    pub fn new(rate: f64, burst: u32) -> Self {
+        assert!(rate > 0.0, "The rate must be positive.");
+        assert!(burst > 0, "The burst must be non-zero.");
+        let capacity = TOKEN * u128::from(burst);
+        RateLimiter {
+            rate: ((rate * 1e9).round() as u128).max(1),
+            capacity,
+            credit: capacity,
+            last: SteadyClock::now(),
+        }
+    }
+
+    /// Consume one token and return true if it is available. Otherwise, return false.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn check(&mut self) -> bool {
+        self.reserve().is_ok()
+    }
+
+    /// Block until a token is available and consume it.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn wait(&mut self) {
+        while let Err(at) = self.reserve() {
+            crate::sleep_until(at).expect("Failed to access the clock.");
+        }
+    }
+
+    /// Return the number of tokens available now, which might be fractional.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn available(&mut self) -> f64 {
+        self.refill(SteadyClock::now());
+        self.credit as f64 / TOKEN as f64
+    }
+
+    /// Accrue the credit up to `now`.
+    #[inline(always)]
+    fn refill(&mut self, now: TimePoint) {
+        let accrued = now
+            .saturating_duration_since(self.last)
+            .as_nanos()
+            .saturating_mul(self.rate);
+        self.credit = self.credit.saturating_add(accrued).min(self.capacity);
+        self.last = self.last.max(now);
+    }
+
+    /// Consume one token if it is available. Otherwise, return the timepoint when it will be.
+    fn reserve(&mut self) -> core::result::Result<(), TimePoint> {
+        let now = SteadyClock::now();
+        self.refill(now);
+        if self.credit >= TOKEN {
+            self.credit -= TOKEN;
+            return Ok(());
+        }
+        // Round up, so that the token is available at the returned timepoint.
+        let nanos = (TOKEN - self.credit).div_ceil(self.rate);
+        Err(now + Duration::from_nanos(nanos as u64))
+    }
+}
+
+/// A [`RateLimiter`] which can be shared between threads. Its clones share the same bucket.
+///
+/// # Examples
+///
+/// ```
+/// use howlong::SharedRateLimiter;
+/// use std::thread;
+///
+/// let limiter = SharedRateLimiter::new(1000.0, 1);
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let limiter = limiter.clone();
+///         thread::spawn(move || limiter.wait())
+///     })
+///     .collect();
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SharedRateLimiter(Arc<Mutex<RateLimiter>>);
+
+impl SharedRateLimiter {
+    /// See [`RateLimiter::new`].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rate` is not positive, or `burst` is zero, or when
+    /// acessing to the underlying clock failed.
+    pub fn new(rate: f64, burst: u32) -> Self {
+        SharedRateLimiter(Arc::new(Mutex::new(RateLimiter::new(rate, burst))))
+    }
+
+    /// See [`RateLimiter::check`].
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn check(&self) -> bool {
+        self.lock().check()
+    }
+
+    /// See [`RateLimiter::wait`]. Other threads can use the limiter while it is blocking.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn wait(&self) {
+        loop {
+            let reserved = self.lock().reserve();
+            match reserved {
+                Ok(()) => return,
+                Err(at) => crate::sleep_until(at).expect("Failed to access the clock."),
+            }
+        }
+    }
+
+    /// See [`RateLimiter::available`].
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn available(&self) -> f64 {
+        self.lock().available()
+    }
+
+    /// Lock the limiter, ignoring poisoning since it is always consistent.
+    fn lock(&self) -> std::sync::MutexGuard<'_, RateLimiter> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
@@ -0,0 +1,127 @@
+//! Measure the CPU time of the workers of a [`rayon`](https://docs.rs/rayon) thread pool.
+//!
+//! When work is farmed out to a pool, [`ThreadTimer`](crate::ThreadTimer) on the calling
+//! thread misses the work done by the workers, and [`ProcessCPUTimer`](crate::ProcessCPUTimer)
+//! includes the other threads of the process. [`PoolCpuTimer`] sums the CPU time consumed by
+//! the workers of a pool instead.
+//!
+//! # Examples
+//!
+//! ```
+//! use howlong::rayon::PoolCpuTimer;
+//! use rayon::prelude::*;
+//!
+//! let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+//! let timer = PoolCpuTimer::install(&pool);
+//! let sum: u64 = pool.install(|| (0..1_000_000u64).into_par_iter().sum());
+//! assert_eq!(sum, 499_999_500_000);
+//! println!("{:?} of CPU time on the pool", timer.elapsed());
+//! ```
+
+use crate::{clock::ThreadClock, Clock, Duration, Result, TimePoint};
+use ::rayon::ThreadPool;
+
+/// A timer summing the CPU time consumed by the workers of a [`ThreadPool`] since it started.
+///
+/// The CPU time of each worker is read from [`ThreadClock`] on the worker itself, using
+/// [`ThreadPool::broadcast`], when the timer starts and whenever it is read, so that the
+/// workers which have not run any job yet are included as well. Reading it blocks until every
+/// worker has finished its current job.
+///
+/// It measures the whole pool: if the pool is shared by other code, its work is included.
+/// The time spent by the calling thread, e.g. in [`ThreadPool::install`] or [`rayon::join`]
+/// called from outside the pool, is not.
+///
+/// [`rayon::join`]: ::rayon::join
+pub struct PoolCpuTimer<'a> {
+    pool: &'a ThreadPool,
+    start: Vec<TimePoint>,
+}
+
+impl<'a> PoolCpuTimer<'a> {
+    /// Construct a timer for the workers of `pool` and start it.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn install(pool: &'a ThreadPool) -> Self {
+        Self::try_install(pool).expect("Failed to access the clock.")
+    }
+
+    /// Construct a timer for the workers of `pool` and start it.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the thread clock of a worker failed.
+    pub fn try_install(pool: &'a ThreadPool) -> Result<Self> {
+        Ok(PoolCpuTimer {
+            pool,
+            start: sample(pool)?,
+        })
+    }
+
+    /// Return the pool of the timer.
+    pub fn pool(&self) -> &'a ThreadPool {
+        self.pool
+    }
+
+    /// Return the CPU time consumed by the workers since the timer started.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn elapsed(&self) -> Duration {
+        self.try_elapsed().expect("Failed to access the clock.")
+    }
+
+    /// Return the CPU time consumed by the workers since the timer started.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the thread clock of a worker failed.
+    pub fn try_elapsed(&self) -> Result<Duration> {
+        Ok(self.try_elapsed_per_worker()?.into_iter().sum())
+    }
+
+    /// Return the CPU time consumed by each worker since the timer started, in the order of
+    /// their indices, see [`rayon::current_thread_index`].
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn elapsed_per_worker(&self) -> Vec<Duration> {
+        self.try_elapsed_per_worker()
+            .expect("Failed to access the clock.")
+    }
+
+    /// Return the CPU time consumed by each worker since the timer started, like
+    /// [`elapsed_per_worker`](PoolCpuTimer::elapsed_per_worker).
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the thread clock of a worker failed.
+    pub fn try_elapsed_per_worker(&self) -> Result<Vec<Duration>> {
+        let now = sample(self.pool)?;
+        Ok(now
+            .iter()
+            .zip(&self.start)
+            .map(|(now, start)| now.saturating_sub(*start))
+            .collect())
+    }
+
+    /// Reset and start the timer.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn restart(&mut self) {
+        self.start = sample(self.pool).expect("Failed to access the clock.");
+    }
+}
+
+/// Read [`ThreadClock`] on every worker of `pool`, in the order of their indices.
+fn sample(pool: &ThreadPool) -> Result<Vec<TimePoint>> {
+    pool.broadcast(|_| ThreadClock::try_now())
+        .into_iter()
+        .collect()
+}
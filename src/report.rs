@@ -0,0 +1,747 @@
+//! Write timing results as CSV for scripts to consume, and as tables for humans to read.
+//!
+//! The times are written as integer nanoseconds, so they can be parsed back exactly. See also
+//! [`ProcessDuration::to_json`] and [`Stats::to_json`]. Use [`Report`] to print the named
+//! phases of a run as a table, and [`ScopeTree`] to print nested scopes as a tree or export
+//! them as folded stacks. Use [`TimerLogger`] to append every measurement to a CSV file as it
+//! happens.
+//!
+//! # Examples
+//!
+//! ```
+//! use howlong::report::CsvWriter;
+//!
+//! let mut writer = CsvWriter::new(Vec::new());
+//! writer.write_header().unwrap();
+//! let timer = howlong::ProcessCPUTimer::new();
+//! // do some computations
+//! writer.write_row("computation", &timer.elapsed()).unwrap();
+//! let csv = String::from_utf8(writer.into_inner()).unwrap();
+//! assert!(csv.starts_with("label,real_ns,user_ns,system_ns,cpu_pct\ncomputation,"));
+//! ```
+
+use crate::{bench::Stats, fmt::compact, Duration, ProcessDuration};
+use core::fmt::Write as _;
+use core::marker::PhantomData;
+use std::io::{self, Write};
+
+/// A timing result that can be written as a CSV row by [`CsvWriter`].
+pub trait Record {
+    /// The names of the columns, not including the leading `label` column.
+    const COLUMNS: &'static [&'static str];
+
+    /// Write the fields of the columns, each preceded by a comma.
+    fn write_fields<W: Write>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+impl Record for ProcessDuration {
+    /// `real_ns,user_ns,system_ns,cpu_pct`. The CPU usage is in percent rounded to one decimal
+    /// place, and empty if the real time is zero.
+    const COLUMNS: &'static [&'static str] = &["real_ns", "user_ns", "system_ns", "cpu_pct"];
+
+    fn write_fields<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write!(
+            writer,
+            ",{},{},{},{}",
+            self.real.as_nanos(),
+            self.user.as_nanos(),
+            self.system.as_nanos(),
+            self.cpu_pct().unwrap_or_default(),
+        )
+    }
+}
+
+impl Record for Stats {
+    /// `iterations,total_ns,min_ns,max_ns,mean_ns,median_ns,stddev_ns`.
+    const COLUMNS: &'static [&'static str] = &[
+        "iterations",
+        "total_ns",
+        "min_ns",
+        "max_ns",
+        "mean_ns",
+        "median_ns",
+        "stddev_ns",
+    ];
+
+    fn write_fields<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write!(
+            writer,
+            ",{},{},{},{},{},{},{}",
+            self.iterations,
+            self.total.as_nanos(),
+            self.min.as_nanos(),
+            self.max.as_nanos(),
+            self.mean.as_nanos(),
+            self.median.as_nanos(),
+            self.stddev.as_nanos(),
+        )
+    }
+}
+
+/// Append labeled [`Record`]s, e.g. [`ProcessDuration`] or [`Stats`], as CSV rows to an
+/// [`io::Write`].
+///
+/// Each row is the label followed by the [`Record::COLUMNS`] of `T`. The label is quoted if
+/// it contains a comma, a double quote, or a line break.
+#[derive(Debug)]
+pub struct CsvWriter<W, T = ProcessDuration> {
+    writer: W,
+    record: PhantomData<fn(&T)>,
+}
+
+impl<W: Write, T: Record> CsvWriter<W, T> {
+    /// Create a [`CsvWriter`] writing to `writer`.
+    pub fn new(writer: W) -> Self {
+        CsvWriter {
+            writer,
+            record: PhantomData,
+        }
+    }
+
+    /// Write the header row, e.g. `label,real_ns,user_ns,system_ns,cpu_pct`.
+    pub fn write_header(&mut self) -> io::Result<()> {
+        self.writer.write_all(b"label")?;
+        for column in T::COLUMNS {
+            write!(self.writer, ",{}", column)?;
+        }
+        self.writer.write_all(b"\n")
+    }
+
+    /// Write `record` as a row labeled `label`.
+    pub fn write_row(&mut self, label: &str, record: &T) -> io::Result<()> {
+        write_label(&mut self.writer, label)?;
+        record.write_fields(&mut self.writer)?;
+        self.writer.write_all(b"\n")
+    }
+
+    /// Flush the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Return the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Write `label` as a CSV field, quoted if it contains a comma, a double quote, or a line
+/// break.
+fn write_label<W: Write>(writer: &mut W, label: &str) -> io::Result<()> {
+    if label.contains([',', '"', '\n', '\r']) {
+        write!(writer, "\"{}\"", label.replace('"', "\"\""))
+    } else {
+        writer.write_all(label.as_bytes())
+    }
+}
+
+/// When a [`TimerLogger`] flushes its rows to the underlying writer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FlushPolicy {
+    /// Flush after every row, so that no row is lost if the process is killed.
+    #[default]
+    EveryRow,
+    /// Flush after a row if the duration has passed since the previous flush.
+    Interval(Duration),
+}
+
+/// Append one CSV row per measurement to a file, e.g. for soak tests.
+///
+/// Each row is `unix_ns,label,real_ns,user_ns,system_ns`, where `unix_ns` is the time of the
+/// row in nanoseconds since the Unix epoch, read from [`SystemClock`](crate::clock::SystemClock).
+/// The label is quoted if it contains a comma, a double quote, or a line break.
+///
+/// # Examples
+///
+/// ```no_run
+/// use howlong::report::TimerLogger;
+///
+/// let mut logger = TimerLogger::create("timings.csv").unwrap();
+/// for _ in 0..10 {
+///     let _scope = logger.log_scope("iteration");
+///     // do some computations
+/// }
+/// ```
+#[derive(Debug)]
+pub struct TimerLogger<W: Write = io::BufWriter<std::fs::File>> {
+    writer: W,
+    policy: FlushPolicy,
+    last_flush: crate::Instant,
+}
+
+impl TimerLogger {
+    /// Open the file `path` to append rows to, creating it if it does not exist. The header
+    /// row is written only if the file is empty.
+    pub fn create<P: AsRef<std::path::Path>>(path: P) -> io::Result<Self> {
+        let file = std::fs::OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(path)?;
+        let is_new = file.metadata()?.len() == 0;
+        let mut logger = TimerLogger::new(io::BufWriter::new(file));
+        if is_new {
+            logger.write_header()?;
+        }
+        Ok(logger)
+    }
+}
+
+impl<W: Write> TimerLogger<W> {
+    /// The header row.
+    pub const HEADER: &'static str = "unix_ns,label,real_ns,user_ns,system_ns";
+
+    fn new(writer: W) -> Self {
+        TimerLogger {
+            writer,
+            policy: FlushPolicy::default(),
+            last_flush: crate::Instant::now(),
+        }
+    }
+
+    /// Append rows to `writer`, after writing the header row to it.
+    pub fn from_writer(writer: W) -> io::Result<Self> {
+        let mut logger = TimerLogger::new(writer);
+        logger.write_header()?;
+        Ok(logger)
+    }
+
+    /// Set when the rows are flushed, see [`FlushPolicy`].
+    pub fn with_flush_policy(mut self, policy: FlushPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    fn write_header(&mut self) -> io::Result<()> {
+        writeln!(self.writer, "{}", Self::HEADER)?;
+        self.flush()
+    }
+
+    /// Append a row of `d` labeled `label`, and flush it according to the [`FlushPolicy`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the system clock cannot be read, or writing to
+    /// the underlying writer failed.
+    pub fn log(&mut self, label: &str, d: &ProcessDuration) -> io::Result<()> {
+        let unix_nanos =
+            crate::clock::SystemClock::unix_nanos().map_err(crate::Error::into_io_error)?;
+        write!(self.writer, "{},", unix_nanos)?;
+        write_label(&mut self.writer, label)?;
+        writeln!(
+            self.writer,
+            ",{},{},{}",
+            d.real.as_nanos(),
+            d.user.as_nanos(),
+            d.system.as_nanos()
+        )?;
+        match self.policy {
+            FlushPolicy::EveryRow => self.flush(),
+            FlushPolicy::Interval(interval) if self.last_flush.elapsed() >= interval => {
+                self.flush()
+            }
+            FlushPolicy::Interval(_) => Ok(()),
+        }
+    }
+
+    /// Return a guard which measures the time elapsed since now with [`ProcessCPUTimer`], and
+    /// logs it labeled `label` when dropped.
+    ///
+    /// [`ProcessCPUTimer`]: crate::ProcessCPUTimer
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn log_scope<'a>(&'a mut self, label: &'a str) -> LoggerScope<'a, W> {
+        LoggerScope {
+            logger: Some(self),
+            label,
+            timer: crate::reporting::is_enabled().then(crate::ProcessCPUTimer::new),
+            forced: false,
+        }
+    }
+
+    /// Flush the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.last_flush = crate::Instant::now();
+        self.writer.flush()
+    }
+
+    /// Flush and return the underlying writer.
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// A guard which logs the time elapsed since its construction to a [`TimerLogger`] when
+/// dropped, see [`TimerLogger::log_scope`].
+///
+/// It logs nothing if the reporting is disabled, see [`crate::reporting`], unless it is
+/// [forced](LoggerScope::force). The errors are ignored when it is dropped. Use
+/// [`finish`](LoggerScope::finish) to handle them.
+pub struct LoggerScope<'a, W: Write> {
+    logger: Option<&'a mut TimerLogger<W>>,
+    label: &'a str,
+    /// `None` if the reporting was disabled at the construction.
+    timer: Option<crate::ProcessCPUTimer>,
+    forced: bool,
+}
+
+impl<W: Write> LoggerScope<'_, W> {
+    /// Log when dropped even if the reporting is disabled. If it was disabled at the
+    /// construction, the timing starts now.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn force(mut self) -> Self {
+        self.timer.get_or_insert_with(crate::ProcessCPUTimer::new);
+        self.forced = true;
+        self
+    }
+
+    /// Log the time elapsed so far, and consume the guard. Like when it is dropped, nothing
+    /// is logged if the reporting is disabled and the guard is not forced.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if [`TimerLogger::log`] failed.
+    pub fn finish(mut self) -> io::Result<()> {
+        self.log()
+    }
+
+    fn log(&mut self) -> io::Result<()> {
+        let timer = match &self.timer {
+            Some(timer) if self.forced || crate::reporting::is_enabled() => timer,
+            _ => return Ok(()),
+        };
+        match self.logger.take() {
+            Some(logger) => logger.log(self.label, &timer.elapsed()),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<W: Write> Drop for LoggerScope<'_, W> {
+    fn drop(&mut self) {
+        let _ = self.log();
+    }
+}
+
+/// Named [`ProcessDuration`]s, e.g. of the phases of a build, printed as a table.
+///
+/// Its [`Display`](core::fmt::Display) sorts the phases by the real time in descending order,
+/// and appends their total, whose times are clamped to [`Duration::MAX`] if they overflow. It
+/// will look something like this:
+/// ```text
+/// phase     wall   user  system    cpu   share
+/// compile  3.20s  3.10s  50.0ms  98.4%   64.0%
+/// link     1.80s  1.20s   400ms  88.9%   36.0%
+/// --------------------------------------------
+/// total    5.00s  4.30s   450ms  95.0%  100.0%
+/// ```
+///
+/// Names longer than 24 characters are truncated with an ellipsis.
+///
+/// # Examples
+///
+/// ```
+/// use howlong::{report::Report, ProcessCPUTimer};
+///
+/// let mut report = Report::new();
+/// let timer = ProcessCPUTimer::new();
+/// // compile
+/// report.add("compile", timer.elapsed());
+/// let timer = ProcessCPUTimer::new();
+/// // link
+/// report.add("link", timer.elapsed());
+/// println!("{}", report);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    phases: Vec<(String, ProcessDuration)>,
+}
+
+impl Report {
+    /// The maximal width of the name column.
+    const NAME_WIDTH: usize = 24;
+
+    /// Create an empty report.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the phase `name` which took `d`.
+    pub fn add(&mut self, name: impl Into<String>, d: ProcessDuration) {
+        self.phases.push((name.into(), d));
+    }
+
+    /// Return the phases in the order they were added.
+    pub fn phases(&self) -> &[(String, ProcessDuration)] {
+        &self.phases
+    }
+
+    /// Return true if no phase has been added.
+    pub fn is_empty(&self) -> bool {
+        self.phases.is_empty()
+    }
+
+    /// Return the total of the durations of the phases.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the total overflows, like [`ProcessDuration`]'s `Sum`.
+    pub fn total(&self) -> ProcessDuration {
+        self.phases.iter().map(|(_, d)| d).sum()
+    }
+
+    /// Return the total like [`total`](Report::total), but clamping each of the times to
+    /// [`Duration::MAX`] instead of panicking, for the outputs which must not fail.
+    fn saturating_total(&self) -> ProcessDuration {
+        self.phases
+            .iter()
+            .fold(ProcessDuration::ZERO, |total, (_, d)| ProcessDuration {
+                real: total.real.saturating_add(d.real),
+                user: total.user.saturating_add(d.user),
+                system: total.system.saturating_add(d.system),
+            })
+    }
+
+    /// Return the phases in the order they were added as CSV written by [`CsvWriter`], e.g.
+    /// ```text
+    /// label,real_ns,user_ns,system_ns,cpu_pct
+    /// compile,3200000000,3100000000,50000000,98.4
+    /// ```
+    pub fn to_csv(&self) -> String {
+        let mut writer = CsvWriter::new(Vec::new());
+        writer.write_header().expect("writing to a Vec never fails");
+        for (name, d) in &self.phases {
+            writer
+                .write_row(name, d)
+                .expect("writing to a Vec never fails");
+        }
+        String::from_utf8(writer.into_inner()).expect("the CSV is valid UTF-8")
+    }
+
+    /// Return the phases in the order they were added, and their total, as a JSON object of
+    /// [`ProcessDuration::to_json`], e.g.
+    /// ```text
+    /// {"phases":[{"name":"compile","duration":{"real_ns":3200000000,...}}],"total":{...}}
+    /// ```
+    ///
+    /// Each of the times of the total is clamped to [`Duration::MAX`] if it overflows.
+    pub fn to_json(&self) -> String {
+        let mut json = String::from(r#"{"phases":["#);
+        for (i, (name, d)) in self.phases.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            json.push_str(r#"{"name":""#);
+            for c in name.chars() {
+                match c {
+                    '"' => json.push_str("\\\""),
+                    '\\' => json.push_str("\\\\"),
+                    c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+                    c => json.push(c),
+                }
+            }
+            write!(json, r#"","duration":{}}}"#, d.to_json()).unwrap();
+        }
+        write!(json, r#"],"total":{}}}"#, self.saturating_total().to_json()).unwrap();
+        json
+    }
+}
+
+impl core::fmt::Display for Report {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.phases.is_empty() {
+            return f.write_str("no phases recorded");
+        }
+        let total = self.saturating_total();
+        let percent = |part: f64, whole: f64| {
+            if whole == 0.0 {
+                "-".to_owned()
+            } else {
+                format!("{:.1}%", part / whole * 100.0)
+            }
+        };
+        let row = |name: &str, d: &ProcessDuration| {
+            let name = if name.chars().count() > Self::NAME_WIDTH {
+                let mut name: String = name.chars().take(Self::NAME_WIDTH - 1).collect();
+                name.push('…');
+                name
+            } else {
+                name.to_owned()
+            };
+            [
+                name,
+                compact(d.real).to_string(),
+                compact(d.user).to_string(),
+                compact(d.system).to_string(),
+                d.cpu_pct().map_or_else(|| "-".to_owned(), |pct| pct + "%"),
+                percent(d.real.as_secs_f64(), total.real.as_secs_f64()),
+            ]
+        };
+
+        let mut phases: Vec<_> = self.phases.iter().collect();
+        phases.sort_by_key(|(_, d)| core::cmp::Reverse(d.real));
+        let rows: Vec<_> = phases.iter().map(|(name, d)| (row(name, d), "")).collect();
+        write_table(
+            f,
+            ["phase", "wall", "user", "system", "cpu", "share"],
+            &rows,
+            Some(&row("total", &total)),
+        )
+    }
+}
+
+/// Write a table of `N` columns, with the first column aligned to the left and the others to
+/// the right, each row followed by its note unless it is empty, and `totals` below a rule.
+pub(crate) fn write_table<const N: usize>(
+    f: &mut core::fmt::Formatter<'_>,
+    header: [&str; N],
+    rows: &[([String; N], &str)],
+    totals: Option<&[String; N]>,
+) -> core::fmt::Result {
+    let header = header.map(String::from);
+    let mut widths = [0; N];
+    for cells in core::iter::once(&header)
+        .chain(rows.iter().map(|(cells, _)| cells))
+        .chain(totals)
+    {
+        for (width, cell) in widths.iter_mut().zip(cells) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let write_row = |f: &mut core::fmt::Formatter<'_>, cells: &[String; N], note: &str| {
+        write!(f, "{:<1$}", cells[0], widths[0])?;
+        for (cell, width) in cells.iter().zip(&widths).skip(1) {
+            write!(f, "  {:>1$}", cell, width)?;
+        }
+        if !note.is_empty() {
+            write!(f, "  {}", note)?;
+        }
+        Ok(())
+    };
+    write_row(f, &header, "")?;
+    for (cells, note) in rows {
+        f.write_str("\n")?;
+        write_row(f, cells, note)?;
+    }
+    if let Some(totals) = totals {
+        let rule = widths.iter().sum::<usize>() + 2 * (N - 1);
+        write!(f, "\n{}\n", "-".repeat(rule))?;
+        write_row(f, totals, "")?;
+    }
+    Ok(())
+}
+
+/// The unit of the values written by [`ScopeTree::write_folded`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FoldedUnit {
+    /// Nanoseconds.
+    Nanoseconds,
+    /// Microseconds.
+    #[default]
+    Microseconds,
+    /// Milliseconds.
+    Milliseconds,
+}
+
+impl FoldedUnit {
+    /// Return `d` in the unit, truncated.
+    fn convert(self, d: Duration) -> u128 {
+        match self {
+            FoldedUnit::Nanoseconds => d.as_nanos(),
+            FoldedUnit::Microseconds => d.as_micros(),
+            FoldedUnit::Milliseconds => d.as_millis(),
+        }
+    }
+}
+
+/// A tree of the durations of nested scopes, e.g. of the functions of a program.
+///
+/// Each node records the total duration and the number of calls of its scope. The self time
+/// of a node is its total minus the totals of its children, clamped to zero.
+///
+/// Its [`Display`](core::fmt::Display) prints the tree indented, with the percentages of the
+/// total of the root. It will look something like this:
+/// ```text
+/// main 10.0ms 100.0% (self 1.00ms, 1 call)
+///   parse 4.00ms 40.0% (self 3.00ms, 2 calls)
+///     lex 1.00ms 10.0% (self 1.00ms, 2 calls)
+///   eval 5.00ms 50.0% (self 5.00ms, 1 call)
+/// ```
+///
+/// Use [`write_folded`](ScopeTree::write_folded) to export it to flame graph tools.
+///
+/// # Examples
+///
+/// ```
+/// use howlong::{report::ScopeTree, Duration};
+///
+/// let ms = Duration::from_millis;
+/// let mut tree = ScopeTree::new("main");
+/// tree.record(ms(10));
+/// tree.child_mut("parse").record(ms(4));
+/// tree.child_mut("parse").child_mut("lex").record(ms(1));
+/// assert_eq!(tree.child("parse").unwrap().self_time(), ms(3));
+///
+/// let mut folded = Vec::new();
+/// tree.write_folded(&mut folded, Default::default()).unwrap();
+/// assert_eq!(
+///     String::from_utf8(folded).unwrap(),
+///     "main 6000\nmain;parse 3000\nmain;parse;lex 1000\n"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScopeTree {
+    name: String,
+    total: Duration,
+    count: u64,
+    children: Vec<ScopeTree>,
+}
+
+impl ScopeTree {
+    /// Create a node of the scope `name` without any calls or children.
+    pub fn new(name: impl Into<String>) -> Self {
+        ScopeTree {
+            name: name.into(),
+            total: Duration::ZERO,
+            count: 0,
+            children: Vec::new(),
+        }
+    }
+
+    /// Return the name of the scope.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Record a call of the scope which took `d`.
+    pub fn record(&mut self, d: Duration) {
+        self.total += d;
+        self.count += 1;
+    }
+
+    /// Return the total duration of the calls of the scope.
+    pub fn total(&self) -> Duration {
+        self.total
+    }
+
+    /// Return the duration of the scope not spent in its children, i.e. the total minus the
+    /// totals of the children, or zero if the children took longer.
+    pub fn self_time(&self) -> Duration {
+        self.children
+            .iter()
+            .try_fold(self.total, |rest, child| rest.checked_sub(child.total))
+            .unwrap_or_default()
+    }
+
+    /// Return the number of calls of the scope.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Return the children in the order they were added.
+    pub fn children(&self) -> &[ScopeTree] {
+        &self.children
+    }
+
+    /// Return the child `name`, or `None` if there is no such child.
+    pub fn child(&self, name: &str) -> Option<&ScopeTree> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    /// Return the child `name`, adding it first if there is no such child.
+    pub fn child_mut(&mut self, name: &str) -> &mut ScopeTree {
+        match self.children.iter().position(|child| child.name == name) {
+            Some(i) => &mut self.children[i],
+            None => {
+                self.children.push(ScopeTree::new(name));
+                self.children.last_mut().unwrap()
+            }
+        }
+    }
+
+    /// Write the tree as folded stacks, e.g. `main;parse;lex 1000`, for flame graph tools such
+    /// as [inferno](https://docs.rs/inferno).
+    ///
+    /// Each line is the path of names from the root to a node joined by `;`, followed by the
+    /// self time of the node in `unit`, truncated. The nodes are written in depth-first order,
+    /// and those whose self time is zero in `unit` are omitted, since they do not show in a
+    /// flame graph. So a line is written for every node, inner or leaf, with a non-zero self
+    /// time.
+    ///
+    /// Since `;` separates the names and whitespace separates the value, each `;`, whitespace,
+    /// or control character in a name is written as `_`.
+    pub fn write_folded<W: Write>(&self, mut writer: W, unit: FoldedUnit) -> io::Result<()> {
+        self.write_folded_into(&mut writer, &mut String::new(), unit)
+    }
+
+    fn write_folded_into<W: Write>(
+        &self,
+        writer: &mut W,
+        path: &mut String,
+        unit: FoldedUnit,
+    ) -> io::Result<()> {
+        let len = path.len();
+        if len > 0 {
+            path.push(';');
+        }
+        path.extend(self.name.chars().map(|c| {
+            if c == ';' || c.is_whitespace() || c.is_control() {
+                '_'
+            } else {
+                c
+            }
+        }));
+        let value = unit.convert(self.self_time());
+        if value > 0 {
+            writeln!(writer, "{} {}", path, value)?;
+        }
+        for child in &self.children {
+            child.write_folded_into(writer, path, unit)?;
+        }
+        path.truncate(len);
+        Ok(())
+    }
+
+    fn fmt_node(
+        &self,
+        f: &mut core::fmt::Formatter<'_>,
+        depth: usize,
+        root: Duration,
+    ) -> core::fmt::Result {
+        let indent = 2 * depth;
+        write!(f, "{:indent$}{} {} ", "", self.name, compact(self.total))?;
+        if root.is_zero() {
+            f.write_str("-")?;
+        } else {
+            write!(
+                f,
+                "{:.1}%",
+                self.total.as_secs_f64() / root.as_secs_f64() * 100.0
+            )?;
+        }
+        write!(
+            f,
+            " (self {}, {} call{})",
+            compact(self.self_time()),
+            self.count,
+            if self.count == 1 { "" } else { "s" }
+        )?;
+        for child in &self.children {
+            f.write_str("\n")?;
+            child.fmt_node(f, depth + 1, root)?;
+        }
+        Ok(())
+    }
+}
+
+impl core::fmt::Display for ScopeTree {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_node(f, 0, self.total)
+    }
+}
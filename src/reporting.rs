@@ -0,0 +1,138 @@
+//! A global switch of the automatic reporting.
+//!
+//! The guards which report when they are dropped, i.e. [`ReportScope`], `LoggedScope`,
+//! [`LoggerScope`](crate::report::LoggerScope), and `metrics::MeteredScope`, check
+//! [`is_enabled`] when they are constructed and dropped. A guard constructed while the
+//! reporting is disabled reads no clock and reports nothing, so that silencing it only costs a
+//! relaxed atomic load. Call `force()` on a guard to report regardless of the switch.
+//!
+//! The switch is initially read from the environment variable `HOWLONG_REPORT` the first time
+//! it is checked: `0`, `false`, and `off`, in any case, disable the reporting, and anything
+//! else, or leaving it unset, enables it. Use [`set_enabled`] to change it at runtime.
+//!
+//! # Examples
+//!
+//! ```
+//! use howlong::reporting::{self, ReportScope};
+//!
+//! reporting::set_enabled(false);
+//! {
+//!     let _scope = ReportScope::new("silenced");
+//! } // prints nothing
+//! {
+//!     let _scope = ReportScope::new("forced").force();
+//! } // prints "forced: 12.4ms wall, 12.3ms user + 0ns system = 12.3ms CPU (99.2%)"
+//! reporting::set_enabled(true);
+//! ```
+
+use crate::ProcessCPUTimer;
+use core::sync::atomic::{AtomicU8, Ordering};
+use std::io::{self, Write};
+
+const UNINITIALIZED: u8 = 0;
+const DISABLED: u8 = 1;
+const ENABLED: u8 = 2;
+
+static STATE: AtomicU8 = AtomicU8::new(UNINITIALIZED);
+
+/// The name of the environment variable giving the initial value of the switch.
+pub const ENV_VAR: &str = "HOWLONG_REPORT";
+
+/// Enable or disable the automatic reporting, overriding `HOWLONG_REPORT`.
+#[inline]
+pub fn set_enabled(enabled: bool) {
+    STATE.store(if enabled { ENABLED } else { DISABLED }, Ordering::Relaxed);
+}
+
+/// Return true if the automatic reporting is enabled.
+///
+/// The first call reads `HOWLONG_REPORT` unless [`set_enabled`] has been called before.
+#[inline]
+pub fn is_enabled() -> bool {
+    match STATE.load(Ordering::Relaxed) {
+        UNINITIALIZED => init(),
+        state => state == ENABLED,
+    }
+}
+
+#[cold]
+fn init() -> bool {
+    let enabled = match std::env::var_os(ENV_VAR) {
+        Some(value) => {
+            let value = value.to_string_lossy();
+            let value = value.trim();
+            !["0", "false", "off"]
+                .iter()
+                .any(|off| value.eq_ignore_ascii_case(off))
+        }
+        None => true,
+    };
+    let state = if enabled { ENABLED } else { DISABLED };
+    // Keep the value of a concurrent `set_enabled`.
+    match STATE.compare_exchange(UNINITIALIZED, state, Ordering::Relaxed, Ordering::Relaxed) {
+        Ok(_) => enabled,
+        Err(state) => state == ENABLED,
+    }
+}
+
+/// A guard which writes the [`ProcessDuration`](crate::ProcessDuration) elapsed since its
+/// construction as `"{label}: {elapsed}"` on a line when dropped, if the reporting is
+/// enabled, see [`crate::reporting`], or the guard is [forced](ReportScope::force).
+///
+/// It writes to the standard error by default. The errors are ignored.
+pub struct ReportScope<W: Write = io::Stderr> {
+    /// `None` if the reporting was disabled at the construction.
+    timer: Option<ProcessCPUTimer>,
+    label: String,
+    writer: W,
+    forced: bool,
+}
+
+impl ReportScope {
+    /// Construct a guard writing to the standard error and start timing.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self::with_writer(label, io::stderr())
+    }
+}
+
+impl<W: Write> ReportScope<W> {
+    /// Construct a guard writing to `writer` and start timing.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn with_writer(label: impl Into<String>, writer: W) -> Self {
+        ReportScope {
+            timer: is_enabled().then(ProcessCPUTimer::new),
+            label: label.into(),
+            writer,
+            forced: false,
+        }
+    }
+
+    /// Report when dropped even if the reporting is disabled. If it was disabled at the
+    /// construction, the timing starts now.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn force(mut self) -> Self {
+        self.timer.get_or_insert_with(ProcessCPUTimer::new);
+        self.forced = true;
+        self
+    }
+}
+
+impl<W: Write> Drop for ReportScope<W> {
+    fn drop(&mut self) {
+        if let Some(timer) = &self.timer {
+            if self.forced || is_enabled() {
+                let _ = writeln!(self.writer, "{}: {}", self.label, timer.elapsed());
+            }
+        }
+    }
+}
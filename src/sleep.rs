@@ -0,0 +1,61 @@
+//! Sleep until an absolute timepoint of [`SteadyClock`].
+
+use crate::{clock::*, Clock, Duration, Result, TimePoint};
+
+/// Block the calling thread until [`SteadyClock`] reaches `target`. Return immediately if
+/// `target` has already passed.
+///
+/// Unlike chaining [`std::thread::sleep`], the time spent between two calls does not
+/// accumulate as drift in a periodic loop. It sleeps by `clock_nanosleep(TIMER_ABSTIME)` on
+/// Linux and Android, and by [`std::thread::sleep`] for the remaining time elsewhere. Either
+/// way, it only returns after checking that `target` has actually passed.
+///
+/// # Examples
+///
+/// ```
+/// use howlong::{clock::*, Clock, Duration};
+///
+/// let target = SteadyClock::now() + Duration::from_millis(10);
+/// howlong::sleep_until(target).unwrap();
+/// assert!(SteadyClock::now() >= target);
+/// ```
+///
+/// # Errors
+///
+/// This function will return an error if acessing to the underlying system calls failed.
+pub fn sleep_until(target: TimePoint) -> Result<()> {
+    loop {
+        let now = SteadyClock::try_now()?;
+        match target.checked_duration_since(now) {
+            Some(remaining) if remaining > Duration::ZERO => sleep(target, remaining)?,
+            _ => return Ok(()),
+        }
+    }
+}
+
+/// Block the calling thread for at least `d` measured by [`SteadyClock`], see
+/// [`sleep_until`].
+///
+/// # Errors
+///
+/// This function will return an error if acessing to the underlying system calls failed.
+pub fn sleep_for(d: Duration) -> Result<()> {
+    let now = SteadyClock::try_now()?;
+    sleep_until(
+        now.checked_add(d)
+            .unwrap_or_else(|| TimePoint::from(Duration::MAX)),
+    )
+}
+
+#[cfg(have_clock_nanosleep)]
+#[inline(always)]
+fn sleep(target: TimePoint, _remaining: Duration) -> Result<()> {
+    monotonic_sleep_until(target)
+}
+
+#[cfg(not(have_clock_nanosleep))]
+#[inline(always)]
+fn sleep(_target: TimePoint, remaining: Duration) -> Result<()> {
+    std::thread::sleep(remaining);
+    Ok(())
+}
@@ -0,0 +1,109 @@
+//! Compare a candidate duration against a baseline, with [`compare_durations`] and
+//! [`ProcessDuration::speedup_over`].
+
+use crate::{Duration, ProcessDuration};
+
+/// The comparison of a candidate [`Duration`] against a baseline, see [`compare_durations`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Speedup {
+    /// The speedup `baseline / candidate`, which is greater than 1 if the candidate is faster.
+    /// It is `None` if the candidate is zero.
+    pub ratio: Option<f64>,
+    /// The difference `candidate - baseline` in nanoseconds, which is negative if the
+    /// candidate is faster.
+    pub delta_nanos: i128,
+}
+
+impl Speedup {
+    /// Return the absolute difference between the candidate and the baseline.
+    pub fn abs_delta(&self) -> Duration {
+        let nanos = self.delta_nanos.unsigned_abs();
+        Duration::new(
+            (nanos / 1_000_000_000) as u64,
+            (nanos % 1_000_000_000) as u32,
+        )
+    }
+}
+
+impl core::fmt::Display for Speedup {
+    /// Formats the [`Speedup`]. It will look something like this:
+    /// ```text
+    /// 1.8× faster (-420ms)
+    /// ```
+    ///
+    /// The ratio is printed as `n/a` if either the baseline or the candidate is zero, and the
+    /// whole comparison as `unchanged` if there is no difference.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let sign = match self.delta_nanos {
+            0 => return f.write_str("unchanged"),
+            d if d < 0 => '-',
+            _ => '+',
+        };
+        match self.ratio {
+            Some(ratio) if ratio >= 1f64 => write!(f, "{:.1}× faster", ratio)?,
+            Some(ratio) if ratio > 0f64 => write!(f, "{:.1}× slower", 1f64 / ratio)?,
+            _ => f.write_str("n/a")?,
+        }
+        write!(f, " ({}{:?})", sign, self.abs_delta())
+    }
+}
+
+/// Compare `candidate` against `baseline`, e.g. the durations after and before an
+/// optimization.
+///
+/// # Examples
+///
+/// ```
+/// use howlong::{compare_durations, Duration};
+///
+/// let speedup = compare_durations(Duration::from_millis(900), Duration::from_millis(500));
+/// assert_eq!(speedup.ratio, Some(1.8));
+/// assert_eq!(speedup.to_string(), "1.8× faster (-400ms)");
+/// ```
+pub fn compare_durations(baseline: Duration, candidate: Duration) -> Speedup {
+    let ratio = if candidate.is_zero() {
+        None
+    } else {
+        Some(baseline.as_secs_f64() / candidate.as_secs_f64())
+    };
+    Speedup {
+        ratio,
+        delta_nanos: candidate.as_nanos() as i128 - baseline.as_nanos() as i128,
+    }
+}
+
+/// The comparison of a candidate [`ProcessDuration`] against a baseline, returned by
+/// [`ProcessDuration::speedup_over`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpeedupReport {
+    /// The comparison of the real times.
+    pub real: Speedup,
+    /// The comparison of the user-CPU times.
+    pub user: Speedup,
+    /// The comparison of the system-CPU times.
+    pub system: Speedup,
+    /// The comparison of the total CPU times.
+    pub cpu: Speedup,
+}
+
+impl SpeedupReport {
+    pub(crate) fn new(candidate: &ProcessDuration, baseline: &ProcessDuration) -> Self {
+        SpeedupReport {
+            real: compare_durations(baseline.real, candidate.real),
+            user: compare_durations(baseline.user, candidate.user),
+            system: compare_durations(baseline.system, candidate.system),
+            cpu: compare_durations(baseline.cpu_time(), candidate.cpu_time()),
+        }
+    }
+}
+
+impl core::fmt::Display for SpeedupReport {
+    /// Formats the [`SpeedupReport`] with the real and CPU times. It will look something like
+    /// this:
+    /// ```text
+    /// wall 1.8× faster (-420ms), cpu 1.1× faster (-50ms)
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "wall {}, cpu {}", self.real, self.cpu)
+    }
+}
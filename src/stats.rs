@@ -0,0 +1,301 @@
+use crate::{Duration, ProcessDuration};
+use core::convert::TryFrom;
+
+/// Aggregate statistics of repeated [`ProcessDuration`] measurements, e.g. of a nightly job.
+///
+/// The minimum, maximum, and mean are taken of each of the real, user-CPU, and system-CPU
+/// times separately, so e.g. [`min`](ProcessStats::min) may combine the times of different
+/// measurements. The sums are accumulated in 128-bit nanoseconds, so they do not overflow in
+/// practice.
+///
+/// # Examples
+///
+/// ```
+/// use howlong::{Duration, ProcessDuration, ProcessStats};
+///
+/// let ms = Duration::from_millis;
+/// let mut stats = ProcessStats::new();
+/// stats.record(ProcessDuration::new(ms(10), ms(8), ms(1)));
+/// stats.record(ProcessDuration::new(ms(20), ms(12), ms(3)));
+/// assert_eq!(stats.count(), 2);
+/// assert_eq!(stats.mean(), Some(ProcessDuration::new(ms(15), ms(10), ms(2))));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProcessStats {
+    count: u64,
+    min: Option<ProcessDuration>,
+    max: Option<ProcessDuration>,
+    real_nanos: u128,
+    user_nanos: u128,
+    system_nanos: u128,
+}
+
+impl ProcessStats {
+    /// Construct statistics without any measurement.
+    pub const fn new() -> Self {
+        ProcessStats {
+            count: 0,
+            min: None,
+            max: None,
+            real_nanos: 0,
+            user_nanos: 0,
+            system_nanos: 0,
+        }
+    }
+
+    /// Add the measurement `d` to the statistics.
+    pub fn record(&mut self, d: ProcessDuration) {
+        let combine = |acc: Option<ProcessDuration>, f: fn(Duration, Duration) -> Duration| {
+            Some(match acc {
+                Some(acc) => ProcessDuration::new(
+                    f(acc.real, d.real),
+                    f(acc.user, d.user),
+                    f(acc.system, d.system),
+                ),
+                None => d,
+            })
+        };
+        self.min = combine(self.min, Duration::min);
+        self.max = combine(self.max, Duration::max);
+        self.count += 1;
+        self.real_nanos = self.real_nanos.saturating_add(d.real.as_nanos());
+        self.user_nanos = self.user_nanos.saturating_add(d.user.as_nanos());
+        self.system_nanos = self.system_nanos.saturating_add(d.system.as_nanos());
+    }
+
+    /// Return the number of recorded measurements.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Return the minimum of each of the times, or `None` if nothing is recorded.
+    pub fn min(&self) -> Option<ProcessDuration> {
+        self.min
+    }
+
+    /// Return the maximum of each of the times, or `None` if nothing is recorded.
+    pub fn max(&self) -> Option<ProcessDuration> {
+        self.max
+    }
+
+    /// Return the arithmetic mean of each of the times, truncated to nanoseconds, or `None` if
+    /// nothing is recorded.
+    pub fn mean(&self) -> Option<ProcessDuration> {
+        if self.count == 0 {
+            return None;
+        }
+        let mean = |sum: u128| {
+            let nanos = sum / self.count as u128;
+            Duration::new(
+                (nanos / 1_000_000_000) as u64,
+                (nanos % 1_000_000_000) as u32,
+            )
+        };
+        Some(ProcessDuration::new(
+            mean(self.real_nanos),
+            mean(self.user_nanos),
+            mean(self.system_nanos),
+        ))
+    }
+
+    /// Return the CPU usage over all the measurements, i.e. the total CPU time divided by the
+    /// total real time, or `None` if nothing is recorded or the total real time is zero.
+    pub fn mean_cpu_usage(&self) -> Option<f64> {
+        if self.real_nanos == 0 {
+            return None;
+        }
+        Some((self.user_nanos as f64 + self.system_nanos as f64) / self.real_nanos as f64)
+    }
+}
+
+impl Extend<ProcessDuration> for ProcessStats {
+    fn extend<I: IntoIterator<Item = ProcessDuration>>(&mut self, iter: I) {
+        for d in iter {
+            self.record(d);
+        }
+    }
+}
+
+impl core::iter::FromIterator<ProcessDuration> for ProcessStats {
+    fn from_iter<I: IntoIterator<Item = ProcessDuration>>(iter: I) -> Self {
+        let mut stats = ProcessStats::new();
+        stats.extend(iter);
+        stats
+    }
+}
+
+impl core::fmt::Display for ProcessStats {
+    /// Formats the [`ProcessStats`] with one line for each of the minimum, mean, and maximum.
+    /// It will look something like this:
+    /// ```text
+    /// min:  5.71s wall, 5.70s user + 0ns system = 5.70s CPU (99.8%)
+    /// mean: 6.02s wall, 6.00s user + 1ms system = 6.00s CPU (99.7%) over 7 runs
+    /// max:  6.50s wall, 6.48s user + 3ms system = 6.48s CPU (99.7%)
+    /// ```
+    ///
+    /// It is printed as `no runs` if nothing is recorded.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match (self.min, self.mean(), self.max) {
+            (Some(min), Some(mean), Some(max)) => write!(
+                f,
+                "min:  {}\nmean: {} over {} run{}\nmax:  {}",
+                min,
+                mean,
+                self.count,
+                if self.count == 1 { "" } else { "s" },
+                max
+            ),
+            _ => f.write_str("no runs"),
+        }
+    }
+}
+
+/// Streaming statistics of [`Duration`] samples by Welford's online algorithm, which keeps
+/// neither the samples nor their sum of squares, e.g. for the latencies of a long-running
+/// service.
+///
+/// The mean and the sum of the squared deviations are accumulated in `f64` nanoseconds. The
+/// mean is exact to the nanosecond for samples up to 2^53 nanoseconds, i.e. about 104 days.
+/// The relative error of the variance is about `1e-16` times the ratio of the mean to the
+/// standard deviation, so it stays small even for samples with a large common offset, unlike
+/// the naive formula `E[x²] - E[x]²`. The minimum and maximum are exact.
+///
+/// It is cheap to copy, so a per-thread accumulator can be sent to another thread and
+/// combined by [`merge`](StreamingStats::merge).
+///
+/// # Examples
+///
+/// ```
+/// use howlong::{Duration, StreamingStats};
+///
+/// let mut stats = StreamingStats::new();
+/// for ms in [2, 4, 4, 4, 5, 5, 7, 9].iter() {
+///     stats.record(Duration::from_millis(*ms));
+/// }
+/// assert_eq!(stats.mean(), Duration::from_millis(5));
+/// assert_eq!(stats.stddev(), Duration::from_millis(2));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct StreamingStats {
+    count: u64,
+    mean: f64,
+    m2: f64,
+    min: Duration,
+    max: Duration,
+}
+
+impl StreamingStats {
+    /// Construct statistics without any sample.
+    pub const fn new() -> Self {
+        StreamingStats {
+            count: 0,
+            mean: 0f64,
+            m2: 0f64,
+            min: Duration::ZERO,
+            max: Duration::ZERO,
+        }
+    }
+
+    /// Add the sample `d` to the statistics.
+    pub fn record(&mut self, d: Duration) {
+        if self.count == 0 {
+            self.min = d;
+            self.max = d;
+        } else {
+            self.min = self.min.min(d);
+            self.max = self.max.max(d);
+        }
+        self.count += 1;
+        let x = d.as_nanos() as f64;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    /// Add the samples recorded by `other` using the parallel variant of the algorithm by Chan
+    /// et al., e.g. to combine the statistics of several threads.
+    pub fn merge(&mut self, other: &StreamingStats) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        let weight = other.count as f64 / count as f64;
+        self.mean += delta * weight;
+        self.m2 += other.m2 + delta * delta * self.count as f64 * weight;
+        self.count = count;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    /// Return the number of recorded samples.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Return the arithmetic mean rounded to nanoseconds, or zero if nothing is recorded.
+    pub fn mean(&self) -> Duration {
+        let nanos = self.mean + 0.5;
+        if nanos < u64::MAX as f64 {
+            Duration::from_nanos(nanos as u64)
+        } else {
+            Duration::try_from_secs_f64(self.mean / 1e9).unwrap_or(Duration::MAX)
+        }
+    }
+
+    /// Return the population variance in squared nanoseconds, or zero if nothing is recorded.
+    pub fn variance(&self) -> f64 {
+        if self.count == 0 {
+            0f64
+        } else {
+            self.m2 / self.count as f64
+        }
+    }
+
+    /// Return the population standard deviation rounded to nanoseconds, or zero if nothing is
+    /// recorded.
+    pub fn stddev(&self) -> Duration {
+        // `f64::sqrt` requires the standard library, so take the integer square root of the
+        // variance, rounded to the nearest.
+        let variance = (self.variance() + 0.5) as u128;
+        let mut nanos = variance.isqrt();
+        if variance - nanos * nanos > nanos {
+            nanos += 1;
+        }
+        match u64::try_from(nanos / 1_000_000_000) {
+            Ok(secs) => Duration::new(secs, (nanos % 1_000_000_000) as u32),
+            Err(_) => Duration::MAX,
+        }
+    }
+
+    /// Return the shortest sample, or zero if nothing is recorded.
+    pub fn min(&self) -> Duration {
+        self.min
+    }
+
+    /// Return the longest sample, or zero if nothing is recorded.
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+}
+
+impl Extend<Duration> for StreamingStats {
+    fn extend<I: IntoIterator<Item = Duration>>(&mut self, iter: I) {
+        for d in iter {
+            self.record(d);
+        }
+    }
+}
+
+impl core::iter::FromIterator<Duration> for StreamingStats {
+    fn from_iter<I: IntoIterator<Item = Duration>>(iter: I) -> Self {
+        let mut stats = StreamingStats::new();
+        stats.extend(iter);
+        stats
+    }
+}
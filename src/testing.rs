@@ -0,0 +1,201 @@
+//! Assertions of duration budgets in tests.
+//!
+//! [`assert_elapsed_under!`](crate::assert_elapsed_under) checks the time elapsed on a timer,
+//! and [`assert_completes_within!`](crate::assert_completes_within) the time an expression
+//! takes. On failure, they panic with the elapsed time, the budget, and the overshoot, e.g.
+//! ```text
+//! budget exceeded: `timer` took 241.312ms, over the budget of 200ms by 20.7%
+//! ```
+//! Use [`check_budget`] to handle the failure otherwise.
+//!
+//! Every budget is multiplied by [`budget_scale`], read from the environment variable
+//! `HOWLONG_BUDGET_SCALE`, so that slow CI machines can relax all of them uniformly, e.g. with
+//! `HOWLONG_BUDGET_SCALE=3`.
+//!
+//! # Examples
+//!
+//! ```
+//! use howlong::{assert_completes_within, assert_elapsed_under, Duration, HighResolutionTimer};
+//!
+//! let timer = HighResolutionTimer::new();
+//! // do some computations
+//! assert_elapsed_under!(timer, Duration::from_secs(10));
+//!
+//! let sum = assert_completes_within!(Duration::from_secs(10), {
+//!     (0..1000u64).sum::<u64>()
+//! });
+//! assert_eq!(sum, 499_500);
+//! ```
+
+use crate::Duration;
+use core::fmt;
+use std::sync::OnceLock;
+
+pub use crate::{assert_completes_within, assert_elapsed_under};
+
+/// The name of the environment variable giving [`budget_scale`].
+pub const ENV_VAR: &str = "HOWLONG_BUDGET_SCALE";
+
+/// Return the factor every budget is multiplied by.
+///
+/// It is read once from `HOWLONG_BUDGET_SCALE`, and is 1 if the variable is unset or is not
+/// a positive number.
+pub fn budget_scale() -> f64 {
+    static SCALE: OnceLock<f64> = OnceLock::new();
+    *SCALE.get_or_init(|| {
+        std::env::var(ENV_VAR)
+            .ok()
+            .and_then(|value| value.trim().parse().ok())
+            .filter(|scale: &f64| scale.is_finite() && *scale > 0.0)
+            .unwrap_or(1.0)
+    })
+}
+
+/// The error returned by [`check_budget`] if a duration exceeds its budget.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BudgetExceeded {
+    /// The measured duration.
+    pub elapsed: Duration,
+    /// The budget, after being multiplied by the scale.
+    pub budget: Duration,
+    /// The scale the budget has been multiplied by, see [`budget_scale`].
+    pub scale: f64,
+}
+
+impl BudgetExceeded {
+    /// Return how much the elapsed time exceeds the budget as a percentage of the budget, or
+    /// infinity if the budget is zero.
+    pub fn overshoot_pct(&self) -> f64 {
+        let overshoot = self.elapsed.saturating_sub(self.budget).as_secs_f64();
+        if self.budget.is_zero() {
+            f64::INFINITY
+        } else {
+            overshoot / self.budget.as_secs_f64() * 100.0
+        }
+    }
+}
+
+impl fmt::Display for BudgetExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "took {:?}, over the budget of {:?} by {:.1}%",
+            self.elapsed,
+            self.budget,
+            self.overshoot_pct()
+        )?;
+        if self.scale != 1.0 {
+            write!(f, " ({}={})", ENV_VAR, self.scale)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for BudgetExceeded {}
+
+/// Check that `elapsed` does not exceed `budget` multiplied by [`budget_scale`].
+///
+/// # Errors
+///
+/// This function will return an error describing the overshoot if `elapsed` is greater than
+/// the scaled budget.
+pub fn check_budget(elapsed: Duration, budget: Duration) -> Result<(), BudgetExceeded> {
+    let scale = budget_scale();
+    let budget = if scale == 1.0 {
+        budget
+    } else {
+        Duration::try_from_secs_f64(budget.as_secs_f64() * scale).unwrap_or(Duration::MAX)
+    };
+    if elapsed <= budget {
+        Ok(())
+    } else {
+        Err(BudgetExceeded {
+            elapsed,
+            budget,
+            scale,
+        })
+    }
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_budget(
+    elapsed: Duration,
+    budget: Duration,
+    expr: &str,
+    msg: Option<fmt::Arguments<'_>>,
+) {
+    if let Err(e) = check_budget(elapsed, budget) {
+        match msg {
+            Some(msg) => panic!("budget exceeded: `{}` {}: {}", expr, e, msg),
+            None => panic!("budget exceeded: `{}` {}", expr, e),
+        }
+    }
+}
+
+/// Assert that the time elapsed on a timer does not exceed a budget, see
+/// [`howlong::testing`](crate::testing).
+///
+/// The timer may be any timer whose `elapsed()` returns a [`Duration`](crate::Duration). A
+/// custom message may follow, like [`assert!`].
+///
+/// # Panics
+///
+/// This macro panics with the elapsed time, the budget, and the overshoot if the elapsed time
+/// exceeds the budget multiplied by [`budget_scale`](crate::testing::budget_scale).
+#[macro_export]
+macro_rules! assert_elapsed_under {
+    ($timer:expr, $budget:expr $(,)?) => {
+        $crate::testing::assert_budget(
+            $timer.elapsed(),
+            $budget,
+            ::core::stringify!($timer),
+            ::core::option::Option::None,
+        )
+    };
+    ($timer:expr, $budget:expr, $($arg:tt)+) => {
+        $crate::testing::assert_budget(
+            $timer.elapsed(),
+            $budget,
+            ::core::stringify!($timer),
+            ::core::option::Option::Some(::core::format_args!($($arg)+)),
+        )
+    };
+}
+
+/// Evaluate an expression, assert that it takes no longer than a budget, and return its value,
+/// see [`howlong::testing`](crate::testing).
+///
+/// The time is measured with [`Instant`](crate::Instant). A custom message may follow, like
+/// [`assert!`].
+///
+/// # Panics
+///
+/// This macro panics with the elapsed time, the budget, and the overshoot if the elapsed time
+/// exceeds the budget multiplied by [`budget_scale`](crate::testing::budget_scale). If the
+/// expression panics, the panic propagates.
+#[macro_export]
+macro_rules! assert_completes_within {
+    ($budget:expr, $body:expr $(,)?) => {{
+        let start = $crate::Instant::now();
+        let output = $body;
+        $crate::testing::assert_budget(
+            start.elapsed(),
+            $budget,
+            ::core::stringify!($body),
+            ::core::option::Option::None,
+        );
+        output
+    }};
+    ($budget:expr, $body:expr, $($arg:tt)+) => {{
+        let start = $crate::Instant::now();
+        let output = $body;
+        $crate::testing::assert_budget(
+            start.elapsed(),
+            $budget,
+            ::core::stringify!($body),
+            ::core::option::Option::Some(::core::format_args!($($arg)+)),
+        );
+        output
+    }};
+}
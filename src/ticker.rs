@@ -0,0 +1,130 @@
+//! Run periodic work at a fixed rate measured by [`SteadyClock`].
+
+use crate::{clock::*, Clock, Duration, TimePoint};
+
+/// How a [`Ticker`] catches up when [`Ticker::wait`] is called after the next tick has already
+/// passed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum MissedTickBehavior {
+    /// Return immediately for every missed tick until the ticker is caught up, then continue
+    /// at the multiples of the period from the start time.
+    #[default]
+    Burst,
+    /// Return immediately for the late tick, drop the other missed ticks, and continue at the
+    /// multiples of the period from the start time.
+    Skip,
+    /// Return immediately for the late tick, drop the other missed ticks, and continue at the
+    /// multiples of the period from now on.
+    Delay,
+}
+
+/// A blocking ticker which waits until the multiples of a period from its start time.
+///
+/// The ticks are computed as absolute timepoints of [`SteadyClock`], so that the time spent
+/// between two calls to [`wait()`](#method.wait) does not accumulate as drift.
+///
+/// # Examples
+///
+/// ```
+/// use howlong::{Duration, Ticker};
+///
+/// let mut ticker = Ticker::new(Duration::from_millis(10));
+/// for _ in 0..3 {
+///     ticker.wait();
+///     // do some periodic work
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Ticker {
+    period: Duration,
+    next: TimePoint,
+    missed: u64,
+    behavior: MissedTickBehavior,
+}
+
+impl Ticker {
+    /// Construct a ticker whose first tick is one `period` from now. The missed ticks are
+    /// handled with [`MissedTickBehavior::Burst`].
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `period` is zero, or when acessing to the underlying clock
+    /// failed.
+    pub fn new(period: Duration) -> Self {
+        Self::with_missed_tick_behavior(period, MissedTickBehavior::default())
+    }
+
+    /// Like [`new()`](#method.new) but handle the missed ticks with `behavior`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `period` is zero, or when acessing to the underlying clock
+    /// failed.
+    pub fn with_missed_tick_behavior(period: Duration, behavior: MissedTickBehavior) -> Self {
+        assert!(period > Duration::ZERO, "The period must be non-zero.");
+        Ticker {
+            period,
+            next: SteadyClock::now() + period,
+            missed: 0,
+            behavior,
+        }
+    }
+
+    /// Return the period of the ticker.
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// Return how the ticker handles the missed ticks.
+    pub fn missed_tick_behavior(&self) -> MissedTickBehavior {
+        self.behavior
+    }
+
+    /// Change how the ticker handles the missed ticks.
+    pub fn set_missed_tick_behavior(&mut self, behavior: MissedTickBehavior) {
+        self.behavior = behavior;
+    }
+
+    /// Block until the next tick. Return immediately if it has already passed.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn wait(&mut self) {
+        let now = SteadyClock::now();
+        let late = now.saturating_duration_since(self.next);
+        if now < self.next {
+            crate::sleep_until(self.next).expect("Failed to access the clock.");
+        }
+        let period = self.period.as_nanos();
+        self.missed = (late.as_nanos() / period) as u64;
+        self.next = match self.behavior {
+            MissedTickBehavior::Burst => self.next + self.period,
+            MissedTickBehavior::Skip => {
+                // The multiple of the period right after `now`.
+                let skipped = late.as_nanos() - late.as_nanos() % period;
+                self.next + from_nanos(skipped) + self.period
+            }
+            MissedTickBehavior::Delay if late > Duration::ZERO => now + self.period,
+            MissedTickBehavior::Delay => self.next + self.period,
+        };
+    }
+
+    /// Return the number of ticks, besides the one waited for, which had already passed when
+    /// the most recent [`wait()`](#method.wait) was called.
+    ///
+    /// With [`MissedTickBehavior::Burst`], these ticks will be returned immediately by the
+    /// following calls to [`wait()`](#method.wait). Otherwise, they are dropped.
+    pub fn missed(&self) -> u64 {
+        self.missed
+    }
+}
+
+/// Convert `nanos`, which must fit in a [`Duration`], to a [`Duration`].
+fn from_nanos(nanos: u128) -> Duration {
+    const NANOS_PER_SEC: u128 = 1_000_000_000;
+    Duration::new(
+        (nanos / NANOS_PER_SEC) as u64,
+        (nanos % NANOS_PER_SEC) as u32,
+    )
+}
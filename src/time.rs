@@ -0,0 +1,92 @@
+//! Convert the timepoints of [`SystemClock`] and durations to and from
+//! [`time`](https://docs.rs/time) types.
+//!
+//! # Examples
+//!
+//! ```
+//! use howlong::{clock::SystemClock, Duration};
+//!
+//! let now = SystemClock::now_offsetdatetime().unwrap();
+//! println!("{}", now);
+//!
+//! let d = howlong::time::to_time_duration(Duration::from_millis(1500)).unwrap();
+//! assert_eq!(d.whole_milliseconds(), 1500);
+//! ```
+
+use crate::{
+    clock::{SystemClock, UNIX_EPOCH_OFFSET},
+    Clock, Duration, Error, ProcessDuration, Result, Stamped, TimePoint,
+};
+use ::time::OffsetDateTime;
+use core::convert::TryFrom;
+
+/// Convert `d` to [`time::Duration`].
+///
+/// # Errors
+///
+/// This function will return [`Error::OutOfRange`] if `d` is longer than
+/// [`time::Duration::MAX`](::time::Duration::MAX).
+pub fn to_time_duration(d: Duration) -> Result<::time::Duration> {
+    ::time::Duration::try_from(d).map_err(|_| Error::OutOfRange)
+}
+
+/// Convert `d` from [`time::Duration`].
+///
+/// # Errors
+///
+/// This function will return [`Error::OutOfRange`] if `d` is negative.
+pub fn from_time_duration(d: ::time::Duration) -> Result<Duration> {
+    Duration::try_from(d).map_err(|_| Error::OutOfRange)
+}
+
+impl SystemClock {
+    /// Return the current time as [`OffsetDateTime`] in UTC.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if accessing the underlying system calls failed,
+    /// or [`Error::OutOfRange`] if the time cannot be represented by [`OffsetDateTime`].
+    pub fn now_offsetdatetime() -> Result<OffsetDateTime> {
+        Self::try_now()?
+            .to_offsetdatetime()
+            .ok_or(Error::OutOfRange)
+    }
+}
+
+impl TimePoint {
+    /// Convert a timepoint of [`SystemClock`] to [`OffsetDateTime`] in UTC, or return `None` if
+    /// it cannot be represented. The timepoints of the other clocks do not have a meaningful
+    /// epoch, so the result is only valid for [`SystemClock`]. Use
+    /// [`Stamped<SystemClock>`](Stamped) to enforce that at compile time.
+    pub fn to_offsetdatetime(&self) -> Option<OffsetDateTime> {
+        // The offset is non-zero on Windows, where the epoch is 1601-01-01.
+        let nanos = self.0.as_nanos() as i128 - UNIX_EPOCH_OFFSET.as_nanos() as i128;
+        OffsetDateTime::from_unix_timestamp_nanos(nanos).ok()
+    }
+}
+
+impl Stamped<SystemClock> {
+    /// Convert the timepoint to [`OffsetDateTime`] in UTC, or return `None` if it cannot be
+    /// represented. See [`TimePoint::to_offsetdatetime`].
+    pub fn to_offsetdatetime(&self) -> Option<OffsetDateTime> {
+        self.into_inner().to_offsetdatetime()
+    }
+}
+
+impl ProcessDuration {
+    /// Convert the real, user-CPU, and system-CPU times to [`time::Duration`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return [`Error::OutOfRange`] if any of the times is longer than
+    /// [`time::Duration::MAX`](::time::Duration::MAX).
+    pub fn to_time_components(
+        &self,
+    ) -> Result<(::time::Duration, ::time::Duration, ::time::Duration)> {
+        Ok((
+            to_time_duration(self.real)?,
+            to_time_duration(self.user)?,
+            to_time_duration(self.system)?,
+        ))
+    }
+}
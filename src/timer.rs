@@ -15,27 +15,88 @@
 //! println!("{}", timer.elapsed()); // 5.71s wall, 5.70s user + 0ns system = 5.70s CPU (99.8%)
 //! ```
 
-use crate::{clock::*, Clock, Duration, ProcessDuration, ProcessTimePoint, TimePoint};
+use crate::{
+    clock::*, Clock, Duration, Error, HostDuration, HostTimePoint, ProcessDuration,
+    ProcessTimePoint, Result, Stamped, StampedClock, TimePoint, TimePointSub,
+};
+#[cfg(feature = "std")]
+use crate::{ThreadDuration, ThreadTimePoint};
 use core::marker::PhantomData;
-use core::ops::Sub;
+use core::ops::{Add, Sub};
+#[cfg(feature = "std")]
 use std::rc::Rc;
+#[cfg(feature = "std")]
+use std::thread::JoinHandle;
 
-/// Generic timer.
-pub struct Timer<ClockType, TimePointType, DurationType>
+/// A [`Clock`] whose timepoints can be measured by a [`Timer`].
+///
+/// It is implemented for every such clock, and derives the timepoint and duration types from
+/// the clock, so that generic code does not need to spell them out, e.g.
+///
+/// ```
+/// use howlong::{clock::*, Timer, TimerClock};
+///
+/// fn measure<C: TimerClock>(f: impl FnOnce()) -> C::Duration {
+///     let timer = Timer::<C>::new();
+///     f();
+///     timer.elapsed()
+/// }
+///
+/// let real = measure::<HighResolutionClock>(|| {});
+/// let cpu = measure::<ProcessCPUClock>(|| {});
+/// println!("{:?}, {}", real, cpu);
+/// ```
+pub trait TimerClock:
+    Clock<Output = <Self as TimerClock>::TimePoint, Duration = <Self as TimerClock>::Elapsed>
+{
+    /// The timepoint returned by the clock, i.e. [`Clock::Output`].
+    type TimePoint: Copy
+        + Sub<Output = <Self as TimerClock>::Elapsed>
+        + TimePointSub
+        + From<<Self as TimerClock>::Elapsed>
+        + Into<<Self as TimerClock>::Elapsed>;
+
+    /// The elapsed time accumulated by a timer, i.e. [`Clock::Duration`].
+    type Elapsed: Copy + Default + Add<Output = <Self as TimerClock>::Elapsed>;
+}
+
+impl<ClockType, TimePointType, DurationType> TimerClock for ClockType
 where
+    ClockType: Clock<Output = TimePointType, Duration = DurationType>,
+    TimePointType:
+        Copy + Sub<Output = DurationType> + TimePointSub + From<DurationType> + Into<DurationType>,
+    DurationType: Copy + Default + Add<Output = DurationType>,
+{
+    type TimePoint = TimePointType;
+    type Elapsed = DurationType;
+}
+
+/// Generic timer.
+///
+/// The timepoint and duration types default to those of the clock, e.g. `Timer<SteadyClock>`
+/// is [`SteadyTimer`]. See [`TimerClock`] to write code generic over the clocks.
+pub struct Timer<
+    ClockType,
+    TimePointType = <ClockType as Clock>::Output,
+    DurationType = <ClockType as Clock>::Duration,
+> where
     ClockType: Clock<Output = TimePointType>,
-    TimePointType: Copy + Sub<Output = DurationType> + From<DurationType> + Into<DurationType>,
+    TimePointType:
+        Copy + Sub<Output = DurationType> + TimePointSub + From<DurationType> + Into<DurationType>,
+    DurationType: Copy + Default + Add<Output = DurationType>,
 {
     running: bool,
     start_time: TimePointType,
+    accumulated: DurationType,
     _clock: PhantomData<ClockType>,
-    _duration: PhantomData<DurationType>,
 }
 
 impl<ClockType, TimePointType, DurationType> Timer<ClockType, TimePointType, DurationType>
 where
     ClockType: Clock<Output = TimePointType>,
-    TimePointType: Copy + Sub<Output = DurationType> + From<DurationType> + Into<DurationType>,
+    TimePointType:
+        Copy + Sub<Output = DurationType> + TimePointSub + From<DurationType> + Into<DurationType>,
+    DurationType: Copy + Default + Add<Output = DurationType>,
 {
     /// Construct a timer and start it.
     ///
@@ -44,14 +105,31 @@ where
     /// This function might panic when acessing to the underlying clock failed.
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
+        Self::with_start(<ClockType>::now())
+    }
+
+    /// Construct a running timer as if it had been started at `start`.
+    ///
+    /// `start` should be a timepoint returned by the same clock.
+    pub fn with_start(start: TimePointType) -> Self {
         Timer {
             running: true,
-            start_time: <ClockType>::now(),
+            start_time: start,
+            accumulated: DurationType::default(),
             _clock: PhantomData,
-            _duration: PhantomData,
         }
     }
 
+    /// Return the resolution of the underlying clock, see [`Clock::resolution`]. An elapsed
+    /// time below it might be reported as zero.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the resolution cannot be obtained.
+    pub fn resolution() -> Result<Duration> {
+        <ClockType>::resolution()
+    }
+
     /// Return true if the timer is running.
     pub fn is_running(&self) -> bool {
         self.running
@@ -67,27 +145,56 @@ where
     /// recent call to [`start()`](#method.start) or [`resume()`](#method.resume) and the
     /// current time values.
     ///
+    /// If the clock went backwards since the timer was started, which may happen for a
+    /// non-steady clock such as [`SystemClock`], zero is returned for the running part. For the
+    /// clocks with several times, e.g. [`ProcessCPUClock`], each of them is clamped to zero
+    /// separately, so that a single time read at a coarser granularity cannot cause a panic.
+    ///
     /// # Panics
     ///
     /// This function might panic when acessing to the underlying clock failed.
     pub fn elapsed(&self) -> DurationType {
+        self.elapsed_with(<ClockType>::now)
+    }
+
+    /// Like [`elapsed()`](#method.elapsed) but return `None` if the clock went backwards since
+    /// the timer was started.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn checked_elapsed(&self) -> Option<DurationType> {
         if self.is_running() {
-            <ClockType>::now() - self.start_time
+            <ClockType>::now()
+                .checked_sub(self.start_time)
+                .map(|elapsed| self.accumulated + elapsed)
         } else {
-            self.start_time.into()
+            Some(self.accumulated)
         }
     }
 
+    /// Return true if the elapsed time, see [`elapsed()`](#method.elapsed), has reached
+    /// `limit`, i.e. is not shorter than it. For a running [`SteadyTimer`], it is equivalent to
+    /// the [`Deadline::is_expired()`](crate::Deadline::is_expired) of a deadline `limit` after
+    /// the start.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn has_exceeded(&self, limit: DurationType) -> bool
+    where
+        DurationType: PartialOrd,
+    {
+        self.elapsed() >= limit
+    }
+
     /// If the timer is not running, reset and start the timer.
     ///
     /// # Panics
     ///
     /// This function might panic when acessing to the underlying clock failed.
     pub fn start(&mut self) {
-        if self.is_stopped() {
-            self.running = true;
-            self.start_time = <ClockType>::now();
-        }
+        self.start_with(<ClockType>::now);
     }
 
     /// Stop the timer.
@@ -96,10 +203,7 @@ where
     ///
     /// This function might panic when acessing to the underlying clock failed.
     pub fn stop(&mut self) {
-        if self.is_running() {
-            self.running = false;
-            self.start_time = <TimePointType>::from(<ClockType>::now() - self.start_time);
-        }
+        self.stop_with(<ClockType>::now);
     }
 
     /// Resume the timer, accumulating additional elapsed time.
@@ -108,13 +212,88 @@ where
     ///
     /// This function might panic when acessing to the underlying clock failed.
     pub fn resume(&mut self) {
+        self.resume_with(<ClockType>::now);
+    }
+
+    // The state machine of the timer, reading the current timepoint with `now` rather than
+    // from `ClockType`, so that the timers of a clock value, e.g. `DynTimer`, can share it.
+
+    pub(crate) fn elapsed_with(&self, now: impl FnOnce() -> TimePointType) -> DurationType {
+        if self.is_running() {
+            self.accumulated + now().saturating_sub(self.start_time)
+        } else {
+            self.accumulated
+        }
+    }
+
+    pub(crate) fn start_with(&mut self, now: impl FnOnce() -> TimePointType) {
+        if self.is_stopped() {
+            self.running = true;
+            self.start_time = now();
+            self.accumulated = DurationType::default();
+        }
+    }
+
+    pub(crate) fn stop_with(&mut self, now: impl FnOnce() -> TimePointType) {
+        if self.is_running() {
+            self.running = false;
+            self.accumulated = self.accumulated + now().saturating_sub(self.start_time);
+        }
+    }
+
+    pub(crate) fn resume_with(&mut self, now: impl FnOnce() -> TimePointType) {
         if self.is_stopped() {
             self.running = true;
-            self.start_time = <TimePointType>::from(<ClockType>::now() - self.start_time);
+            self.start_time = now();
+        }
+    }
+
+    /// Capture the accumulated elapsed time of a stopped timer.
+    ///
+    /// The snapshot can be persisted (with the `serde` feature) and later turned back into a
+    /// timer using [`from_snapshot()`](#method.from_snapshot).
+    ///
+    /// # Errors
+    ///
+    /// This function will return [`Error::TimerRunning`] if the timer is running. Call
+    /// [`stop()`](#method.stop) first to take a snapshot of a running timer.
+    pub fn snapshot(&self) -> Result<PersistedTimer<DurationType>> {
+        if self.is_running() {
+            return Err(Error::TimerRunning);
+        }
+        Ok(PersistedTimer {
+            elapsed: self.accumulated,
+        })
+    }
+
+    /// Construct a stopped timer from a snapshot. The accumulated elapsed time is restored and
+    /// the timer can be continued with [`resume()`](#method.resume).
+    pub fn from_snapshot(snapshot: PersistedTimer<DurationType>) -> Self {
+        Timer {
+            running: false,
+            start_time: <TimePointType>::from(DurationType::default()),
+            accumulated: snapshot.elapsed,
+            _clock: PhantomData,
         }
     }
 }
 
+/// The accumulated elapsed time of a stopped [`Timer`].
+///
+/// It is obtained by [`Timer::snapshot()`] and restored by [`Timer::from_snapshot()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PersistedTimer<DurationType> {
+    elapsed: DurationType,
+}
+
+impl<DurationType: Copy> PersistedTimer<DurationType> {
+    /// Return the accumulated elapsed time.
+    pub fn elapsed(&self) -> DurationType {
+        self.elapsed
+    }
+}
+
 /// A timer to measure system time.
 pub type SystemTimer = Timer<SystemClock, TimePoint, Duration>;
 
@@ -122,9 +301,32 @@ pub type SystemTimer = Timer<SystemClock, TimePoint, Duration>;
 #[doc = "A timer using steady clock."]
 pub type SteadyTimer = Timer<SteadyClock, TimePoint, Duration>;
 
+#[cfg(have_suspend_aware_clock)]
+#[doc = "A timer using suspend-aware clock."]
+pub type SuspendAwareTimer = Timer<SuspendAwareClock, TimePoint, Duration>;
+
+#[cfg(have_coarse_clock)]
+#[doc = "A timer using coarse system clock."]
+pub type CoarseSystemTimer = Timer<CoarseSystemClock, TimePoint, Duration>;
+
+#[cfg(have_coarse_clock)]
+#[doc = "A timer using coarse steady clock."]
+pub type CoarseSteadyTimer = Timer<CoarseSteadyClock, TimePoint, Duration>;
+
+#[cfg(have_clock_tai)]
+#[doc = "A timer using TAI clock."]
+pub type TaiTimer = Timer<TaiClock, TimePoint, Duration>;
+
+#[cfg(all(feature = "tsc", any(target_arch = "x86", target_arch = "x86_64")))]
+#[doc = "A timer using the timestamp counter."]
+pub type TscTimer = Timer<TscClock, TimePoint, Duration>;
+
 /// A timer using high resolution clock.
 pub type HighResolutionTimer = Timer<HighResolutionClock, TimePoint, Duration>;
 
+/// A timer using the clock `C` whose timepoints are tagged with it, see [`Stamped`].
+pub type StampedTimer<C> = Timer<StampedClock<C>, Stamped<C>, Duration>;
+
 /// A timer to measure the real process wall-clock.
 pub type ProcessRealCPUTimer = Timer<ProcessRealCPUClock, TimePoint, Duration>;
 
@@ -137,13 +339,175 @@ pub type ProcessSystemCPUTimer = Timer<ProcessSystemCPUClock, TimePoint, Duratio
 /// A timer to measure real, user-CPU, and system-CPU clocks at the same time.
 pub type ProcessCPUTimer = Timer<ProcessCPUClock, ProcessTimePoint, ProcessDuration>;
 
+/// A timer to measure real, user-CPU, and system-CPU clocks of the process, excluding the
+/// child processes. It is the same as [`ProcessCPUTimer`].
+pub type ProcessSelfCPUTimer = ProcessCPUTimer;
+
+/// A timer to measure real, user-CPU, and system-CPU clocks of the process, including the
+/// child processes.
+pub type ProcessWithChildrenCPUTimer =
+    Timer<ProcessWithChildrenCPUClock, ProcessTimePoint, ProcessDuration>;
+
+/// A timer to measure the user and system CPU time of the child processes, see
+/// [`ChildrenCPUClock`].
+pub type ChildrenCPUTimer = Timer<ChildrenCPUClock, ProcessTimePoint, ProcessDuration>;
+
+/// A timer to measure the user, system, and idle CPU time of the whole host, see
+/// [`HostCPUClock`].
+pub type HostCPUTimer = Timer<HostCPUClock, HostTimePoint, HostDuration>;
+
+#[cfg(all(target_os = "linux", not(howlong_unsupported)))]
+#[doc = "A timer to measure real, user-CPU, and system-CPU clocks by `/proc/self/stat`."]
+pub type ProcStatTimer = Timer<ProcStatClock, ProcessTimePoint, ProcessDuration>;
+
+#[cfg(all(target_os = "linux", not(howlong_unsupported)))]
+#[doc = "A timer to measure the user-CPU and system-CPU clocks of the cgroup of the process."]
+pub type CgroupCPUTimer = Timer<CgroupCPUClock, ProcessTimePoint, ProcessDuration>;
+
+#[cfg(all(windows, not(howlong_unsupported)))]
+#[doc = "A timer to measure the CPU cycles spent by the calling thread."]
+pub type ThreadCycleTimer = Timer<ThreadCycleClock, crate::CycleTimePoint, crate::Cycles>;
+
+#[cfg(all(windows, not(howlong_unsupported)))]
+#[doc = "A timer to measure the CPU cycles spent by the process."]
+pub type ProcessCycleTimer = Timer<ProcessCycleClock, crate::CycleTimePoint, crate::Cycles>;
+
+#[cfg(all(windows, not(howlong_unsupported)))]
+impl ChildrenCPUTimer {
+    /// Return the job object to which the child processes have to be assigned to be measured.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if creating the job object failed.
+    pub fn job() -> Result<&'static ChildrenJob> {
+        ChildrenJob::get()
+    }
+}
+
+#[cfg(all(
+    unix,
+    not(any(target_os = "fuchsia", target_os = "haiku")),
+    not(howlong_unsupported)
+))]
+#[doc = "A timer to measure the user cpu-clock by `getrusage`."]
+pub type RusageUserCPUTimer = Timer<RusageUserCPUClock, TimePoint, Duration>;
+
+#[cfg(all(
+    unix,
+    not(any(target_os = "fuchsia", target_os = "haiku")),
+    not(howlong_unsupported)
+))]
+#[doc = "A timer to measure the system cpu-clock by `getrusage`."]
+pub type RusageSystemCPUTimer = Timer<RusageSystemCPUClock, TimePoint, Duration>;
+
+#[cfg(all(
+    unix,
+    not(any(target_os = "fuchsia", target_os = "haiku")),
+    not(howlong_unsupported)
+))]
+#[doc = "A timer to measure real, user-CPU, and system-CPU clocks by `getrusage`."]
+pub type RusageCPUTimer = Timer<RusageCPUClock, ProcessTimePoint, ProcessDuration>;
+
+#[cfg(all(
+    unix,
+    not(any(target_os = "fuchsia", target_os = "haiku")),
+    not(howlong_unsupported)
+))]
+#[doc = "A timer to measure the CPU clocks of the child processes by `getrusage`."]
+pub type RusageChildrenCPUTimer = Timer<RusageChildrenCPUClock, ProcessTimePoint, ProcessDuration>;
+
+/// A timer to measure real, user-CPU, and system-CPU clocks of another process at the same
+/// time, see [`PidCpuClock`].
+pub struct PidCpuTimer {
+    clock: PidCpuClock,
+    // The timepoints are read from `clock`, the clock type of `inner` is never used.
+    inner: Timer<ProcessCPUClock, ProcessTimePoint, ProcessDuration>,
+}
+
+impl PidCpuTimer {
+    /// Construct a timer for the process `pid` and start it.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the process does not exist or cannot be
+    /// accessed.
+    pub fn new(pid: u32) -> Result<Self> {
+        let clock = ProcessCPUClock::for_pid(pid)?;
+        let inner = Timer::with_start(clock.try_now()?);
+        Ok(PidCpuTimer { clock, inner })
+    }
+
+    /// Return the underlying clock.
+    pub fn clock(&self) -> &PidCpuClock {
+        &self.clock
+    }
+
+    /// Return true if the timer is running.
+    pub fn is_running(&self) -> bool {
+        self.inner.is_running()
+    }
+
+    /// Return true if the timer is not running.
+    pub fn is_stopped(&self) -> bool {
+        self.inner.is_stopped()
+    }
+
+    /// Return the accumulated elapsed times as of the previous [`stop()`](#method.stop)
+    /// if the timer is stopped. Otherwise, the elapsed times accumulated between the most
+    /// recent call to [`start()`](#method.start) or [`resume()`](#method.resume) and the
+    /// current time values.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed, e.g. the
+    /// process does not exist anymore.
+    pub fn elapsed(&self) -> ProcessDuration {
+        self.inner.elapsed_with(|| self.clock.now())
+    }
+
+    /// If the timer is not running, reset and start the timer.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed, e.g. the
+    /// process does not exist anymore.
+    pub fn start(&mut self) {
+        let clock = &self.clock;
+        self.inner.start_with(|| clock.now());
+    }
+
+    /// Stop the timer.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed, e.g. the
+    /// process does not exist anymore.
+    pub fn stop(&mut self) {
+        let clock = &self.clock;
+        self.inner.stop_with(|| clock.now());
+    }
+
+    /// Resume the timer, accumulating additional elapsed time.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed, e.g. the
+    /// process does not exist anymore.
+    pub fn resume(&mut self) {
+        let clock = &self.clock;
+        self.inner.resume_with(|| clock.now());
+    }
+}
+
 /// A timer to measure thread CPU time.
+#[cfg(feature = "std")]
 pub struct ThreadTimer {
     inner: Timer<ThreadClock, TimePoint, Duration>,
     // makes type non-sync and non-send
     _no_sync: PhantomData<Rc<()>>,
 }
 
+#[cfg(feature = "std")]
 impl ThreadTimer {
     /// Construct a timer and start it.
     ///
@@ -158,6 +522,27 @@ impl ThreadTimer {
         }
     }
 
+    /// Construct a timer measuring the CPU time of the thread of `handle` and start it. Like
+    /// [`ThreadCpuHandle::for_join_handle`], it must not be read after `handle` is joined.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the thread clock cannot be accessed, e.g. the
+    /// thread has already exited.
+    pub fn for_thread<T>(handle: &JoinHandle<T>) -> Result<ThreadTimerFor> {
+        ThreadTimerFor::new(ThreadCpuHandle::for_join_handle(handle)?)
+    }
+
+    /// Return the resolution of the underlying clock, see [`Clock::resolution`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the resolution cannot be obtained.
+    #[inline(always)]
+    pub fn resolution() -> Result<Duration> {
+        ThreadClock::resolution()
+    }
+
     /// Return true if the timer is running.
     #[inline(always)]
     pub fn is_running(&self) -> bool {
@@ -183,6 +568,201 @@ impl ThreadTimer {
         self.inner.elapsed()
     }
 
+    /// Like [`elapsed()`](#method.elapsed) but return `None` if the clock went backwards since
+    /// the timer was started.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    #[inline(always)]
+    pub fn checked_elapsed(&self) -> Option<Duration> {
+        self.inner.checked_elapsed()
+    }
+
+    /// If the timer is not running, reset and start the timer.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    #[inline(always)]
+    pub fn start(&mut self) {
+        self.inner.start();
+    }
+
+    /// Stop the timer.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    #[inline(always)]
+    pub fn stop(&mut self) {
+        self.inner.stop();
+    }
+
+    /// Resume the timer, accumulating additional elapsed time.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    #[inline(always)]
+    pub fn resume(&mut self) {
+        self.inner.resume();
+    }
+}
+
+/// A timer to measure the CPU time of another thread, see [`ThreadCpuHandle`].
+///
+/// It is constructed by [`ThreadTimer::for_thread`].
+#[cfg(feature = "std")]
+pub struct ThreadTimerFor {
+    handle: ThreadCpuHandle,
+    // The timepoints are read from `handle`, the clock type of `inner` is never used.
+    inner: Timer<ThreadClock, TimePoint, Duration>,
+}
+
+#[cfg(feature = "std")]
+impl ThreadTimerFor {
+    /// Construct a timer for the thread of `handle` and start it.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the thread clock failed.
+    pub fn new(handle: ThreadCpuHandle) -> Result<Self> {
+        let inner = Timer::with_start(handle.try_now()?);
+        Ok(ThreadTimerFor { handle, inner })
+    }
+
+    /// Return the underlying thread handle.
+    pub fn handle(&self) -> &ThreadCpuHandle {
+        &self.handle
+    }
+
+    /// Return true if the timer is running.
+    pub fn is_running(&self) -> bool {
+        self.inner.is_running()
+    }
+
+    /// Return true if the timer is not running.
+    pub fn is_stopped(&self) -> bool {
+        self.inner.is_stopped()
+    }
+
+    /// Return the accumulated elapsed times as of the previous [`stop()`](#method.stop)
+    /// if the timer is stopped. Otherwise, the elapsed times accumulated between the most
+    /// recent call to [`start()`](#method.start) or [`resume()`](#method.resume) and the
+    /// current time values.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed, e.g. the
+    /// thread has exited.
+    pub fn elapsed(&self) -> Duration {
+        self.inner.elapsed_with(|| self.handle.now())
+    }
+
+    /// If the timer is not running, reset and start the timer.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed, e.g. the
+    /// thread has exited.
+    pub fn start(&mut self) {
+        let handle = &self.handle;
+        self.inner.start_with(|| handle.now());
+    }
+
+    /// Stop the timer.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed, e.g. the
+    /// thread has exited.
+    pub fn stop(&mut self) {
+        let handle = &self.handle;
+        self.inner.stop_with(|| handle.now());
+    }
+
+    /// Resume the timer, accumulating additional elapsed time.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed, e.g. the
+    /// thread has exited.
+    pub fn resume(&mut self) {
+        let handle = &self.handle;
+        self.inner.resume_with(|| handle.now());
+    }
+}
+
+/// A timer to measure user-CPU and system-CPU thread times.
+#[cfg(feature = "std")]
+pub struct ThreadCPUTimer {
+    inner: Timer<ThreadCPUClock, ThreadTimePoint, ThreadDuration>,
+    // makes type non-sync and non-send
+    _no_sync: PhantomData<Rc<()>>,
+}
+
+#[cfg(feature = "std")]
+impl ThreadCPUTimer {
+    /// Construct a timer and start it.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        ThreadCPUTimer {
+            inner: Timer::<ThreadCPUClock, ThreadTimePoint, ThreadDuration>::new(),
+            _no_sync: PhantomData,
+        }
+    }
+
+    /// Return the resolution of the underlying clock, see [`Clock::resolution`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the resolution cannot be obtained.
+    #[inline(always)]
+    pub fn resolution() -> Result<Duration> {
+        ThreadCPUClock::resolution()
+    }
+
+    /// Return true if the timer is running.
+    #[inline(always)]
+    pub fn is_running(&self) -> bool {
+        self.inner.is_running()
+    }
+
+    /// Return true if the timer is not running.
+    #[inline(always)]
+    pub fn is_stopped(&self) -> bool {
+        self.inner.is_stopped()
+    }
+
+    /// Return the accumulated elapsed times as of the previous [`stop()`](#method.stop)
+    /// if the timer is stopped. Otherwise, the elapsed times accumulated between the most
+    /// recent call to [`start()`](#method.start) or [`resume()`](#method.resume) and the
+    /// current time values.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    #[inline(always)]
+    pub fn elapsed(&self) -> ThreadDuration {
+        self.inner.elapsed()
+    }
+
+    /// Like [`elapsed()`](#method.elapsed) but return `None` if the clock went backwards since
+    /// the timer was started.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    #[inline(always)]
+    pub fn checked_elapsed(&self) -> Option<ThreadDuration> {
+        self.inner.checked_elapsed()
+    }
+
     /// If the timer is not running, reset and start the timer.
     ///
     /// # Panics
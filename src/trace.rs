@@ -0,0 +1,149 @@
+use crate::{clock::*, timer::*, Clock, Duration, ProcessDuration, ProcessTimePoint, TimePoint};
+use tracing::{span, Span, Subscriber};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+
+#[inline(always)]
+fn nanos(d: Duration) -> u64 {
+    d.as_nanos().min(u64::MAX as u128) as u64
+}
+
+impl<ClockType> Timer<ClockType, TimePoint, Duration>
+where
+    ClockType: Clock<Output = TimePoint>,
+{
+    /// Record the elapsed nanoseconds as `field` on the current span.
+    ///
+    /// The field must be declared when the span is created, e.g. using
+    /// `tracing::field::Empty`.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn record_in_span(&self, field: &str) {
+        Span::current().record(field, nanos(self.elapsed()));
+    }
+}
+
+impl<ClockType> Timer<ClockType, ProcessTimePoint, ProcessDuration>
+where
+    ClockType: Clock<Output = ProcessTimePoint>,
+{
+    /// Record the elapsed nanoseconds as `{field}.real`, `{field}.user`, and `{field}.system`
+    /// on the current span.
+    ///
+    /// The fields must be declared when the span is created, e.g. using
+    /// `tracing::field::Empty`.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn record_in_span(&self, field: &str) {
+        let elapsed = self.elapsed();
+        let span = Span::current();
+        span.record(format!("{}.real", field).as_str(), nanos(elapsed.real));
+        span.record(format!("{}.user", field).as_str(), nanos(elapsed.user));
+        span.record(format!("{}.system", field).as_str(), nanos(elapsed.system));
+    }
+}
+
+impl ThreadTimer {
+    /// Record the elapsed nanoseconds as `field` on the current span.
+    ///
+    /// The field must be declared when the span is created, e.g. using
+    /// `tracing::field::Empty`.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying clock failed.
+    pub fn record_in_span(&self, field: &str) {
+        Span::current().record(field, nanos(self.elapsed()));
+    }
+}
+
+/// The time a span spent entered (busy) and not entered (idle), measured by [`HowlongLayer`].
+///
+/// It is stored in the span extensions and is complete when the span is closed.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SpanTimings {
+    /// The total time the span was entered.
+    pub busy: Duration,
+    /// The total time between the first enter and the close of the span, during which the
+    /// span was not entered.
+    pub idle: Duration,
+    last: Option<TimePoint>,
+    entered: bool,
+}
+
+/// A [`Layer`] which measures the busy and idle times of each span using
+/// [`HighResolutionClock`], from the first enter to the close of the span.
+///
+/// The measured [`SpanTimings`] are stored in the span extensions, which can be read by the
+/// layers added after it, e.g. in their `on_close`.
+///
+/// # Examples
+///
+/// ```
+/// use tracing_subscriber::layer::SubscriberExt;
+///
+/// let subscriber = tracing_subscriber::Registry::default().with(howlong::HowlongLayer);
+/// tracing::subscriber::with_default(subscriber, || {
+///     let _span = tracing::info_span!("computation").entered();
+///     // do some computations
+/// });
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HowlongLayer;
+
+impl<S> Layer<S> for HowlongLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, _attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(SpanTimings::default());
+        }
+    }
+
+    fn on_enter(&self, id: &span::Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            let now = HighResolutionClock::now();
+            if let Some(timings) = span.extensions_mut().get_mut::<SpanTimings>() {
+                if let Some(last) = timings.last {
+                    timings.idle += now.saturating_sub(last);
+                }
+                timings.last = Some(now);
+                timings.entered = true;
+            }
+        }
+    }
+
+    fn on_exit(&self, id: &span::Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            let now = HighResolutionClock::now();
+            if let Some(timings) = span.extensions_mut().get_mut::<SpanTimings>() {
+                if let Some(last) = timings.last {
+                    timings.busy += now.saturating_sub(last);
+                }
+                timings.last = Some(now);
+                timings.entered = false;
+            }
+        }
+    }
+
+    fn on_close(&self, id: span::Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(&id) {
+            let now = HighResolutionClock::now();
+            if let Some(timings) = span.extensions_mut().get_mut::<SpanTimings>() {
+                // Close the final interval, which is idle unless the span is closed while it
+                // is still entered.
+                if let Some(last) = timings.last.take() {
+                    if timings.entered {
+                        timings.busy += now.saturating_sub(last);
+                    } else {
+                        timings.idle += now.saturating_sub(last);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,167 +1,1896 @@
-use core::ops::{Add, AddAssign, Sub, SubAssign};
+use alloc::{borrow::ToOwned, format, string::String};
+#[cfg(feature = "std")]
+use core::any::TypeId;
+use core::borrow::Borrow;
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::sync::{OnceLock, RwLock};
 
 /// Error type for this crate.
 #[non_exhaustive]
-#[derive(thiserror::Error, Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub enum Error {
     /// Error during the system call.
-    #[error("failed to call `{0}` [errno: {1}]")]
     SystemError(&'static str, i32),
     /// Error if `sysconf(_SC_CLK_TCK)` returns a too large value
-    #[error("the clock frequence is too high.")]
     ClkFreqTooHigh,
+    /// Error if an operation requires a stopped timer but the timer is running.
+    TimerRunning,
+    /// Error if a measurement is requested with zero iterations.
+    ZeroIterations,
+    /// Error if an operation is not supported by the system or the platform. It contains the
+    /// name of the operation.
+    Unsupported(&'static str),
+    /// Error if the cgroup CPU accounting files of the calling process cannot be found.
+    CgroupUnavailable,
+    /// Error if a time is before the epoch of the clock, e.g. converted by
+    /// `SystemClock::from_system_time`.
+    BeforeEpoch,
+    /// Error if a string cannot be parsed, e.g. by `ProcessDuration::from_str`. It describes
+    /// what is malformed.
+    ParseError(String),
+    /// Error if a time or a duration cannot be represented by the target type, e.g. converted
+    /// by `howlong::chrono::to_chrono` or `howlong::time::to_time_duration`.
+    OutOfRange,
+    /// Error if a thread joins a `CpuGroup` while it is already in one.
+    AlreadyInGroup,
 }
 
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::SystemError(name, code) => {
+                write!(f, "failed to call `{}` [errno: {}]", name, code)
+            }
+            Error::ClkFreqTooHigh => f.write_str("the clock frequence is too high."),
+            Error::TimerRunning => f.write_str("the timer is still running."),
+            Error::ZeroIterations => {
+                f.write_str("the number of iterations must be greater than zero.")
+            }
+            Error::Unsupported(name) => write!(f, "`{}` is not supported by the system.", name),
+            Error::CgroupUnavailable => {
+                f.write_str("the cgroup CPU accounting files are not available.")
+            }
+            Error::BeforeEpoch => f.write_str("the time is before the epoch of the clock."),
+            Error::ParseError(msg) => write!(f, "failed to parse: {}", msg),
+            Error::OutOfRange => f.write_str("the time is out of the range of the target type."),
+            Error::AlreadyInGroup => f.write_str("the thread is already in a CPU group."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
 /// Alias to `core::result::Result<T, howlong::Error>`
 pub type Result<T> = core::result::Result<T, Error>;
 
-pub use core::time::Duration;
+// The errors are sent across threads, e.g. by `CpuUsageMonitor`.
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync + 'static>() {}
+    assert_send_sync::<Error>();
+};
+
+impl Error {
+    /// Create an [`Error::SystemError`] of the system call `name` failing with the raw OS error
+    /// `code`, e.g. `Error::system("clock_gettime", libc::EINVAL)`.
+    pub const fn system(name: &'static str, code: i32) -> Self {
+        Error::SystemError(name, code)
+    }
+
+    /// Create an [`Error::Unsupported`] of the operation `name`.
+    pub const fn unsupported(name: &'static str) -> Self {
+        Error::Unsupported(name)
+    }
+
+    /// Return the raw OS error code of [`Error::SystemError`], i.e. `errno` on Unix,
+    /// `GetLastError()` on Windows, `zx_status_t` on Fuchsia, and the WASI `errno` on WASI.
+    pub fn raw_os_error(&self) -> Option<i32> {
+        match self {
+            Error::SystemError(_, code) => Some(*code),
+            _ => None,
+        }
+    }
+
+    /// Return the name of the failed system call of [`Error::SystemError`].
+    pub fn syscall(&self) -> Option<&'static str> {
+        match self {
+            Error::SystemError(name, _) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Return the [`ErrorKind`] of the error. The kind of [`Error::SystemError`] is derived from
+    /// its raw OS error code, see [`ErrorKind::from_raw_os_error`].
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::SystemError(_, code) => ErrorKind::from_raw_os_error(*code),
+            Error::Unsupported(_) => ErrorKind::Unsupported,
+            Error::CgroupUnavailable => ErrorKind::ClockUnavailable,
+            _ => ErrorKind::Other,
+        }
+    }
+
+    /// Convert the error into a [`std::io::Error`]. Equivalent to `std::io::Error::from(&self)`,
+    /// which keeps the raw OS error code of [`Error::SystemError`] where possible.
+    #[cfg(feature = "std")]
+    pub fn into_io_error(self) -> std::io::Error {
+        (&self).into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<&Error> for std::io::Error {
+    /// Convert [`Error::SystemError`] by [`std::io::Error::from_raw_os_error`] on the
+    /// platforms whose codes are understood by the standard library, so that
+    /// [`raw_os_error()`](std::io::Error::raw_os_error) is preserved. Other errors are
+    /// converted by their [`ErrorKind`] and [`Display`](core::fmt::Display) message.
+    fn from(e: &Error) -> Self {
+        match e {
+            #[cfg(any(all(unix, not(target_os = "fuchsia")), windows, target_os = "wasi"))]
+            Error::SystemError(_, code) if *code != 0 => std::io::Error::from_raw_os_error(*code),
+            _ => {
+                let kind = match e.kind() {
+                    ErrorKind::PermissionDenied => std::io::ErrorKind::PermissionDenied,
+                    ErrorKind::Unsupported => std::io::ErrorKind::Unsupported,
+                    _ => std::io::ErrorKind::Other,
+                };
+                std::io::Error::new(kind, e.to_string())
+            }
+        }
+    }
+}
+
+/// A platform-independent classification of [`Error`], returned by [`Error::kind`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// The calling process lacks the permission, e.g. `EPERM` or `ERROR_ACCESS_DENIED`.
+    PermissionDenied,
+    /// The operation is not supported by the system or the platform, e.g. `ENOSYS` or
+    /// [`Error::Unsupported`].
+    Unsupported,
+    /// The clock cannot be read on this system, e.g. `EINVAL` for an unknown clock id, or
+    /// [`Error::CgroupUnavailable`].
+    ClockUnavailable,
+    /// Any other error.
+    Other,
+}
+
+impl ErrorKind {
+    /// Classify a raw OS error code of the current platform, as contained in
+    /// [`Error::SystemError`].
+    pub fn from_raw_os_error(code: i32) -> ErrorKind {
+        cfg_if::cfg_if! {
+            if #[cfg(windows)] {
+                kind_from_win32_error(code)
+            } else if #[cfg(target_os = "fuchsia")] {
+                // zx_status_t values from zircon/errors.h.
+                match code {
+                    -30 => ErrorKind::PermissionDenied, // ZX_ERR_ACCESS_DENIED
+                    -2 => ErrorKind::Unsupported, // ZX_ERR_NOT_SUPPORTED
+                    -10 | -25 => ErrorKind::ClockUnavailable, // ZX_ERR_INVALID_ARGS, ZX_ERR_NOT_FOUND
+                    _ => ErrorKind::Other,
+                }
+            } else if #[cfg(unix)] {
+                if code == libc::EPERM || code == libc::EACCES {
+                    ErrorKind::PermissionDenied
+                } else if code == libc::ENOSYS || code == libc::ENOTSUP || code == libc::EOPNOTSUPP {
+                    ErrorKind::Unsupported
+                } else if code == libc::EINVAL || code == libc::ENOENT {
+                    ErrorKind::ClockUnavailable
+                } else {
+                    ErrorKind::Other
+                }
+            } else if #[cfg(target_os = "wasi")] {
+                let is = |errnos: &[::wasi::Errno]| errnos.iter().any(|e| i32::from(e.raw()) == code);
+                if is(&[::wasi::ERRNO_PERM, ::wasi::ERRNO_ACCES]) {
+                    ErrorKind::PermissionDenied
+                } else if is(&[::wasi::ERRNO_NOSYS, ::wasi::ERRNO_NOTSUP]) {
+                    ErrorKind::Unsupported
+                } else if is(&[::wasi::ERRNO_INVAL, ::wasi::ERRNO_BADF, ::wasi::ERRNO_NOENT]) {
+                    ErrorKind::ClockUnavailable
+                } else {
+                    ErrorKind::Other
+                }
+            } else {
+                let _ = code;
+                ErrorKind::Other
+            }
+        }
+    }
+}
+
+/// Classify a `GetLastError()` code. It is compiled on all platforms for testing.
+#[cfg(any(windows, test))]
+fn kind_from_win32_error(code: i32) -> ErrorKind {
+    // Values from winerror.h.
+    match code {
+        5 => ErrorKind::PermissionDenied,       // ERROR_ACCESS_DENIED
+        1 | 50 | 120 => ErrorKind::Unsupported, // ERROR_INVALID_FUNCTION, ERROR_NOT_SUPPORTED, ERROR_CALL_NOT_IMPLEMENTED
+        2 | 87 => ErrorKind::ClockUnavailable,  // ERROR_FILE_NOT_FOUND, ERROR_INVALID_PARAMETER
+        _ => ErrorKind::Other,
+    }
+}
+
+pub use core::time::Duration;
+
+/// Checked and saturating subtraction between two time points.
+///
+/// Time points from a non-steady clock, e.g. [`SystemClock`](crate::clock::SystemClock), can
+/// go backwards. The plain `-` operator panics in that case, while these methods do not.
+pub trait TimePointSub: Sub + Sized {
+    /// Return `self - other`, or `None` if `other` is later than `self`.
+    fn checked_sub(self, other: Self) -> Option<Self::Output>;
+
+    /// Return `self - other`, or zero if `other` is later than `self`.
+    fn saturating_sub(self, other: Self) -> Self::Output;
+}
+
+/// A point in time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimePoint(pub(crate) Duration);
+
+impl TimePoint {
+    /// Return the [`Duration`] elapsed from `other` to `self`, or `None` if `other` is later
+    /// than `self`.
+    #[inline(always)]
+    pub fn checked_sub(self, other: Self) -> Option<Duration> {
+        self.0.checked_sub(other.0)
+    }
+
+    /// Return the [`Duration`] elapsed from `other` to `self`, or zero if `other` is later
+    /// than `self`.
+    #[inline(always)]
+    pub fn saturating_sub(self, other: Self) -> Duration {
+        self.0.checked_sub(other.0).unwrap_or_default()
+    }
+
+    /// Return the [`Duration`] elapsed from `earlier` to `self`, or `None` if `earlier` is
+    /// actually later than `self`, e.g. the system clock was set backwards in between.
+    #[inline(always)]
+    pub fn checked_duration_since(self, earlier: Self) -> Option<Duration> {
+        self.checked_sub(earlier)
+    }
+
+    /// Return the [`Duration`] elapsed from `earlier` to `self`, or zero if `earlier` is
+    /// actually later than `self`, e.g. the system clock was set backwards in between.
+    #[inline(always)]
+    pub fn saturating_duration_since(self, earlier: Self) -> Duration {
+        self.saturating_sub(earlier)
+    }
+
+    /// Return the difference from `earlier` to `self` as a pair of whether it is negative, i.e.
+    /// `earlier` is actually later than `self`, and its magnitude.
+    ///
+    /// ```
+    /// use howlong::{Duration, TimePoint};
+    ///
+    /// let earlier = TimePoint::from(Duration::from_secs(2));
+    /// let later = TimePoint::from(Duration::from_secs(3));
+    /// assert_eq!(later.signed_duration_since(earlier), (false, Duration::from_secs(1)));
+    /// assert_eq!(earlier.signed_duration_since(later), (true, Duration::from_secs(1)));
+    /// ```
+    #[inline(always)]
+    pub fn signed_duration_since(self, earlier: Self) -> (bool, Duration) {
+        match self.0.checked_sub(earlier.0) {
+            Some(d) => (false, d),
+            None => (true, earlier.0 - self.0),
+        }
+    }
+
+    /// Return the timepoint `d` after `self`, or `None` if it overflows.
+    ///
+    /// ```
+    /// use howlong::{clock::*, Clock, Duration};
+    ///
+    /// let deadline = HighResolutionClock::now()
+    ///     .checked_add(Duration::from_millis(50))
+    ///     .unwrap();
+    /// assert!(HighResolutionClock::now() < deadline);
+    /// ```
+    #[inline(always)]
+    pub fn checked_add(self, d: Duration) -> Option<TimePoint> {
+        self.0.checked_add(d).map(TimePoint)
+    }
+
+    /// Return the timepoint `d` before `self`, or `None` if it is before the epoch of the
+    /// clock.
+    #[inline(always)]
+    pub fn checked_sub_duration(self, d: Duration) -> Option<TimePoint> {
+        self.0.checked_sub(d).map(TimePoint)
+    }
+
+    /// Return the number of nanoseconds since the epoch of the clock.
+    #[inline(always)]
+    pub fn as_nanos(&self) -> u128 {
+        self.0.as_nanos()
+    }
+}
+
+impl Sub for TimePoint {
+    type Output = Duration;
+
+    /// Return the [`Duration`] elapsed from `other` to `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `other` is later than `self`, which can happen to the
+    /// timepoints of a non-steady clock, e.g. [`SystemClock`](crate::clock::SystemClock) set
+    /// backwards. Use [`TimePoint::saturating_duration_since`] or
+    /// [`TimePoint::checked_duration_since`] instead if that is possible.
+    #[inline(always)]
+    fn sub(self, other: Self) -> Self::Output {
+        self.0 - other.0
+    }
+}
+
+impl Add<Duration> for TimePoint {
+    type Output = TimePoint;
+
+    /// Return the timepoint `d` after `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the result overflows. Use [`TimePoint::checked_add`]
+    /// instead if that is possible.
+    #[inline(always)]
+    fn add(self, d: Duration) -> Self::Output {
+        TimePoint(self.0 + d)
+    }
+}
+
+impl AddAssign<Duration> for TimePoint {
+    #[inline(always)]
+    fn add_assign(&mut self, d: Duration) {
+        *self = *self + d;
+    }
+}
+
+impl Sub<Duration> for TimePoint {
+    type Output = TimePoint;
+
+    /// Return the timepoint `d` before `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the result is before the epoch of the clock. Use
+    /// [`TimePoint::checked_sub_duration`] instead if that is possible.
+    #[inline(always)]
+    fn sub(self, d: Duration) -> Self::Output {
+        TimePoint(self.0 - d)
+    }
+}
+
+impl SubAssign<Duration> for TimePoint {
+    #[inline(always)]
+    fn sub_assign(&mut self, d: Duration) {
+        *self = *self - d;
+    }
+}
+
+impl TimePointSub for TimePoint {
+    #[inline(always)]
+    fn checked_sub(self, other: Self) -> Option<Self::Output> {
+        TimePoint::checked_sub(self, other)
+    }
+
+    #[inline(always)]
+    fn saturating_sub(self, other: Self) -> Self::Output {
+        TimePoint::saturating_sub(self, other)
+    }
+}
+
+impl From<Duration> for TimePoint {
+    fn from(d: Duration) -> Self {
+        TimePoint(d)
+    }
+}
+
+impl From<TimePoint> for Duration {
+    fn from(t: TimePoint) -> Self {
+        t.0
+    }
+}
+
+/// A [`TimePoint`] tagged with the clock `C` which returned it.
+///
+/// Unlike [`TimePoint`], two stamped timepoints can only be subtracted if they come from the
+/// same clock, so that mixing up clocks is a compile error. Use [`into_inner()`](#method.into_inner)
+/// to get the untyped [`TimePoint`] back.
+///
+/// # Examples
+///
+/// ```
+/// use howlong::{clock::*, Stamped};
+///
+/// let start = Stamped::<HighResolutionClock>::now();
+/// // do some computations
+/// let elapsed = Stamped::<HighResolutionClock>::now() - start;
+/// println!("{:?} have passed.", elapsed);
+/// ```
+///
+/// ```compile_fail
+/// use howlong::{clock::*, Stamped};
+///
+/// let start = Stamped::<SystemClock>::now();
+/// let elapsed = Stamped::<ProcessRealCPUClock>::now() - start;
+/// ```
+pub struct Stamped<C> {
+    point: TimePoint,
+    _clock: PhantomData<fn() -> C>,
+}
+
+impl<C: Clock<Output = TimePoint>> Stamped<C> {
+    /// Return the current timepoint of `C`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the underlying system calls failed.
+    pub fn try_now() -> Result<Self> {
+        C::try_now().map(Self::new)
+    }
+
+    /// Return the current timepoint of `C`.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying system calls failed.
+    pub fn now() -> Self {
+        Self::new(C::now())
+    }
+}
+
+impl<C> Stamped<C> {
+    /// Tag `point` with the clock `C`. `point` should be a timepoint returned by `C`.
+    pub fn new(point: TimePoint) -> Self {
+        Stamped {
+            point,
+            _clock: PhantomData,
+        }
+    }
+
+    /// Return the untyped [`TimePoint`].
+    pub fn into_inner(self) -> TimePoint {
+        self.point
+    }
+
+    /// See [`TimePoint::checked_duration_since`].
+    #[inline(always)]
+    pub fn checked_duration_since(self, earlier: Self) -> Option<Duration> {
+        self.point.checked_duration_since(earlier.point)
+    }
+
+    /// See [`TimePoint::saturating_duration_since`].
+    #[inline(always)]
+    pub fn saturating_duration_since(self, earlier: Self) -> Duration {
+        self.point.saturating_duration_since(earlier.point)
+    }
+}
+
+impl<C> Clone for Stamped<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for Stamped<C> {}
+
+impl<C> core::fmt::Debug for Stamped<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let name = core::any::type_name::<C>();
+        f.debug_tuple(&format!(
+            "Stamped<{}>",
+            name.rsplit("::").next().unwrap_or(name)
+        ))
+        .field(&self.point)
+        .finish()
+    }
+}
+
+impl<C> PartialEq for Stamped<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.point == other.point
+    }
+}
+
+impl<C> Eq for Stamped<C> {}
+
+impl<C> PartialOrd for Stamped<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C> Ord for Stamped<C> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.point.cmp(&other.point)
+    }
+}
+
+impl<C> Sub for Stamped<C> {
+    type Output = Duration;
+
+    /// Return the [`Duration`] elapsed from `other` to `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `other` is later than `self`, see [`TimePoint::sub`].
+    #[inline(always)]
+    fn sub(self, other: Self) -> Self::Output {
+        self.point - other.point
+    }
+}
+
+impl<C> TimePointSub for Stamped<C> {
+    #[inline(always)]
+    fn checked_sub(self, other: Self) -> Option<Self::Output> {
+        self.point.checked_sub(other.point)
+    }
+
+    #[inline(always)]
+    fn saturating_sub(self, other: Self) -> Self::Output {
+        self.point.saturating_sub(other.point)
+    }
+}
+
+impl<C> From<Duration> for Stamped<C> {
+    fn from(d: Duration) -> Self {
+        Self::new(TimePoint(d))
+    }
+}
+
+impl<C> From<Stamped<C>> for Duration {
+    fn from(t: Stamped<C>) -> Self {
+        t.point.0
+    }
+}
+
+/// A clock which returns the timepoints of `C` as [`Stamped<C>`], see
+/// [`StampedTimer`](crate::StampedTimer).
+pub struct StampedClock<C>(PhantomData<fn() -> C>);
+
+impl<C: Clock<Output = TimePoint>> Clock for StampedClock<C> {
+    type Output = Stamped<C>;
+    type Duration = Duration;
+    const IS_STEADY: bool = C::IS_STEADY;
+    const IS_CPU_TIME: bool = C::IS_CPU_TIME;
+
+    fn try_now() -> Result<Self::Output> {
+        Stamped::try_now()
+    }
+
+    fn resolution() -> Result<Duration> {
+        C::resolution()
+    }
+
+    fn info() -> ClockInfo {
+        C::info()
+    }
+}
+
+/// Like [`TimePoint`] but captures real, user-CPU, and system-CPU process times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessTimePoint {
+    pub(crate) real: Duration,
+    pub(crate) user: Duration,
+    pub(crate) system: Duration,
+}
+
+impl Sub for ProcessTimePoint {
+    type Output = ProcessDuration;
+
+    /// Return the times elapsed from `other` to `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if any of the times of `other` is later than that of `self`,
+    /// which may happen even for a steady process clock since the real and CPU times are read
+    /// at different granularities. Use [`ProcessTimePoint::checked_sub`] or
+    /// [`ProcessTimePoint::saturating_sub`] instead if that is possible.
+    #[inline(always)]
+    fn sub(self, other: Self) -> Self::Output {
+        ProcessDuration {
+            real: self.real - other.real,
+            user: self.user - other.user,
+            system: self.system - other.system,
+        }
+    }
+}
+
+impl ProcessTimePoint {
+    /// Construct a timepoint from the real, user-CPU, and system-CPU times since the epoch of
+    /// the clock.
+    #[inline(always)]
+    pub const fn new(real: Duration, user: Duration, system: Duration) -> Self {
+        ProcessTimePoint { real, user, system }
+    }
+
+    /// Construct a timepoint from the real, user-CPU, and system-CPU times since the epoch of
+    /// the clock in seconds.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if any of the times is negative, overflows [`Duration`], or is
+    /// not finite, like [`Duration::from_secs_f64`].
+    #[inline(always)]
+    pub fn from_secs_f64(real: f64, user: f64, system: f64) -> Self {
+        ProcessDuration::from_secs_f64(real, user, system).into()
+    }
+
+    /// Return the real time since the epoch of the clock.
+    #[inline(always)]
+    pub fn real(&self) -> Duration {
+        self.real
+    }
+
+    /// Return the user-CPU time since the epoch of the clock.
+    #[inline(always)]
+    pub fn user(&self) -> Duration {
+        self.user
+    }
+
+    /// Return the system-CPU time since the epoch of the clock.
+    #[inline(always)]
+    pub fn system(&self) -> Duration {
+        self.system
+    }
+
+    /// Return the total CPU time. Equivalent to `user + system`.
+    #[inline(always)]
+    pub fn cpu_time(&self) -> Duration {
+        self.user + self.system
+    }
+
+    /// Return the times elapsed from `other` to `self`, or `None` if any of the times of
+    /// `other` is later than that of `self`.
+    #[inline(always)]
+    pub fn checked_sub(self, other: Self) -> Option<ProcessDuration> {
+        self.into_duration().checked_sub(other.into_duration())
+    }
+
+    /// Return the times elapsed from `other` to `self`, where each of the times of `other`
+    /// later than that of `self` is clamped to zero separately.
+    #[inline(always)]
+    pub fn saturating_sub(self, other: Self) -> ProcessDuration {
+        self.into_duration().saturating_sub(other.into_duration())
+    }
+
+    /// Return the timepoint `d` after `self` in each of the real, user-CPU, and system-CPU
+    /// times, or `None` if any of them overflows.
+    #[inline(always)]
+    pub fn checked_add(self, d: ProcessDuration) -> Option<ProcessTimePoint> {
+        Some(ProcessTimePoint {
+            real: self.real.checked_add(d.real)?,
+            user: self.user.checked_add(d.user)?,
+            system: self.system.checked_add(d.system)?,
+        })
+    }
+
+    /// Return the timepoint `d` before `self` in each of the real, user-CPU, and system-CPU
+    /// times, or `None` if any of them is before the epoch of the clock.
+    #[inline(always)]
+    pub fn checked_sub_duration(self, d: ProcessDuration) -> Option<ProcessTimePoint> {
+        Some(ProcessTimePoint {
+            real: self.real.checked_sub(d.real)?,
+            user: self.user.checked_sub(d.user)?,
+            system: self.system.checked_sub(d.system)?,
+        })
+    }
+
+    /// Return the real, user-CPU, and system-CPU times since the epoch of the clock as a
+    /// [`ProcessDuration`].
+    #[inline(always)]
+    pub fn into_duration(self) -> ProcessDuration {
+        ProcessDuration {
+            real: self.real,
+            user: self.user,
+            system: self.system,
+        }
+    }
+}
+
+impl Add<ProcessDuration> for ProcessTimePoint {
+    type Output = ProcessTimePoint;
+
+    /// Return the timepoint `d` after `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the result overflows. Use
+    /// [`ProcessTimePoint::checked_add`] instead if that is possible.
+    #[inline(always)]
+    fn add(self, d: ProcessDuration) -> Self::Output {
+        ProcessTimePoint {
+            real: self.real + d.real,
+            user: self.user + d.user,
+            system: self.system + d.system,
+        }
+    }
+}
+
+impl AddAssign<ProcessDuration> for ProcessTimePoint {
+    #[inline(always)]
+    fn add_assign(&mut self, d: ProcessDuration) {
+        *self = *self + d;
+    }
+}
+
+impl Sub<ProcessDuration> for ProcessTimePoint {
+    type Output = ProcessTimePoint;
+
+    /// Return the timepoint `d` before `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the result is before the epoch of the clock. Use
+    /// [`ProcessTimePoint::checked_sub_duration`] instead if that is possible.
+    #[inline(always)]
+    fn sub(self, d: ProcessDuration) -> Self::Output {
+        ProcessTimePoint {
+            real: self.real - d.real,
+            user: self.user - d.user,
+            system: self.system - d.system,
+        }
+    }
+}
+
+impl SubAssign<ProcessDuration> for ProcessTimePoint {
+    #[inline(always)]
+    fn sub_assign(&mut self, d: ProcessDuration) {
+        *self = *self - d;
+    }
+}
+
+impl TimePointSub for ProcessTimePoint {
+    #[inline(always)]
+    fn checked_sub(self, other: Self) -> Option<Self::Output> {
+        ProcessTimePoint::checked_sub(self, other)
+    }
+
+    #[inline(always)]
+    fn saturating_sub(self, other: Self) -> Self::Output {
+        ProcessTimePoint::saturating_sub(self, other)
+    }
+}
+
+impl core::fmt::Display for ProcessTimePoint {
+    /// Formats the [`ProcessTimePoint`] like [`ProcessDuration`], but without the CPU usage
+    /// since the epochs of the real and CPU clocks differ. It will look something like this:
+    /// ```text
+    /// 51.36s wall, 5.70s user + 0ns system = 5.70s CPU
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{:?} wall, {:?} user + {:?} system = {:?} CPU",
+            self.real,
+            self.user,
+            self.system,
+            self.cpu_time(),
+        )
+    }
+}
+
+impl From<ProcessDuration> for ProcessTimePoint {
+    fn from(d: ProcessDuration) -> Self {
+        ProcessTimePoint {
+            real: d.real,
+            user: d.user,
+            system: d.system,
+        }
+    }
+}
+
+impl From<ProcessTimePoint> for ProcessDuration {
+    fn from(t: ProcessTimePoint) -> Self {
+        t.into_duration()
+    }
+}
+
+/// Like [`Duration`] but captures real, user-CPU, and system-CPU process times.
+///
+/// It is not [`Ord`] since there is no single meaningful order of the three times. Use
+/// [`cmp_by_real`](#method.cmp_by_real) or [`cmp_by_cpu`](#method.cmp_by_cpu) instead, e.g.
+/// `durations.sort_by(ProcessDuration::cmp_by_real)`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProcessDuration {
+    /// [`Duration`] measured by wall-time clock.
+    pub real: Duration,
+    /// [`Duration`] measured by user-CPU clock.
+    pub user: Duration,
+    /// [`Duration`] measured by system-CPU clock.
+    pub system: Duration,
+}
+
+impl ProcessDuration {
+    /// A duration of zero in each of the real, user-CPU, and system-CPU times.
+    pub const ZERO: ProcessDuration =
+        ProcessDuration::new(Duration::ZERO, Duration::ZERO, Duration::ZERO);
+
+    /// Construct a duration from the real, user-CPU, and system-CPU times.
+    #[inline(always)]
+    pub const fn new(real: Duration, user: Duration, system: Duration) -> Self {
+        ProcessDuration { real, user, system }
+    }
+
+    /// Construct a duration from the real, user-CPU, and system-CPU times in seconds.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if any of the times is negative, overflows [`Duration`], or is
+    /// not finite, like [`Duration::from_secs_f64`].
+    #[inline(always)]
+    pub fn from_secs_f64(real: f64, user: f64, system: f64) -> Self {
+        ProcessDuration {
+            real: Duration::from_secs_f64(real),
+            user: Duration::from_secs_f64(user),
+            system: Duration::from_secs_f64(system),
+        }
+    }
+
+    /// Return true if each of the real, user-CPU, and system-CPU times is zero.
+    #[inline(always)]
+    pub const fn is_zero(&self) -> bool {
+        self.real.is_zero() && self.user.is_zero() && self.system.is_zero()
+    }
+
+    /// Return the total CPU time. Equivalent to `user + system`.
+    pub fn cpu_time(&self) -> Duration {
+        self.user + self.system
+    }
+
+    /// Return the percentage of the CPU time that the process used.
+    /// Equivalent to `(user + system) / real`.
+    ///
+    /// It keeps the semantics of the floating-point division, i.e. it returns NaN if all the
+    /// times are zero, and infinity if only the real time is zero, which happens when the
+    /// measured interval is shorter than the resolution of the real clock. Use
+    /// [`checked_cpu_usage`](#method.checked_cpu_usage) to handle that case.
+    pub fn cpu_usage(&self) -> f64 {
+        self.cpu_time().as_secs_f64() / self.real.as_secs_f64()
+    }
+
+    /// Like [`cpu_usage`](#method.cpu_usage) but return `None` if the real time is zero,
+    /// regardless of the CPU time.
+    pub fn checked_cpu_usage(&self) -> Option<f64> {
+        if self.real.is_zero() {
+            None
+        } else {
+            Some(self.cpu_usage())
+        }
+    }
+
+    /// Like [`cpu_usage`](#method.cpu_usage) but divided by `cores`, so that it does not exceed
+    /// 1 for a multithreaded process running on `cores` cores, like the per-core percentages
+    /// reported by `top`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `cores` is zero.
+    pub fn cpu_usage_per_core(&self, cores: usize) -> f64 {
+        assert!(cores != 0, "the number of cores must be greater than zero");
+        self.cpu_usage() / cores as f64
+    }
+
+    /// Like [`cpu_usage_per_core`](#method.cpu_usage_per_core) with the number of cores
+    /// reported by [`std::thread::available_parallelism`].
+    ///
+    /// # Errors
+    ///
+    /// This function will return [`Error::Unsupported`] if the available parallelism is unknown
+    /// on the platform, e.g. on `wasm32-unknown-unknown`, or an error if querying it failed.
+    #[cfg(feature = "std")]
+    pub fn cpu_usage_normalized(&self) -> Result<f64> {
+        let cores = std::thread::available_parallelism().map_err(|e| match e.raw_os_error() {
+            Some(code) => Error::SystemError("available_parallelism", code),
+            None => Error::Unsupported("available_parallelism"),
+        })?;
+        Ok(self.cpu_usage_per_core(cores.get()))
+    }
+
+    /// Serialize the times as a JSON object of integer nanoseconds, with the CPU usage in
+    /// percent rounded to one decimal place, or `null` if the real time is zero, e.g.
+    /// ```text
+    /// {"real_ns":5710000000,"user_ns":5700000000,"system_ns":0,"cpu_pct":99.8}
+    /// ```
+    ///
+    /// It does not require the `serde` feature.
+    pub fn to_json(&self) -> String {
+        format!(
+            r#"{{"real_ns":{},"user_ns":{},"system_ns":{},"cpu_pct":{}}}"#,
+            self.real.as_nanos(),
+            self.user.as_nanos(),
+            self.system.as_nanos(),
+            self.cpu_pct().unwrap_or_else(|| "null".to_owned()),
+        )
+    }
+
+    /// The CPU usage in percent rounded to one decimal place, as printed by
+    /// [`Display`](core::fmt::Display).
+    pub(crate) fn cpu_pct(&self) -> Option<String> {
+        self.checked_cpu_usage()
+            .map(|usage| format!("{:.1}", usage * 100f64))
+    }
+
+    /// Compare `self` against `baseline`, e.g. the durations after and before an optimization.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use howlong::{Duration, ProcessDuration};
+    ///
+    /// let ms = Duration::from_millis;
+    /// let before = ProcessDuration::new(ms(900), ms(550), ms(0));
+    /// let after = ProcessDuration::new(ms(480), ms(500), ms(0));
+    /// let report = after.speedup_over(&before);
+    /// assert_eq!(report.to_string(), "wall 1.9× faster (-420ms), cpu 1.1× faster (-50ms)");
+    /// ```
+    pub fn speedup_over(&self, baseline: &ProcessDuration) -> crate::SpeedupReport {
+        crate::SpeedupReport::new(self, baseline)
+    }
+
+    /// Return a wrapper whose [`Display`](core::fmt::Display) formats the times by
+    /// [`fmt::human`](crate::fmt::human), e.g.
+    /// ```text
+    /// 2h 0m 30.5s wall, 1h 59m user + 12.3s system = 1h 59m CPU (99.1%)
+    /// ```
+    pub fn human(&self) -> crate::fmt::HumanProcessDuration {
+        crate::fmt::HumanProcessDuration(*self)
+    }
+
+    /// Return a wrapper whose [`Display`](core::fmt::Display) formats the times exactly by
+    /// [`fmt::format_duration`](crate::fmt::format_duration), e.g.
+    /// ```text
+    /// 1m 30s wall, 1m 20s user + 5s 250ms system = 1m 25s 250ms CPU (94.7%)
+    /// ```
+    ///
+    /// Use [`parse_human`](ProcessDuration::parse_human) to parse it back.
+    pub fn format_human(&self) -> crate::fmt::HumantimeProcessDuration {
+        crate::fmt::HumantimeProcessDuration(*self)
+    }
+
+    /// Parse the output of [`format_human`](ProcessDuration::format_human), e.g.
+    /// ```text
+    /// 1m 30s wall, 1m 20s user + 5s 250ms system = 1m 25s 250ms CPU (94.7%)
+    /// ```
+    ///
+    /// The durations are parsed by [`fmt::parse_duration`](crate::fmt::parse_duration). The
+    /// CPU time and the percentage are only validated against the real, user-CPU, and
+    /// system-CPU times.
+    ///
+    /// # Errors
+    ///
+    /// This function will return [`Error::ParseError`] if `s` is malformed or inconsistent.
+    pub fn parse_human(s: &str) -> Result<Self> {
+        parse_process_duration(s, crate::fmt::parse_duration)
+    }
+
+    /// Return the real time not spent on the CPU, e.g. waiting for I/O. Equivalent to
+    /// `real - (user + system)`, but zero if the CPU time is longer, which is common for a
+    /// multithreaded process.
+    pub fn idle_time(&self) -> Duration {
+        self.real.saturating_sub(self.cpu_time())
+    }
+
+    /// Return true if the [`cpu_usage`](#method.cpu_usage) is at least `threshold`, e.g. `0.9`
+    /// for 90%. Return false if the real time is zero, since the usage is unknown then.
+    pub fn is_cpu_bound(&self, threshold: f64) -> bool {
+        self.checked_cpu_usage()
+            .is_some_and(|usage| usage >= threshold)
+    }
+
+    /// Return `self - rhs`, or `None` if any of the times of `rhs` is longer than that of
+    /// `self`.
+    #[inline(always)]
+    pub fn checked_sub(self, rhs: Self) -> Option<ProcessDuration> {
+        Some(ProcessDuration {
+            real: self.real.checked_sub(rhs.real)?,
+            user: self.user.checked_sub(rhs.user)?,
+            system: self.system.checked_sub(rhs.system)?,
+        })
+    }
+
+    /// Return `self - rhs`, where each of the times of `rhs` longer than that of `self` is
+    /// clamped to zero separately.
+    #[inline(always)]
+    pub fn saturating_sub(self, rhs: Self) -> ProcessDuration {
+        ProcessDuration {
+            real: self.real.saturating_sub(rhs.real),
+            user: self.user.saturating_sub(rhs.user),
+            system: self.system.saturating_sub(rhs.system),
+        }
+    }
+
+    /// Return the componentwise arithmetic mean of `durations`, or `None` if it is empty. The
+    /// remainders of the divisions are truncated, like `Duration / u32`.
+    ///
+    /// The sums are computed in nanoseconds with 128 bits, so that they do not overflow.
+    pub fn mean<I>(durations: I) -> Option<ProcessDuration>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<ProcessDuration>,
+    {
+        let mut count = 0u128;
+        let mut sums = [0u128; 3];
+        for d in durations {
+            let d = d.borrow();
+            count += 1;
+            for (sum, time) in sums.iter_mut().zip([d.real, d.user, d.system].iter()) {
+                *sum += time.as_nanos();
+            }
+        }
+        if count == 0 {
+            return None;
+        }
+        // The mean of durations is itself at most `Duration::MAX`.
+        let mean = |sum: u128| {
+            let nanos = sum / count;
+            Duration::new(
+                (nanos / 1_000_000_000) as u64,
+                (nanos % 1_000_000_000) as u32,
+            )
+        };
+        Some(ProcessDuration {
+            real: mean(sums[0]),
+            user: mean(sums[1]),
+            system: mean(sums[2]),
+        })
+    }
+
+    /// Compare the real times. The ties are broken by the user-CPU and then the system-CPU
+    /// times, so that only the equal durations compare equal.
+    pub fn cmp_by_real(&self, other: &Self) -> core::cmp::Ordering {
+        (self.real, self.user, self.system).cmp(&(other.real, other.user, other.system))
+    }
+
+    /// Compare the CPU times, i.e. `user + system`. The ties are broken by the real, user-CPU,
+    /// and then system-CPU times, so that only the equal durations compare equal.
+    pub fn cmp_by_cpu(&self, other: &Self) -> core::cmp::Ordering {
+        (self.cpu_time(), self.real, self.user, self.system).cmp(&(
+            other.cpu_time(),
+            other.real,
+            other.user,
+            other.system,
+        ))
+    }
+
+    /// Return the one of `self` and `other` with the longer real time, see
+    /// [`cmp_by_real`](#method.cmp_by_real). Return `other` if they are equal.
+    pub fn max_by_real(self, other: Self) -> Self {
+        core::cmp::max_by(self, other, Self::cmp_by_real)
+    }
+
+    /// Return the one of `self` and `other` with the longer CPU time, see
+    /// [`cmp_by_cpu`](#method.cmp_by_cpu). Return `other` if they are equal.
+    pub fn max_by_cpu(self, other: Self) -> Self {
+        core::cmp::max_by(self, other, Self::cmp_by_cpu)
+    }
+
+    /// Format the [`ProcessDuration`] according to `fmt`, like `boost::timer::format`.
+    ///
+    /// The following sequences in `fmt` are replaced:
+    ///
+    /// | Sequence | Replacement |
+    /// |----------|-------------|
+    /// | `%w` | `real` in seconds |
+    /// | `%u` | `user` in seconds |
+    /// | `%s` | `system` in seconds |
+    /// | `%t` | `user + system` in seconds |
+    /// | `%p` | the percentage of `(user + system) / real` |
+    /// | `%%` | `%` |
+    ///
+    /// Times are printed with 6 decimal places, and the percentage with 1 decimal place, or as
+    /// `--` if the real time is zero, see [`checked_cpu_usage`](#method.checked_cpu_usage).
+    /// Other characters are copied as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use howlong::{Duration, ProcessDuration};
+    ///
+    /// let d = ProcessDuration {
+    ///     real: Duration::from_millis(2000),
+    ///     user: Duration::from_millis(1500),
+    ///     system: Duration::from_millis(500),
+    /// };
+    /// assert_eq!(
+    ///     d.format("%ws wall, %us user + %ss system = %ts CPU (%p%)"),
+    ///     "2.000000s wall, 1.500000s user + 0.500000s system = 2.000000s CPU (100.0%)"
+    /// );
+    /// ```
+    pub fn format(&self, fmt: &str) -> String {
+        let mut result = String::with_capacity(fmt.len() * 2);
+        let mut chars = fmt.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                result.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('w') => result.push_str(&format!("{:.6}", self.real.as_secs_f64())),
+                Some('u') => result.push_str(&format!("{:.6}", self.user.as_secs_f64())),
+                Some('s') => result.push_str(&format!("{:.6}", self.system.as_secs_f64())),
+                Some('t') => result.push_str(&format!("{:.6}", self.cpu_time().as_secs_f64())),
+                Some('p') => match self.checked_cpu_usage() {
+                    Some(usage) => result.push_str(&format!("{:.1}", usage * 100f64)),
+                    None => result.push_str("--"),
+                },
+                Some('%') => result.push('%'),
+                Some(other) => {
+                    result.push('%');
+                    result.push(other);
+                }
+                None => result.push('%'),
+            }
+        }
+        result
+    }
+}
+
+impl Add for ProcessDuration {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        ProcessDuration {
+            real: self.real + rhs.real,
+            user: self.user + rhs.user,
+            system: self.system + rhs.system,
+        }
+    }
+}
+
+impl AddAssign for ProcessDuration {
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for ProcessDuration {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// This function will panic if any of the times of `rhs` is longer than that of `self`.
+    /// Use [`ProcessDuration::checked_sub`] or [`ProcessDuration::saturating_sub`] instead if
+    /// that is possible.
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        ProcessDuration {
+            real: self.real - rhs.real,
+            user: self.user - rhs.user,
+            system: self.system - rhs.system,
+        }
+    }
+}
+
+impl SubAssign for ProcessDuration {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<u32> for ProcessDuration {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// This function will panic if any of the products overflows, like `Duration * u32`.
+    #[inline(always)]
+    fn mul(self, rhs: u32) -> Self::Output {
+        ProcessDuration {
+            real: self.real * rhs,
+            user: self.user * rhs,
+            system: self.system * rhs,
+        }
+    }
+}
+
+impl MulAssign<u32> for ProcessDuration {
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: u32) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div<u32> for ProcessDuration {
+    type Output = Self;
+
+    /// Divide each of the times by `rhs`. The remainders are truncated, like `Duration / u32`.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `rhs` is zero.
+    #[inline(always)]
+    fn div(self, rhs: u32) -> Self::Output {
+        ProcessDuration {
+            real: self.real / rhs,
+            user: self.user / rhs,
+            system: self.system / rhs,
+        }
+    }
+}
+
+impl DivAssign<u32> for ProcessDuration {
+    #[inline(always)]
+    fn div_assign(&mut self, rhs: u32) {
+        *self = *self / rhs;
+    }
+}
+
+impl core::iter::Sum for ProcessDuration {
+    /// Add up the durations componentwise.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if any of the sums overflows, like [`Add`].
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(ProcessDuration::default(), Add::add)
+    }
+}
+
+impl<'a> core::iter::Sum<&'a ProcessDuration> for ProcessDuration {
+    /// Add up the durations componentwise.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if any of the sums overflows, like [`Add`].
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl core::fmt::Display for ProcessDuration {
+    /// Formats the [`ProcessDuration`]. It will look something like this:
+    /// ```text
+    /// 5.71s wall, 5.70s user + 0ns system = 5.70s CPU (99.8%)
+    /// ```
+    ///
+    /// The CPU usage is printed as `--%` if the real time is zero, see
+    /// [`checked_cpu_usage`](ProcessDuration::checked_cpu_usage).
+    ///
+    /// The formatter flags are honored as follows:
+    ///
+    /// * The precision, e.g. `{:.3}`, prints the times as fixed-point seconds with that many
+    ///   decimal places, e.g. `5.705s`, instead of the [`Debug`](core::fmt::Debug) format of
+    ///   [`Duration`].
+    /// * The width, fill, and alignment, e.g. `{:>60}` or `{:*^60}`, pad the output. The
+    ///   output is left-aligned by default, like strings.
+    /// * The alternate form, i.e. `{:#}`, prints one component per line. The width then
+    ///   applies to every line.
+    ///   ```text
+    ///   wall:   5.71s
+    ///   user:   5.70s
+    ///   system: 0ns
+    ///   CPU:    5.70s (99.8%)
+    ///   ```
+    ///
+    /// Only the default form can be parsed back by the [`FromStr`](core::str::FromStr)
+    /// implementation.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let precision = f.precision();
+        if f.width().is_none() {
+            return self.write_formatted(f, precision, f.alternate());
+        }
+
+        let mut s = String::new();
+        self.write_formatted(&mut s, precision, f.alternate())?;
+        for (i, line) in s.split('\n').enumerate() {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+            write_padded(f, line)?;
+        }
+        Ok(())
+    }
+}
+
+impl ProcessDuration {
+    fn write_formatted<W: core::fmt::Write>(
+        &self,
+        w: &mut W,
+        precision: Option<usize>,
+        alternate: bool,
+    ) -> core::fmt::Result {
+        let real = DisplayDuration(self.real, precision);
+        let user = DisplayDuration(self.user, precision);
+        let system = DisplayDuration(self.system, precision);
+        let cpu = DisplayDuration(self.cpu_time(), precision);
+        if alternate {
+            write!(
+                w,
+                "wall:   {}\nuser:   {}\nsystem: {}\nCPU:    {} (",
+                real, user, system, cpu
+            )?;
+        } else {
+            write!(
+                w,
+                "{} wall, {} user + {} system = {} CPU (",
+                real, user, system, cpu
+            )?;
+        }
+        match self.checked_cpu_usage() {
+            Some(usage) => write!(w, "{:.1}%)", usage * 100f64),
+            None => write!(w, "--%)"),
+        }
+    }
+}
+
+/// Formats a [`Duration`] in its [`Debug`](core::fmt::Debug) format, or as fixed-point seconds
+/// if a precision is given.
+struct DisplayDuration(Duration, Option<usize>);
+
+impl core::fmt::Display for DisplayDuration {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.1 {
+            Some(precision) => write!(f, "{:.*}s", precision, self.0.as_secs_f64()),
+            None => write!(f, "{:?}", self.0),
+        }
+    }
+}
+
+/// Writes `s` padded to the width of `f` with its fill and alignment. Unlike
+/// [`Formatter::pad`](core::fmt::Formatter::pad), the precision does not truncate `s`.
+fn write_padded(f: &mut core::fmt::Formatter<'_>, s: &str) -> core::fmt::Result {
+    use core::fmt::{Alignment, Write};
+
+    let len = s.chars().count();
+    let padding = f.width().unwrap_or(0).saturating_sub(len);
+    let (pre, post) = match f.align() {
+        None | Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Right) => (padding, 0),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+    };
+    let fill = f.fill();
+    for _ in 0..pre {
+        f.write_char(fill)?;
+    }
+    f.write_str(s)?;
+    for _ in 0..post {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+impl core::str::FromStr for ProcessDuration {
+    type Err = Error;
+
+    /// Parse the output of the [`Display`](core::fmt::Display) implementation, e.g.
+    /// ```text
+    /// 5.71s wall, 5.70s user + 0ns system = 5.70s CPU (99.8%)
+    /// ```
+    ///
+    /// The durations are in the format of the [`Debug`](core::fmt::Debug) implementation of
+    /// [`Duration`], whose unit is one of `s`, `ms`, `µs` or `us`, and `ns`. The CPU time and
+    /// the percentage are only validated against the real, user-CPU, and system-CPU times.
+    ///
+    /// # Errors
+    ///
+    /// This function will return [`Error::ParseError`] if `s` is malformed or inconsistent.
+    fn from_str(s: &str) -> Result<Self> {
+        parse_process_duration(s, parse_debug_duration)
+    }
+}
+
+/// Parse a [`ProcessDuration`] in the format of its [`Display`](core::fmt::Display)
+/// implementation, with the durations parsed by `parse`.
+fn parse_process_duration(s: &str, parse: fn(&str) -> Result<Duration>) -> Result<ProcessDuration> {
+    let mut rest = s;
+    let mut field = |sep: &str, name: &str| {
+        let (value, tail) = rest.split_once(sep).ok_or_else(|| {
+            Error::ParseError(format!("missing `{}` after the {} in `{}`", sep, name, s))
+        })?;
+        rest = tail;
+        Ok::<_, Error>(value)
+    };
+    let real = parse(field(" wall, ", "real time")?)?;
+    let user = parse(field(" user + ", "user-CPU time")?)?;
+    let system = parse(field(" system = ", "system-CPU time")?)?;
+    let cpu_time = parse(field(" CPU (", "CPU time")?)?;
+    let usage = field("%)", "CPU usage")?;
+    if !rest.is_empty() {
+        return Err(Error::ParseError(format!(
+            "unexpected `{}` at the end of `{}`",
+            rest, s
+        )));
+    }
 
-/// A point in time.
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub struct TimePoint(pub(crate) Duration);
+    let d = ProcessDuration { real, user, system };
+    if user.checked_add(system) != Some(cpu_time) {
+        return Err(Error::ParseError(format!(
+            "the CPU time {:?} is not the sum of the user-CPU and system-CPU times in `{}`",
+            cpu_time, s
+        )));
+    }
+    let expected = match d.checked_cpu_usage() {
+        Some(usage) => format!("{:.1}", usage * 100f64),
+        None => "--".to_owned(),
+    };
+    if expected != usage {
+        return Err(Error::ParseError(format!(
+            "the CPU usage {}% does not match the times in `{}`",
+            usage, s
+        )));
+    }
+    Ok(d)
+}
 
-impl Sub for TimePoint {
-    type Output = Duration;
+/// Parse a [`Duration`] in the format of its [`Debug`](core::fmt::Debug) implementation, e.g.
+/// `1.5s`, `20ms`, `3.001µs`, or `7ns`.
+fn parse_debug_duration(s: &str) -> Result<Duration> {
+    let invalid = || Error::ParseError(format!("invalid duration `{}`", s));
+    let (number, digits) = [("ns", 0), ("µs", 3), ("us", 3), ("ms", 6), ("s", 9)]
+        .iter()
+        .find_map(|&(unit, digits)| Some((s.strip_suffix(unit)?, digits)))
+        .ok_or_else(invalid)?;
+    let (int, frac) = number.split_once('.').unwrap_or((number, ""));
+    if int.is_empty()
+        || frac.len() > digits
+        || number.ends_with('.')
+        || !int.bytes().chain(frac.bytes()).all(|b| b.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+    let scale = 10u128.pow(digits as u32);
+    let int: u128 = int.parse().map_err(|_| invalid())?;
+    let frac = if frac.is_empty() {
+        0
+    } else {
+        frac.parse::<u128>().map_err(|_| invalid())? * 10u128.pow((digits - frac.len()) as u32)
+    };
+    let nanos = int
+        .checked_mul(scale)
+        .and_then(|n| n.checked_add(frac))
+        .ok_or_else(invalid)?;
+    if nanos / 1_000_000_000 > u64::MAX as u128 {
+        return Err(invalid());
+    }
+    Ok(Duration::new(
+        (nanos / 1_000_000_000) as u64,
+        (nanos % 1_000_000_000) as u32,
+    ))
+}
+
+/// Like [`TimePoint`] but captures user-CPU and system-CPU thread times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThreadTimePoint {
+    pub(crate) user: Duration,
+    pub(crate) system: Duration,
+}
+
+impl Sub for ThreadTimePoint {
+    type Output = ThreadDuration;
 
     #[inline(always)]
     fn sub(self, other: Self) -> Self::Output {
-        self.0 - other.0
+        ThreadDuration {
+            user: self.user - other.user,
+            system: self.system - other.system,
+        }
     }
 }
 
-impl From<Duration> for TimePoint {
-    fn from(d: Duration) -> Self {
-        TimePoint(d)
+impl TimePointSub for ThreadTimePoint {
+    #[inline(always)]
+    fn checked_sub(self, other: Self) -> Option<Self::Output> {
+        Some(ThreadDuration {
+            user: self.user.checked_sub(other.user)?,
+            system: self.system.checked_sub(other.system)?,
+        })
+    }
+
+    #[inline(always)]
+    fn saturating_sub(self, other: Self) -> Self::Output {
+        ThreadDuration {
+            user: self.user.checked_sub(other.user).unwrap_or_default(),
+            system: self.system.checked_sub(other.system).unwrap_or_default(),
+        }
     }
 }
 
-impl From<TimePoint> for Duration {
-    fn from(t: TimePoint) -> Self {
-        t.0
+impl From<ThreadDuration> for ThreadTimePoint {
+    fn from(d: ThreadDuration) -> Self {
+        ThreadTimePoint {
+            user: d.user,
+            system: d.system,
+        }
     }
 }
 
-/// Like [`TimePoint`] but captures real, user-CPU, and system-CPU process times.
+impl From<ThreadTimePoint> for ThreadDuration {
+    fn from(t: ThreadTimePoint) -> Self {
+        ThreadDuration {
+            user: t.user,
+            system: t.system,
+        }
+    }
+}
+
+/// Like [`Duration`] but captures user-CPU and system-CPU thread times.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThreadDuration {
+    /// [`Duration`] measured by user-CPU clock.
+    pub user: Duration,
+    /// [`Duration`] measured by system-CPU clock.
+    pub system: Duration,
+}
+
+impl ThreadDuration {
+    /// Return the total CPU time. Equivalent to `user + system`.
+    pub fn cpu_time(&self) -> Duration {
+        self.user + self.system
+    }
+}
+
+impl Add for ThreadDuration {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        ThreadDuration {
+            user: self.user + rhs.user,
+            system: self.system + rhs.system,
+        }
+    }
+}
+
+impl AddAssign for ThreadDuration {
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for ThreadDuration {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        ThreadDuration {
+            user: self.user - rhs.user,
+            system: self.system - rhs.system,
+        }
+    }
+}
+
+impl SubAssign for ThreadDuration {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl core::fmt::Display for ThreadDuration {
+    /// Formats the [`ThreadDuration`]. It will look something like this:
+    /// ```text
+    /// 5.70s user + 10ms system = 5.71s CPU
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{:?} user + {:?} system = {:?} CPU",
+            self.user,
+            self.system,
+            self.cpu_time(),
+        )
+    }
+}
+
+/// Like [`TimePoint`] but captures the user-CPU, system-CPU, and idle times of all the
+/// processors of the host.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct ProcessTimePoint {
-    pub(crate) real: Duration,
+pub struct HostTimePoint {
     pub(crate) user: Duration,
     pub(crate) system: Duration,
+    pub(crate) idle: Duration,
 }
 
-impl Sub for ProcessTimePoint {
-    type Output = ProcessDuration;
+impl Sub for HostTimePoint {
+    type Output = HostDuration;
 
     #[inline(always)]
     fn sub(self, other: Self) -> Self::Output {
-        ProcessDuration {
-            real: self.real - other.real,
+        HostDuration {
             user: self.user - other.user,
             system: self.system - other.system,
+            idle: self.idle - other.idle,
         }
     }
 }
 
-impl From<ProcessDuration> for ProcessTimePoint {
-    fn from(d: ProcessDuration) -> Self {
-        ProcessTimePoint {
-            real: d.real,
+impl TimePointSub for HostTimePoint {
+    #[inline(always)]
+    fn checked_sub(self, other: Self) -> Option<Self::Output> {
+        Some(HostDuration {
+            user: self.user.checked_sub(other.user)?,
+            system: self.system.checked_sub(other.system)?,
+            idle: self.idle.checked_sub(other.idle)?,
+        })
+    }
+
+    #[inline(always)]
+    fn saturating_sub(self, other: Self) -> Self::Output {
+        HostDuration {
+            user: self.user.checked_sub(other.user).unwrap_or_default(),
+            system: self.system.checked_sub(other.system).unwrap_or_default(),
+            idle: self.idle.checked_sub(other.idle).unwrap_or_default(),
+        }
+    }
+}
+
+impl From<HostDuration> for HostTimePoint {
+    fn from(d: HostDuration) -> Self {
+        HostTimePoint {
             user: d.user,
             system: d.system,
+            idle: d.idle,
         }
     }
 }
 
-impl From<ProcessTimePoint> for ProcessDuration {
-    fn from(t: ProcessTimePoint) -> Self {
-        ProcessDuration {
-            real: t.real,
+impl From<HostTimePoint> for HostDuration {
+    fn from(t: HostTimePoint) -> Self {
+        HostDuration {
             user: t.user,
-            system: t.user,
+            system: t.system,
+            idle: t.idle,
         }
     }
 }
 
-/// Like [`Duration`] but captures real, user-CPU, and system-CPU process times.
-#[derive(Clone, Copy, Debug, Default)]
-pub struct ProcessDuration {
-    /// [`Duration`] measured by wall-time clock.
-    pub real: Duration,
-    /// [`Duration`] measured by user-CPU clock.
+/// Like [`Duration`] but captures the user-CPU, system-CPU, and idle times of all the
+/// processors of the host.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HostDuration {
+    /// The time spent by all the processors in user mode.
     pub user: Duration,
-    /// [`Duration`] measured by system-CPU clock.
+    /// The time spent by all the processors in kernel mode.
     pub system: Duration,
+    /// The time spent by all the processors idle.
+    pub idle: Duration,
 }
 
-impl ProcessDuration {
-    /// Return the total CPU time. Equivalent to `user + system`.
+impl HostDuration {
+    /// Return the total busy CPU time. Equivalent to `user + system`.
     pub fn cpu_time(&self) -> Duration {
         self.user + self.system
     }
 
-    /// Return the percentage of the CPU time that the process used.
-    /// Equivalent to `(user + system) / real`.
-    pub fn cpu_usage(&self) -> f64 {
-        self.cpu_time().as_secs_f64() / self.real.as_secs_f64()
+    /// Return the total CPU time. Equivalent to `user + system + idle`.
+    pub fn total(&self) -> Duration {
+        self.cpu_time() + self.idle
+    }
+
+    /// Return the percentage of the CPU time that the processors were busy.
+    /// Equivalent to `(user + system) / (user + system + idle)`.
+    ///
+    /// It returns NaN if all the times are zero, e.g. when the measured interval is shorter than
+    /// the scheduler tick. Use [`checked_utilization`](#method.checked_utilization) to handle
+    /// that case.
+    pub fn utilization(&self) -> f64 {
+        self.cpu_time().as_secs_f64() / self.total().as_secs_f64()
+    }
+
+    /// Like [`utilization`](#method.utilization) but return `None` if the total time is zero.
+    pub fn checked_utilization(&self) -> Option<f64> {
+        if self.total().is_zero() {
+            None
+        } else {
+            Some(self.utilization())
+        }
     }
 }
 
-impl Add for ProcessDuration {
+impl Add for HostDuration {
     type Output = Self;
 
     #[inline(always)]
     fn add(self, rhs: Self) -> Self::Output {
-        ProcessDuration {
-            real: self.real + rhs.real,
+        HostDuration {
             user: self.user + rhs.user,
             system: self.system + rhs.system,
+            idle: self.idle + rhs.idle,
         }
     }
 }
 
-impl AddAssign for ProcessDuration {
+impl AddAssign for HostDuration {
     #[inline(always)]
     fn add_assign(&mut self, rhs: Self) {
         *self = *self + rhs;
     }
 }
 
-impl Sub for ProcessDuration {
+impl Sub for HostDuration {
     type Output = Self;
 
     #[inline(always)]
     fn sub(self, rhs: Self) -> Self::Output {
-        ProcessDuration {
-            real: self.real - rhs.real,
+        HostDuration {
             user: self.user - rhs.user,
             system: self.system - rhs.system,
+            idle: self.idle - rhs.idle,
         }
     }
 }
 
-impl SubAssign for ProcessDuration {
+impl SubAssign for HostDuration {
     #[inline(always)]
     fn sub_assign(&mut self, rhs: Self) {
         *self = *self - rhs;
     }
 }
 
-impl core::fmt::Display for ProcessDuration {
-    /// Formats the [`ProcessDuration`]. It will look something like this:
+impl core::fmt::Display for HostDuration {
+    /// Formats the [`HostDuration`]. It will look something like this:
     /// ```text
-    /// 5.71s wall, 5.70s user + 0ns system = 5.70s CPU (99.8%)
+    /// 5.70s user + 10ms system + 10.29s idle = 5.71s CPU (35.7%)
     /// ```
+    ///
+    /// The utilization is printed as `--%` if the total time is zero, see
+    /// [`checked_utilization`](HostDuration::checked_utilization).
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
-            "{:?} wall, {:?} user + {:?} system = {:?} CPU ({:.1}%)",
-            self.real,
+            "{:?} user + {:?} system + {:?} idle = {:?} CPU (",
             self.user,
             self.system,
+            self.idle,
             self.cpu_time(),
-            self.cpu_usage() * 100f64,
-        )
+        )?;
+        match self.checked_utilization() {
+            Some(utilization) => write!(f, "{:.1}%)", utilization * 100f64),
+            None => write!(f, "--%)"),
+        }
+    }
+}
+
+/// Like [`TimePoint`] but captures a raw count of CPU cycles.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CycleTimePoint(pub(crate) u64);
+
+impl Sub for CycleTimePoint {
+    type Output = Cycles;
+
+    #[inline(always)]
+    fn sub(self, other: Self) -> Self::Output {
+        Cycles(self.0 - other.0)
+    }
+}
+
+impl TimePointSub for CycleTimePoint {
+    #[inline(always)]
+    fn checked_sub(self, other: Self) -> Option<Self::Output> {
+        self.0.checked_sub(other.0).map(Cycles)
+    }
+
+    #[inline(always)]
+    fn saturating_sub(self, other: Self) -> Self::Output {
+        Cycles(self.0.saturating_sub(other.0))
+    }
+}
+
+impl From<Cycles> for CycleTimePoint {
+    fn from(c: Cycles) -> Self {
+        CycleTimePoint(c.0)
+    }
+}
+
+impl From<CycleTimePoint> for Cycles {
+    fn from(t: CycleTimePoint) -> Self {
+        Cycles(t.0)
+    }
+}
+
+/// A raw reading of the counter behind [`SteadyClock`](crate::clock::SteadyClock), which is not
+/// converted to a time yet, e.g. the ticks of `QueryPerformanceCounter` on Windows or
+/// `mach_absolute_time` on Darwin, and the nanoseconds of `CLOCK_MONOTONIC` on Posix.
+///
+/// Use `SteadyClock::raw_now()` to read it, and `SteadyClock::convert()` or
+/// `RawInstant::delta()` to convert it to a time later. Subtracting two raw instants gives the
+/// number of raw ticks between them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RawInstant(pub(crate) u64);
+
+impl Sub for RawInstant {
+    type Output = u64;
+
+    #[inline(always)]
+    fn sub(self, other: Self) -> Self::Output {
+        self.0 - other.0
+    }
+}
+
+impl TimePointSub for RawInstant {
+    #[inline(always)]
+    fn checked_sub(self, other: Self) -> Option<Self::Output> {
+        self.0.checked_sub(other.0)
+    }
+
+    #[inline(always)]
+    fn saturating_sub(self, other: Self) -> Self::Output {
+        self.0.saturating_sub(other.0)
+    }
+}
+
+impl From<RawInstant> for u64 {
+    fn from(t: RawInstant) -> Self {
+        t.0
+    }
+}
+
+/// A number of CPU cycles, e.g. measured by `ThreadCycleClock` on Windows.
+///
+/// The cycles are not directly comparable across changes of the CPU frequency, and converting
+/// them to a [`Duration`] is only an approximation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cycles(pub u64);
+
+impl Cycles {
+    /// Convert the cycles to an approximate [`Duration`], using the cycle frequency calibrated
+    /// against [`SteadyClock`](crate::clock::SteadyClock), see
+    /// `ThreadCycleClock::frequency()`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if acessing to the underlying clocks failed.
+    #[cfg(all(windows, not(howlong_unsupported)))]
+    pub fn to_duration(self) -> Result<Duration> {
+        let frequency = crate::clock::ThreadCycleClock::frequency()?;
+        let nanos = (self.0 as u128) * 1_000_000_000 / (frequency as u128);
+        Ok(Duration::from_nanos(nanos as u64))
+    }
+}
+
+impl Add for Cycles {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        Cycles(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Cycles {
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for Cycles {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Cycles(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for Cycles {
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl core::fmt::Display for Cycles {
+    /// Formats the [`Cycles`]. It will look something like this:
+    /// ```text
+    /// 12345 cycles
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} cycles", self.0)
     }
 }
 
@@ -170,6 +1899,17 @@ pub trait Clock {
     /// The returned timepoint type.
     type Output;
 
+    /// The duration between two timepoints, i.e. the result of subtracting them, e.g.
+    /// [`Duration`] for [`TimePoint`], and [`ProcessDuration`] for [`ProcessTimePoint`].
+    type Duration;
+
+    /// Whether the clock is monotonic, i.e. it never goes backwards.
+    const IS_STEADY: bool;
+
+    /// Whether the clock measures CPU time, i.e. it only advances while the process or thread
+    /// is running.
+    const IS_CPU_TIME: bool;
+
     /// Return the current timepoint.
     ///
     /// # Errors
@@ -186,4 +1926,109 @@ pub trait Clock {
     fn now() -> Self::Output {
         Self::try_now().expect("Failed to access the clock.")
     }
+
+    /// Return the duration elapsed from `start`, a timepoint returned by this clock, to now.
+    ///
+    /// # Panics
+    ///
+    /// This function might panic when acessing to the underlying system calls failed, or if
+    /// the clock went backwards since `start`, which may happen for a non-steady clock. Use a
+    /// [`Timer`](crate::Timer) if that is possible.
+    fn elapsed_since(start: Self::Output) -> Self::Duration
+    where
+        Self::Output: Sub<Output = Self::Duration> + Copy,
+    {
+        Self::now() - start
+    }
+
+    /// Return the resolution of the clock, i.e. the smallest difference between two
+    /// timepoints which the clock can report.
+    ///
+    /// # Errors
+    ///
+    /// This function will return [`Error::Unsupported`] if the resolution of the clock is
+    /// unknown, or an error if acessing to the underlying system calls failed.
+    fn resolution() -> Result<Duration> {
+        Err(Error::Unsupported("Clock::resolution"))
+    }
+
+    /// Return the metadata of the clock.
+    fn info() -> ClockInfo
+    where
+        Self: Sized,
+    {
+        let name = core::any::type_name::<Self>();
+        ClockInfo {
+            name: name.rsplit("::").next().unwrap_or(name),
+            is_steady: Self::IS_STEADY,
+            is_cpu_time: Self::IS_CPU_TIME,
+            resolution: Self::resolution().ok(),
+        }
+    }
+
+    /// Return true if the clock can be read on the running system.
+    ///
+    /// The first call probes the clock with one [`try_now`](Clock::try_now), and the answer
+    /// is cached for the rest of the process. An error whose [`kind`](Error::kind) is
+    /// [`ErrorKind::Other`] is considered transient, so it is not cached and the next call
+    /// probes again. Without the `std` feature, nothing is cached.
+    fn is_available() -> bool
+    where
+        Self: Sized + 'static,
+    {
+        #[cfg(feature = "std")]
+        {
+            let id = TypeId::of::<Self>();
+            if let Some(available) = availability_cache().read().unwrap().get(&id) {
+                return *available;
+            }
+            let available = match Self::try_now() {
+                Ok(_) => true,
+                Err(e) if e.kind() == ErrorKind::Other => return false,
+                Err(_) => false,
+            };
+            availability_cache().write().unwrap().insert(id, available);
+            available
+        }
+        #[cfg(not(feature = "std"))]
+        Self::try_now().is_ok()
+    }
+}
+
+#[cfg(feature = "std")]
+fn availability_cache() -> &'static RwLock<HashMap<TypeId, bool>> {
+    static CACHE: OnceLock<RwLock<HashMap<TypeId, bool>>> = OnceLock::new();
+    CACHE.get_or_init(Default::default)
+}
+
+/// The metadata of a clock, returned by [`Clock::info`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClockInfo {
+    /// The name of the clock type, e.g. `SteadyClock`. For an alias such as
+    /// [`HighResolutionClock`](crate::clock::HighResolutionClock), it is the name of the
+    /// aliased clock.
+    pub name: &'static str,
+    /// See [`Clock::IS_STEADY`].
+    pub is_steady: bool,
+    /// See [`Clock::IS_CPU_TIME`].
+    pub is_cpu_time: bool,
+    /// See [`Clock::resolution`], or `None` if it is unknown.
+    pub resolution: Option<Duration>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kind_from_win32_error() {
+        assert_eq!(kind_from_win32_error(5), ErrorKind::PermissionDenied);
+        assert_eq!(kind_from_win32_error(1), ErrorKind::Unsupported);
+        assert_eq!(kind_from_win32_error(50), ErrorKind::Unsupported);
+        assert_eq!(kind_from_win32_error(120), ErrorKind::Unsupported);
+        assert_eq!(kind_from_win32_error(2), ErrorKind::ClockUnavailable);
+        assert_eq!(kind_from_win32_error(87), ErrorKind::ClockUnavailable);
+        assert_eq!(kind_from_win32_error(0), ErrorKind::Other);
+        assert_eq!(kind_from_win32_error(6), ErrorKind::Other);
+    }
 }
@@ -0,0 +1,40 @@
+#![cfg(all(
+    have_steady_clock,
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+
+use howlong::{clock::*, Clock, ClockAnchor, Duration};
+use std::thread;
+use std::time::Instant;
+
+fn instant_distance(a: Instant, b: Instant) -> Duration {
+    a.saturating_duration_since(b) + b.saturating_duration_since(a)
+}
+
+#[test]
+fn test_now_with_instant() {
+    let (start, start_instant) = SteadyClock::now_with_instant();
+    thread::sleep(Duration::from_millis(20));
+    let (end, end_instant) = SteadyClock::now_with_instant();
+    let elapsed = end - start;
+    let elapsed_instant = end_instant - start_instant;
+    assert!(elapsed >= Duration::from_millis(20));
+    assert!(elapsed.abs_diff(elapsed_instant) < Duration::from_millis(1));
+}
+
+#[test]
+fn test_clock_anchor() {
+    let one_milli = Duration::from_millis(1);
+    let earlier = Instant::now();
+    let anchor = ClockAnchor::new();
+    thread::sleep(Duration::from_millis(20));
+
+    let (now, now_instant) = SteadyClock::now_with_instant();
+    assert!(instant_distance(anchor.to_instant(now), now_instant) < one_milli);
+    assert!(Duration::from(anchor.to_timepoint(now_instant)).abs_diff(now.into()) < one_milli);
+
+    // An instant before the anchor.
+    let t = anchor.to_timepoint(earlier);
+    assert!(t <= SteadyClock::now());
+    assert!(instant_distance(anchor.to_instant(t), earlier) < one_milli);
+}
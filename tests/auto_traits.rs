@@ -0,0 +1,120 @@
+#![cfg(feature = "std")]
+
+use howlong::mock::{MockClock, MockProcessClock, MockProcessTimer, MockTimer};
+use howlong::{clock::*, timer::*, Duration, DynClockAdapter, StampedClock};
+use std::panic::{RefUnwindSafe, UnwindSafe};
+
+fn assert_auto_traits<T: Send + Sync + Unpin + UnwindSafe + RefUnwindSafe>() {}
+
+macro_rules! assert_auto_traits {
+    ($($(#[$attr:meta])* $type:ty,)*) => {
+        const _: fn() = || {
+            $($(#[$attr])* assert_auto_traits::<$type>();)*
+        };
+    };
+}
+
+// The clocks.
+assert_auto_traits! {
+    SystemClock,
+    #[cfg(have_steady_clock)]
+    SteadyClock,
+    HighResolutionClock,
+    #[cfg(have_suspend_aware_clock)]
+    SuspendAwareClock,
+    #[cfg(have_coarse_clock)]
+    CoarseSystemClock,
+    #[cfg(have_coarse_clock)]
+    CoarseSteadyClock,
+    #[cfg(have_clock_tai)]
+    TaiClock,
+    #[cfg(all(feature = "tsc", any(target_arch = "x86", target_arch = "x86_64")))]
+    TscClock,
+    UptimeClock,
+    ProcessUptimeClock,
+    ProcessRealCPUClock,
+    ProcessUserCPUClock,
+    ProcessSystemCPUClock,
+    ProcessCPUClock,
+    ProcessWithChildrenCPUClock,
+    ChildrenCPUClock,
+    HostCPUClock,
+    ThreadClock,
+    ThreadCPUClock,
+    #[cfg(target_os = "linux")]
+    ProcStatClock,
+    #[cfg(target_os = "linux")]
+    CgroupCPUClock,
+    #[cfg(all(unix, not(any(target_os = "fuchsia", target_os = "haiku"))))]
+    RusageCPUClock,
+    #[cfg(all(unix, not(any(target_os = "fuchsia", target_os = "haiku"))))]
+    RusageChildrenCPUClock,
+    MockClock,
+    MockProcessClock,
+    StampedClock<SystemClock>,
+    DynClockAdapter<SystemClock>,
+}
+
+// The timers based on the clocks above.
+assert_auto_traits! {
+    SystemTimer,
+    #[cfg(have_steady_clock)]
+    SteadyTimer,
+    HighResolutionTimer,
+    #[cfg(have_suspend_aware_clock)]
+    SuspendAwareTimer,
+    #[cfg(have_coarse_clock)]
+    CoarseSystemTimer,
+    #[cfg(have_coarse_clock)]
+    CoarseSteadyTimer,
+    #[cfg(have_clock_tai)]
+    TaiTimer,
+    #[cfg(all(feature = "tsc", any(target_arch = "x86", target_arch = "x86_64")))]
+    TscTimer,
+    ProcessRealCPUTimer,
+    ProcessUserCPUTimer,
+    ProcessSystemCPUTimer,
+    ProcessCPUTimer,
+    ProcessWithChildrenCPUTimer,
+    ChildrenCPUTimer,
+    HostCPUTimer,
+    #[cfg(target_os = "linux")]
+    ProcStatTimer,
+    #[cfg(target_os = "linux")]
+    CgroupCPUTimer,
+    #[cfg(all(unix, not(any(target_os = "fuchsia", target_os = "haiku"))))]
+    RusageCPUTimer,
+    #[cfg(all(unix, not(any(target_os = "fuchsia", target_os = "haiku"))))]
+    RusageChildrenCPUTimer,
+    StampedTimer<SystemClock>,
+    MockTimer,
+    MockProcessTimer,
+}
+
+#[test]
+fn test_timer_send() {
+    let timer = ProcessCPUTimer::new();
+    let elapsed = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(1));
+        timer.elapsed()
+    })
+    .join()
+    .unwrap();
+    assert!(elapsed.real >= Duration::from_millis(1), "{}", elapsed);
+
+    let timer = SystemTimer::new();
+    std::thread::sleep(Duration::from_millis(1));
+    std::thread::scope(|s| {
+        for thread in [s.spawn(|| timer.elapsed()), s.spawn(|| timer.elapsed())] {
+            assert!(thread.join().unwrap() >= Duration::from_millis(1));
+        }
+    });
+}
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+#[test]
+fn test_thread_timer_not_send() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/thread_*.rs");
+    t.compile_fail("tests/ui/dyn_timer_*.rs");
+}
@@ -0,0 +1,179 @@
+#![cfg(feature = "std")]
+
+use howlong::{
+    baseline::{Baseline, Regression},
+    Duration, ProcessDuration,
+};
+
+fn ms(real: u64, user: u64, system: u64) -> ProcessDuration {
+    ProcessDuration::new(
+        Duration::from_millis(real),
+        Duration::from_millis(user),
+        Duration::from_millis(system),
+    )
+}
+
+fn baseline() -> Baseline {
+    vec![
+        ("parse", ms(3200, 3100, 50)),
+        ("encode", ms(100, 90, 10)),
+        ("a \"quoted\",\nname", ms(1, 1, 0)),
+        ("legacy", ms(3000, 2900, 0)),
+        ("idle", ProcessDuration::ZERO),
+    ]
+    .into_iter()
+    .collect()
+}
+
+#[test]
+fn test_save_load() {
+    let path = std::env::temp_dir().join(format!("howlong-baseline-{}.csv", std::process::id()));
+    let old = baseline();
+    old.save(&path).unwrap();
+    let mut new = Baseline::load(&path).unwrap();
+    assert_eq!(new, old);
+    assert_eq!(new.len(), 5);
+    assert_eq!(new.get("a \"quoted\",\nname"), Some(ms(1, 1, 0)));
+
+    new.record("encode", ms(130, 100, 10));
+    new.remove("legacy");
+    new.record("render", ms(12, 11, 0));
+    new.save(&path).unwrap();
+    let reloaded = Baseline::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(reloaded, new);
+    assert_ne!(reloaded, old);
+    let names: Vec<_> = reloaded.iter().map(|(name, _)| name).collect();
+    assert_eq!(
+        names,
+        ["a \"quoted\",\nname", "encode", "idle", "parse", "render"]
+    );
+
+    let regressions = old.compare(&reloaded, 0.1);
+    assert_eq!(
+        regressions,
+        [
+            Regression {
+                name: "encode".to_owned(),
+                old: Some(ms(100, 90, 10)),
+                new: Some(ms(130, 100, 10)),
+                ratio: Some(1.3),
+            },
+            Regression {
+                name: "legacy".to_owned(),
+                old: Some(ms(3000, 2900, 0)),
+                new: None,
+                ratio: None,
+            },
+            Regression {
+                name: "render".to_owned(),
+                old: None,
+                new: Some(ms(12, 11, 0)),
+                ratio: None,
+            },
+        ]
+    );
+    assert!(regressions[1].is_removed());
+    assert!(regressions[2].is_added());
+}
+
+#[test]
+fn test_load_malformed() {
+    for csv in [
+        "",
+        "name,real_ns\n",
+        "label,real_ns,user_ns,system_ns,cpu_pct\nparse,1,2\n",
+        "label,real_ns,user_ns,system_ns,cpu_pct\nparse,1,2,x,\n",
+        "label,real_ns,user_ns,system_ns,cpu_pct\n\"parse,1,2,3,\n",
+        "label,real_ns,user_ns,system_ns,cpu_pct\nparse,1,2,3,\nparse,1,2,3,\n",
+    ] {
+        assert!(Baseline::from_csv(csv).is_err(), "{:?}", csv);
+    }
+    let csv = "label,real_ns,user_ns,system_ns,cpu_pct\r\nparse,3,2,1,100.0\r\n\n";
+    let baseline = Baseline::from_csv(csv).unwrap();
+    assert_eq!(
+        baseline.get("parse"),
+        Some(ProcessDuration::new(
+            Duration::from_nanos(3),
+            Duration::from_nanos(2),
+            Duration::from_nanos(1),
+        ))
+    );
+
+    let path = std::env::temp_dir().join(format!("howlong-malformed-{}.csv", std::process::id()));
+    std::fs::write(&path, "label\n").unwrap();
+    let e = Baseline::load(&path).unwrap_err();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(e.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_threshold_boundary() {
+    let old: Baseline = vec![("wall", ms(100, 50, 0)), ("cpu", ms(100, 80, 20))]
+        .into_iter()
+        .collect();
+    let at: Baseline = vec![("wall", ms(125, 50, 0)), ("cpu", ms(100, 100, 25))]
+        .into_iter()
+        .collect();
+    assert!(old.compare(&at, 0.25).is_empty());
+    let ratios: Vec<_> = old
+        .comparison(&at, 0.25)
+        .entries()
+        .iter()
+        .map(|r| r.ratio)
+        .collect();
+    assert_eq!(ratios, [Some(1.25), Some(1.25)]);
+
+    let nanosecond = ProcessDuration::new(Duration::from_nanos(1), Duration::ZERO, Duration::ZERO);
+    let mut beyond = at.clone();
+    beyond.record("wall", ms(125, 50, 0) + nanosecond);
+    let regressions = old.compare(&beyond, 0.25);
+    assert_eq!(regressions.len(), 1);
+    assert_eq!(regressions[0].name, "wall");
+    assert!(regressions[0].exceeds(0.25));
+
+    let mut beyond = at;
+    beyond.record(
+        "cpu",
+        ms(100, 100, 25)
+            + ProcessDuration::new(Duration::ZERO, Duration::ZERO, Duration::from_nanos(1)),
+    );
+    let regressions = old.compare(&beyond, 0.25);
+    assert_eq!(regressions.len(), 1);
+    assert_eq!(regressions[0].name, "cpu");
+
+    // A time which was zero regresses by any amount.
+    let zero: Baseline = vec![("idle", ProcessDuration::ZERO)].into_iter().collect();
+    let busy: Baseline = vec![("idle", nanosecond)].into_iter().collect();
+    assert!(zero.compare(&zero, 0.0).is_empty());
+    assert_eq!(zero.compare(&busy, 1e9)[0].ratio, Some(f64::INFINITY));
+}
+
+#[test]
+fn test_comparison_display() {
+    let mut old = baseline();
+    old.remove("a \"quoted\",\nname");
+    let mut new = old.clone();
+    new.record("encode", ms(130, 100, 10));
+    new.record("parse", ms(3100, 3000, 50));
+    new.remove("legacy");
+    new.record("render", ms(12, 11, 0));
+    let comparison = old.comparison(&new, 0.1);
+    assert_eq!(comparison.threshold(), 0.1);
+    assert_eq!(
+        comparison.to_string(),
+        "\
+entry   old wall  new wall  old cpu  new cpu  ratio
+encode     100ms     130ms    100ms    110ms  1.30×  REGRESSED
+idle         0ns       0ns      0ns      0ns  1.00×
+legacy     3.00s         -    2.90s        -      -  removed
+parse      3.20s     3.10s    3.15s    3.05s  0.97×
+render         -    12.0ms        -   11.0ms      -  added"
+    );
+    assert_eq!(
+        Baseline::new()
+            .comparison(&Baseline::new(), 0.1)
+            .to_string(),
+        "no entries recorded"
+    );
+}
@@ -0,0 +1,113 @@
+#![cfg(feature = "std")]
+
+use howlong::{bench, timer::*, Duration, Error};
+
+fn jittery_task(i: u64) -> u64 {
+    (0..(1000 + (i % 7) * 10_000)).fold(0, |acc, x| acc ^ bench::black_box(x))
+}
+
+#[test]
+fn test_best_of() {
+    let n = 20;
+    let mut i = 0;
+    let timer = HighResolutionTimer::new();
+    let best = bench::best_of(n, || {
+        i += 1;
+        bench::black_box(jittery_task(i));
+    })
+    .unwrap();
+    let mean = timer.elapsed() / n as u32;
+    assert_eq!(i, n as u64);
+    assert!(best > Duration::from_nanos(0));
+    assert!(best <= mean);
+}
+
+#[test]
+fn test_best_of_with_result() {
+    let mut i = 0;
+    let (best, result) = bench::best_of_with_result(5, || {
+        i += 1;
+        i
+    })
+    .unwrap();
+    assert_eq!(result, 5);
+    assert!(best < Duration::from_secs(1));
+}
+
+#[test]
+fn test_best_of_process_cpu() {
+    let n = 10;
+    let mut i = 0;
+    let timer = ProcessCPUTimer::new();
+    let best = bench::best_of_process_cpu(n, || {
+        i += 1;
+        bench::black_box(jittery_task(i));
+    })
+    .unwrap();
+    let total = timer.elapsed();
+    assert_eq!(i, n as u64);
+    assert!(best.real <= total.real / n as u32);
+}
+
+#[test]
+fn test_best_of_zero_iterations() {
+    assert!(matches!(
+        bench::best_of(0, || {}),
+        Err(Error::ZeroIterations)
+    ));
+    assert!(matches!(
+        bench::best_of_with_result(0, || 1),
+        Err(Error::ZeroIterations)
+    ));
+    assert!(matches!(
+        bench::best_of_process_cpu(0, || {}),
+        Err(Error::ZeroIterations)
+    ));
+}
+
+fn busy_loop() -> u64 {
+    (0..10_000u64).fold(0, |acc, x| acc.wrapping_add(bench::black_box(x)))
+}
+
+#[test]
+fn test_run() {
+    let mut count = 0;
+    let stats = bench::run(100, || {
+        count += 1;
+        bench::black_box(busy_loop());
+    })
+    .unwrap();
+    println!("{}", stats);
+    assert_eq!(count, 101);
+    assert_eq!(stats.iterations, 100);
+    assert!(stats.min > Duration::from_nanos(0));
+    assert!(stats.min <= stats.mean);
+    assert!(stats.mean <= stats.max);
+    assert!(stats.min <= stats.median);
+    assert!(stats.median <= stats.max);
+    assert!(stats.total >= stats.min * 100);
+    assert!(stats.total <= stats.max * 100);
+}
+
+#[test]
+fn test_run_with_warmup() {
+    let mut count = 0;
+    let stats = bench::run_with_warmup(0, 2000, || {
+        count += 1;
+        bench::black_box(count);
+    })
+    .unwrap();
+    assert_eq!(count, 2000);
+    assert_eq!(stats.iterations, 2000);
+    assert!(stats.min <= stats.median);
+    assert!(stats.median <= stats.max);
+
+    let mut count = 0;
+    bench::run_with_warmup(5, 10, || count += 1).unwrap();
+    assert_eq!(count, 15);
+}
+
+#[test]
+fn test_run_zero_iterations() {
+    assert!(matches!(bench::run(0, || {}), Err(Error::ZeroIterations)));
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "std")]
+
+use howlong::{calibrate, clock::*, recalibrate, CalibratedTimer, Duration};
+
+#[test]
+fn test_calibrate() {
+    let overhead = calibrate::<HighResolutionClock>();
+    assert!(overhead < Duration::from_millis(1));
+    assert_eq!(calibrate::<HighResolutionClock>(), overhead);
+    assert!(recalibrate::<HighResolutionClock>() < Duration::from_millis(1));
+    assert!(calibrate::<SystemClock>() < Duration::from_millis(1));
+}
+
+#[test]
+fn test_calibrated_timer_empty_region() {
+    let mut calibrated = Duration::from_nanos(0);
+    let mut raw = Duration::from_nanos(0);
+    for _ in 0..1000 {
+        let mut timer = CalibratedTimer::<HighResolutionClock>::new();
+        timer.stop();
+        assert!(timer.elapsed() <= timer.elapsed_raw());
+        calibrated += timer.elapsed();
+        raw += timer.elapsed_raw();
+    }
+    assert!(calibrated <= raw);
+    if raw > Duration::from_nanos(0) {
+        assert!(calibrated.as_secs_f64() < raw.as_secs_f64() * 0.9);
+    }
+}
+
+#[test]
+fn test_calibrated_timer() {
+    let ten_millis = Duration::from_millis(10);
+    let mut timer = CalibratedTimer::<HighResolutionClock>::new();
+    assert!(timer.is_running());
+    std::thread::sleep(ten_millis);
+    timer.stop();
+    assert!(timer.is_stopped());
+    assert!(timer.elapsed_raw() >= ten_millis);
+    assert!(timer.elapsed() + calibrate::<HighResolutionClock>() >= ten_millis);
+}
+
+#[test]
+fn test_calibrated_timer_intervals() {
+    let overhead = calibrate::<HighResolutionClock>();
+    let mut timer = CalibratedTimer::<HighResolutionClock>::new();
+    timer.stop();
+    for _ in 0..2 {
+        timer.resume();
+        timer.resume();
+        timer.stop();
+    }
+    assert_eq!(
+        timer.elapsed(),
+        timer.elapsed_raw().saturating_sub(overhead * 3)
+    );
+
+    timer.start();
+    timer.stop();
+    assert_eq!(
+        timer.elapsed(),
+        timer.elapsed_raw().saturating_sub(overhead)
+    );
+}
@@ -0,0 +1,57 @@
+#![cfg(all(feature = "std", target_os = "linux"))]
+
+use howlong::{clock::*, timer::*, Clock, Duration, Error};
+
+mod utils;
+
+/// Return true if the calling process is the only one in its cgroup.
+fn is_single_process_cgroup() -> bool {
+    let procs = match std::fs::read_to_string(CgroupCPUClock::path().unwrap().join("cgroup.procs"))
+    {
+        Ok(procs) => procs,
+        Err(_) => return false,
+    };
+    let pids: Vec<&str> = procs.lines().collect();
+    pids == [std::process::id().to_string()]
+}
+
+#[test]
+fn test_cgroup_cpu_clock() {
+    if !CgroupCPUClock::is_available() {
+        assert!(matches!(
+            CgroupCPUClock::try_now(),
+            Err(Error::CgroupUnavailable)
+        ));
+        return;
+    }
+    let start = CgroupCPUClock::now();
+    let process_start = ProcessCPUClock::now();
+    utils::busy_loop::<HighResolutionClock>(Duration::from_millis(200));
+    let elapsed = CgroupCPUClock::now() - start;
+    let process_elapsed = ProcessCPUClock::now() - process_start;
+    println!("{} vs {}", elapsed, process_elapsed);
+    assert!(elapsed.real >= Duration::from_millis(200));
+    assert!(CgroupCPUClock::usage().unwrap() >= elapsed.user + elapsed.system);
+    assert!(CgroupCPUClock::resolution().unwrap() > Duration::ZERO);
+
+    if is_single_process_cgroup() {
+        let cpu = (elapsed.user + elapsed.system).as_nanos();
+        let process_cpu = (process_elapsed.user + process_elapsed.system).as_nanos();
+        let tolerance = Duration::from_millis(20).as_nanos() + process_cpu / 10;
+        assert!(cpu.abs_diff(process_cpu) <= tolerance);
+    }
+}
+
+#[test]
+fn test_cgroup_cpu_timer() {
+    if !CgroupCPUClock::is_available() {
+        return;
+    }
+    let mut timer = CgroupCPUTimer::new();
+    utils::busy_loop::<HighResolutionClock>(Duration::from_millis(50));
+    timer.stop();
+    let stopped = timer.elapsed();
+    assert!(stopped.real >= Duration::from_millis(50));
+    utils::busy_loop::<HighResolutionClock>(Duration::from_millis(10));
+    assert_eq!(timer.elapsed(), stopped);
+}
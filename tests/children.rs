@@ -0,0 +1,62 @@
+#![cfg(feature = "std")]
+
+use howlong::{timer::*, Duration};
+use std::process::{Command, Stdio};
+
+mod utils;
+
+const HELPER_ENV: &str = "HOWLONG_TEST_CHILDREN_HELPER";
+
+/// Burn CPU when spawned as a helper by the other tests.
+#[test]
+fn helper() {
+    if std::env::var_os(HELPER_ENV).is_some() {
+        for _ in 0..3 {
+            utils::black_box(utils::computation_task());
+        }
+    }
+}
+
+#[test]
+fn test_children_cpu_timer() {
+    // The children of the other tests, running concurrently, might be accounted for too, so
+    // only the growth over the helper is checked.
+    let timer = ChildrenCPUTimer::new();
+    let before = timer.elapsed();
+
+    let mut child = Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", "helper", "--test-threads=1"])
+        .env(HELPER_ENV, "1")
+        .stdout(Stdio::null())
+        .spawn()
+        .unwrap();
+    #[cfg(windows)]
+    ChildrenCPUTimer::job().unwrap().assign(&child).unwrap();
+    assert!(child.wait().unwrap().success());
+
+    let elapsed = timer.elapsed();
+    println!("{}", elapsed);
+    assert!(elapsed.user > before.user + Duration::from_millis(50));
+    assert!(elapsed.real >= (elapsed.user - before.user) / 2);
+}
+
+#[test]
+fn test_process_self_and_with_children_cpu_timers() {
+    let self_timer = ProcessSelfCPUTimer::new();
+    let with_children_timer = ProcessWithChildrenCPUTimer::new();
+
+    let mut child = Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", "helper", "--test-threads=1"])
+        .env(HELPER_ENV, "1")
+        .stdout(Stdio::null())
+        .spawn()
+        .unwrap();
+    #[cfg(windows)]
+    ChildrenCPUTimer::job().unwrap().assign(&child).unwrap();
+    assert!(child.wait().unwrap().success());
+
+    let with_children = with_children_timer.elapsed();
+    let self_only = self_timer.elapsed();
+    println!("{} vs {}", self_only, with_children);
+    assert!(with_children.user > self_only.user + Duration::from_millis(50));
+}
@@ -0,0 +1,92 @@
+#![cfg(all(feature = "chrono", not(howlong_unsupported)))]
+
+use chrono::{DateTime, TimeDelta, Utc};
+use howlong::{
+    chrono::{from_chrono, to_chrono},
+    clock::SystemClock,
+    Duration, Error, Stamped,
+};
+use std::time::UNIX_EPOCH;
+
+#[test]
+fn test_now_datetime() {
+    let before = Utc::now();
+    let now = SystemClock::now_datetime().unwrap();
+    let after = Utc::now();
+    let tolerance = TimeDelta::milliseconds(100);
+    assert!(now >= before - tolerance, "{} < {}", now, before);
+    assert!(now <= after + tolerance, "{} > {}", now, after);
+
+    let stamped = Stamped::<SystemClock>::now().to_datetime_utc().unwrap();
+    assert!((stamped - Utc::now()).abs() < tolerance);
+}
+
+#[test]
+fn test_to_datetime_utc() {
+    let cases = [
+        (0, 0),
+        (1, 500_000_000),
+        (1_700_000_000, 123_456_789),
+        (4_102_444_800, 999_999_999),
+    ];
+    for &(secs, nanos) in cases.iter() {
+        let time = UNIX_EPOCH + Duration::new(secs, nanos);
+        let t = SystemClock::from_system_time(time).unwrap();
+        let expected = DateTime::from_timestamp(secs as i64, nanos).unwrap();
+        assert_eq!(t.to_datetime_utc(), Some(expected));
+        assert_eq!(DateTime::<Utc>::from(t.to_system_time()), expected);
+    }
+
+    let t = SystemClock::from_system_time(UNIX_EPOCH + Duration::from_secs(1_700_000_000)).unwrap();
+    assert_eq!(
+        t.to_datetime_utc().unwrap().to_rfc3339(),
+        "2023-11-14T22:13:20+00:00"
+    );
+}
+
+#[test]
+#[cfg(windows)]
+fn test_to_datetime_utc_before_unix_epoch() {
+    let time = UNIX_EPOCH - Duration::new(86_400, 250_000_000);
+    let t = SystemClock::from_system_time(time).unwrap();
+    assert_eq!(
+        t.to_datetime_utc(),
+        DateTime::from_timestamp(-86_401, 750_000_000)
+    );
+    assert_eq!(
+        howlong::TimePoint::from(Duration::ZERO)
+            .to_datetime_utc()
+            .unwrap()
+            .to_rfc3339(),
+        "1601-01-01T00:00:00+00:00"
+    );
+}
+
+#[test]
+fn test_duration_conversion() {
+    let cases = [
+        Duration::ZERO,
+        Duration::from_nanos(1),
+        Duration::new(1, 500_000_000),
+        Duration::from_secs(86_400 * 365),
+    ];
+    for &d in cases.iter() {
+        let c = to_chrono(d).unwrap();
+        assert_eq!(c.num_nanoseconds(), Some(d.as_nanos() as i64));
+        assert_eq!(from_chrono(c).unwrap(), d);
+    }
+
+    assert_eq!(
+        to_chrono(Duration::from_millis(1500)).unwrap(),
+        TimeDelta::milliseconds(1500)
+    );
+    assert_eq!(to_chrono(Duration::MAX), Err(Error::OutOfRange));
+    assert_eq!(
+        from_chrono(TimeDelta::nanoseconds(-1)),
+        Err(Error::OutOfRange)
+    );
+    assert_eq!(
+        from_chrono(TimeDelta::MAX).unwrap().as_secs(),
+        TimeDelta::MAX.num_seconds() as u64
+    );
+}
@@ -0,0 +1,68 @@
+#![cfg(all(feature = "cli", unix))]
+
+use std::process::Command;
+
+fn howlong() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_howlong"))
+}
+
+#[test]
+fn test_cli_report() {
+    let output = howlong().args(["--", "sleep", "0.1"]).output().unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains(" wall, "));
+    assert!(stderr.contains(" user + "));
+    assert!(stderr.contains(" system = "));
+    assert!(stderr.trim_end().ends_with("%)"));
+}
+
+#[test]
+fn test_cli_format() {
+    let output = howlong()
+        .args(["-f", "wall=%w cpu=%t", "sleep", "0.1"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let wall: f64 = stderr
+        .trim()
+        .strip_prefix("wall=")
+        .unwrap()
+        .split(' ')
+        .next()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(wall >= 0.1);
+}
+
+#[test]
+fn test_cli_output_file() {
+    let path = std::env::temp_dir().join(format!("howlong-cli-{}.txt", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    for _ in 0..2 {
+        let output = howlong()
+            .arg("-o")
+            .arg(&path)
+            .args(["-f", "%p%%", "true"])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        assert!(output.stderr.is_empty());
+    }
+    let report = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(report.lines().count(), 2);
+    assert!(report.lines().all(|line| line.ends_with('%')));
+}
+
+#[test]
+fn test_cli_exit_code() {
+    let status = howlong().args(["sh", "-c", "exit 3"]).status().unwrap();
+    assert_eq!(status.code(), Some(3));
+    let status = howlong().arg("/nonexistent/command").status().unwrap();
+    assert_eq!(status.code(), Some(127));
+    let status = howlong().status().unwrap();
+    assert_eq!(status.code(), Some(2));
+}
@@ -1,4 +1,8 @@
-use howlong::{clock::*, Clock, Duration};
+#![cfg(feature = "std")]
+
+use howlong::{
+    clock::*, Clock, Duration, ProcessDuration, ProcessTimePoint, TimePoint, TimePointSub,
+};
 use std::thread;
 
 mod utils;
@@ -19,9 +23,283 @@ macro_rules! test_clock {
 test_clock!(test_system_clock, SystemClock);
 #[cfg(have_steady_clock)]
 test_clock!(test_steady_clock, SteadyClock);
+#[cfg(have_suspend_aware_clock)]
+test_clock!(test_suspend_aware_clock, SuspendAwareClock);
+test_clock!(test_uptime_clock, UptimeClock);
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    windows
+))]
+test_clock!(test_process_uptime_clock, ProcessUptimeClock);
 test_clock!(test_high_resolution_clock, HighResolutionClock);
 test_clock!(test_process_real_cpu_clock, ProcessRealCPUClock);
 
+#[test]
+#[cfg(have_steady_clock)]
+fn test_steady_clock_precision() {
+    let overhead = (0..100)
+        .map(|_| {
+            let start = SteadyClock::now();
+            SteadyClock::now() - start
+        })
+        .min()
+        .unwrap();
+    assert!(overhead < Duration::from_micros(1), "{:?}", overhead);
+
+    let ten_millis = Duration::from_millis(10);
+    let start = SteadyClock::now();
+    thread::sleep(ten_millis);
+    let elapsed = SteadyClock::now() - start;
+    assert!(elapsed >= ten_millis);
+    assert!(elapsed < ten_millis * 5, "{:?}", elapsed);
+}
+
+#[test]
+#[cfg(have_steady_clock)]
+fn test_steady_clock_deadline() {
+    let fifty_millis = Duration::from_millis(50);
+    let start = SteadyClock::now();
+    let deadline = start + fifty_millis;
+    assert_eq!(deadline - start, fifty_millis);
+    assert_eq!(deadline - fifty_millis, start);
+    assert!(SteadyClock::now() < deadline);
+    thread::sleep(fifty_millis);
+    assert!(SteadyClock::now() >= deadline);
+
+    let mut point = start;
+    point += fifty_millis;
+    assert_eq!(point, deadline);
+    point -= fifty_millis;
+    assert_eq!(point, start);
+    assert_eq!(
+        deadline.as_nanos() - start.as_nanos(),
+        fifty_millis.as_nanos()
+    );
+}
+
+#[test]
+#[cfg(have_steady_clock)]
+fn test_steady_clock_raw() {
+    use howlong::RawInstant;
+
+    let one_micro = Duration::from_micros(1);
+
+    // The raw and the converted readings cannot be taken at the same instant, so take the
+    // closest of a few attempts.
+    let error = (0..100)
+        .map(|_| {
+            let (raw_start, start) = (SteadyClock::raw_now().unwrap(), SteadyClock::now());
+            thread::sleep(Duration::from_micros(100));
+            let (raw_end, end) = (SteadyClock::raw_now().unwrap(), SteadyClock::now());
+            let delta = RawInstant::delta(raw_start, raw_end);
+            let converted = SteadyClock::convert(raw_end) - SteadyClock::convert(raw_start);
+            assert!(
+                delta.abs_diff(converted) <= one_micro,
+                "{:?}",
+                (delta, converted)
+            );
+            delta.abs_diff(end - start)
+        })
+        .min()
+        .unwrap();
+    assert!(error <= one_micro, "{:?}", error);
+
+    let raw = SteadyClock::raw_now().unwrap();
+    let now = SteadyClock::now();
+    assert!(SteadyClock::convert(raw) <= now);
+    assert!(now - SteadyClock::convert(raw) < Duration::from_millis(100));
+
+    let later = SteadyClock::raw_now().unwrap();
+    assert!(later >= raw);
+    assert_eq!(later - raw, u64::from(later) - u64::from(raw));
+    assert_eq!(RawInstant::delta(later, raw), Duration::ZERO);
+}
+
+#[test]
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+fn test_precise_system_clock_darwin() {
+    assert!(SystemClock::resolution().unwrap() <= Duration::from_micros(1));
+    // `gettimeofday` would only report whole microseconds.
+    let readings: Vec<_> = (0..1000).map(|_| SystemClock::now()).collect();
+    assert!(
+        readings.iter().any(|now| now.as_nanos() % 1000 != 0),
+        "{:?}",
+        &readings[..10]
+    );
+}
+
+#[test]
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+fn test_steady_clock_timebase() {
+    // A wrong scaling of the mach timebase shows up as a systematic error against the system
+    // clock, regardless of how long the sleep actually takes.
+    let hundred_millis = Duration::from_millis(100);
+    let (start, system_start) = (SteadyClock::now(), SystemClock::now());
+    thread::sleep(hundred_millis);
+    let (end, system_end) = (SteadyClock::now(), SystemClock::now());
+    let elapsed = end - start;
+    assert!(elapsed >= hundred_millis, "{:?}", elapsed);
+    let system_elapsed = system_end - system_start;
+    assert!(
+        elapsed.abs_diff(system_elapsed) < Duration::from_millis(2),
+        "{:?} {:?}",
+        elapsed,
+        system_elapsed
+    );
+}
+
+#[test]
+#[cfg(all(have_steady_clock, have_suspend_aware_clock))]
+fn test_suspend_aware_clock_monotonic() {
+    assert!(SuspendAwareClock::is_available());
+    let start = SuspendAwareClock::now();
+    let steady_start = SteadyClock::now();
+    let mut last = start;
+    for _ in 0..1000 {
+        let now = SuspendAwareClock::now();
+        assert!(now >= last);
+        last = now;
+    }
+    thread::sleep(Duration::from_millis(10));
+    let steady_elapsed = SteadyClock::now() - steady_start;
+    let elapsed = SuspendAwareClock::now() - start;
+    // `GetTickCount64` on old Windows only has a millisecond resolution.
+    assert!(elapsed + Duration::from_millis(20) >= steady_elapsed);
+}
+
+#[test]
+#[cfg(have_coarse_clock)]
+fn test_coarse_clocks() {
+    assert!(CoarseSystemClock::is_available());
+    assert!(CoarseSteadyClock::is_available());
+    let coarse = CoarseSteadyClock::resolution().unwrap();
+    assert!(coarse > Duration::from_nanos(0));
+    assert!(coarse < Duration::from_millis(100));
+    assert!(CoarseSystemClock::resolution().unwrap() < Duration::from_millis(100));
+
+    let mut last = CoarseSteadyClock::now();
+    for _ in 0..1000 {
+        let now = CoarseSteadyClock::now();
+        assert!(now >= last);
+        last = now;
+    }
+
+    let delta = coarse + Duration::from_millis(5);
+    let precise = SystemClock::now();
+    let coarse_now = CoarseSystemClock::now();
+    let distance = precise
+        .checked_sub(coarse_now)
+        .or_else(|| coarse_now.checked_sub(precise))
+        .unwrap();
+    assert!(distance <= delta, "{:?}", distance);
+}
+
+#[test]
+fn test_resolution() {
+    let system = SystemClock::resolution().unwrap();
+    assert!(system > Duration::from_nanos(0));
+    assert!(system < Duration::from_millis(100));
+    assert!(HighResolutionClock::resolution().unwrap() <= system);
+    for resolution in [
+        ProcessRealCPUClock::resolution(),
+        ProcessUserCPUClock::resolution(),
+        ProcessSystemCPUClock::resolution(),
+        ProcessCPUClock::resolution(),
+        ThreadClock::resolution(),
+        ThreadCPUClock::resolution(),
+        ChildrenCPUClock::resolution(),
+        UptimeClock::resolution(),
+    ] {
+        let resolution = resolution.unwrap();
+        assert!(resolution > Duration::from_nanos(0));
+        assert!(resolution < Duration::from_millis(100));
+    }
+    #[cfg(target_os = "linux")]
+    assert!(SteadyClock::resolution().unwrap() < ProcessUserCPUClock::resolution().unwrap());
+}
+
+#[test]
+#[cfg(have_clock_tai)]
+fn test_tai_clock() {
+    if !TaiClock::is_available() {
+        assert!(matches!(
+            TaiClock::try_now(),
+            Err(howlong::Error::Unsupported(_))
+        ));
+        return;
+    }
+    let offset = TaiClock::tai_utc_offset().unwrap();
+    assert_eq!(offset.subsec_nanos(), 0);
+    let start = TaiClock::now();
+    thread::sleep(Duration::from_millis(10));
+    assert!(TaiClock::now() - start >= Duration::from_millis(10));
+    let distance = TaiClock::now().saturating_sub(SystemClock::now());
+    assert!(distance <= offset + Duration::from_secs(1));
+}
+
+#[test]
+#[cfg(windows)]
+fn test_precise_system_clock() {
+    assert!(SystemClock::resolution().unwrap() < Duration::from_millis(1));
+    let start = SystemClock::now();
+    let mut now = SystemClock::now();
+    while now == start {
+        now = SystemClock::now();
+    }
+    assert!(now - start < Duration::from_millis(1));
+}
+
+#[test]
+#[cfg(unix)]
+fn test_process_cpu_clock_resolution() {
+    let start = ProcessUserCPUClock::now();
+    let total_start = ProcessCPUClock::total().unwrap();
+    utils::busy_loop::<HighResolutionClock>(Duration::from_millis(5));
+    assert!(ProcessUserCPUClock::now() - start > Duration::from_nanos(0));
+    assert!(ProcessCPUClock::total().unwrap() > total_start);
+    assert!(ProcessUserCPUClock::resolution().unwrap() <= Duration::from_micros(1));
+}
+
+#[test]
+fn test_uptime() {
+    let first = UptimeClock::uptime().unwrap();
+    let second = UptimeClock::uptime().unwrap();
+    assert!(first > Duration::from_nanos(0));
+    assert!(second >= first);
+    // The process cannot have run longer than the system.
+    assert!(UptimeClock::now() > ProcessUserCPUClock::now());
+    assert!(UptimeClock::now() > ProcessSystemCPUClock::now());
+    let timer = howlong::Timer::<UptimeClock, TimePoint, Duration>::new();
+    thread::sleep(Duration::from_millis(10));
+    assert!(timer.elapsed() >= Duration::from_millis(10));
+}
+
+#[test]
+#[cfg(any(
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    windows
+))]
+fn test_process_uptime() {
+    let timer = howlong::HighResolutionTimer::new();
+    thread::sleep(Duration::from_millis(10));
+    let first = ProcessUptimeClock::uptime().unwrap();
+    assert!(first >= timer.elapsed(), "{:?}", first);
+    assert!(first <= UptimeClock::uptime().unwrap());
+    thread::sleep(Duration::from_millis(10));
+    let second = ProcessUptimeClock::uptime().unwrap();
+    assert!(
+        second >= first + Duration::from_millis(10),
+        "{:?} {:?}",
+        first,
+        second
+    );
+    assert!(ProcessUptimeClock::resolution().unwrap() > Duration::ZERO);
+}
+
 #[test]
 fn test_process_user_cpu_clock() {
     let start = ProcessUserCPUClock::now();
@@ -34,7 +312,8 @@ fn test_process_user_cpu_clock() {
 fn test_process_system_cpu_clock() {
     let start = ProcessSystemCPUClock::now();
     let elapsed = ProcessSystemCPUClock::now() - start;
-    assert!(elapsed < Duration::from_nanos(10));
+    // Reading the clock itself might take a few microseconds of system time.
+    assert!(elapsed < Duration::from_millis(1));
 }
 
 #[test]
@@ -61,3 +340,227 @@ fn test_thread_clock() {
     assert!(elapsed_inner > Duration::from_nanos(0));
     assert!(elapsed_inner > elapsed_outter);
 }
+
+#[test]
+fn test_time_point_checked_sub() {
+    let earlier = SystemClock::now();
+    let later = TimePoint::from(Duration::from(earlier) + Duration::from_millis(10));
+    assert_eq!(later.checked_sub(earlier), Some(Duration::from_millis(10)));
+    assert_eq!(earlier.checked_sub(later), None);
+    assert_eq!(later.saturating_sub(earlier), Duration::from_millis(10));
+    assert_eq!(earlier.saturating_sub(later), Duration::from_nanos(0));
+}
+
+#[test]
+fn test_system_time_conversion() {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let one_micro = Duration::from_micros(1);
+
+    let now = SystemTime::now();
+    let t = SystemClock::from_system_time(now).unwrap();
+    assert_eq!(t.to_system_time(), now);
+
+    let before = SystemTime::now();
+    let t = SystemClock::now().to_system_time();
+    let nanos = SystemClock::unix_nanos().unwrap();
+    let after = SystemTime::now();
+    assert!(t + one_micro >= before, "{:?} {:?}", t, before);
+    assert!(t <= after + one_micro, "{:?} {:?}", t, after);
+    let before = before.duration_since(UNIX_EPOCH).unwrap();
+    let after = after.duration_since(UNIX_EPOCH).unwrap();
+    assert!(nanos + one_micro.as_nanos() >= before.as_nanos());
+    assert!(nanos <= (after + one_micro).as_nanos());
+
+    // 2000-01-01T00:00:00Z
+    let y2k = UNIX_EPOCH + Duration::from_secs(946_684_800);
+    let t = SystemClock::from_system_time(y2k).unwrap();
+    assert_eq!(t.to_system_time(), y2k);
+    if cfg!(windows) {
+        // The `FILETIME` of 2000-01-01T00:00:00Z in 100ns.
+        assert_eq!(
+            Duration::from(t),
+            Duration::from_nanos(125_911_584_000_000_000 * 100)
+        );
+        // The `FILETIME` of the Unix epoch in 100ns.
+        assert_eq!(
+            Duration::from(SystemClock::from_system_time(UNIX_EPOCH).unwrap()),
+            Duration::from_nanos(116_444_736_000_000_000 * 100)
+        );
+    } else {
+        assert_eq!(Duration::from(t), Duration::from_secs(946_684_800));
+        assert!(matches!(
+            SystemClock::from_system_time(UNIX_EPOCH - Duration::from_secs(1)),
+            Err(howlong::Error::BeforeEpoch)
+        ));
+    }
+
+    // Before 1601-01-01, which is before the epoch everywhere.
+    if let Some(t) = UNIX_EPOCH.checked_sub(Duration::from_secs(400 * 365 * 24 * 3600)) {
+        assert!(matches!(
+            SystemClock::from_system_time(t),
+            Err(howlong::Error::BeforeEpoch)
+        ));
+    }
+}
+
+#[test]
+fn test_process_time_point_checked_sub() {
+    let earlier = ProcessCPUClock::now();
+    let later = ProcessTimePoint::from(
+        ProcessDuration::from(earlier)
+            + ProcessDuration::new(
+                Duration::from_millis(10),
+                Duration::from_millis(0),
+                Duration::from_millis(0),
+            ),
+    );
+    assert!(TimePointSub::checked_sub(earlier, later).is_none());
+    let d = TimePointSub::saturating_sub(earlier, later);
+    assert_eq!(d.real, Duration::from_nanos(0));
+    let d = TimePointSub::checked_sub(later, earlier).unwrap();
+    assert_eq!(d.real, Duration::from_millis(10));
+}
+
+#[test]
+fn test_process_time_point_accessors() {
+    let earlier = ProcessCPUClock::now();
+    utils::black_box(utils::computation_task());
+    let later = ProcessCPUClock::now();
+    let d = later - earlier;
+    assert_eq!(later.real() - earlier.real(), d.real);
+    assert_eq!(later.user() - earlier.user(), d.user);
+    assert_eq!(later.system() - earlier.system(), d.system);
+    assert_eq!(later.cpu_time(), later.user() + later.system());
+    assert_eq!(
+        ProcessTimePoint::new(later.real(), later.user(), later.system()),
+        later
+    );
+}
+
+#[test]
+fn test_clock_overhead() {
+    assert!(overhead::<HighResolutionClock>().unwrap() < Duration::from_millis(1));
+    assert!(overhead::<ProcessCPUClock>().unwrap() < Duration::from_millis(1));
+    let report = overhead_report().unwrap();
+    assert!(report.contains_key("SystemClock"));
+    assert!(report.contains_key("ProcessCPUClock"));
+    assert!(report.contains_key("ThreadClock"));
+    for (clock, overhead) in report {
+        assert!(overhead > Duration::from_nanos(0), "{}", clock);
+        assert!(overhead < Duration::from_millis(1), "{}", clock);
+    }
+}
+
+#[test]
+#[cfg(have_steady_clock)]
+fn test_clock_metadata() {
+    const _: () = assert!(!SystemClock::IS_STEADY && !SystemClock::IS_CPU_TIME);
+    const _: () = assert!(SteadyClock::IS_STEADY && !SteadyClock::IS_CPU_TIME);
+    // The fallback of `HighResolutionClock` is not steady.
+    const _: () = assert!(!HighResolutionClock::IS_STEADY);
+    const _: () = assert!(UptimeClock::IS_STEADY && !UptimeClock::IS_CPU_TIME);
+    const _: () = assert!(ProcessRealCPUClock::IS_STEADY && !ProcessRealCPUClock::IS_CPU_TIME);
+    const _: () = assert!(ProcessUserCPUClock::IS_CPU_TIME && ProcessCPUClock::IS_CPU_TIME);
+    const _: () = assert!(ThreadClock::IS_STEADY && ThreadClock::IS_CPU_TIME);
+
+    let info = SteadyClock::info();
+    assert_eq!(info.name, "SteadyClock");
+    assert!(info.is_steady);
+    assert!(!info.is_cpu_time);
+    assert_eq!(info.resolution, SteadyClock::resolution().ok());
+    assert_eq!(HighResolutionClock::info(), info);
+    assert_eq!(ThreadClock::info().name, "ThreadClock");
+}
+
+#[test]
+#[cfg(not(howlong_unsupported))]
+fn test_is_supported() {
+    assert!(howlong::is_supported());
+}
+
+#[test]
+#[cfg(not(howlong_unsupported))]
+fn test_is_available() {
+    assert!(SystemClock::is_available());
+    #[cfg(have_steady_clock)]
+    assert!(SteadyClock::is_available());
+    assert!(HighResolutionClock::is_available());
+    assert!(ProcessRealCPUClock::is_available());
+    assert!(ProcessCPUClock::is_available());
+    #[cfg(any(target_os = "linux", target_os = "macos", windows))]
+    assert!(ThreadClock::is_available());
+}
+
+#[test]
+fn test_is_available_cached() {
+    use howlong::{Error, Result};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static PROBES: AtomicUsize = AtomicUsize::new(0);
+    static TRANSIENT_PROBES: AtomicUsize = AtomicUsize::new(0);
+
+    struct MissingClock;
+
+    impl Clock for MissingClock {
+        type Output = TimePoint;
+        type Duration = Duration;
+        const IS_STEADY: bool = true;
+        const IS_CPU_TIME: bool = false;
+
+        fn try_now() -> Result<TimePoint> {
+            PROBES.fetch_add(1, Ordering::SeqCst);
+            Err(Error::Unsupported("MissingClock"))
+        }
+    }
+
+    struct FlakyClock;
+
+    impl Clock for FlakyClock {
+        type Output = TimePoint;
+        type Duration = Duration;
+        const IS_STEADY: bool = true;
+        const IS_CPU_TIME: bool = false;
+
+        fn try_now() -> Result<TimePoint> {
+            if TRANSIENT_PROBES.fetch_add(1, Ordering::SeqCst) == 0 {
+                Err(Error::TimerRunning)
+            } else {
+                Ok(TimePoint::from(Duration::from_secs(1)))
+            }
+        }
+    }
+
+    for _ in 0..10 {
+        assert!(!MissingClock::is_available());
+    }
+    assert_eq!(PROBES.load(Ordering::SeqCst), 1);
+
+    // A transient error is probed again, and the success is cached afterwards.
+    assert!(!FlakyClock::is_available());
+    for _ in 0..10 {
+        assert!(FlakyClock::is_available());
+    }
+    assert_eq!(TRANSIENT_PROBES.load(Ordering::SeqCst), 2);
+}
+
+fn measure_sleep<C: Clock>(d: Duration) -> C::Duration
+where
+    C::Output: std::ops::Sub<Output = C::Duration> + Copy,
+{
+    let start = C::now();
+    thread::sleep(d);
+    C::elapsed_since(start)
+}
+
+#[cfg(have_steady_clock)]
+#[test]
+fn test_elapsed_since() {
+    let ten_millis = Duration::from_millis(10);
+    let real: Duration = measure_sleep::<SteadyClock>(ten_millis);
+    assert!(real >= ten_millis);
+    let thread: Duration = measure_sleep::<ThreadClock>(ten_millis);
+    assert!(thread < ten_millis);
+    let process: ProcessDuration = measure_sleep::<ProcessCPUClock>(ten_millis);
+    assert!(process.real >= ten_millis);
+    assert!(process.cpu_time() < ten_millis);
+}
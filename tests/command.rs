@@ -0,0 +1,60 @@
+#![cfg(feature = "std")]
+
+use howlong::{clock::ThreadClock, command::TimedCommand, Clock, Duration};
+use std::process::{Command, Stdio};
+
+mod utils;
+
+const HELPER_ENV: &str = "HOWLONG_TEST_COMMAND_HELPER";
+
+/// Burn CPU when spawned as a helper by the other tests.
+#[test]
+fn helper() {
+    if std::env::var_os(HELPER_ENV).is_some() {
+        print!("stdout from helper");
+        eprint!("stderr from helper");
+        for _ in 0..3 {
+            utils::black_box(utils::computation_task());
+        }
+    }
+}
+
+fn helper_command() -> Command {
+    let mut cmd = Command::new(std::env::current_exe().unwrap());
+    cmd.args(["--exact", "helper", "--nocapture", "--test-threads=1"])
+        .env(HELPER_ENV, "1");
+    cmd
+}
+
+#[test]
+fn test_timed_status() {
+    let start = ThreadClock::now();
+    let (status, elapsed) = helper_command()
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .timed_status()
+        .unwrap();
+    let parent = ThreadClock::now() - start;
+    println!("child: {}, parent: {:?}", elapsed, parent);
+    assert!(status.success());
+    assert!(elapsed.user > Duration::from_millis(50));
+    assert!(elapsed.real >= elapsed.user / 2);
+    assert!(parent < elapsed.user / 2);
+}
+
+#[test]
+fn test_timed_output() {
+    let (output, elapsed) = helper_command().timed_output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stdout.contains("stdout from helper"));
+    assert!(stderr.contains("stderr from helper"));
+    assert!(elapsed.user > Duration::from_millis(50));
+}
+
+#[test]
+fn test_timed_command_spawn_error() {
+    assert!(Command::new("/nonexistent/command").timed_status().is_err());
+    assert!(Command::new("/nonexistent/command").timed_output().is_err());
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "std")]
+
+use howlong::{clock::ThreadClock, CpuGroup, Duration, Error};
+
+mod utils;
+
+#[test]
+fn test_cpu_group() {
+    let work = Duration::from_millis(50);
+    let group = CpuGroup::new();
+    let members: Vec<_> = (0..4)
+        .map(|_| {
+            let group = group.clone();
+            std::thread::spawn(move || {
+                let _guard = group.join();
+                utils::busy_loop::<ThreadClock>(work);
+            })
+        })
+        .collect();
+    // A busy thread outside of the group is excluded.
+    let outsider = std::thread::spawn(move || utils::busy_loop::<ThreadClock>(work * 4));
+    for thread in members {
+        thread.join().unwrap();
+    }
+    outsider.join().unwrap();
+
+    let total = group.total();
+    assert_eq!(group.members(), 0);
+    assert!(total >= work * 4, "{:?}", total);
+    assert!(total < work * 6, "{:?}", total);
+}
+
+#[test]
+#[cfg(any(target_os = "linux", target_os = "macos", windows))]
+fn test_cpu_group_live_members() {
+    use std::sync::{Arc, Barrier};
+
+    let work = Duration::from_millis(50);
+    let group = CpuGroup::new();
+    let worked = Arc::new(Barrier::new(2));
+    let checked = Arc::new(Barrier::new(2));
+    let member = {
+        let group = group.clone();
+        let (worked, checked) = (worked.clone(), checked.clone());
+        std::thread::spawn(move || {
+            let _guard = group.join();
+            utils::busy_loop::<ThreadClock>(work);
+            worked.wait();
+            checked.wait();
+        })
+    };
+    worked.wait();
+    // The time of a live member is read from its thread handle.
+    assert_eq!(group.members(), 1);
+    let live = group.total();
+    assert!(live >= work, "{:?}", live);
+    checked.wait();
+    member.join().unwrap();
+    assert_eq!(group.members(), 0);
+    assert!(group.total() >= live);
+}
+
+#[test]
+fn test_cpu_group_join_twice() {
+    let group = CpuGroup::new();
+    let other = CpuGroup::new();
+    let guard = group.join();
+    assert_eq!(guard.group().members(), 1);
+    assert_eq!(group.try_join().unwrap_err(), Error::AlreadyInGroup);
+    assert_eq!(other.try_join().unwrap_err(), Error::AlreadyInGroup);
+    assert_eq!(group.members(), 1);
+    drop(guard);
+
+    let guard = other.join();
+    assert_eq!((group.members(), other.members()), (0, 1));
+    drop(guard);
+    assert!(std::panic::catch_unwind(|| {
+        let _guard = group.join();
+        let _again = group.join();
+    })
+    .is_err());
+    // The guard has left while unwinding.
+    assert_eq!(group.members(), 0);
+    drop(group.join());
+}
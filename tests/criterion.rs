@@ -0,0 +1,66 @@
+#![cfg(feature = "criterion")]
+
+mod utils;
+
+use criterion::measurement::Measurement;
+use howlong::criterion::*;
+use howlong::Duration;
+
+#[test]
+fn test_steady_measurement() {
+    let m = SteadyMeasurement;
+    let start = m.start();
+    std::thread::sleep(Duration::from_millis(10));
+    let value = m.end(start);
+    assert!(value >= Duration::from_millis(10));
+    assert_eq!(m.add(&value, &m.zero()), value);
+    assert_eq!(m.to_f64(&value), value.as_nanos() as f64);
+}
+
+#[test]
+fn test_process_cpu_measurement() {
+    let m = ProcessCpuMeasurement::default();
+    assert_eq!(m.kind(), ProcessCpuTime::UserSystem);
+    let start = m.start();
+    utils::black_box(utils::computation_task());
+    let value = m.end(start);
+    assert!(value > Duration::from_nanos(0));
+
+    let m = ProcessCpuMeasurement::new(ProcessCpuTime::User);
+    let start = m.start();
+    utils::black_box(utils::computation_task());
+    assert!(m.end(start) > Duration::from_nanos(0));
+}
+
+#[test]
+fn test_thread_cpu_measurement() {
+    let m = ThreadCpuMeasurement;
+    let start = m.start();
+    let first = m.end(start);
+    utils::black_box(utils::computation_task());
+    let second = m.end(start);
+    assert!(second > Duration::from_nanos(0));
+    assert!(second >= first);
+}
+
+#[test]
+fn test_formatter() {
+    let m = ThreadCpuMeasurement;
+    let f = m.formatter();
+    let mut values = [1_500.0, 2_500.0];
+    assert_eq!(f.scale_values(2_000.0, &mut values), "µs");
+    assert_eq!(values, [1.5, 2.5]);
+    let mut values = [5.0e6];
+    assert_eq!(f.scale_values(5.0e6, &mut values), "ms");
+    assert_eq!(values, [5.0]);
+    let mut values = [500.0];
+    assert_eq!(f.scale_for_machines(&mut values), "ns");
+    assert_eq!(values, [500.0]);
+    let mut values = [1.0e9];
+    let throughput = criterion::Throughput::Elements(2_000);
+    assert_eq!(
+        f.scale_throughputs(1.0e9, &throughput, &mut values),
+        "Kelem/s"
+    );
+    assert_eq!(values, [2.0]);
+}
@@ -0,0 +1,54 @@
+#![cfg(windows)]
+
+use howlong::{clock::*, timer::*, Clock, Cycles, Duration};
+
+mod utils;
+
+#[test]
+fn test_thread_cycle_clock() {
+    // `GetThreadTimes` ticks at about 15.6ms, so a short loop usually reports zero CPU time.
+    // Retry in case the loop happens to cross a tick.
+    for _ in 0..10 {
+        let start = ThreadCycleClock::now();
+        let thread_start = ThreadClock::now();
+        utils::busy_loop::<HighResolutionClock>(Duration::from_micros(200));
+        let cycles = ThreadCycleClock::now() - start;
+        let thread = ThreadClock::now() - thread_start;
+        if thread == Duration::ZERO {
+            assert!(cycles > Cycles(0));
+            return;
+        }
+    }
+    panic!("ThreadClock never reported zero for a short loop.");
+}
+
+#[test]
+fn test_process_cycle_clock() {
+    let start = ProcessCycleClock::now();
+    utils::busy_loop::<HighResolutionClock>(Duration::from_micros(200));
+    assert!(ProcessCycleClock::now() - start > Cycles(0));
+}
+
+#[test]
+fn test_cycles_to_duration() {
+    let frequency = ThreadCycleClock::frequency().unwrap();
+    assert!(frequency > 0);
+    assert_eq!(
+        Cycles(frequency).to_duration().unwrap(),
+        Duration::from_secs(1)
+    );
+    assert!(ThreadCycleClock::resolution().unwrap() > Duration::ZERO);
+}
+
+#[test]
+fn test_cycle_timers() {
+    let mut timer = ThreadCycleTimer::new();
+    let process_timer = ProcessCycleTimer::new();
+    utils::busy_loop::<HighResolutionClock>(Duration::from_millis(1));
+    timer.stop();
+    let stopped = timer.elapsed();
+    assert!(stopped > Cycles(0));
+    utils::busy_loop::<HighResolutionClock>(Duration::from_millis(1));
+    assert_eq!(timer.elapsed(), stopped);
+    assert!(process_timer.elapsed() >= stopped);
+}
@@ -0,0 +1,76 @@
+#![cfg(all(
+    have_steady_clock,
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+
+use howlong::{clock::*, Clock, Deadline, Duration, SteadyTimer, TimePoint};
+use std::thread;
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+#[test]
+fn test_deadline_expiry() {
+    let deadline = Deadline::after(ms(20));
+    assert!(!deadline.is_expired());
+    assert_eq!(deadline.elapsed_past(), Duration::ZERO);
+
+    let mut last = deadline.remaining();
+    assert!(last <= ms(20));
+    while !deadline.is_expired() {
+        let remaining = deadline.remaining();
+        assert!(remaining <= last);
+        last = remaining;
+    }
+    assert_eq!(deadline.remaining(), Duration::ZERO);
+    thread::sleep(ms(5));
+    assert!(deadline.elapsed_past() >= ms(5));
+}
+
+#[test]
+fn test_deadline_at() {
+    let now = SteadyClock::now();
+    let deadline = Deadline::at(now);
+    assert!(deadline.is_expired());
+    assert_eq!(deadline.time_point(), now);
+    assert_eq!(TimePoint::from(deadline), now);
+
+    let mut deadline = Deadline::at(now + ms(10));
+    assert!(deadline < Deadline::at(now + ms(11)));
+    deadline.extend(ms(10));
+    assert_eq!(deadline.time_point(), now + ms(20));
+}
+
+#[test]
+fn test_deadline_sleep() {
+    let deadline = Deadline::after(ms(20));
+    deadline.sleep().unwrap();
+    assert!(deadline.is_expired());
+    assert!(deadline.elapsed_past() < ms(10));
+}
+
+#[test]
+fn test_deadline_overflow() {
+    assert_eq!(Deadline::checked_after(Duration::MAX), None);
+    assert!(Deadline::checked_after(ms(1)).is_some());
+}
+
+#[test]
+#[should_panic]
+fn test_deadline_after_overflow() {
+    let _ = Deadline::after(Duration::MAX);
+}
+
+#[test]
+fn test_timer_has_exceeded() {
+    let mut timer = SteadyTimer::new();
+    let deadline = Deadline::after(ms(20));
+    assert!(!timer.has_exceeded(ms(20)));
+    deadline.sleep().unwrap();
+    assert!(timer.has_exceeded(ms(20)));
+    timer.stop();
+    assert!(!timer.has_exceeded(ms(1000)));
+    // The limit is reached when the elapsed time is equal to it, like a deadline.
+    assert!(timer.has_exceeded(timer.elapsed()));
+}
@@ -0,0 +1,149 @@
+#![cfg(not(howlong_unsupported))]
+
+use howlong::{
+    clock::*, timer_for, ClockKind, Duration, DynClock, DynClockAdapter, DynTimer, Error,
+};
+use std::thread;
+
+#[test]
+fn test_dyn_clocks() {
+    let clocks: Vec<Box<dyn DynClock>> = vec![
+        Box::new(DynClockAdapter::<SystemClock>::new()),
+        <dyn DynClock>::by_name("high_resolution").unwrap(),
+        <dyn DynClock>::by_name("uptime").unwrap(),
+        <dyn DynClock>::by_name("process_real").unwrap(),
+    ];
+    let starts: Vec<_> = clocks.iter().map(|clock| clock.now()).collect();
+    thread::sleep(Duration::from_millis(10));
+    for (clock, start) in clocks.iter().zip(starts) {
+        let elapsed = clock.now().saturating_sub(start);
+        assert!(
+            elapsed >= Duration::from_millis(9),
+            "{}: {:?}",
+            clock.name(),
+            elapsed
+        );
+        assert!(!clock.is_cpu_time());
+        assert!(clock.resolution().unwrap() > Duration::ZERO);
+    }
+    assert_eq!(clocks[0].name(), "SystemClock");
+    assert!(!clocks[0].is_steady());
+}
+
+#[test]
+fn test_dyn_clock_by_name() {
+    let thread = <dyn DynClock>::by_name("thread").unwrap();
+    assert_eq!(thread.name(), "ThreadClock");
+    assert!(thread.is_steady());
+    assert!(thread.is_cpu_time());
+    assert!(<dyn DynClock>::by_name("system").is_some());
+    assert!(<dyn DynClock>::by_name("no_such_clock").is_none());
+    // The process clocks with several times have no `DynClock`.
+    assert!(<dyn DynClock>::by_name("process_cpu").is_none());
+    for kind in ClockKind::ALL {
+        let clock = <dyn DynClock>::by_name(kind.name());
+        assert_eq!(clock.is_some(), kind.to_dyn_clock().is_some(), "{}", kind);
+    }
+}
+
+#[test]
+fn test_dyn_timer() {
+    let mut timers: Vec<DynTimer> = ["system", "high_resolution", "uptime"]
+        .iter()
+        .map(|name| DynTimer::new(<dyn DynClock>::by_name(name).unwrap()))
+        .collect();
+    thread::sleep(Duration::from_millis(10));
+    for timer in timers.iter_mut() {
+        assert!(timer.is_running());
+        timer.stop();
+        let stopped = timer.elapsed();
+        assert!(
+            stopped >= Duration::from_millis(9),
+            "{}",
+            timer.clock().name()
+        );
+        thread::sleep(Duration::from_millis(5));
+        assert_eq!(timer.elapsed(), stopped);
+        timer.resume();
+        thread::sleep(Duration::from_millis(5));
+        assert!(timer.elapsed() > stopped);
+    }
+}
+
+#[test]
+fn test_clock_kind_from_str() {
+    for kind in ClockKind::ALL {
+        assert_eq!(kind.name().parse::<ClockKind>(), Ok(*kind));
+        assert_eq!(kind.to_string(), kind.name());
+    }
+    assert_eq!("steady".parse(), Ok(ClockKind::Steady));
+    assert_eq!("process_cpu".parse(), Ok(ClockKind::ProcessCPU));
+    assert!(matches!(
+        "no_such_clock".parse::<ClockKind>(),
+        Err(Error::ParseError(_))
+    ));
+}
+
+#[test]
+fn test_timer_for() {
+    let mut supported = 0;
+    for kind in ClockKind::ALL {
+        let mut timer = match timer_for(*kind) {
+            Ok(timer) => timer,
+            Err(e) => {
+                assert!(
+                    matches!(e, Error::Unsupported(_) | Error::SystemError(..)),
+                    "{}: {:?}",
+                    kind,
+                    e
+                );
+                continue;
+            }
+        };
+        supported += 1;
+        assert!(timer.is_running());
+        thread::sleep(Duration::from_millis(10));
+        timer.stop();
+        assert!(!timer.is_running());
+        let stopped = timer.elapsed();
+        let is_process = matches!(
+            kind,
+            ClockKind::ProcessCPU | ClockKind::ProcessWithChildrenCPU | ClockKind::ChildrenCPU
+        );
+        assert_eq!(timer.process_elapsed().is_some(), is_process, "{}", kind);
+        if let Some(elapsed) = timer.process_elapsed() {
+            assert_eq!(elapsed.real, stopped);
+        }
+        if matches!(
+            kind,
+            ClockKind::System
+                | ClockKind::Steady
+                | ClockKind::HighResolution
+                | ClockKind::Uptime
+                | ClockKind::ProcessReal
+                | ClockKind::ProcessCPU
+        ) {
+            assert!(stopped >= Duration::from_millis(9), "{}", kind);
+        }
+        thread::sleep(Duration::from_millis(5));
+        assert_eq!(timer.elapsed(), stopped);
+        timer.resume();
+        assert!(timer.is_running());
+        assert!(timer.elapsed() >= stopped);
+        timer.start();
+        assert!(timer.is_running());
+    }
+    assert!(supported >= 10);
+
+    let timer = timer_for("thread".parse().unwrap()).unwrap();
+    assert!(timer.process_elapsed().is_none());
+}
+
+#[cfg(not(all(feature = "tsc", any(target_arch = "x86", target_arch = "x86_64"))))]
+#[test]
+fn test_timer_for_unsupported() {
+    assert!(matches!(
+        timer_for(ClockKind::Tsc),
+        Err(Error::Unsupported("tsc"))
+    ));
+}
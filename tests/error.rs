@@ -0,0 +1,168 @@
+#![cfg(feature = "std")]
+
+use howlong::{Error, ErrorKind};
+use std::io;
+
+#[test]
+fn test_accessors() {
+    let e = Error::SystemError("clock_gettime", 22);
+    assert_eq!(e.raw_os_error(), Some(22));
+    assert_eq!(e.syscall(), Some("clock_gettime"));
+    assert_eq!(e.to_string(), "failed to call `clock_gettime` [errno: 22]");
+
+    for e in [
+        Error::ClkFreqTooHigh,
+        Error::TimerRunning,
+        Error::Unsupported("CLOCK_TAI"),
+        Error::ParseError("oops".to_owned()),
+    ] {
+        assert_eq!(e.raw_os_error(), None);
+        assert_eq!(e.syscall(), None);
+    }
+}
+
+#[test]
+fn test_kind_of_non_system_errors() {
+    assert_eq!(
+        Error::Unsupported("CLOCK_TAI").kind(),
+        ErrorKind::Unsupported
+    );
+    assert_eq!(Error::CgroupUnavailable.kind(), ErrorKind::ClockUnavailable);
+    assert_eq!(Error::TimerRunning.kind(), ErrorKind::Other);
+    assert_eq!(Error::BeforeEpoch.kind(), ErrorKind::Other);
+    assert_eq!(Error::ParseError(String::new()).kind(), ErrorKind::Other);
+}
+
+#[test]
+#[cfg(all(unix, not(target_os = "fuchsia")))]
+fn test_kind_unix() {
+    let cases = [
+        (libc::EPERM, ErrorKind::PermissionDenied),
+        (libc::EACCES, ErrorKind::PermissionDenied),
+        (libc::ENOSYS, ErrorKind::Unsupported),
+        (libc::EOPNOTSUPP, ErrorKind::Unsupported),
+        (libc::EINVAL, ErrorKind::ClockUnavailable),
+        (libc::ENOENT, ErrorKind::ClockUnavailable),
+        (libc::EFAULT, ErrorKind::Other),
+        (0, ErrorKind::Other),
+    ];
+    for &(errno, kind) in cases.iter() {
+        assert_eq!(ErrorKind::from_raw_os_error(errno), kind, "{}", errno);
+        assert_eq!(Error::SystemError("clock_gettime", errno).kind(), kind);
+    }
+}
+
+#[test]
+#[cfg(windows)]
+fn test_kind_windows() {
+    let cases = [
+        (5, ErrorKind::PermissionDenied),  // ERROR_ACCESS_DENIED
+        (120, ErrorKind::Unsupported),     // ERROR_CALL_NOT_IMPLEMENTED
+        (50, ErrorKind::Unsupported),      // ERROR_NOT_SUPPORTED
+        (87, ErrorKind::ClockUnavailable), // ERROR_INVALID_PARAMETER
+        (6, ErrorKind::Other),             // ERROR_INVALID_HANDLE
+    ];
+    for &(code, kind) in cases.iter() {
+        assert_eq!(ErrorKind::from_raw_os_error(code), kind, "{}", code);
+        assert_eq!(Error::SystemError("GetProcessTimes", code).kind(), kind);
+    }
+}
+
+#[test]
+#[cfg(any(all(unix, not(target_os = "fuchsia")), windows))]
+fn test_into_io_error_keeps_raw_os_error() {
+    #[cfg(unix)]
+    let (code, kind) = (libc::EPERM, io::ErrorKind::PermissionDenied);
+    #[cfg(windows)]
+    let (code, kind) = (5, io::ErrorKind::PermissionDenied); // ERROR_ACCESS_DENIED
+
+    let e = Error::SystemError("clock_gettime", code);
+    let io_error = io::Error::from(&e);
+    assert_eq!(io_error.raw_os_error(), Some(code));
+    assert_eq!(io_error.kind(), kind);
+    assert_eq!(e.into_io_error().raw_os_error(), Some(code));
+}
+
+#[test]
+fn test_into_io_error() {
+    let e = Error::Unsupported("CLOCK_TAI");
+    let io_error = io::Error::from(&e);
+    assert_eq!(io_error.kind(), io::ErrorKind::Unsupported);
+    assert_eq!(io_error.raw_os_error(), None);
+    assert_eq!(io_error.to_string(), e.to_string());
+
+    let io_error = Error::TimerRunning.into_io_error();
+    assert_eq!(io_error.kind(), io::ErrorKind::Other);
+    assert_eq!(io_error.to_string(), "the timer is still running.");
+
+    // Zero is not a meaningful OS error code.
+    let io_error = Error::SystemError("wait4", 0).into_io_error();
+    assert_eq!(io_error.raw_os_error(), None);
+    assert_eq!(io_error.to_string(), "failed to call `wait4` [errno: 0]");
+}
+
+#[test]
+fn test_equality() {
+    assert_eq!(
+        Error::system("clock_gettime", 22),
+        Error::SystemError("clock_gettime", 22)
+    );
+    assert_ne!(
+        Error::system("clock_gettime", 22),
+        Error::system("clock_gettime", 1)
+    );
+    assert_ne!(
+        Error::system("clock_gettime", 22),
+        Error::system("clock_getres", 22)
+    );
+    assert_eq!(
+        Error::unsupported("ThreadClock"),
+        Error::Unsupported("ThreadClock")
+    );
+    assert_ne!(
+        Error::unsupported("ThreadClock"),
+        Error::unsupported("SteadyClock")
+    );
+    assert_eq!(
+        Error::ParseError("oops".to_owned()),
+        Error::ParseError("oops".to_owned())
+    );
+
+    let variants = [
+        Error::system("times", 1),
+        Error::ClkFreqTooHigh,
+        Error::TimerRunning,
+        Error::ZeroIterations,
+        Error::unsupported("times"),
+        Error::CgroupUnavailable,
+        Error::BeforeEpoch,
+        Error::ParseError("times".to_owned()),
+        Error::OutOfRange,
+        Error::AlreadyInGroup,
+    ];
+    for (i, a) in variants.iter().enumerate() {
+        for (j, b) in variants.iter().enumerate() {
+            assert_eq!(i == j, a == b, "{:?} {:?}", a, b);
+        }
+    }
+}
+
+#[test]
+#[cfg(unix)]
+fn test_equality_with_errno() {
+    let err = Error::system("times", libc::EPERM);
+    assert_eq!(err, Error::system("times", libc::EPERM));
+    assert_ne!(err, Error::system("times", libc::EINVAL));
+}
+
+#[test]
+fn test_send_sync() {
+    fn assert_send_sync<T: Send + Sync + 'static>() {}
+    assert_send_sync::<Error>();
+    assert_send_sync::<howlong::Result<howlong::ProcessDuration>>();
+
+    let err = std::thread::spawn(|| Error::system("clock_gettime", 22))
+        .join()
+        .unwrap();
+    assert_eq!(err.raw_os_error(), Some(22));
+}
@@ -0,0 +1,55 @@
+#![cfg(not(howlong_unsupported))]
+
+use howlong::{clock::*, ext::*, Clock, Duration, ProcessDuration};
+use std::panic;
+use std::thread;
+
+#[test]
+fn test_timed() {
+    let ten_millis = Duration::from_millis(10);
+    let (s, d) = (|| {
+        thread::sleep(ten_millis);
+        String::from("done")
+    })
+    .timed::<HighResolutionClock>();
+    assert_eq!(s, "done");
+    assert!(d >= ten_millis);
+
+    let (v, d) = (|| {
+        thread::sleep(ten_millis);
+        vec![1, 2, 3]
+    })
+    .timed::<ProcessCPUClock>();
+    assert_eq!(v, [1, 2, 3]);
+    let d: ProcessDuration = d;
+    assert!(d.real >= ten_millis);
+
+    let (s, d): (String, Duration) = (|| "thread".to_owned()).timed::<ThreadClock>();
+    assert_eq!(s, "thread");
+    assert!(d < ten_millis);
+}
+
+#[test]
+fn test_timed_hr() {
+    let owned = [String::from("a"), String::from("b")];
+    let (joined, d) = (move || owned.concat()).timed_hr();
+    assert_eq!(joined, "ab");
+    assert!(d < Duration::from_secs(1));
+
+    let outer = HighResolutionClock::now();
+    let (_, d) = (|| thread::sleep(Duration::from_millis(10))).timed_hr();
+    assert!(d <= HighResolutionClock::now() - outer);
+}
+
+#[test]
+fn test_timed_panic() {
+    let result = panic::catch_unwind(|| (|| -> u32 { panic!("boom") }).timed_hr());
+    let payload = result.unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"boom"));
+
+    let result = panic::catch_unwind(|| {
+        (|| -> String { panic!("{}", "process") }).timed::<ProcessCPUClock>()
+    });
+    let payload = result.unwrap_err();
+    assert_eq!(payload.downcast_ref::<String>().unwrap(), "process");
+}
@@ -0,0 +1,280 @@
+use howlong::{fmt, Error, ProcessDuration};
+use std::time::Duration;
+
+const NS: u64 = 1;
+const US: u64 = 1_000 * NS;
+const MS: u64 = 1_000 * US;
+const S: u64 = 1_000 * MS;
+const M: u64 = 60 * S;
+const H: u64 = 60 * M;
+const D: u64 = 24 * H;
+
+// (nanoseconds, human, full, compact)
+const CASES: &[(u64, &str, &str, &str)] = &[
+    (0, "0ns", "0d 0h 0m 0s", "0ns"),
+    (NS, "1ns", "0d 0h 0m 0.000000001s", "1ns"),
+    (9 * NS, "9ns", "0d 0h 0m 0.000000009s", "9ns"),
+    (10 * NS, "10ns", "0d 0h 0m 0.00000001s", "10ns"),
+    (999 * NS, "999ns", "0d 0h 0m 0.000000999s", "999ns"),
+    (US, "1.00µs", "0d 0h 0m 0.000001s", "1.00µs"),
+    (US + NS, "1.00µs", "0d 0h 0m 0.000001001s", "1.00µs"),
+    (1_234 * NS, "1.23µs", "0d 0h 0m 0.000001234s", "1.23µs"),
+    (1_235 * NS, "1.24µs", "0d 0h 0m 0.000001235s", "1.24µs"),
+    (9_994 * NS, "9.99µs", "0d 0h 0m 0.000009994s", "9.99µs"),
+    (9_995 * NS, "10.0µs", "0d 0h 0m 0.000009995s", "10.0µs"),
+    (12_345 * NS, "12.3µs", "0d 0h 0m 0.000012345s", "12.3µs"),
+    (99_949 * NS, "99.9µs", "0d 0h 0m 0.000099949s", "99.9µs"),
+    (99_950 * NS, "100µs", "0d 0h 0m 0.00009995s", "100µs"),
+    (123_456 * NS, "123µs", "0d 0h 0m 0.000123456s", "123µs"),
+    (999_499 * NS, "999µs", "0d 0h 0m 0.000999499s", "999µs"),
+    (999_500 * NS, "1.00ms", "0d 0h 0m 0.0009995s", "1.00ms"),
+    (MS, "1.00ms", "0d 0h 0m 0.001s", "1.00ms"),
+    (1_234_567 * NS, "1.23ms", "0d 0h 0m 0.001234567s", "1.23ms"),
+    (10 * MS, "10.0ms", "0d 0h 0m 0.01s", "10.0ms"),
+    (100 * MS, "100ms", "0d 0h 0m 0.1s", "100ms"),
+    (999_999_999 * NS, "1.00s", "0d 0h 0m 0.999999999s", "1.00s"),
+    (S, "1.00s", "0d 0h 0m 1s", "1.00s"),
+    (5_705 * MS, "5.71s", "0d 0h 0m 5.705s", "5.71s"),
+    (10 * S, "10.0s", "0d 0h 0m 10s", "10.0s"),
+    (59 * S, "59.0s", "0d 0h 0m 59s", "59.0s"),
+    (59_949 * MS, "59.9s", "0d 0h 0m 59.949s", "59.9s"),
+    (59_999 * MS, "60.0s", "0d 0h 0m 59.999s", "60.0s"),
+    (M, "1m", "0d 0h 1m 0s", "60.0s"),
+    (M + 99 * MS, "1m", "0d 0h 1m 0.099s", "60.1s"),
+    (M + 100 * MS, "1m 0.1s", "0d 0h 1m 0.1s", "60.1s"),
+    (M + S, "1m 1s", "0d 0h 1m 1s", "61.0s"),
+    (90 * S, "1m 30s", "0d 0h 1m 30s", "90.0s"),
+    (2 * M - NS, "1m 59.9s", "0d 0h 1m 59.999999999s", "120s"),
+    (999 * S, "16m 39s", "0d 0h 16m 39s", "999s"),
+    (999_500 * MS, "16m 39.5s", "0d 0h 16m 39.5s", "1000s"),
+    (1_234_567 * MS, "20m 34.5s", "0d 0h 20m 34.567s", "1230s"),
+    (H - NS, "59m 59.9s", "0d 0h 59m 59.999999999s", "3600s"),
+    (H, "1h", "0d 1h 0m 0s", "3600s"),
+    (H + NS, "1h", "0d 1h 0m 0.000000001s", "3600s"),
+    (H + S, "1h 0m 1s", "0d 1h 0m 1s", "3600s"),
+    (H + M, "1h 1m", "0d 1h 1m 0s", "3660s"),
+    (H + M + S, "1h 1m", "0d 1h 1m 1s", "3660s"),
+    (7_230_500 * MS, "2h 0m 30.5s", "0d 2h 0m 30.5s", "7230s"),
+    (D - NS, "23h 59m", "0d 23h 59m 59.999999999s", "86400s"),
+    (D, "1d", "1d 0h 0m 0s", "86400s"),
+    (D + S, "1d 0h 0m 1s", "1d 0h 0m 1s", "86400s"),
+    (D + M, "1d 0h 1m", "1d 0h 1m 0s", "86500s"),
+    (D + H, "1d 1h", "1d 1h 0m 0s", "90000s"),
+    (2 * D - NS, "1d 23h", "1d 23h 59m 59.999999999s", "173000s"),
+    (7 * D + 3 * H + 25 * M, "7d 3h", "7d 3h 25m 0s", "617000s"),
+    (30 * D, "30d", "30d 0h 0m 0s", "2590000s"),
+    (30 * D + NS, "30d", "30d 0h 0m 0.000000001s", "2590000s"),
+];
+
+#[test]
+fn test_human() {
+    for &(nanos, expected, _, _) in CASES {
+        let d = Duration::from_nanos(nanos);
+        assert_eq!(fmt::human(d).to_string(), expected, "{:?}", d);
+    }
+}
+
+#[test]
+fn test_full() {
+    for &(nanos, _, expected, _) in CASES {
+        let d = Duration::from_nanos(nanos);
+        assert_eq!(fmt::full(d).to_string(), expected, "{:?}", d);
+    }
+}
+
+#[test]
+fn test_compact() {
+    for &(nanos, _, _, expected) in CASES {
+        let d = Duration::from_nanos(nanos);
+        assert_eq!(fmt::compact(d).to_string(), expected, "{:?}", d);
+    }
+}
+
+#[test]
+fn test_max() {
+    assert_eq!(fmt::human(Duration::MAX).to_string(), "213503982334601d 7h");
+    assert_eq!(
+        fmt::full(Duration::MAX).to_string(),
+        "213503982334601d 7h 0m 15.999999999s"
+    );
+    assert_eq!(
+        fmt::compact(Duration::MAX).to_string(),
+        "18400000000000000000s"
+    );
+}
+
+#[test]
+fn test_process_duration_human() {
+    let d = ProcessDuration::new(
+        Duration::from_millis(7_230_500),
+        Duration::from_secs(7_150),
+        Duration::from_millis(12_345),
+    );
+    assert_eq!(
+        d.human().to_string(),
+        "2h 0m 30.5s wall, 1h 59m user + 12.3s system = 1h 59m CPU (99.1%)"
+    );
+    assert_eq!(
+        ProcessDuration::ZERO.human().to_string(),
+        "0ns wall, 0ns user + 0ns system = 0ns CPU (--%)"
+    );
+}
+
+// (nanoseconds, format_duration)
+const HUMANTIME_CASES: &[(u64, &str)] = &[
+    (0, "0s"),
+    (NS, "1ns"),
+    (999 * NS, "999ns"),
+    (US + NS, "1us 1ns"),
+    (1_234_567 * NS, "1ms 234us 567ns"),
+    (S, "1s"),
+    (5_705 * MS, "5s 705ms"),
+    (M, "1m"),
+    (90 * S, "1m 30s"),
+    (H + S, "1h 1s"),
+    (H + 30 * M + 15 * S, "1h 30m 15s"),
+    (7_230_500 * MS, "2h 30s 500ms"),
+    (D - NS, "23h 59m 59s 999ms 999us 999ns"),
+    (D, "1day"),
+    (30 * D + NS, "30days 1ns"),
+    (400 * D, "400days"),
+];
+
+#[test]
+fn test_format_duration() {
+    for &(nanos, expected) in HUMANTIME_CASES {
+        let d = Duration::from_nanos(nanos);
+        assert_eq!(fmt::format_duration(d).to_string(), expected, "{:?}", d);
+        assert_eq!(fmt::parse_duration(expected).unwrap(), d, "{}", expected);
+    }
+    let s = howlong::format_duration(Duration::MAX).to_string();
+    assert_eq!(s, "213503982334601days 7h 15s 999ms 999us 999ns");
+    assert_eq!(howlong::parse_duration(&s).unwrap(), Duration::MAX);
+    for &nanos in CASES.iter().map(|(nanos, _, _, _)| nanos) {
+        let d = Duration::from_nanos(nanos);
+        let s = fmt::format_duration(d).to_string();
+        assert_eq!(fmt::parse_duration(&s).unwrap(), d, "{}", s);
+    }
+}
+
+#[test]
+fn test_parse_duration() {
+    let cases = [
+        ("1h30m", 90 * M),
+        ("  1h  30m  ", 90 * M),
+        ("2days 4hours", 2 * D + 4 * H),
+        ("1week", 7 * D),
+        ("3w 1d", 22 * D),
+        ("1hr 2min 3sec", H + 2 * M + 3 * S),
+        ("1hour 1minute 1second", H + M + S),
+        ("5msec 6usec 7nsec", 5 * MS + 6 * US + 7 * NS),
+        ("5millis 7nanos", 5 * MS + 7 * NS),
+        ("2hrs 3mins 4secs", 2 * H + 3 * M + 4 * S),
+        ("5µs", 5 * US),
+        ("1.5s", 1_500 * MS),
+        ("0.25s", 250 * MS),
+        ("1.5h", 90 * M),
+        ("0.000000001s", NS),
+        ("0.0000000019s", NS),
+        ("1.5ns", NS),
+        ("007s", 7 * S),
+    ];
+    for &(s, nanos) in cases.iter() {
+        assert_eq!(
+            fmt::parse_duration(s),
+            Ok(Duration::from_nanos(nanos)),
+            "{}",
+            s
+        );
+    }
+}
+
+#[test]
+fn test_parse_duration_error() {
+    let cases = [
+        ("", "empty duration"),
+        ("   ", "empty duration"),
+        ("90", "missing unit after `90`"),
+        ("1h 30", "missing unit after `30`"),
+        ("1 week", "missing unit after `1`"),
+        ("h", "expected a number at `h`"),
+        ("-1s", "expected a number at `-1s`"),
+        ("1s, 2m", "expected a number at `, 2m`"),
+        ("1.s", "invalid number `1.`"),
+        (".5s", "invalid number `.5`"),
+        ("1.2.3s", "invalid number `1.2.3`"),
+        ("1x", "unknown unit `x`"),
+        ("1H", "unknown unit `H`"),
+        ("1M", "ambiguous unit `M`"),
+        ("2years", "ambiguous unit `years`"),
+        ("1m 2m", "repeated unit `m`"),
+        ("1m 2min", "repeated unit `min`"),
+        ("18446744073709551616s", "overflow"),
+        ("99999999999999999999999999999999999999999w", "overflow"),
+    ];
+    for &(s, message) in cases.iter() {
+        match fmt::parse_duration(s) {
+            Err(Error::ParseError(e)) => assert!(e.starts_with(message), "{}: {}", s, e),
+            r => panic!("{}: {:?}", s, r),
+        }
+    }
+}
+
+#[test]
+fn test_parse_duration_malformed() {
+    // Random strings over the alphabet of the grammar must be rejected or accepted, not panic.
+    let alphabet: Vec<char> = "0123456789. hmsdwunµMy-,".chars().collect();
+    let mut state = 0x9e37_79b9_7f4a_7c15u64;
+    for _ in 0..10_000 {
+        let mut s = String::new();
+        loop {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            if state.is_multiple_of(12) {
+                break;
+            }
+            s.push(alphabet[(state >> 32) as usize % alphabet.len()]);
+        }
+        if let Ok(d) = fmt::parse_duration(&s) {
+            let formatted = fmt::format_duration(d).to_string();
+            assert_eq!(fmt::parse_duration(&formatted), Ok(d), "{}", s);
+        }
+    }
+}
+
+#[test]
+fn test_process_duration_format_human() {
+    let d = ProcessDuration::new(
+        Duration::from_millis(7_230_500),
+        Duration::from_secs(7_150),
+        Duration::from_millis(12_300),
+    );
+    let s = d.format_human().to_string();
+    assert_eq!(
+        s,
+        "2h 30s 500ms wall, 1h 59m 10s user + 12s 300ms system = 1h 59m 22s 300ms CPU (99.1%)"
+    );
+    assert_eq!(ProcessDuration::parse_human(&s), Ok(d));
+
+    let s = ProcessDuration::ZERO.format_human().to_string();
+    assert_eq!(s, "0s wall, 0s user + 0s system = 0s CPU (--%)");
+    assert_eq!(ProcessDuration::parse_human(&s), Ok(ProcessDuration::ZERO));
+
+    for s in [
+        "",
+        "1m wall, 1m user + 0s system = 1m CPU (100.0%",
+        "1m wall, 1m user + 0s system = 2m CPU (100.0%)",
+        "1m wall, 1m user + 0s system = 1m CPU (99.0%)",
+        "1m wall, 1m user + 0 system = 1m CPU (100.0%)",
+        "1M wall, 1m user + 0s system = 1m CPU (100.0%)",
+    ]
+    .iter()
+    {
+        assert!(
+            matches!(ProcessDuration::parse_human(s), Err(Error::ParseError(_))),
+            "{}",
+            s
+        );
+    }
+}
@@ -0,0 +1,43 @@
+#![cfg(not(howlong_unsupported))]
+
+use howlong::{clock::*, Clock};
+
+// The backend is decided once for the whole process, so it is tested by a single test in its
+// own binary, see also `high_resolution_forced.rs`.
+#[test]
+fn test_high_resolution_backend() {
+    let backend = HighResolutionClock::backend();
+    if cfg!(have_steady_clock) {
+        assert_eq!(backend, BackendKind::Steady);
+        assert!(HighResolutionClock::is_steady());
+        assert!(HighResolutionClock::info().is_steady);
+    } else {
+        assert_eq!(backend, BackendKind::System);
+        assert!(!HighResolutionClock::is_steady());
+    }
+    let start = HighResolutionClock::now();
+    for _ in 0..100 {
+        assert_eq!(HighResolutionClock::backend(), backend);
+        assert!(HighResolutionClock::now() >= start);
+    }
+
+    // The backend cannot be switched once the clock has been read.
+    let other = match backend {
+        BackendKind::Steady => BackendKind::System,
+        BackendKind::System => BackendKind::Steady,
+    };
+    assert_eq!(HighResolutionClock::force_backend(other), Err(backend));
+    assert_eq!(HighResolutionClock::force_backend(backend), Ok(()));
+    assert_eq!(HighResolutionClock::backend(), backend);
+    assert!(HighResolutionClock::now() >= start);
+}
+
+#[cfg(have_steady_clock)]
+#[test]
+fn test_high_resolution_raw_now() {
+    let before = HighResolutionClock::now();
+    let raw = HighResolutionClock::raw_now().unwrap();
+    let after = HighResolutionClock::now();
+    let converted = HighResolutionClock::convert(raw);
+    assert!(before <= converted && converted <= after);
+}
@@ -0,0 +1,27 @@
+#![cfg(not(howlong_unsupported))]
+
+use howlong::{clock::*, Clock, Duration};
+
+// Forcing the backend affects the whole process and must happen before the first read, so it
+// is tested by a single test in its own binary.
+#[test]
+fn test_high_resolution_force_backend() {
+    assert_eq!(
+        HighResolutionClock::force_backend(BackendKind::System),
+        Ok(())
+    );
+    assert_eq!(HighResolutionClock::backend(), BackendKind::System);
+    assert!(!HighResolutionClock::is_steady());
+    assert_eq!(HighResolutionClock::info(), SystemClock::info());
+    let system = SystemClock::now();
+    let now = HighResolutionClock::now();
+    assert!(now.saturating_duration_since(system) < Duration::from_secs(1));
+
+    assert_eq!(
+        HighResolutionClock::force_backend(BackendKind::Steady),
+        Err(BackendKind::System)
+    );
+    assert_eq!(HighResolutionClock::backend(), BackendKind::System);
+    #[cfg(have_steady_clock)]
+    assert!(HighResolutionClock::raw_now().is_err());
+}
@@ -0,0 +1,148 @@
+use howlong::{Duration, DurationHistogram};
+
+fn us(n: u64) -> Duration {
+    Duration::from_micros(n)
+}
+
+/// Assert that `d` is within the relative error of two significant digits above `expected`.
+fn assert_near(d: Duration, expected: Duration) {
+    assert!(
+        d >= expected && d <= expected + expected / 100,
+        "{:?} is not near {:?}",
+        d,
+        expected
+    );
+}
+
+#[test]
+fn test_empty() {
+    let histogram = DurationHistogram::default();
+    assert_eq!(histogram.count(), 0);
+    assert_eq!(histogram.min(), Duration::ZERO);
+    assert_eq!(histogram.max(), Duration::ZERO);
+    assert_eq!(histogram.percentile(50.0), Duration::ZERO);
+    assert_eq!(histogram.to_string(), "no samples");
+    assert_eq!(format!("{:#}", histogram), "no samples");
+}
+
+#[test]
+fn test_uniform() {
+    let mut histogram = DurationHistogram::default();
+    for n in 1..=1000 {
+        histogram.record(us(n));
+    }
+    assert_eq!(histogram.count(), 1000);
+    assert_eq!(histogram.min(), us(1));
+    assert_eq!(histogram.max(), us(1000));
+    assert_near(histogram.percentile(0.0), us(1));
+    assert_near(histogram.percentile(0.1), us(1));
+    assert_near(histogram.percentile(50.0), us(500));
+    assert_near(histogram.percentile(90.0), us(900));
+    assert_near(histogram.percentile(95.0), us(950));
+    assert_near(histogram.percentile(99.0), us(990));
+    assert_eq!(histogram.percentile(100.0), us(1000));
+    assert_eq!(
+        histogram.to_string(),
+        "1000 samples: p50 502µs, p90 901µs, p95 950µs, p99 991µs, max 1.00ms"
+    );
+}
+
+#[test]
+fn test_bimodal() {
+    let mut histogram = DurationHistogram::default();
+    for n in 0..900 {
+        histogram.record(Duration::from_millis(1) + Duration::from_nanos(n));
+    }
+    for n in 0..100 {
+        histogram.record(Duration::from_millis(100) + us(n));
+    }
+    assert_near(histogram.percentile(50.0), Duration::from_millis(1));
+    assert_near(histogram.percentile(90.0), Duration::from_millis(1));
+    assert_near(histogram.percentile(90.1), Duration::from_millis(100));
+    assert_near(histogram.percentile(99.0), Duration::from_millis(100));
+    assert_eq!(
+        histogram.percentile(100.0),
+        Duration::from_millis(100) + us(99)
+    );
+    assert_eq!(format!("{:#}", histogram), "≤ 1.00ms: 900\n≤ 100ms: 100");
+}
+
+#[test]
+fn test_exact_below_precision() {
+    let mut histogram = DurationHistogram::default();
+    for n in 1..=100 {
+        histogram.record(Duration::from_nanos(n));
+    }
+    for p in 1..=100 {
+        assert_eq!(
+            histogram.percentile(p as f64),
+            Duration::from_nanos(p),
+            "p{}",
+            p
+        );
+    }
+}
+
+#[test]
+fn test_range() {
+    let mut histogram = DurationHistogram::new(us(1), Duration::from_secs(1), 3);
+    histogram.record(Duration::from_nanos(10));
+    histogram.record(Duration::from_secs(10));
+    histogram.record(Duration::MAX);
+    assert_eq!(histogram.count(), 3);
+    assert_eq!(histogram.min(), Duration::from_nanos(10));
+    assert_eq!(histogram.percentile(0.0), Duration::from_nanos(10));
+    assert_eq!(histogram.max(), Duration::MAX);
+    assert_near(histogram.percentile(50.0), Duration::from_secs(1));
+    assert_eq!(histogram.percentile(100.0), Duration::MAX);
+}
+
+#[test]
+fn test_merge() {
+    let mut a = DurationHistogram::default();
+    let mut b = DurationHistogram::default();
+    let mut all = DurationHistogram::default();
+    for n in 1..=500 {
+        a.record(us(n));
+        all.record(us(n));
+    }
+    for n in 501..=1000 {
+        b.record(us(n));
+        all.record(us(n));
+    }
+    let mut merged = a.clone();
+    merged.merge(&b);
+    assert_eq!(merged, all);
+    assert_eq!(merged.count(), 1000);
+    assert_eq!(merged.min(), us(1));
+    assert_eq!(merged.max(), us(1000));
+    assert_near(merged.percentile(75.0), us(750));
+
+    let mut empty = DurationHistogram::default();
+    empty.merge(&a);
+    assert_eq!(empty, a);
+    a.merge(&DurationHistogram::default());
+    assert_eq!(a.count(), 500);
+
+    merged.clear();
+    assert_eq!(merged, DurationHistogram::default());
+}
+
+#[test]
+#[should_panic(expected = "the histograms must have the same range and precision")]
+fn test_merge_mismatch() {
+    let mut a = DurationHistogram::default();
+    a.merge(&DurationHistogram::new(us(1), Duration::from_secs(3600), 2));
+}
+
+#[test]
+#[should_panic(expected = "the percentile must be between 0 and 100")]
+fn test_percentile_out_of_range() {
+    DurationHistogram::default().percentile(100.5);
+}
+
+#[test]
+#[should_panic(expected = "the significant digits must be between 1 and 5")]
+fn test_too_precise() {
+    DurationHistogram::new(us(1), us(2), 6);
+}
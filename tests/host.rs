@@ -0,0 +1,46 @@
+#![cfg(all(
+    any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "macos",
+        windows
+    ),
+    not(howlong_unsupported)
+))]
+
+use howlong::{clock::*, Clock, Duration, HostCPUTimer, TimePointSub};
+
+#[test]
+fn test_host_cpu_clock() {
+    let host_start = HostCPUClock::now();
+    let process_start = ProcessCPUClock::now();
+    let start = HighResolutionClock::now();
+    let mut x = 0u64;
+    while HighResolutionClock::now() - start < Duration::from_millis(500) {
+        x = howlong::bench::black_box(x.wrapping_add(1));
+    }
+    let process = ProcessCPUClock::now().saturating_sub(process_start);
+    let host = HostCPUClock::now().saturating_sub(host_start);
+
+    // The host times are sampled at the scheduler tick, which is coarser than the process
+    // times.
+    let slack = Duration::from_millis(100);
+    assert!(
+        host.cpu_time() + slack >= process.cpu_time(),
+        "{} {}",
+        host,
+        process
+    );
+    assert!(host.total() >= host.cpu_time());
+    let utilization = host.utilization();
+    assert!((0.0..=1.0).contains(&utilization), "{}", utilization);
+    assert!(HostCPUClock::resolution().unwrap() > Duration::ZERO);
+}
+
+#[test]
+fn test_host_cpu_timer() {
+    let timer = HostCPUTimer::new();
+    std::thread::sleep(Duration::from_millis(50));
+    let elapsed = timer.elapsed();
+    assert!(elapsed.total() > Duration::ZERO, "{}", elapsed);
+}
@@ -0,0 +1,92 @@
+#![cfg(not(howlong_unsupported))]
+
+use howlong::{clock::*, Clock, Duration, Instant, TimePoint};
+use std::collections::HashSet;
+use std::thread::sleep;
+
+#[test]
+fn test_now_elapsed() {
+    let instant = Instant::now();
+    let three_millis = Duration::from_millis(3);
+    sleep(three_millis);
+    assert!(instant.elapsed() >= three_millis);
+}
+
+#[test]
+fn test_duration_since() {
+    let now = Instant::now();
+    sleep(Duration::new(0, 1_000_000));
+    let new_now = Instant::now();
+    assert!(new_now.duration_since(now) >= Duration::from_millis(1));
+    assert_eq!(now.duration_since(new_now), Duration::ZERO);
+    assert_eq!(new_now - now, new_now.duration_since(now));
+    assert_eq!(now - new_now, Duration::ZERO);
+}
+
+#[test]
+fn test_checked_duration_since() {
+    let now = Instant::now();
+    sleep(Duration::new(0, 1_000_000));
+    let new_now = Instant::now();
+    assert!(new_now.checked_duration_since(now).is_some());
+    assert_eq!(now.checked_duration_since(new_now), None);
+}
+
+#[test]
+fn test_saturating_duration_since() {
+    let now = Instant::now();
+    sleep(Duration::new(0, 1_000_000));
+    let new_now = Instant::now();
+    assert!(new_now.saturating_duration_since(now) >= Duration::from_millis(1));
+    assert_eq!(now.saturating_duration_since(new_now), Duration::new(0, 0));
+}
+
+#[test]
+fn test_arithmetic() {
+    let now = Instant::now();
+    let second = Duration::from_secs(1);
+    let later = now + second;
+    assert!(later > now);
+    assert_eq!(later - now, second);
+    assert_eq!(later - second, now);
+    assert_eq!(now.checked_add(second), Some(later));
+    assert_eq!(later.checked_sub(second), Some(now));
+    assert_eq!(now.checked_add(Duration::MAX), None);
+    assert_eq!(now.checked_sub(Duration::MAX), None);
+
+    let mut instant = now;
+    instant += second;
+    assert_eq!(instant, later);
+    instant -= second;
+    assert_eq!(instant, now);
+}
+
+#[test]
+#[should_panic(expected = "overflow when adding duration to instant")]
+fn test_add_overflow() {
+    let _ = Instant::now() + Duration::MAX;
+}
+
+#[test]
+fn test_ord_hash() {
+    let first = Instant::now();
+    let second = first + Duration::from_nanos(1);
+    assert!(first < second);
+    assert_eq!(first.max(second), second);
+    let mut instants = vec![second, first, second];
+    instants.sort();
+    assert_eq!(instants, [first, second, second]);
+    let set: HashSet<Instant> = instants.into_iter().collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn test_time_point_conversion() {
+    let before = HighResolutionClock::now();
+    let instant = Instant::now();
+    let after = HighResolutionClock::now();
+    let point = TimePoint::from(instant);
+    assert!(before <= point && point <= after);
+    assert_eq!(Instant::from(point), instant);
+    assert_eq!(Instant::from(after) - Instant::from(before), after - before);
+}
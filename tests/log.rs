@@ -0,0 +1,95 @@
+#![cfg(feature = "log")]
+
+use howlong::{timer::*, LoggedScope};
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::sync::{Mutex, Once};
+
+struct CaptureLogger;
+
+static RECORDS: Mutex<Vec<(String, Level, String)>> = Mutex::new(Vec::new());
+
+impl Log for CaptureLogger {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        RECORDS.lock().unwrap().push((
+            record.target().to_owned(),
+            record.level(),
+            record.args().to_string(),
+        ));
+    }
+
+    fn flush(&self) {}
+}
+
+fn records_for(target: &str) -> Vec<(Level, String)> {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        log::set_logger(&CaptureLogger).unwrap();
+        log::set_max_level(LevelFilter::Trace);
+    });
+    RECORDS
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|(t, _, _)| t == target)
+        .map(|(_, level, msg)| (*level, msg.clone()))
+        .collect()
+}
+
+fn contains_duration(msg: &str) -> bool {
+    ["ns", "µs", "ms", "s"]
+        .iter()
+        .any(|unit| msg.ends_with(unit))
+}
+
+#[test]
+fn test_timer_log_elapsed() {
+    records_for("");
+    let timer = HighResolutionTimer::new();
+    timer.log_elapsed(Level::Info, "test_timer", "computation");
+    let records = records_for("test_timer");
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].0, Level::Info);
+    assert!(records[0].1.starts_with("computation: "));
+    assert!(contains_duration(&records[0].1));
+}
+
+#[test]
+fn test_process_cpu_timer_log_elapsed() {
+    records_for("");
+    let timer = ProcessCPUTimer::new();
+    timer.log_elapsed(Level::Warn, "test_process_cpu_timer", "computation");
+    let records = records_for("test_process_cpu_timer");
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].0, Level::Warn);
+    assert!(records[0].1.starts_with("computation: "));
+    assert!(records[0].1.contains(" wall, "));
+}
+
+#[test]
+fn test_thread_timer_log_elapsed() {
+    records_for("");
+    let timer = ThreadTimer::new();
+    timer.log_elapsed(Level::Debug, "test_thread_timer", "computation");
+    let records = records_for("test_thread_timer");
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].0, Level::Debug);
+    assert!(contains_duration(&records[0].1));
+}
+
+#[test]
+fn test_logged_scope() {
+    records_for("");
+    {
+        let _scope = LoggedScope::new(Level::Trace, "test_logged_scope", "scope");
+        assert!(records_for("test_logged_scope").is_empty());
+    }
+    let records = records_for("test_logged_scope");
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].0, Level::Trace);
+    assert!(records[0].1.starts_with("scope: "));
+    assert!(contains_duration(&records[0].1));
+}
@@ -0,0 +1,97 @@
+#![cfg(all(
+    feature = "metrics",
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+
+use howlong::{metrics::*, Duration, ProcessDuration};
+use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+use metrics_util::MetricKind;
+
+type Histogram = (String, Vec<(String, String)>, Vec<f64>);
+
+fn capture<F: FnOnce()>(f: F) -> Vec<Histogram> {
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    metrics::with_local_recorder(&recorder, f);
+    let mut result: Vec<_> = snapshotter
+        .snapshot()
+        .into_vec()
+        .into_iter()
+        .map(|(key, _, _, value)| {
+            assert_eq!(key.kind(), MetricKind::Histogram);
+            let labels = key
+                .key()
+                .labels()
+                .map(|l| (l.key().to_owned(), l.value().to_owned()))
+                .collect();
+            let values = match value {
+                DebugValue::Histogram(values) => values.into_iter().map(|v| v.0).collect(),
+                _ => unreachable!(),
+            };
+            (key.key().name().to_owned(), labels, values)
+        })
+        .collect();
+    result.sort_by(|a, b| a.0.cmp(&b.0));
+    result
+}
+
+#[test]
+fn test_record_duration() {
+    let metrics = capture(|| {
+        record_duration("request", Duration::from_millis(1500));
+        record_duration_with_labels(
+            "labeled",
+            Duration::from_millis(250),
+            &[("route", "/".to_owned())],
+        );
+    });
+    assert_eq!(metrics.len(), 2);
+    assert_eq!(metrics[0].0, "labeled_seconds");
+    assert_eq!(metrics[0].1, vec![("route".to_owned(), "/".to_owned())]);
+    assert!((metrics[0].2[0] - 0.25).abs() < 1e-9);
+    assert_eq!(metrics[1].0, "request_seconds");
+    assert!(metrics[1].1.is_empty());
+    assert!((metrics[1].2[0] - 1.5).abs() < 1e-9);
+}
+
+#[test]
+fn test_record_process_duration() {
+    let d = ProcessDuration {
+        real: Duration::from_millis(3000),
+        user: Duration::from_millis(2000),
+        system: Duration::from_millis(500),
+    };
+    let metrics = capture(|| {
+        record_process_duration_with_labels("job", d, &[("kind", "batch".to_owned())]);
+    });
+    let names: Vec<_> = metrics.iter().map(|m| m.0.as_str()).collect();
+    assert_eq!(
+        names,
+        vec!["job_system_seconds", "job_user_seconds", "job_wall_seconds"]
+    );
+    assert!((metrics[0].2[0] - 0.5).abs() < 1e-9);
+    assert!((metrics[1].2[0] - 2.0).abs() < 1e-9);
+    assert!((metrics[2].2[0] - 3.0).abs() < 1e-9);
+    for m in &metrics {
+        assert_eq!(m.1, vec![("kind".to_owned(), "batch".to_owned())]);
+    }
+}
+
+#[test]
+fn test_metered_scope() {
+    let metrics = capture(|| {
+        let _scope = MeteredScope::new("scope");
+        std::thread::sleep(Duration::from_millis(10));
+    });
+    let names: Vec<_> = metrics.iter().map(|m| m.0.as_str()).collect();
+    assert_eq!(
+        names,
+        vec![
+            "scope_system_seconds",
+            "scope_user_seconds",
+            "scope_wall_seconds"
+        ]
+    );
+    assert!(metrics[2].2[0] >= 0.0);
+    assert!(metrics[2].2[0] < 10.0);
+}
@@ -0,0 +1,103 @@
+#![cfg(feature = "std")]
+
+use howlong::mock::*;
+use howlong::{Clock, Duration, ProcessDuration};
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+#[test]
+fn test_mock_clock() {
+    MockClock::reset();
+    let start = MockClock::now();
+    assert_eq!(Duration::from(start), Duration::ZERO);
+    MockClock::advance(ms(3));
+    assert_eq!(MockClock::now() - start, ms(3));
+    MockClock::set(ms(1));
+    assert_eq!(MockClock::now().checked_duration_since(start), Some(ms(1)));
+    MockClock::reset();
+    assert_eq!(MockClock::now(), start);
+    assert_eq!(MockClock::resolution().unwrap(), Duration::from_nanos(1));
+}
+
+#[test]
+fn test_mock_clock_thread_local() {
+    MockClock::set(ms(100));
+    std::thread::spawn(|| {
+        assert_eq!(Duration::from(MockClock::now()), Duration::ZERO);
+        MockClock::advance(ms(5));
+    })
+    .join()
+    .unwrap();
+    assert_eq!(Duration::from(MockClock::now()), ms(100));
+}
+
+#[test]
+fn test_mock_timer() {
+    MockClock::set(ms(10));
+    let mut timer = MockTimer::new();
+    assert!(timer.is_running());
+    MockClock::advance(ms(7));
+    assert_eq!(timer.elapsed(), ms(7));
+
+    timer.stop();
+    assert!(timer.is_stopped());
+    MockClock::advance(ms(100));
+    assert_eq!(timer.elapsed(), ms(7));
+
+    timer.resume();
+    MockClock::advance(ms(5));
+    assert_eq!(timer.elapsed(), ms(12));
+    timer.resume();
+    MockClock::advance(ms(1));
+    assert_eq!(timer.elapsed(), ms(13));
+
+    timer.stop();
+    timer.stop();
+    assert_eq!(timer.elapsed(), ms(13));
+
+    timer.start();
+    assert_eq!(timer.elapsed(), Duration::ZERO);
+    MockClock::advance(ms(2));
+    timer.start();
+    assert_eq!(timer.elapsed(), ms(2));
+}
+
+#[test]
+fn test_mock_timer_backwards() {
+    MockClock::set(ms(10));
+    let timer = MockTimer::new();
+    MockClock::set(ms(4));
+    assert_eq!(timer.elapsed(), Duration::ZERO);
+    assert_eq!(timer.checked_elapsed(), None);
+}
+
+#[test]
+fn test_mock_process_timer() {
+    MockProcessClock::reset();
+    let timer = MockProcessTimer::new();
+    MockProcessClock::advance(ProcessDuration::new(ms(10), ms(6), ms(2)));
+    let elapsed = timer.elapsed();
+    assert_eq!(elapsed, ProcessDuration::new(ms(10), ms(6), ms(2)));
+    assert_eq!(elapsed.cpu_time(), ms(8));
+
+    MockProcessClock::set(ProcessDuration::new(ms(20), ms(1), ms(3)));
+    assert_eq!(
+        timer.checked_elapsed(),
+        Some(ProcessDuration::new(ms(20), ms(1), ms(3)))
+    );
+}
+
+#[test]
+fn test_mock_process_timer_regressed() {
+    MockProcessClock::set(ProcessDuration::new(ms(10), ms(6), ms(2)));
+    let mut timer = MockProcessTimer::new();
+    // Only the user-CPU time goes backwards.
+    MockProcessClock::set(ProcessDuration::new(ms(20), ms(5), ms(3)));
+    let elapsed = ProcessDuration::new(ms(10), ms(0), ms(1));
+    assert_eq!(timer.elapsed(), elapsed);
+    assert_eq!(timer.checked_elapsed(), None);
+    timer.stop();
+    assert_eq!(timer.elapsed(), elapsed);
+}
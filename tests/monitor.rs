@@ -0,0 +1,43 @@
+#![cfg(all(
+    feature = "std",
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+
+use howlong::{clock::*, Clock, CpuUsageMonitor, Duration};
+
+#[test]
+fn test_cpu_usage_monitor() {
+    let mut monitor = CpuUsageMonitor::start(Duration::from_millis(20));
+    let start = HighResolutionClock::now();
+    let mut x = 0u64;
+    while HighResolutionClock::now() - start < Duration::from_millis(300) {
+        x = howlong::bench::black_box(x.wrapping_add(1));
+    }
+    monitor.stop();
+    assert!(monitor.is_stopped());
+    assert!(monitor.latest().is_some());
+
+    let samples: Vec<_> = monitor.iter().map(Result::unwrap).collect();
+    assert!(!samples.is_empty());
+    for sample in &samples {
+        assert!(sample.real > Duration::ZERO);
+    }
+    let max = samples
+        .iter()
+        .map(|sample| sample.cpu_usage())
+        .fold(0.0, f64::max);
+    assert!(max > 0.5, "{:?}", samples);
+    assert!(monitor.recv().is_none());
+}
+
+#[test]
+fn test_cpu_usage_monitor_stop() {
+    let monitor = CpuUsageMonitor::start(Duration::from_secs(60));
+    // The first reading is only used as the starting point.
+    assert!(monitor.latest().is_none());
+    assert_eq!(monitor.try_iter().count(), 0);
+
+    let start = HighResolutionClock::now();
+    drop(monitor);
+    assert!(HighResolutionClock::now() - start < Duration::from_secs(5));
+}
@@ -0,0 +1,160 @@
+//! The API available without the `std` feature, used with clocks of your own. Run it with
+//! `cargo test --no-default-features --test no_std`.
+
+use core::sync::atomic::{AtomicU64, Ordering};
+use howlong::{
+    fmt, Clock, Duration, Error, ErrorKind, ProcessDuration, ProcessTimePoint, Result, TimePoint,
+    Timer,
+};
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+static TICKS: AtomicU64 = AtomicU64::new(0);
+
+/// A clock advancing one millisecond per reading, like a hardware counter.
+struct TickClock;
+
+impl Clock for TickClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<TimePoint> {
+        Ok(TimePoint::from(ms(TICKS.fetch_add(1, Ordering::SeqCst))))
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(ms(1))
+    }
+}
+
+/// A clock of the real, user-CPU, and system-CPU times advancing 1ms, 2ms, and 3ms per reading.
+struct ProcessTickClock;
+
+impl Clock for ProcessTickClock {
+    type Output = ProcessTimePoint;
+    type Duration = ProcessDuration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = true;
+
+    fn try_now() -> Result<ProcessTimePoint> {
+        static TICKS: AtomicU64 = AtomicU64::new(0);
+        let n = TICKS.fetch_add(1, Ordering::SeqCst);
+        Ok(ProcessTimePoint::new(ms(n), ms(2 * n), ms(3 * n)))
+    }
+}
+
+/// A clock stuck at its epoch.
+struct FixedClock;
+
+impl Clock for FixedClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = true;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<TimePoint> {
+        Ok(TimePoint::from(Duration::ZERO))
+    }
+
+    fn resolution() -> Result<Duration> {
+        Ok(ms(1))
+    }
+}
+
+/// A clock which cannot be read.
+struct BrokenClock;
+
+impl Clock for BrokenClock {
+    type Output = TimePoint;
+    type Duration = Duration;
+    const IS_STEADY: bool = false;
+    const IS_CPU_TIME: bool = false;
+
+    fn try_now() -> Result<TimePoint> {
+        Err(Error::unsupported("BrokenClock"))
+    }
+}
+
+#[test]
+fn test_timer() {
+    let mut timer = Timer::<TickClock, TimePoint, Duration>::new();
+    assert!(timer.is_running());
+    assert_eq!(timer.elapsed(), ms(1));
+    timer.stop();
+    assert!(timer.is_stopped());
+    assert_eq!(timer.elapsed(), ms(2));
+    assert_eq!(timer.snapshot().unwrap().elapsed(), ms(2));
+    timer.resume();
+    timer.stop();
+    assert_eq!(timer.elapsed(), ms(3));
+    assert_eq!(
+        Timer::<TickClock, TimePoint, Duration>::resolution(),
+        Ok(ms(1))
+    );
+}
+
+#[test]
+fn test_process_timer() {
+    let mut timer = Timer::<ProcessTickClock, ProcessTimePoint, ProcessDuration>::new();
+    timer.stop();
+    let d = timer.elapsed();
+    assert_eq!(d, ProcessDuration::new(ms(1), ms(2), ms(3)));
+    assert_eq!(d.cpu_time(), ms(5));
+    assert_eq!(
+        d.to_string(),
+        "1ms wall, 2ms user + 3ms system = 5ms CPU (500.0%)"
+    );
+    assert_eq!(d.to_string().parse::<ProcessDuration>(), Ok(d));
+    assert_eq!(
+        ProcessDuration::parse_human(&d.format_human().to_string()),
+        Ok(d)
+    );
+}
+
+#[test]
+fn test_clock_info() {
+    assert!(FixedClock::is_available());
+    assert!(!BrokenClock::is_available());
+    let info = FixedClock::info();
+    assert_eq!(info.name, "FixedClock");
+    assert!(info.is_steady);
+    assert_eq!(info.resolution, Some(ms(1)));
+}
+
+#[test]
+fn test_error() {
+    let e = BrokenClock::try_now().unwrap_err();
+    assert_eq!(e, Error::Unsupported("BrokenClock"));
+    assert_eq!(e.kind(), ErrorKind::Unsupported);
+    assert_eq!(
+        e.to_string(),
+        "`BrokenClock` is not supported by the system."
+    );
+    assert_eq!(
+        Error::system("clock_gettime", 22).to_string(),
+        "failed to call `clock_gettime` [errno: 22]"
+    );
+}
+
+#[test]
+fn test_fmt() {
+    let d = fmt::parse_duration("1h 30m 1.5s").unwrap();
+    assert_eq!(d, Duration::new(5401, 500_000_000));
+    assert_eq!(fmt::format_duration(d).to_string(), "1h 30m 1s 500ms");
+    assert_eq!(fmt::human(d).to_string(), "1h 30m");
+}
+
+#[test]
+#[cfg(not(feature = "std"))]
+fn test_unsupported_clocks() {
+    assert!(!howlong::clock::is_supported());
+    assert_eq!(
+        howlong::SystemClock::try_now().unwrap_err().kind(),
+        ErrorKind::Unsupported
+    );
+    assert!(!howlong::ProcessCPUClock::is_available());
+}
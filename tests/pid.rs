@@ -0,0 +1,93 @@
+#![cfg(all(
+    feature = "std",
+    any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "macos",
+        target_os = "ios",
+        windows
+    )
+))]
+
+use howlong::{clock::*, timer::*, Clock, Duration};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::process::{Command, Stdio};
+
+mod utils;
+
+const HELPER_ENV: &str = "HOWLONG_TEST_PID_HELPER";
+
+/// Burn CPU, report the own CPU time, and wait for stdin to be closed when spawned as a
+/// helper by the other tests.
+#[test]
+fn helper() {
+    if std::env::var_os(HELPER_ENV).is_some() {
+        for _ in 0..3 {
+            utils::black_box(utils::computation_task());
+        }
+        let t = ProcessCPUClock::now();
+        let d = howlong::ProcessDuration::from(t);
+        println!("cpu: {}", (d.user + d.system).as_nanos());
+        std::io::stdout().flush().unwrap();
+        let mut buf = Vec::new();
+        std::io::stdin().read_to_end(&mut buf).unwrap();
+    }
+}
+
+#[test]
+fn test_pid_cpu_clock() {
+    let mut child = Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", "helper", "--nocapture", "--test-threads=1"])
+        .env(HELPER_ENV, "1")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let clock = ProcessCPUClock::for_pid(child.id()).unwrap();
+    assert_eq!(clock.pid(), child.id());
+    let timer = PidCpuTimer::new(child.id()).unwrap();
+    let start = clock.now();
+
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+    let own_cpu = loop {
+        let mut line = String::new();
+        assert!(stdout.read_line(&mut line).unwrap() > 0);
+        // The line might be prefixed by the output of the test harness.
+        if let Some((_, cpu)) = line.trim().split_once("cpu: ") {
+            break Duration::from_nanos(cpu.parse().unwrap());
+        }
+    };
+
+    let now = clock.now();
+    let observed_cpu = now.saturating_sub(start).cpu_time();
+    let total = howlong::ProcessDuration::from(now);
+    println!(
+        "own: {:?}, observed: {:?}",
+        own_cpu,
+        total.user + total.system
+    );
+    assert!(observed_cpu > Duration::from_millis(50));
+    assert!(own_cpu.abs_diff(total.user + total.system) < Duration::from_millis(100));
+    assert!(timer.elapsed().real > Duration::from_nanos(0));
+
+    drop(child.stdin.take());
+    assert!(child.wait().unwrap().success());
+    // On Windows, the open handle keeps reporting the final CPU times of the process.
+    #[cfg(not(windows))]
+    assert!(clock.try_now().is_err());
+}
+
+#[test]
+fn test_pid_cpu_clock_nonexistent() {
+    assert!(ProcessCPUClock::for_pid(u32::MAX - 1).is_err());
+    assert!(PidCpuTimer::new(u32::MAX - 1).is_err());
+}
+
+#[test]
+fn test_pid_cpu_clock_self() {
+    let clock = ProcessCPUClock::for_pid(std::process::id()).unwrap();
+    let start = clock.now();
+    utils::black_box(utils::computation_task());
+    let elapsed = clock.now().saturating_sub(start);
+    assert!(elapsed.user > Duration::from_nanos(0));
+}
@@ -0,0 +1,50 @@
+#![cfg(all(feature = "std", target_os = "linux"))]
+
+use howlong::{clock::*, timer::*, Clock, Duration};
+
+mod utils;
+
+#[test]
+fn test_proc_stat_parse() {
+    let tick =
+        Duration::from_nanos(1_000_000_000 / unsafe { libc::sysconf(libc::_SC_CLK_TCK) } as u64);
+    let stat = b"1234 ((my prog) (x)) R 1 1234 1234 0 -1 4194560 100 0 0 0 \
+                 12 34 56 78 20 0 1 0 100 1000 100 18446744073709551615";
+    let stat = ProcStat::parse(stat).unwrap();
+    assert_eq!(stat.user, tick * 12);
+    assert_eq!(stat.system, tick * 34);
+    assert_eq!(stat.children_user, tick * 56);
+    assert_eq!(stat.children_system, tick * 78);
+
+    assert!(ProcStat::parse(b"1234 (prog) R 1 2 3").is_err());
+    assert!(ProcStat::parse(b"1234 prog R").is_err());
+}
+
+#[test]
+fn test_proc_stat_clock() {
+    let tick = ProcStatClock::resolution().unwrap();
+    let start = ProcStatClock::now();
+    let reference = ProcessCPUClock::now();
+    for _ in 0..3 {
+        utils::black_box(utils::computation_task());
+    }
+    let elapsed = ProcStatClock::now() - start;
+    let reference = ProcessCPUClock::now() - reference;
+    println!("{} vs {}", elapsed, reference);
+    let cpu = (elapsed.user + elapsed.system).as_nanos();
+    let reference_cpu = (reference.user + reference.system).as_nanos();
+    assert!(cpu > 0);
+    assert!(cpu.abs_diff(reference_cpu) <= 2 * tick.as_nanos());
+
+    let children = howlong::ProcessDuration::from(ProcStatClock::children().unwrap());
+    let stat = ProcStatClock::stat().unwrap();
+    assert_eq!(children.user, stat.children_user);
+    assert_eq!(children.system, stat.children_system);
+}
+
+#[test]
+fn test_proc_stat_timer() {
+    let timer = ProcStatTimer::new();
+    utils::black_box(utils::computation_task());
+    assert!(timer.elapsed().real > Duration::ZERO);
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "proptest")]
+
+use howlong::{
+    proptest::{duration_within, process_duration_within, DEFAULT_MAX},
+    Duration, ProcessDuration, ProcessTimePoint, TimePoint,
+};
+use proptest::{prelude::*, strategy::ValueTree, test_runner::TestRunner};
+
+fn components(d: &ProcessDuration) -> [Duration; 3] {
+    [d.real, d.user, d.system]
+}
+
+proptest! {
+    #[test]
+    fn test_process_duration_within(
+        (max, d) in duration_within(DEFAULT_MAX)
+            .prop_flat_map(|max| (Just(max), process_duration_within(max)))
+    ) {
+        for time in &components(&d) {
+            prop_assert!(*time <= max, "{:?} > {:?}", time, max);
+        }
+    }
+
+    #[test]
+    fn test_arbitrary(d in any::<ProcessDuration>(), t in any::<TimePoint>()) {
+        for time in &components(&d) {
+            prop_assert!(*time <= DEFAULT_MAX);
+        }
+        prop_assert!(Duration::from(t) <= DEFAULT_MAX);
+    }
+
+    #[test]
+    fn test_checked_sub(a in any::<ProcessDuration>(), b in any::<ProcessDuration>()) {
+        prop_assert_eq!((a + b).checked_sub(b), Some(a));
+        prop_assert_eq!((a + b) - a, b);
+        let fits = components(&a).iter().zip(&components(&b)).all(|(a, b)| a >= b);
+        prop_assert_eq!(a.checked_sub(b).is_some(), fits);
+        if let Some(d) = a.checked_sub(b) {
+            prop_assert_eq!(d, a.saturating_sub(b));
+            prop_assert_eq!(d + b, a);
+        }
+    }
+
+    #[test]
+    fn test_sum(durations in proptest::collection::vec(any::<ProcessDuration>(), 0..16)) {
+        let sum: ProcessDuration = durations.iter().sum();
+        prop_assert_eq!(sum, durations.iter().copied().sum());
+        prop_assert_eq!(sum.real, durations.iter().map(|d| d.real).sum::<Duration>());
+        prop_assert_eq!(sum.user, durations.iter().map(|d| d.user).sum::<Duration>());
+        prop_assert_eq!(sum.system, durations.iter().map(|d| d.system).sum::<Duration>());
+        if let Some(mean) = ProcessDuration::mean(&durations) {
+            prop_assert_eq!(mean, sum / durations.len() as u32);
+        }
+    }
+
+    #[test]
+    fn test_mul_div(d in any::<ProcessDuration>(), n in 0..64u32) {
+        prop_assert_eq!(d * n, std::iter::repeat_n(d, n as usize).sum());
+        let mut product = d;
+        product *= n;
+        prop_assert_eq!(product, d * n);
+        if n != 0 {
+            prop_assert_eq!(d * n / n, d);
+            let mut quotient = d;
+            quotient /= n;
+            prop_assert_eq!(quotient, d / n);
+            for (rounded, time) in components(&(d / n * n)).iter().zip(&components(&d)) {
+                prop_assert!(*time - *rounded < Duration::from_nanos(n.into()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_time_point(t in any::<TimePoint>(), d in duration_within(DEFAULT_MAX)) {
+        prop_assert_eq!((t + d) - t, d);
+        prop_assert_eq!((t + d).checked_sub(t), Some(d));
+        prop_assert_eq!(t.checked_sub(t + d).is_none(), !d.is_zero());
+    }
+
+    #[test]
+    fn test_process_time_point(p in any::<ProcessTimePoint>(), d in any::<ProcessDuration>()) {
+        prop_assert_eq!((p + d) - p, d);
+        prop_assert_eq!((p + d).checked_sub(p), Some(d));
+        prop_assert_eq!((p + d).checked_sub_duration(d), Some(p));
+    }
+}
+
+#[test]
+fn test_process_duration_within_distribution() {
+    let mut runner = TestRunner::deterministic();
+    let strategy = process_duration_within(Duration::from_secs(60));
+    let samples: Vec<_> = (0..1000)
+        .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+        .collect();
+    let count = |f: fn(&ProcessDuration) -> bool| samples.iter().filter(|d| f(d)).count();
+    assert!(count(|d| d.is_zero()) > 0);
+    assert!(count(|d| d.system.is_zero() && !d.user.is_zero()) > 0);
+    assert!(count(|d| d.cpu_time() > d.real) > 0);
+    assert!(count(|d| d.cpu_time() <= d.real) > 500);
+    assert!(count(|d| d.real < Duration::from_micros(1)) > 0);
+    assert!(count(|d| d.real > Duration::from_secs(1)) > 0);
+}
+
+#[test]
+fn test_process_duration_within_shrink() {
+    let mut runner = TestRunner::deterministic();
+    let strategy = process_duration_within(Duration::from_secs(60));
+    for _ in 0..100 {
+        let mut tree = strategy.new_tree(&mut runner).unwrap();
+        // Shrinking as long as a test keeps failing reaches zero.
+        while tree.simplify() {}
+        assert_eq!(tree.current(), ProcessDuration::ZERO);
+    }
+}
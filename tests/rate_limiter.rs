@@ -0,0 +1,74 @@
+#![cfg(all(
+    have_steady_clock,
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+
+use howlong::{clock::*, Clock, Duration, RateLimiter, SharedRateLimiter};
+use std::thread;
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+#[test]
+fn test_rate_limiter_wait() {
+    let mut limiter = RateLimiter::new(1000.0, 1);
+    let start = SteadyClock::now();
+    for _ in 0..100 {
+        limiter.wait();
+    }
+    // The first permit is available immediately.
+    let elapsed = SteadyClock::now() - start;
+    assert!(elapsed >= ms(99), "{:?}", elapsed);
+    assert!(elapsed < ms(150), "{:?}", elapsed);
+}
+
+#[test]
+fn test_rate_limiter_check() {
+    let mut limiter = RateLimiter::new(10.0, 3);
+    assert!(limiter.available() >= 3.0 - f64::EPSILON);
+    assert!(limiter.check());
+    assert!(limiter.check());
+    assert!(limiter.check());
+    assert!(!limiter.check());
+    assert!(limiter.available() < 1.0);
+
+    thread::sleep(ms(110));
+    assert!(limiter.available() >= 1.0);
+    assert!(limiter.check());
+    assert!(!limiter.check());
+
+    // The bucket never holds more than `burst` tokens.
+    thread::sleep(ms(500));
+    assert!(limiter.available() <= 3.0);
+}
+
+#[test]
+fn test_shared_rate_limiter() {
+    let limiter = SharedRateLimiter::new(1000.0, 1);
+    let start = SteadyClock::now();
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let limiter = limiter.clone();
+            thread::spawn(move || {
+                for _ in 0..25 {
+                    limiter.wait();
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    let elapsed = SteadyClock::now() - start;
+    assert!(elapsed >= ms(99), "{:?}", elapsed);
+    assert!(elapsed < ms(200), "{:?}", elapsed);
+    assert!(!limiter.check());
+    assert!(limiter.available() < 1.0);
+}
+
+#[test]
+#[should_panic]
+fn test_rate_limiter_zero_rate() {
+    let _ = RateLimiter::new(0.0, 1);
+}
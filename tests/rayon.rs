@@ -0,0 +1,75 @@
+#![cfg(feature = "rayon")]
+
+use howlong::{clock::ThreadClock, rayon::PoolCpuTimer, Duration, ProcessCPUTimer, ThreadTimer};
+use rayon::prelude::*;
+
+mod utils;
+
+#[test]
+fn test_pool_cpu_timer() {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(4)
+        .build()
+        .unwrap();
+    let thread_timer = ThreadTimer::new();
+    let process_timer = ProcessCPUTimer::new();
+    let pool_timer = PoolCpuTimer::install(&pool);
+    assert_eq!(pool_timer.elapsed_per_worker().len(), 4);
+
+    pool.install(|| {
+        (0..16)
+            .into_par_iter()
+            .for_each(|_| utils::busy_loop::<ThreadClock>(Duration::from_millis(25)))
+    });
+    let pool_cpu = pool_timer.elapsed();
+    let process = process_timer.elapsed();
+    let thread_cpu = thread_timer.elapsed();
+
+    assert!(pool_cpu >= Duration::from_millis(400), "{:?}", pool_cpu);
+    // The workers are nearly all of the CPU time of the process, mostly spent in user mode.
+    assert!(
+        pool_cpu <= process.cpu_time(),
+        "{:?} > {}",
+        pool_cpu,
+        process
+    );
+    assert!(
+        pool_cpu >= process.user.mul_f64(0.8),
+        "{:?} < {}",
+        pool_cpu,
+        process
+    );
+    assert!(
+        thread_cpu * 10 < pool_cpu,
+        "{:?} {:?}",
+        thread_cpu,
+        pool_cpu
+    );
+    let later: Duration = pool_timer.elapsed_per_worker().into_iter().sum();
+    assert!(later >= pool_cpu);
+}
+
+#[test]
+fn test_pool_cpu_timer_idle_workers() {
+    // Workers which have not run any job are included, and idle ones consume nearly nothing.
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(3)
+        .build()
+        .unwrap();
+    let mut timer = PoolCpuTimer::install(&pool);
+    assert!(timer.elapsed() < Duration::from_millis(50));
+
+    pool.install(|| utils::busy_loop::<ThreadClock>(Duration::from_millis(50)));
+    let elapsed = timer.elapsed_per_worker();
+    assert_eq!(elapsed.len(), 3);
+    assert!(
+        elapsed.iter().any(|d| *d >= Duration::from_millis(50)),
+        "{:?}",
+        elapsed
+    );
+    assert!(timer.elapsed() >= Duration::from_millis(50));
+
+    timer.restart();
+    assert!(timer.elapsed() < Duration::from_millis(50));
+    assert!(std::ptr::eq(timer.pool(), &pool));
+}
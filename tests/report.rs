@@ -0,0 +1,438 @@
+#![cfg(feature = "std")]
+
+use howlong::{
+    bench::Stats,
+    clock::SystemClock,
+    report::{CsvWriter, FlushPolicy, FoldedUnit, Report, ScopeTree, TimerLogger},
+    Duration, ProcessDuration,
+};
+use serde_json::Value;
+
+fn durations() -> Vec<ProcessDuration> {
+    vec![
+        ProcessDuration::new(
+            Duration::from_millis(5710),
+            Duration::from_millis(5700),
+            Duration::from_nanos(0),
+        ),
+        ProcessDuration::new(
+            Duration::from_nanos(1),
+            Duration::from_nanos(123_456_789_012),
+            Duration::from_nanos(987),
+        ),
+        ProcessDuration::ZERO,
+        ProcessDuration::new(Duration::MAX, Duration::MAX, Duration::from_nanos(0)),
+    ]
+}
+
+fn stats() -> Stats {
+    Stats {
+        iterations: 100,
+        total: Duration::from_micros(1200),
+        min: Duration::from_micros(10),
+        max: Duration::from_micros(20),
+        mean: Duration::from_micros(12),
+        median: Duration::from_micros(11),
+        stddev: Duration::from_nanos(1001),
+    }
+}
+
+fn nanos(value: &Value) -> Duration {
+    let nanos = value.as_u64().unwrap();
+    Duration::new(nanos / 1_000_000_000, (nanos % 1_000_000_000) as u32)
+}
+
+#[test]
+fn test_process_duration_to_json() {
+    for d in durations().into_iter().take(3) {
+        let json: Value = serde_json::from_str(&d.to_json()).unwrap();
+        assert_eq!(json.as_object().unwrap().len(), 4);
+        let parsed = ProcessDuration::new(
+            nanos(&json["real_ns"]),
+            nanos(&json["user_ns"]),
+            nanos(&json["system_ns"]),
+        );
+        assert_eq!(parsed, d);
+        match d.checked_cpu_usage() {
+            Some(usage) => {
+                let pct = json["cpu_pct"].as_f64().unwrap();
+                assert!((pct - usage * 100.0).abs() <= 0.05);
+            }
+            None => assert!(json["cpu_pct"].is_null()),
+        }
+    }
+
+    assert_eq!(
+        durations()[0].to_json(),
+        r#"{"real_ns":5710000000,"user_ns":5700000000,"system_ns":0,"cpu_pct":99.8}"#
+    );
+    // Too large for u64, but still valid JSON with exact integers.
+    let json: Value = serde_json::from_str(&durations()[3].to_json()).unwrap();
+    assert!(json["real_ns"].is_number());
+}
+
+#[test]
+fn test_stats_to_json() {
+    let stats = stats();
+    let json: Value = serde_json::from_str(&stats.to_json()).unwrap();
+    assert_eq!(json.as_object().unwrap().len(), 7);
+    assert_eq!(json["iterations"].as_u64(), Some(stats.iterations));
+    assert_eq!(nanos(&json["total_ns"]), stats.total);
+    assert_eq!(nanos(&json["min_ns"]), stats.min);
+    assert_eq!(nanos(&json["max_ns"]), stats.max);
+    assert_eq!(nanos(&json["mean_ns"]), stats.mean);
+    assert_eq!(nanos(&json["median_ns"]), stats.median);
+    assert_eq!(nanos(&json["stddev_ns"]), stats.stddev);
+}
+
+#[test]
+fn test_csv_writer_process_duration() {
+    let mut writer = CsvWriter::new(Vec::new());
+    writer.write_header().unwrap();
+    for (i, d) in durations().iter().enumerate() {
+        writer.write_row(&format!("run {}", i), d).unwrap();
+    }
+    writer.flush().unwrap();
+    let csv = String::from_utf8(writer.into_inner()).unwrap();
+
+    let mut lines = csv.lines();
+    assert_eq!(
+        lines.next(),
+        Some("label,real_ns,user_ns,system_ns,cpu_pct")
+    );
+    for (i, d) in durations().iter().enumerate() {
+        let fields: Vec<&str> = lines.next().unwrap().split(',').collect();
+        assert_eq!(fields.len(), 5);
+        assert_eq!(fields[0], format!("run {}", i));
+        assert_eq!(fields[1], d.real.as_nanos().to_string());
+        assert_eq!(fields[2], d.user.as_nanos().to_string());
+        assert_eq!(fields[3], d.system.as_nanos().to_string());
+        match d.checked_cpu_usage() {
+            Some(usage) => {
+                let pct: f64 = fields[4].parse().unwrap();
+                assert!((pct - usage * 100.0).abs() <= 0.05);
+            }
+            None => assert_eq!(fields[4], ""),
+        }
+    }
+    assert_eq!(lines.next(), None);
+}
+
+#[test]
+fn test_csv_writer_stats() {
+    let stats = stats();
+    let mut writer = CsvWriter::new(Vec::new());
+    writer.write_header().unwrap();
+    writer.write_row("sum", &stats).unwrap();
+    let csv = String::from_utf8(writer.into_inner()).unwrap();
+    assert_eq!(
+        csv,
+        "label,iterations,total_ns,min_ns,max_ns,mean_ns,median_ns,stddev_ns\n\
+         sum,100,1200000,10000,20000,12000,11000,1001\n"
+    );
+}
+
+#[test]
+fn test_csv_writer_quotes_label() {
+    let mut writer = CsvWriter::new(Vec::new());
+    for label in ["a,b", "say \"hi\"", "two\nlines", "plain"] {
+        writer.write_row(label, &ProcessDuration::ZERO).unwrap();
+    }
+    let csv = String::from_utf8(writer.into_inner()).unwrap();
+    assert_eq!(
+        csv,
+        "\"a,b\",0,0,0,\n\"say \"\"hi\"\"\",0,0,0,\n\"two\nlines\",0,0,0,\nplain,0,0,0,\n"
+    );
+}
+
+fn report() -> Report {
+    let ms = Duration::from_millis;
+    let mut report = Report::new();
+    report.add("link", ProcessDuration::new(ms(1800), ms(1200), ms(400)));
+    report.add("compile", ProcessDuration::new(ms(3200), ms(3100), ms(50)));
+    report.add(
+        "generate the documentation of every crate",
+        ProcessDuration::new(Duration::from_micros(250), Duration::ZERO, Duration::ZERO),
+    );
+    report.add("idle", ProcessDuration::ZERO);
+    report
+}
+
+#[test]
+fn test_report_table() {
+    let report = report();
+    assert_eq!(
+        report.total(),
+        ProcessDuration::new(
+            Duration::from_micros(5_000_250),
+            Duration::from_millis(4300),
+            Duration::from_millis(450)
+        )
+    );
+    assert_eq!(
+        report.to_string(),
+        "\
+phase                      wall   user  system    cpu   share
+compile                   3.20s  3.10s  50.0ms  98.4%   64.0%
+link                      1.80s  1.20s   400ms  88.9%   36.0%
+generate the documentat…  250µs    0ns     0ns   0.0%    0.0%
+idle                        0ns    0ns     0ns      -    0.0%
+-------------------------------------------------------------
+total                     5.00s  4.30s   450ms  95.0%  100.0%"
+    );
+
+    let mut report = Report::new();
+    report.add("a", ProcessDuration::ZERO);
+    assert_eq!(
+        report.to_string(),
+        "\
+phase  wall  user  system  cpu  share
+a       0ns   0ns     0ns    -      -
+-------------------------------------
+total   0ns   0ns     0ns    -      -"
+    );
+}
+
+#[test]
+fn test_report_empty() {
+    let report = Report::new();
+    assert!(report.is_empty());
+    assert_eq!(report.total(), ProcessDuration::ZERO);
+    assert_eq!(report.to_string(), "no phases recorded");
+    assert_eq!(report.to_csv(), "label,real_ns,user_ns,system_ns,cpu_pct\n");
+    assert_eq!(
+        report.to_json(),
+        r#"{"phases":[],"total":{"real_ns":0,"user_ns":0,"system_ns":0,"cpu_pct":null}}"#
+    );
+}
+
+#[test]
+fn test_report_csv_json() {
+    let report = report();
+    assert_eq!(report.phases()[0].0, "link");
+    assert_eq!(
+        report.to_csv(),
+        "\
+label,real_ns,user_ns,system_ns,cpu_pct
+link,1800000000,1200000000,400000000,88.9
+compile,3200000000,3100000000,50000000,98.4
+generate the documentation of every crate,250000,0,0,0.0
+idle,0,0,0,
+"
+    );
+
+    let json: Value = serde_json::from_str(&report.to_json()).unwrap();
+    let phases = json["phases"].as_array().unwrap();
+    assert_eq!(phases.len(), 4);
+    for (phase, (name, d)) in phases.iter().zip(report.phases()) {
+        assert_eq!(phase["name"], name.as_str());
+        assert_eq!(
+            phase["duration"],
+            serde_json::from_str::<Value>(&d.to_json()).unwrap()
+        );
+    }
+    assert_eq!(json["total"]["real_ns"], 5_000_250_000u64);
+
+    let mut report = Report::new();
+    report.add("a \"quoted\"\\\n name", ProcessDuration::ZERO);
+    let json: Value = serde_json::from_str(&report.to_json()).unwrap();
+    assert_eq!(json["phases"][0]["name"], "a \"quoted\"\\\n name");
+}
+
+#[test]
+fn test_report_overflow() {
+    let mut report = Report::new();
+    let max = ProcessDuration::new(Duration::MAX, Duration::ZERO, Duration::ZERO);
+    report.add("a", max);
+    report.add("b", max);
+    assert!(std::panic::catch_unwind(|| report.total()).is_err());
+    assert!(report.to_string().ends_with("100.0%"));
+    let json: Value = serde_json::from_str(&report.to_json()).unwrap();
+    assert_eq!(json["total"], json["phases"][0]["duration"]);
+}
+
+fn scope_tree() -> ScopeTree {
+    let ms = Duration::from_millis;
+    let mut tree = ScopeTree::new("main");
+    tree.record(ms(10));
+    let parse = tree.child_mut("parse");
+    parse.record(ms(1));
+    parse.record(ms(3));
+    let lex = parse.child_mut("lex");
+    lex.record(ms(1));
+    lex.record(Duration::from_micros(500));
+    tree.child_mut("eval").record(ms(5));
+    // The children took longer than the node, e.g. they ran in parallel.
+    let io = tree.child_mut("io");
+    io.record(Duration::from_micros(200));
+    io.child_mut("read").record(Duration::from_micros(150));
+    io.child_mut("write").record(Duration::from_micros(100));
+    tree
+}
+
+#[test]
+fn test_scope_tree() {
+    let tree = scope_tree();
+    assert_eq!(tree.name(), "main");
+    assert_eq!(tree.count(), 1);
+    assert_eq!(
+        tree.children()
+            .iter()
+            .map(ScopeTree::name)
+            .collect::<Vec<_>>(),
+        ["parse", "eval", "io"]
+    );
+    assert_eq!(tree.self_time(), Duration::from_micros(800));
+
+    let parse = tree.child("parse").unwrap();
+    assert_eq!(parse.count(), 2);
+    assert_eq!(parse.total(), Duration::from_millis(4));
+    assert_eq!(parse.self_time(), Duration::from_micros(2500));
+    let lex = parse.child("lex").unwrap();
+    assert_eq!(lex.total(), Duration::from_micros(1500));
+    assert_eq!(lex.self_time(), lex.total());
+    assert!(tree.child("lex").is_none());
+
+    let io = tree.child("io").unwrap();
+    assert_eq!(io.self_time(), Duration::ZERO);
+    assert_eq!(io.children().len(), 2);
+}
+
+#[test]
+fn test_scope_tree_display() {
+    assert_eq!(
+        scope_tree().to_string(),
+        "\
+main 10.0ms 100.0% (self 800µs, 1 call)
+  parse 4.00ms 40.0% (self 2.50ms, 2 calls)
+    lex 1.50ms 15.0% (self 1.50ms, 2 calls)
+  eval 5.00ms 50.0% (self 5.00ms, 1 call)
+  io 200µs 2.0% (self 0ns, 1 call)
+    read 150µs 1.5% (self 150µs, 1 call)
+    write 100µs 1.0% (self 100µs, 1 call)"
+    );
+
+    let mut tree = ScopeTree::new("empty");
+    tree.child_mut("child");
+    assert_eq!(
+        tree.to_string(),
+        "empty 0ns - (self 0ns, 0 calls)\n  child 0ns - (self 0ns, 0 calls)"
+    );
+}
+
+#[test]
+fn test_scope_tree_write_folded() {
+    let folded = |unit| {
+        let mut folded = Vec::new();
+        scope_tree().write_folded(&mut folded, unit).unwrap();
+        String::from_utf8(folded).unwrap()
+    };
+    assert_eq!(FoldedUnit::default(), FoldedUnit::Microseconds);
+    assert_eq!(
+        folded(FoldedUnit::Microseconds),
+        "\
+main 800
+main;parse 2500
+main;parse;lex 1500
+main;eval 5000
+main;io;read 150
+main;io;write 100
+"
+    );
+    assert_eq!(
+        folded(FoldedUnit::Milliseconds),
+        "\
+main;parse 2
+main;parse;lex 1
+main;eval 5
+"
+    );
+    assert_eq!(
+        folded(FoldedUnit::Nanoseconds).lines().next(),
+        Some("main 800000")
+    );
+
+    let mut folded = Vec::new();
+    ScopeTree::new("idle")
+        .write_folded(&mut folded, FoldedUnit::Nanoseconds)
+        .unwrap();
+    assert!(folded.is_empty());
+
+    let mut tree = ScopeTree::new("a;b c");
+    tree.record(Duration::from_micros(2));
+    tree.child_mut("d\ne\tf").record(Duration::from_micros(1));
+    let mut folded = Vec::new();
+    tree.write_folded(&mut folded, FoldedUnit::Microseconds)
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(folded).unwrap(),
+        "a_b_c 1\na_b_c;d_e_f 1\n"
+    );
+}
+
+#[test]
+fn test_timer_logger() {
+    let path = std::env::temp_dir().join(format!("howlong-logger-{}.csv", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let start = SystemClock::unix_nanos().unwrap();
+    {
+        let mut logger = TimerLogger::create(&path).unwrap();
+        for _ in 0..3 {
+            let _scope = logger.log_scope("iteration");
+            std::thread::sleep(Duration::from_millis(2));
+        }
+        logger.log_scope("a \"quoted\", label").finish().unwrap();
+    }
+    {
+        // The header is not written again to an existing file.
+        let mut logger = TimerLogger::create(&path)
+            .unwrap()
+            .with_flush_policy(FlushPolicy::Interval(Duration::from_secs(60)));
+        logger.log("reopened", &durations()[0]).unwrap();
+    }
+    let end = SystemClock::unix_nanos().unwrap();
+
+    let csv = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let mut lines = csv.lines();
+    assert_eq!(
+        lines.next(),
+        Some("unix_ns,label,real_ns,user_ns,system_ns")
+    );
+    let rows: Vec<_> = lines.collect();
+    assert_eq!(rows.len(), 5);
+    let mut previous = start;
+    for (i, row) in rows.iter().enumerate() {
+        let (unix_ns, rest) = row.split_once(',').unwrap();
+        let unix_ns: u128 = unix_ns.parse().unwrap();
+        assert!(previous <= unix_ns && unix_ns <= end);
+        previous = unix_ns;
+        // The label may contain commas, but the times do not.
+        let mut fields: Vec<_> = rest.rsplitn(4, ',').collect();
+        let label = fields.pop().unwrap();
+        let times: Vec<u64> = fields.iter().rev().map(|f| f.parse().unwrap()).collect();
+        assert_eq!(times.len(), 3);
+        match i {
+            0..=2 => {
+                assert_eq!(label, "iteration");
+                assert!(times[0] >= 2_000_000, "{}", row);
+            }
+            3 => assert_eq!(label, r#""a ""quoted"", label""#),
+            _ => {
+                assert_eq!(label, "reopened");
+                assert_eq!(times, [5_710_000_000, 5_700_000_000, 0]);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_timer_logger_flush_policy() {
+    let mut logger = TimerLogger::from_writer(std::io::BufWriter::new(Vec::new()))
+        .unwrap()
+        .with_flush_policy(FlushPolicy::Interval(Duration::from_secs(3600)));
+    logger.log("a", &ProcessDuration::ZERO).unwrap();
+    let csv = String::from_utf8(logger.into_inner().unwrap().into_inner().unwrap()).unwrap();
+    assert_eq!(csv.lines().count(), 2);
+    assert!(csv.ends_with(",a,0,0,0\n"), "{}", csv);
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "std")]
+
+use howlong::{
+    report::TimerLogger,
+    reporting::{self, ReportScope},
+};
+use std::sync::{Mutex, MutexGuard};
+
+// The switch is global, so that the tests toggling it must not run concurrently.
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn lock() -> MutexGuard<'static, ()> {
+    LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn report(label: &str, force: bool) -> String {
+    let mut output = Vec::new();
+    {
+        let scope = ReportScope::with_writer(label, &mut output);
+        if force {
+            scope.force();
+        }
+    }
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn test_report_scope() {
+    let _lock = lock();
+    reporting::set_enabled(true);
+    assert!(reporting::is_enabled());
+    let output = report("enabled", false);
+    assert!(output.starts_with("enabled: "), "{}", output);
+    assert!(output.ends_with("%)\n"), "{}", output);
+    assert_eq!(output.lines().count(), 1);
+
+    reporting::set_enabled(false);
+    assert!(!reporting::is_enabled());
+    assert_eq!(report("disabled", false), "");
+    assert!(report("forced", true).starts_with("forced: "));
+
+    reporting::set_enabled(true);
+    assert!(report("reenabled", false).starts_with("reenabled: "));
+
+    // A guard constructed while the reporting is disabled does not measure anything.
+    reporting::set_enabled(false);
+    let mut output = Vec::new();
+    {
+        let _scope = ReportScope::with_writer("silenced", &mut output);
+        reporting::set_enabled(true);
+    }
+    assert!(output.is_empty());
+}
+
+#[test]
+fn test_logger_scope() {
+    let _lock = lock();
+    let mut logger = TimerLogger::from_writer(Vec::new()).unwrap();
+    reporting::set_enabled(false);
+    drop(logger.log_scope("disabled"));
+    drop(logger.log_scope("forced").force());
+    logger.log_scope("silenced").finish().unwrap();
+    logger.log_scope("finished").force().finish().unwrap();
+    reporting::set_enabled(true);
+    drop(logger.log_scope("enabled"));
+
+    let csv = String::from_utf8(logger.into_inner().unwrap()).unwrap();
+    let labels: Vec<_> = csv
+        .lines()
+        .skip(1)
+        .map(|row| row.split(',').nth(1).unwrap())
+        .collect();
+    assert_eq!(labels, ["forced", "finished", "enabled"]);
+}
@@ -0,0 +1,19 @@
+#![cfg(feature = "std")]
+
+use howlong::reporting::{self, ReportScope};
+
+#[test]
+fn test_env_var() {
+    // The only test of this binary, so that the switch has not been read yet.
+    std::env::set_var(reporting::ENV_VAR, " OFF ");
+    assert!(!reporting::is_enabled());
+    let mut output = Vec::new();
+    drop(ReportScope::with_writer("disabled", &mut output));
+    assert!(output.is_empty());
+
+    // The variable is read only once.
+    std::env::set_var(reporting::ENV_VAR, "1");
+    assert!(!reporting::is_enabled());
+    reporting::set_enabled(true);
+    assert!(reporting::is_enabled());
+}
@@ -0,0 +1,50 @@
+#![cfg(all(
+    feature = "std",
+    unix,
+    not(any(target_os = "fuchsia", target_os = "haiku"))
+))]
+
+use howlong::{clock::*, timer::*, Clock, Duration};
+
+mod utils;
+
+#[test]
+fn test_rusage_clocks() {
+    let tick = Duration::from_millis(10);
+    let start = RusageUserCPUClock::now();
+    let reference = ProcessUserCPUClock::now();
+    let cpu_start = RusageCPUClock::now();
+    utils::busy_loop::<HighResolutionClock>(Duration::from_millis(100));
+    let elapsed = RusageUserCPUClock::now() - start;
+    let reference = ProcessUserCPUClock::now() - reference;
+    let cpu = RusageCPUClock::now() - cpu_start;
+    assert!(elapsed > Duration::from_nanos(0));
+    let diff = elapsed.as_nanos().abs_diff(reference.as_nanos());
+    assert!(diff <= tick.as_nanos(), "{:?} vs {:?}", elapsed, reference);
+    assert!(
+        cpu.user + tick >= elapsed,
+        "{:?} vs {:?}",
+        cpu.user,
+        elapsed
+    );
+    assert!(cpu.real >= Duration::from_millis(100));
+    let system = RusageSystemCPUClock::now();
+    assert!(RusageSystemCPUClock::now() >= system);
+    assert_eq!(
+        RusageUserCPUClock::resolution().unwrap(),
+        Duration::from_micros(1)
+    );
+}
+
+#[test]
+fn test_rusage_timers() {
+    let timer = RusageCPUTimer::new();
+    let user_timer = RusageUserCPUTimer::new();
+    let system_timer = RusageSystemCPUTimer::new();
+    let children_timer = RusageChildrenCPUTimer::new();
+    utils::busy_loop::<HighResolutionClock>(Duration::from_millis(20));
+    assert!(timer.elapsed().user > Duration::from_nanos(0));
+    assert!(user_timer.elapsed() > Duration::from_nanos(0));
+    assert!(system_timer.elapsed() < Duration::from_millis(20));
+    assert_eq!(children_timer.elapsed().user, Duration::from_nanos(0));
+}
@@ -0,0 +1,126 @@
+#![cfg(feature = "serde")]
+
+use howlong::{timer::*, Duration, Error, ProcessDuration, ProcessStats};
+use std::thread;
+
+#[test]
+fn test_process_duration_round_trip() {
+    let d = ProcessDuration::new(
+        Duration::new(5, 710_000_001),
+        Duration::new(5, 700_000_002),
+        Duration::new(0, 3),
+    );
+
+    let json = serde_json::to_string(&d).unwrap();
+    let from_json: ProcessDuration = serde_json::from_str(&json).unwrap();
+    assert_eq!(from_json, d);
+
+    let bin = bincode::serialize(&d).unwrap();
+    let from_bin: ProcessDuration = bincode::deserialize(&bin).unwrap();
+    assert_eq!(from_bin, d);
+}
+
+#[test]
+fn test_system_timer_snapshot() {
+    let mut timer = SystemTimer::new();
+    assert!(matches!(timer.snapshot(), Err(Error::TimerRunning)));
+    thread::sleep(Duration::from_millis(10));
+    timer.stop();
+    let snapshot = timer.snapshot().unwrap();
+    assert_eq!(snapshot.elapsed(), timer.elapsed());
+
+    let json = serde_json::to_string(&snapshot).unwrap();
+    let restored = SystemTimer::from_snapshot(serde_json::from_str(&json).unwrap());
+    assert!(restored.is_stopped());
+    assert_eq!(restored.elapsed(), timer.elapsed());
+
+    let bin = bincode::serialize(&snapshot).unwrap();
+    let mut restored = SystemTimer::from_snapshot(bincode::deserialize(&bin).unwrap());
+    assert!(restored.is_stopped());
+    assert_eq!(restored.elapsed(), timer.elapsed());
+
+    restored.resume();
+    thread::sleep(Duration::from_millis(10));
+    restored.stop();
+    assert!(restored.elapsed() >= timer.elapsed() + Duration::from_millis(10));
+}
+
+#[test]
+fn test_process_cpu_timer_snapshot() {
+    let mut timer = ProcessCPUTimer::new();
+    timer.stop();
+    let snapshot = timer.snapshot().unwrap();
+    let elapsed = timer.elapsed();
+
+    let json = serde_json::to_string(&snapshot).unwrap();
+    let restored = ProcessCPUTimer::from_snapshot(serde_json::from_str(&json).unwrap()).elapsed();
+    assert_eq!(restored, elapsed);
+
+    let bin = bincode::serialize(&snapshot).unwrap();
+    let restored = ProcessCPUTimer::from_snapshot(bincode::deserialize(&bin).unwrap()).elapsed();
+    assert_eq!(restored, elapsed);
+}
+
+#[test]
+fn test_steady_timer_resume_snapshot() {
+    let elapsed = Duration::from_secs(100_000);
+    let json = serde_json::json!({ "elapsed": elapsed });
+    let mut timer = SteadyTimer::from_snapshot(serde_json::from_value(json).unwrap());
+    assert_eq!(timer.elapsed(), elapsed);
+
+    timer.resume();
+    assert!(timer.elapsed() >= elapsed);
+    timer.stop();
+    assert!(timer.elapsed() >= elapsed);
+}
+
+#[test]
+fn test_process_cpu_timer_resume_snapshot() {
+    let elapsed = ProcessDuration::new(
+        Duration::from_secs(100_000),
+        Duration::from_secs(600),
+        Duration::from_secs(60),
+    );
+    let json = serde_json::json!({ "elapsed": elapsed });
+    let mut timer = ProcessCPUTimer::from_snapshot(serde_json::from_value(json).unwrap());
+    assert_eq!(timer.elapsed(), elapsed);
+
+    timer.resume();
+    let resumed = timer.elapsed();
+    assert!(resumed.real >= elapsed.real);
+    assert!(resumed.user >= elapsed.user);
+    assert!(resumed.system >= elapsed.system);
+
+    timer.stop();
+    let stopped = timer.elapsed();
+    assert!(stopped.real >= elapsed.real);
+    assert!(stopped.user >= elapsed.user);
+    assert!(stopped.system >= elapsed.system);
+}
+
+#[test]
+fn test_process_stats_round_trip() {
+    let mut stats = ProcessStats::new();
+    stats.record(ProcessDuration::new(
+        Duration::new(5, 710_000_001),
+        Duration::new(5, 700_000_002),
+        Duration::new(0, 3),
+    ));
+    stats.record(ProcessDuration::new(
+        Duration::MAX,
+        Duration::ZERO,
+        Duration::new(1, 0),
+    ));
+
+    let json = serde_json::to_string(&stats).unwrap();
+    let from_json: ProcessStats = serde_json::from_str(&json).unwrap();
+    assert_eq!(from_json, stats);
+
+    let bin = bincode::serialize(&stats).unwrap();
+    let from_bin: ProcessStats = bincode::deserialize(&bin).unwrap();
+    assert_eq!(from_bin, stats);
+
+    let empty: ProcessStats =
+        serde_json::from_str(&serde_json::to_string(&ProcessStats::new()).unwrap()).unwrap();
+    assert_eq!(empty.mean(), None);
+}
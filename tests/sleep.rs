@@ -0,0 +1,43 @@
+#![cfg(all(
+    have_steady_clock,
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+
+use howlong::{clock::*, sleep_for, sleep_until, Clock, Duration};
+
+#[test]
+fn test_sleep_until() {
+    for _ in 0..5 {
+        let target = SteadyClock::now() + Duration::from_millis(20);
+        sleep_until(target).unwrap();
+        let now = SteadyClock::now();
+        assert!(now >= target);
+        assert!(
+            now - target < Duration::from_millis(10),
+            "{:?}",
+            now - target
+        );
+    }
+}
+
+#[test]
+fn test_sleep_until_past() {
+    let start = SteadyClock::now();
+    sleep_until(start).unwrap();
+    sleep_until(start - Duration::from_nanos(1)).unwrap();
+    sleep_until(howlong::TimePoint::from(Duration::ZERO)).unwrap();
+    assert!(SteadyClock::now() - start < Duration::from_millis(10));
+}
+
+#[test]
+fn test_sleep_for() {
+    let start = SteadyClock::now();
+    sleep_for(Duration::from_millis(20)).unwrap();
+    let elapsed = SteadyClock::now() - start;
+    assert!(elapsed >= Duration::from_millis(20));
+    assert!(elapsed < Duration::from_millis(30), "{:?}", elapsed);
+
+    let start = SteadyClock::now();
+    sleep_for(Duration::ZERO).unwrap();
+    assert!(SteadyClock::now() - start < Duration::from_millis(10));
+}
@@ -0,0 +1,81 @@
+use howlong::{compare_durations, Duration, ProcessDuration, Speedup};
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+#[test]
+fn test_compare_durations() {
+    let cases = [
+        (
+            ms(900),
+            ms(500),
+            Some(1.8),
+            -400_000_000,
+            "1.8× faster (-400ms)",
+        ),
+        (
+            ms(500),
+            ms(1000),
+            Some(0.5),
+            500_000_000,
+            "2.0× slower (+500ms)",
+        ),
+        (ms(100), ms(100), Some(1.0), 0, "unchanged"),
+        (ms(100), ms(0), None, -100_000_000, "n/a (-100ms)"),
+        (ms(0), ms(0), None, 0, "unchanged"),
+        (ms(0), ms(3), Some(0.0), 3_000_000, "n/a (+3ms)"),
+        (
+            Duration::from_nanos(1_000_001),
+            Duration::from_nanos(1_000_000),
+            Some(1.000001),
+            -1,
+            "1.0× faster (-1ns)",
+        ),
+    ];
+    for &(baseline, candidate, ratio, delta_nanos, text) in cases.iter() {
+        let speedup = compare_durations(baseline, candidate);
+        assert_eq!(speedup, Speedup { ratio, delta_nanos });
+        assert_eq!(speedup.to_string(), text);
+        assert_eq!(
+            speedup.abs_delta(),
+            Duration::from_nanos(delta_nanos.unsigned_abs() as u64)
+        );
+    }
+
+    let huge = compare_durations(Duration::MAX, Duration::ZERO);
+    assert_eq!(huge.ratio, None);
+    assert_eq!(huge.delta_nanos, -(Duration::MAX.as_nanos() as i128));
+    assert_eq!(huge.abs_delta(), Duration::MAX);
+}
+
+#[test]
+fn test_speedup_over() {
+    let before = ProcessDuration::new(ms(900), ms(500), ms(50));
+    let after = ProcessDuration::new(ms(480), ms(480), ms(0));
+    let report = after.speedup_over(&before);
+
+    assert_eq!(report.real.ratio, Some(0.9 / 0.48));
+    assert_eq!(report.real.delta_nanos, -420_000_000);
+    assert_eq!(report.user.ratio, Some(0.5 / 0.48));
+    assert_eq!(report.user.delta_nanos, -20_000_000);
+    assert_eq!(report.system.ratio, None);
+    assert_eq!(report.system.delta_nanos, -50_000_000);
+    assert_eq!(report.cpu.ratio, Some(0.55 / 0.48));
+    assert_eq!(report.cpu.delta_nanos, -70_000_000);
+    assert_eq!(
+        report.to_string(),
+        "wall 1.9× faster (-420ms), cpu 1.1× faster (-70ms)"
+    );
+
+    let report = before.speedup_over(&after);
+    assert_eq!(report.system.ratio, Some(0.0));
+    assert_eq!(
+        report.to_string(),
+        "wall 1.9× slower (+420ms), cpu 1.1× slower (+70ms)"
+    );
+
+    let report = before.speedup_over(&before);
+    assert_eq!(report.real.ratio, Some(1.0));
+    assert_eq!(report.to_string(), "wall unchanged, cpu unchanged");
+}
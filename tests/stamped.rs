@@ -0,0 +1,59 @@
+#![cfg(feature = "std")]
+
+use howlong::mock::MockClock;
+use howlong::{
+    clock::*, Clock, Duration, Stamped, StampedClock, StampedTimer, TimePoint, TimePointSub,
+};
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+#[test]
+fn test_stamped_arithmetic() {
+    MockClock::set(ms(10));
+    let start = Stamped::<MockClock>::now();
+    MockClock::advance(ms(5));
+    let end = Stamped::<MockClock>::try_now().unwrap();
+    assert!(start < end);
+    assert_eq!(end - start, ms(5));
+    assert_eq!(end.checked_duration_since(start), Some(ms(5)));
+    assert_eq!(start.checked_duration_since(end), None);
+    assert_eq!(start.saturating_duration_since(end), Duration::ZERO);
+    assert_eq!(TimePointSub::checked_sub(end, start), Some(ms(5)));
+    assert_eq!(end.into_inner(), TimePoint::from(ms(15)));
+    assert_eq!(Stamped::<MockClock>::new(end.into_inner()), end);
+    assert_eq!(Duration::from(end), ms(15));
+}
+
+#[test]
+fn test_stamped_timer() {
+    MockClock::set(ms(10));
+    let mut timer = StampedTimer::<MockClock>::new();
+    MockClock::advance(ms(7));
+    timer.stop();
+    MockClock::advance(ms(100));
+    timer.resume();
+    MockClock::advance(ms(5));
+    assert_eq!(timer.elapsed(), ms(12));
+
+    let timer = StampedTimer::<SystemClock>::new();
+    let _ = timer.elapsed();
+    assert_eq!(StampedClock::<SystemClock>::info(), SystemClock::info());
+}
+
+#[test]
+fn test_stamped_debug() {
+    let point = Stamped::<SystemClock>::new(TimePoint::from(ms(1)));
+    assert_eq!(
+        format!("{:?}", point),
+        format!("Stamped<SystemClock>({:?})", TimePoint::from(ms(1)))
+    );
+}
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+#[test]
+fn test_stamped_cross_clock() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/stamped_*.rs");
+}
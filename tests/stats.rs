@@ -0,0 +1,199 @@
+use howlong::{Duration, ProcessDuration, ProcessStats, StreamingStats};
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+#[test]
+fn test_empty() {
+    let stats = ProcessStats::new();
+    assert_eq!(stats, ProcessStats::default());
+    assert_eq!(stats.count(), 0);
+    assert_eq!(stats.min(), None);
+    assert_eq!(stats.max(), None);
+    assert_eq!(stats.mean(), None);
+    assert_eq!(stats.mean_cpu_usage(), None);
+    assert_eq!(stats.to_string(), "no runs");
+}
+
+#[test]
+fn test_single_sample() {
+    let d = ProcessDuration::new(ms(40), ms(30), ms(10));
+    let mut stats = ProcessStats::new();
+    stats.record(d);
+    assert_eq!(stats.count(), 1);
+    assert_eq!(stats.min(), Some(d));
+    assert_eq!(stats.max(), Some(d));
+    assert_eq!(stats.mean(), Some(d));
+    assert_eq!(stats.mean_cpu_usage(), Some(1f64));
+    assert_eq!(
+        stats.to_string(),
+        "min:  40ms wall, 30ms user + 10ms system = 40ms CPU (100.0%)\n\
+         mean: 40ms wall, 30ms user + 10ms system = 40ms CPU (100.0%) over 1 run\n\
+         max:  40ms wall, 30ms user + 10ms system = 40ms CPU (100.0%)"
+    );
+}
+
+#[test]
+fn test_aggregates() {
+    let stats: ProcessStats = vec![
+        ProcessDuration::new(ms(10), ms(8), ms(4)),
+        ProcessDuration::new(ms(30), ms(2), ms(1)),
+        ProcessDuration::new(ms(20), ms(5), Duration::from_nanos(1)),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(stats.count(), 3);
+    assert_eq!(
+        stats.min(),
+        Some(ProcessDuration::new(ms(10), ms(2), Duration::from_nanos(1)))
+    );
+    assert_eq!(
+        stats.max(),
+        Some(ProcessDuration::new(ms(30), ms(8), ms(4)))
+    );
+    // The mean system time 5000001ns / 3 is truncated.
+    assert_eq!(
+        stats.mean(),
+        Some(ProcessDuration::new(
+            ms(20),
+            ms(5),
+            Duration::from_nanos(1_666_667)
+        ))
+    );
+    assert_eq!(stats.mean_cpu_usage(), Some(20_000_001f64 / 60_000_000f64));
+    assert_eq!(
+        stats.to_string(),
+        "min:  10ms wall, 2ms user + 1ns system = 2.000001ms CPU (20.0%)\n\
+         mean: 20ms wall, 5ms user + 1.666667ms system = 6.666667ms CPU (33.3%) over 3 runs\n\
+         max:  30ms wall, 8ms user + 4ms system = 12ms CPU (40.0%)"
+    );
+}
+
+#[test]
+fn test_zero_real_time() {
+    let mut stats = ProcessStats::new();
+    stats.extend(vec![ProcessDuration::ZERO; 2]);
+    assert_eq!(stats.count(), 2);
+    assert_eq!(stats.mean(), Some(ProcessDuration::ZERO));
+    assert_eq!(stats.mean_cpu_usage(), None);
+}
+
+#[test]
+fn test_no_overflow() {
+    let d = ProcessDuration::new(Duration::MAX, Duration::MAX, ms(1));
+    let mut stats = ProcessStats::new();
+    for _ in 0..1000 {
+        stats.record(d);
+    }
+    assert_eq!(stats.count(), 1000);
+    assert_eq!(stats.mean(), Some(d));
+    assert_eq!(stats.max(), Some(d));
+}
+
+/// A xorshift generator of durations up to about a second.
+fn samples(mut seed: u64, n: usize) -> Vec<Duration> {
+    (0..n)
+        .map(|_| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            Duration::from_nanos(seed % 1_000_000_000)
+        })
+        .collect()
+}
+
+/// The mean and the population variance in nanoseconds by the two-pass algorithm.
+fn two_pass(samples: &[Duration]) -> (f64, f64) {
+    let n = samples.len() as f64;
+    let mean = samples.iter().map(|d| d.as_nanos() as f64).sum::<f64>() / n;
+    let variance = samples
+        .iter()
+        .map(|d| (d.as_nanos() as f64 - mean).powi(2))
+        .sum::<f64>()
+        / n;
+    (mean, variance)
+}
+
+fn assert_stats(stats: &StreamingStats, samples: &[Duration], tolerance: f64) {
+    let (mean, variance) = two_pass(samples);
+    assert_eq!(stats.count(), samples.len() as u64);
+    assert_eq!(stats.min(), *samples.iter().min().unwrap());
+    assert_eq!(stats.max(), *samples.iter().max().unwrap());
+    assert_eq!(stats.mean(), Duration::from_nanos(mean.round() as u64));
+    assert!(
+        (stats.variance() - variance).abs() <= variance * tolerance,
+        "{} != {}",
+        stats.variance(),
+        variance
+    );
+    let stddev = stats.stddev().as_nanos() as f64;
+    assert!((stddev - variance.sqrt()).abs() <= 0.5 + 1e-6, "{}", stddev);
+}
+
+#[test]
+fn test_streaming_stats() {
+    let empty = StreamingStats::new();
+    assert_eq!(empty, StreamingStats::default());
+    assert_eq!(empty.count(), 0);
+    assert_eq!(empty.mean(), Duration::ZERO);
+    assert_eq!(empty.variance(), 0f64);
+    assert_eq!(empty.stddev(), Duration::ZERO);
+    assert_eq!(empty.min(), Duration::ZERO);
+    assert_eq!(empty.max(), Duration::ZERO);
+
+    let single: StreamingStats = vec![ms(3)].into_iter().collect();
+    assert_eq!(single.mean(), ms(3));
+    assert_eq!(single.stddev(), Duration::ZERO);
+
+    for &seed in [1, 0x9e37_79b9_7f4a_7c15, 0x2545_f491_4f6c_dd1d].iter() {
+        let samples = samples(seed, 10_000);
+        let stats: StreamingStats = samples.iter().copied().collect();
+        assert_stats(&stats, &samples, 1e-12);
+    }
+}
+
+#[test]
+fn test_streaming_stats_large_offset() {
+    // A naive `E[x²] - E[x]²` loses all the digits of the variance here, while the error of
+    // Welford's algorithm only grows with the ratio of the mean to the deviation, about 1e7.
+    let offset = Duration::from_secs(3600);
+    let samples: Vec<_> = samples(7, 1000)
+        .into_iter()
+        .map(|d| offset + d / 1000)
+        .collect();
+    let stats: StreamingStats = samples.iter().copied().collect();
+    assert_stats(&stats, &samples, 1e-8);
+}
+
+#[test]
+fn test_streaming_stats_merge() {
+    let samples = samples(42, 3000);
+    for &split in [0, 1, 1000, 2999, 3000].iter() {
+        let mut a: StreamingStats = samples[..split].iter().copied().collect();
+        let b: StreamingStats = samples[split..].iter().copied().collect();
+        a.merge(&b);
+        assert_stats(&a, &samples, 1e-12);
+    }
+
+    let parts: Vec<StreamingStats> = samples
+        .chunks(7)
+        .map(|chunk| chunk.iter().copied().collect())
+        .collect();
+    let mut merged = StreamingStats::new();
+    for part in &parts {
+        merged.merge(part);
+    }
+    assert_stats(&merged, &samples, 1e-12);
+}
+
+#[test]
+fn test_streaming_stats_send() {
+    let samples = samples(3, 100);
+    let (left, right) = samples.split_at(50);
+    let right = right.to_vec();
+    let handle = std::thread::spawn(move || right.into_iter().collect::<StreamingStats>());
+    let mut stats: StreamingStats = left.iter().copied().collect();
+    stats.merge(&handle.join().unwrap());
+    assert_stats(&stats, &samples, 1e-12);
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "std")]
+
+use howlong::{
+    assert_completes_within, assert_elapsed_under,
+    mock::{MockClock, MockTimer},
+    testing::{budget_scale, check_budget, BudgetExceeded},
+    Duration,
+};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+fn panic_message(f: impl FnOnce()) -> String {
+    let payload = catch_unwind(AssertUnwindSafe(f)).unwrap_err();
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().unwrap().to_string(),
+    }
+}
+
+#[test]
+fn test_check_budget() {
+    assert_eq!(budget_scale(), 1.0);
+    let budget = Duration::from_millis(200);
+    assert_eq!(check_budget(Duration::ZERO, budget), Ok(()));
+    assert_eq!(check_budget(budget, budget), Ok(()));
+
+    let e = check_budget(Duration::from_millis(250), budget).unwrap_err();
+    assert_eq!(
+        e,
+        BudgetExceeded {
+            elapsed: Duration::from_millis(250),
+            budget,
+            scale: 1.0,
+        }
+    );
+    assert!((e.overshoot_pct() - 25.0).abs() < 1e-9);
+    assert_eq!(
+        e.to_string(),
+        "took 250ms, over the budget of 200ms by 25.0%"
+    );
+
+    let e = check_budget(Duration::from_nanos(1), Duration::ZERO).unwrap_err();
+    assert_eq!(e.overshoot_pct(), f64::INFINITY);
+}
+
+#[test]
+fn test_assert_elapsed_under() {
+    MockClock::reset();
+    let timer = MockTimer::new();
+    MockClock::advance(Duration::from_millis(150));
+    assert_elapsed_under!(timer, Duration::from_millis(200));
+    assert_elapsed_under!(
+        timer,
+        Duration::from_millis(150),
+        "exactly {}",
+        "the budget"
+    );
+
+    MockClock::advance(Duration::from_micros(91_500));
+    let message = panic_message(|| assert_elapsed_under!(timer, Duration::from_millis(200)));
+    assert_eq!(
+        message,
+        "budget exceeded: `timer` took 241.5ms, over the budget of 200ms by 20.8%"
+    );
+    let message = panic_message(|| {
+        assert_elapsed_under!(timer, Duration::from_millis(200), "parsing {} rows", 1000)
+    });
+    assert!(message.contains("took 241.5ms"), "{}", message);
+    assert!(message.ends_with(": parsing 1000 rows"), "{}", message);
+}
+
+#[test]
+fn test_assert_completes_within() {
+    let sum = assert_completes_within!(Duration::from_secs(60), (0..1000u64).sum::<u64>());
+    assert_eq!(sum, 499_500);
+    let v = assert_completes_within!(Duration::from_secs(60), { vec![1, 2, 3] }, "small vec");
+    assert_eq!(v, [1, 2, 3]);
+
+    let message = panic_message(|| {
+        assert_completes_within!(Duration::from_millis(1), {
+            std::thread::sleep(Duration::from_millis(20))
+        })
+    });
+    assert!(message.starts_with("budget exceeded: `"), "{}", message);
+    assert!(
+        message.contains("over the budget of 1ms by "),
+        "{}",
+        message
+    );
+    // The measured value is reported, and is at least the sleep.
+    let took = message.split(" took ").nth(1).unwrap();
+    let took = took.split(',').next().unwrap();
+    let took = took.strip_suffix("ms").unwrap().parse::<f64>().unwrap();
+    assert!(took >= 20.0, "{}", message);
+}
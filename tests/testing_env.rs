@@ -0,0 +1,21 @@
+#![cfg(feature = "std")]
+
+use howlong::{
+    testing::{budget_scale, check_budget, ENV_VAR},
+    Duration,
+};
+
+#[test]
+fn test_budget_scale() {
+    // The only test of this binary, so that the scale has not been read yet.
+    std::env::set_var(ENV_VAR, "2.5");
+    assert_eq!(budget_scale(), 2.5);
+    let budget = Duration::from_millis(100);
+    assert_eq!(check_budget(Duration::from_millis(250), budget), Ok(()));
+    let e = check_budget(Duration::from_millis(300), budget).unwrap_err();
+    assert_eq!(e.budget, Duration::from_millis(250));
+    assert_eq!(
+        e.to_string(),
+        "took 300ms, over the budget of 250ms by 20.0% (HOWLONG_BUDGET_SCALE=2.5)"
+    );
+}
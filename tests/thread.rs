@@ -0,0 +1,134 @@
+#![cfg(feature = "std")]
+
+use howlong::{bench::black_box, clock::*, timer::*, Clock, Duration};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+fn spawn_worker(stop: Arc<AtomicBool>) -> thread::JoinHandle<u64> {
+    thread::spawn(move || {
+        let mut acc = 0u64;
+        while !stop.load(Ordering::Relaxed) {
+            acc = (0..10_000u64).fold(acc, |acc, x| acc.wrapping_add(black_box(x)));
+        }
+        acc
+    })
+}
+
+#[test]
+fn test_thread_cpu_handle() {
+    let stop = Arc::new(AtomicBool::new(false));
+    let worker = spawn_worker(stop.clone());
+    let handle = ThreadCpuHandle::for_join_handle(&worker).unwrap();
+    let start = handle.now();
+    let mut last = start;
+    for _ in 0..3 {
+        thread::sleep(Duration::from_millis(50));
+        let now = handle.now();
+        assert!(now > last);
+        last = now;
+    }
+    assert!(handle.elapsed_since(&start) > Duration::from_millis(10));
+    stop.store(true, Ordering::Relaxed);
+    worker.join().unwrap();
+
+    // The duplicated handle keeps reporting the final CPU time. On POSIX, the clock of a
+    // joined thread must not be read at all.
+    #[cfg(windows)]
+    assert_eq!(handle.try_now().unwrap(), handle.now());
+}
+
+#[test]
+fn test_thread_timer_for() {
+    let stop = Arc::new(AtomicBool::new(false));
+    let worker = spawn_worker(stop.clone());
+    let mut timer = ThreadTimer::for_thread(&worker).unwrap();
+    thread::sleep(Duration::from_millis(50));
+    let e1 = timer.elapsed();
+    thread::sleep(Duration::from_millis(50));
+    let e2 = timer.elapsed();
+    assert!(e2 > e1);
+
+    timer.stop();
+    let stopped = timer.elapsed();
+    thread::sleep(Duration::from_millis(50));
+    assert_eq!(timer.elapsed(), stopped);
+
+    timer.resume();
+    thread::sleep(Duration::from_millis(50));
+    assert!(timer.elapsed() > stopped);
+
+    stop.store(true, Ordering::Relaxed);
+    worker.join().unwrap();
+}
+
+#[cfg(any(
+    all(unix, not(any(target_os = "fuchsia", target_os = "haiku"))),
+    windows
+))]
+fn assert_close(handle: &ThreadCpuHandle, delta: Duration) {
+    let before = ThreadClock::now();
+    let observed = handle.now();
+    let after = ThreadClock::now();
+    assert!(observed <= after, "{:?} > {:?}", observed, after);
+    assert!(before.saturating_sub(observed) <= delta);
+}
+
+#[test]
+#[cfg(all(unix, not(any(target_os = "fuchsia", target_os = "haiku"))))]
+fn test_thread_cpu_handle_from_pthread() {
+    let handle = unsafe { ThreadCpuHandle::from_pthread(libc::pthread_self()) }.unwrap();
+    black_box((0..100_000u64).sum::<u64>());
+    assert_close(&handle, Duration::from_millis(1));
+    let handle = unsafe { ThreadClock::for_native_id(libc::pthread_self()) }.unwrap();
+    assert_close(&handle, Duration::from_millis(1));
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_thread_cpu_handle_from_tid() {
+    let tid = unsafe { libc::gettid() };
+    let handle = ThreadCpuHandle::from_tid(tid as u32).unwrap();
+    black_box((0..100_000u64).sum::<u64>());
+    // `/proc` reports CPU times in clock ticks.
+    assert_close(&handle, Duration::from_millis(20));
+    assert!(ThreadCpuHandle::from_tid(u32::MAX).is_err());
+}
+
+#[test]
+#[cfg(windows)]
+fn test_thread_cpu_handle_from_thread_id() {
+    let id = unsafe { winapi::um::processthreadsapi::GetCurrentThreadId() };
+    let handle = ThreadCpuHandle::from_thread_id(id).unwrap();
+    black_box((0..100_000u64).sum::<u64>());
+    assert_close(&handle, Duration::from_millis(1));
+    let handle = ThreadClock::for_native_id(id).unwrap();
+    assert_close(&handle, Duration::from_millis(1));
+}
+
+#[test]
+fn test_thread_cpu_clock() {
+    let start = ThreadCPUClock::now();
+    let end = std::time::Instant::now() + Duration::from_millis(100);
+    while std::time::Instant::now() < end {
+        black_box(std::fs::metadata(".").unwrap());
+    }
+    let elapsed = ThreadCPUClock::now() - start;
+    assert!(elapsed.cpu_time() > Duration::from_millis(10));
+    #[cfg(any(target_os = "linux", target_os = "macos", windows))]
+    assert!(elapsed.system > Duration::ZERO);
+}
+
+#[test]
+fn test_thread_cpu_timer() {
+    let mut timer = ThreadCPUTimer::new();
+    black_box((0..1_000_000u64).map(black_box).sum::<u64>());
+    timer.stop();
+    let stopped = timer.elapsed();
+    assert!(stopped.cpu_time() > Duration::ZERO);
+    black_box((0..1_000_000u64).map(black_box).sum::<u64>());
+    assert_eq!(timer.elapsed(), stopped);
+    timer.resume();
+    black_box((0..1_000_000u64).map(black_box).sum::<u64>());
+    assert!(timer.elapsed().cpu_time() > stopped.cpu_time());
+}
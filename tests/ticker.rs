@@ -0,0 +1,84 @@
+#![cfg(all(
+    have_steady_clock,
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+
+use howlong::{clock::*, Clock, Duration, MissedTickBehavior, Ticker};
+use std::thread;
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+#[test]
+fn test_ticker() {
+    let start = SteadyClock::now();
+    let mut ticker = Ticker::new(ms(10));
+    for i in 1..=10 {
+        ticker.wait();
+        assert!(SteadyClock::now() - start >= ms(10) * i);
+        assert_eq!(ticker.missed(), 0);
+        // The work inside the loop must not accumulate as drift.
+        thread::sleep(ms(2));
+    }
+    let elapsed = SteadyClock::now() - start;
+    assert!(elapsed >= ms(100), "{:?}", elapsed);
+    assert!(elapsed < ms(150), "{:?}", elapsed);
+}
+
+/// Wait for the first tick, then sleep past the second one. Return the ticker and the start
+/// time.
+fn late_ticker(behavior: MissedTickBehavior) -> (Ticker, howlong::TimePoint) {
+    let start = SteadyClock::now();
+    let mut ticker = Ticker::with_missed_tick_behavior(ms(10), behavior);
+    assert_eq!(ticker.missed_tick_behavior(), behavior);
+    ticker.wait();
+    thread::sleep(ms(25));
+    (ticker, start)
+}
+
+#[test]
+fn test_ticker_burst() {
+    let (mut ticker, start) = late_ticker(MissedTickBehavior::Burst);
+    // The ticks at 20ms and 30ms are returned immediately.
+    ticker.wait();
+    assert_eq!(ticker.missed(), 1);
+    ticker.wait();
+    assert_eq!(ticker.missed(), 0);
+    assert!(SteadyClock::now() - start < ms(40));
+    ticker.wait();
+    assert_eq!(ticker.missed(), 0);
+    assert!(SteadyClock::now() - start >= ms(40));
+}
+
+#[test]
+fn test_ticker_skip() {
+    let (mut ticker, start) = late_ticker(MissedTickBehavior::Skip);
+    // The tick at 20ms is returned immediately and the one at 30ms is dropped.
+    ticker.wait();
+    assert_eq!(ticker.missed(), 1);
+    assert!(SteadyClock::now() - start < ms(40));
+    ticker.wait();
+    assert_eq!(ticker.missed(), 0);
+    let elapsed = SteadyClock::now() - start;
+    assert!(elapsed >= ms(40), "{:?}", elapsed);
+    assert!(elapsed < ms(50), "{:?}", elapsed);
+}
+
+#[test]
+fn test_ticker_delay() {
+    let (mut ticker, _) = late_ticker(MissedTickBehavior::Delay);
+    // The tick at 20ms is returned immediately and the next one is a period later.
+    ticker.wait();
+    assert_eq!(ticker.missed(), 1);
+    let late = SteadyClock::now();
+    ticker.wait();
+    assert_eq!(ticker.missed(), 0);
+    assert!(SteadyClock::now() - late >= ms(9));
+}
+
+#[test]
+#[should_panic]
+fn test_ticker_zero_period() {
+    let _ = Ticker::new(Duration::ZERO);
+}
@@ -0,0 +1,114 @@
+#![cfg(all(feature = "time", not(howlong_unsupported)))]
+
+use howlong::{
+    clock::SystemClock,
+    time::{from_time_duration, to_time_duration},
+    Duration, Error, ProcessDuration, Stamped,
+};
+use std::time::UNIX_EPOCH;
+use time::OffsetDateTime;
+
+#[test]
+fn test_now_offsetdatetime() {
+    let before = OffsetDateTime::now_utc();
+    let now = SystemClock::now_offsetdatetime().unwrap();
+    let after = OffsetDateTime::now_utc();
+    let tolerance = time::Duration::milliseconds(100);
+    assert!(now >= before - tolerance, "{} < {}", now, before);
+    assert!(now <= after + tolerance, "{} > {}", now, after);
+
+    let stamped = Stamped::<SystemClock>::now().to_offsetdatetime().unwrap();
+    assert!((stamped - OffsetDateTime::now_utc()).abs() < tolerance);
+}
+
+#[test]
+fn test_to_offsetdatetime() {
+    let cases = [
+        (0, 0),
+        (1, 500_000_000),
+        (1_700_000_000, 123_456_789),
+        (4_102_444_800, 999_999_999),
+    ];
+    for &(secs, nanos) in cases.iter() {
+        let time = UNIX_EPOCH + Duration::new(secs, nanos);
+        let t = SystemClock::from_system_time(time).unwrap();
+        let expected =
+            OffsetDateTime::from_unix_timestamp_nanos(secs as i128 * 1_000_000_000 + nanos as i128)
+                .unwrap();
+        assert_eq!(t.to_offsetdatetime(), Some(expected));
+        assert_eq!(OffsetDateTime::from(t.to_system_time()), expected);
+    }
+
+    let t = SystemClock::from_system_time(UNIX_EPOCH + Duration::from_secs(1_700_000_000)).unwrap();
+    assert_eq!(
+        t.to_offsetdatetime().unwrap(),
+        time::macros::datetime!(2023-11-14 22:13:20 UTC)
+    );
+}
+
+#[test]
+#[cfg(windows)]
+fn test_to_offsetdatetime_before_unix_epoch() {
+    let time = UNIX_EPOCH - Duration::new(86_400, 250_000_000);
+    let t = SystemClock::from_system_time(time).unwrap();
+    assert_eq!(
+        t.to_offsetdatetime(),
+        OffsetDateTime::from_unix_timestamp_nanos(-86_400_250_000_000).ok()
+    );
+    assert_eq!(
+        howlong::TimePoint::from(Duration::ZERO).to_offsetdatetime(),
+        Some(time::macros::datetime!(1601-01-01 0:00 UTC))
+    );
+}
+
+#[test]
+fn test_duration_conversion() {
+    let cases = [
+        Duration::ZERO,
+        Duration::from_nanos(1),
+        Duration::new(1, 500_000_000),
+        Duration::from_secs(86_400 * 365),
+    ];
+    for &d in cases.iter() {
+        let t = to_time_duration(d).unwrap();
+        assert_eq!(t.whole_nanoseconds(), d.as_nanos() as i128);
+        assert_eq!(from_time_duration(t).unwrap(), d);
+    }
+
+    assert_eq!(
+        to_time_duration(Duration::from_millis(1500)).unwrap(),
+        time::Duration::milliseconds(1500)
+    );
+    assert_eq!(to_time_duration(Duration::MAX), Err(Error::OutOfRange));
+    assert_eq!(
+        from_time_duration(time::Duration::nanoseconds(-1)),
+        Err(Error::OutOfRange)
+    );
+    assert_eq!(
+        from_time_duration(time::Duration::MIN),
+        Err(Error::OutOfRange)
+    );
+    assert_eq!(
+        from_time_duration(time::Duration::MAX).unwrap().as_secs(),
+        i64::MAX as u64
+    );
+}
+
+#[test]
+fn test_to_time_components() {
+    let d = ProcessDuration::new(
+        Duration::from_millis(1500),
+        Duration::from_millis(1200),
+        Duration::from_micros(250),
+    );
+    assert_eq!(
+        d.to_time_components(),
+        Ok((
+            time::Duration::milliseconds(1500),
+            time::Duration::milliseconds(1200),
+            time::Duration::microseconds(250),
+        ))
+    );
+    let d = ProcessDuration::new(Duration::ZERO, Duration::MAX, Duration::ZERO);
+    assert_eq!(d.to_time_components(), Err(Error::OutOfRange));
+}
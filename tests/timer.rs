@@ -1,4 +1,6 @@
-use howlong::{timer::*, Duration};
+#![cfg(feature = "std")]
+
+use howlong::{clock::*, timer::*, Clock, Duration, ProcessDuration, TimePoint};
 use std::thread;
 
 mod utils;
@@ -31,11 +33,48 @@ macro_rules! test_timer {
 }
 
 test_timer!(test_system_timer, SystemTimer);
-#[cfg(have_steady_timer)]
+#[cfg(have_steady_clock)]
 test_timer!(test_steady_timer, SteadyTimer);
+#[cfg(have_suspend_aware_clock)]
+test_timer!(test_suspend_aware_timer, SuspendAwareTimer);
 test_timer!(test_high_resolution_timer, HighResolutionTimer);
 test_timer!(test_process_real_cpu_timer, ProcessRealCPUTimer);
 
+#[cfg(have_coarse_clock)]
+macro_rules! test_coarse_timer {
+    ($name: ident, $timer: ty, $clock: ty) => {
+        #[test]
+        fn $name() {
+            // The elapsed time is quantized to the resolution of the clock.
+            let ten_millis = Duration::from_millis(10) - <$clock>::resolution().unwrap();
+            let mut timer = <$timer>::new();
+            thread::sleep(Duration::from_millis(10));
+            let elapsed = timer.elapsed();
+            assert!(elapsed >= ten_millis);
+            timer.stop();
+            thread::sleep(Duration::from_millis(10));
+            assert_eq!(timer.elapsed(), elapsed);
+            timer.resume();
+            thread::sleep(Duration::from_millis(10));
+            timer.stop();
+            assert!(timer.elapsed() >= elapsed + ten_millis);
+        }
+    };
+}
+
+#[cfg(have_coarse_clock)]
+test_coarse_timer!(
+    test_coarse_system_timer,
+    CoarseSystemTimer,
+    CoarseSystemClock
+);
+#[cfg(have_coarse_clock)]
+test_coarse_timer!(
+    test_coarse_steady_timer,
+    CoarseSteadyTimer,
+    CoarseSteadyClock
+);
+
 #[test]
 fn test_process_user_cpu_timer() {
     let timer = ProcessUserCPUTimer::new();
@@ -48,7 +87,8 @@ fn test_process_user_cpu_timer() {
 fn test_process_system_cpu_timer() {
     let timer = ProcessSystemCPUTimer::new();
     let elapsed = timer.elapsed();
-    assert!(elapsed < Duration::from_nanos(10));
+    // Reading the clock itself might take a few microseconds of system time.
+    assert!(elapsed < Duration::from_millis(1));
 }
 
 #[test]
@@ -63,6 +103,92 @@ fn test_process_cpu_timer() {
     assert!(elapsed.user + elapsed.system >= elapsed.real);
 }
 
+#[test]
+#[cfg(not(target_arch = "wasm32"))]
+fn test_process_cpu_timer_stop_resume() {
+    // Spend most of the time in the kernel, so that the user-CPU and system-CPU times differ.
+    fn syscall_task(inner: &mut howlong::ProcessDuration) {
+        let start = ProcessCPUClock::now();
+        while (ProcessCPUClock::now() - start).real < Duration::from_millis(50) {
+            for _ in 0..1000 {
+                utils::black_box(std::fs::metadata(".").unwrap());
+            }
+        }
+        *inner += ProcessCPUClock::now() - start;
+    }
+
+    let mut inner = howlong::ProcessDuration::ZERO;
+    let mut timer = ProcessCPUTimer::new();
+    syscall_task(&mut inner);
+    timer.stop();
+    let stopped = timer.elapsed();
+    thread::sleep(Duration::from_millis(10));
+    assert_eq!(timer.elapsed().system, stopped.system);
+    timer.resume();
+    syscall_task(&mut inner);
+    timer.stop();
+    let elapsed = timer.elapsed();
+
+    // The timer only adds the few clock readings around `inner`.
+    let slack = Duration::from_millis(1);
+    assert!(inner.system > Duration::ZERO, "{}", inner);
+    assert!(elapsed.system >= inner.system, "{} {}", elapsed, inner);
+    assert!(
+        elapsed.system < inner.system + slack,
+        "{} {}",
+        elapsed,
+        inner
+    );
+    assert!(elapsed.user >= inner.user, "{} {}", elapsed, inner);
+    assert!(elapsed.user < inner.user + slack, "{} {}", elapsed, inner);
+    assert!(elapsed.real >= inner.real, "{} {}", elapsed, inner);
+    assert!(elapsed.real < inner.real + Duration::from_millis(10));
+}
+
+#[test]
+fn test_process_cpu_timer_idle_time() {
+    let timer = ProcessCPUTimer::new();
+    thread::sleep(Duration::from_millis(50));
+    let elapsed = timer.elapsed();
+    assert!(elapsed.idle_time() <= elapsed.real);
+    assert!(
+        elapsed.idle_time() + Duration::from_millis(5) >= elapsed.real,
+        "{}",
+        elapsed
+    );
+    assert!(!elapsed.is_cpu_bound(0.5), "{}", elapsed);
+
+    let timer = ProcessCPUTimer::new();
+    utils::black_box(utils::multithreading_task());
+    let elapsed = timer.elapsed();
+    assert!(elapsed.idle_time() * 4 <= elapsed.real, "{}", elapsed);
+    assert!(elapsed.is_cpu_bound(0.75), "{}", elapsed);
+
+    // The CPU time exceeds the real time on a multicore system, where the idle time saturates.
+    let parallel = ProcessDuration::new(
+        Duration::from_millis(100),
+        Duration::from_millis(300),
+        Duration::from_millis(50),
+    );
+    assert_eq!(parallel.idle_time(), Duration::ZERO);
+}
+
+#[test]
+#[cfg(not(target_arch = "wasm32"))]
+fn test_process_cpu_timer_normalized() {
+    let timer = ProcessCPUTimer::new();
+    utils::black_box(utils::multithreading_task());
+    let elapsed = timer.elapsed();
+    let cores = std::thread::available_parallelism().unwrap().get();
+    let normalized = elapsed.cpu_usage_normalized().unwrap();
+    assert_eq!(normalized, elapsed.cpu_usage_per_core(cores));
+    // Allow for the different resolutions of the real and CPU clocks.
+    assert!(normalized <= 1.05, "{} on {} cores", elapsed, cores);
+    if cores > 1 {
+        assert!(elapsed.cpu_usage() > 1.0, "{} on {} cores", elapsed, cores);
+    }
+}
+
 #[test]
 fn test_thread_timer() {
     let timer_outer = ThreadTimer::new();
@@ -77,3 +203,72 @@ fn test_thread_timer() {
     assert!(elapsed_inner > Duration::from_nanos(0));
     assert!(elapsed_inner > elapsed_outter);
 }
+
+#[test]
+fn test_system_timer_clock_backwards() {
+    let future = TimePoint::from(Duration::from(SystemClock::now()) + Duration::from_secs(3600));
+    let mut timer = SystemTimer::with_start(future);
+    assert!(timer.is_running());
+    assert_eq!(timer.checked_elapsed(), None);
+    assert_eq!(timer.elapsed(), Duration::from_nanos(0));
+    timer.stop();
+    assert_eq!(timer.checked_elapsed(), Some(Duration::from_nanos(0)));
+    assert_eq!(timer.elapsed(), Duration::from_nanos(0));
+}
+
+#[test]
+fn test_timer_with_start() {
+    let past = TimePoint::from(Duration::from(SystemClock::now()) - Duration::from_secs(1));
+    let timer = SystemTimer::with_start(past);
+    assert!(timer.checked_elapsed().unwrap() >= Duration::from_secs(1));
+    assert!(timer.elapsed() >= Duration::from_secs(1));
+}
+
+#[test]
+fn test_timer_resolution() {
+    assert_eq!(
+        SystemTimer::resolution().unwrap(),
+        SystemClock::resolution().unwrap()
+    );
+    assert_eq!(
+        ProcessCPUTimer::resolution().unwrap(),
+        ProcessCPUClock::resolution().unwrap()
+    );
+    assert_eq!(
+        ThreadTimer::resolution().unwrap(),
+        ThreadClock::resolution().unwrap()
+    );
+}
+
+#[cfg(have_steady_clock)]
+#[test]
+fn test_timer_default_type_parameters() {
+    let timer: Timer<SteadyClock> = Timer::new();
+    thread::sleep(Duration::from_millis(10));
+    let elapsed: Duration = timer.elapsed();
+    assert!(elapsed >= Duration::from_millis(10));
+    let _: SteadyTimer = timer;
+
+    let timer = Timer::<ProcessCPUClock>::new();
+    let _: ProcessDuration = timer.elapsed();
+    let _: ProcessCPUTimer = timer;
+}
+
+fn measure<C: TimerClock>(f: impl FnOnce()) -> C::Duration {
+    let timer = Timer::<C>::new();
+    f();
+    timer.elapsed()
+}
+
+#[test]
+fn test_timer_clock() {
+    let task = || {
+        utils::black_box(utils::computation_task());
+    };
+    let real: Duration = measure::<HighResolutionClock>(task);
+    assert!(real > Duration::from_nanos(0));
+    let thread: Duration = measure::<ThreadClock>(task);
+    assert!(thread > Duration::from_nanos(0));
+    let process: ProcessDuration = measure::<ProcessCPUClock>(task);
+    assert!(process.real > Duration::from_nanos(0));
+}
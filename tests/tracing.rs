@@ -0,0 +1,102 @@
+#![cfg(feature = "tracing")]
+
+use howlong::{timer::*, Duration, HowlongLayer, SpanTimings};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use tracing::{field::Field, span, Subscriber};
+use tracing_subscriber::{
+    field::Visit, layer::Context, layer::SubscriberExt, registry::LookupSpan, Layer, Registry,
+};
+
+#[derive(Clone, Default)]
+struct Capture {
+    fields: Arc<Mutex<HashMap<String, u64>>>,
+    timings: Arc<Mutex<HashMap<&'static str, SpanTimings>>>,
+}
+
+struct FieldVisitor<'a>(&'a mut HashMap<String, u64>);
+
+impl Visit for FieldVisitor<'_> {
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.insert(field.name().to_owned(), value);
+    }
+
+    fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+}
+
+impl<S> Layer<S> for Capture
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_record(&self, _id: &span::Id, values: &span::Record<'_>, _ctx: Context<'_, S>) {
+        values.record(&mut FieldVisitor(&mut self.fields.lock().unwrap()));
+    }
+
+    fn on_close(&self, id: span::Id, ctx: Context<'_, S>) {
+        let span = ctx.span(&id).unwrap();
+        let timings = span.extensions().get::<SpanTimings>().copied();
+        if let Some(timings) = timings {
+            self.timings.lock().unwrap().insert(span.name(), timings);
+        }
+    }
+}
+
+#[test]
+fn test_record_in_span() {
+    let capture = Capture::default();
+    let subscriber = Registry::default().with(capture.clone());
+    tracing::subscriber::with_default(subscriber, || {
+        let span = tracing::info_span!(
+            "computation",
+            elapsed = tracing::field::Empty,
+            cpu.real = tracing::field::Empty,
+            cpu.user = tracing::field::Empty,
+            cpu.system = tracing::field::Empty,
+        );
+        let _guard = span.enter();
+        let timer = HighResolutionTimer::new();
+        let cpu_timer = ProcessCPUTimer::new();
+        thread::sleep(Duration::from_millis(20));
+        timer.record_in_span("elapsed");
+        cpu_timer.record_in_span("cpu");
+    });
+    let fields = capture.fields.lock().unwrap();
+    assert!(fields["elapsed"] >= 20_000_000);
+    assert!(fields["cpu.real"] > 0);
+    assert!(fields.contains_key("cpu.user"));
+    assert!(fields.contains_key("cpu.system"));
+}
+
+#[test]
+fn test_howlong_layer() {
+    let capture = Capture::default();
+    let subscriber = Registry::default().with(HowlongLayer).with(capture.clone());
+    tracing::subscriber::with_default(subscriber, || {
+        let outer = tracing::info_span!("outer");
+        let _outer_guard = outer.enter();
+        thread::sleep(Duration::from_millis(10));
+        {
+            let inner = tracing::info_span!("inner");
+            let _inner_guard = inner.enter();
+            thread::sleep(Duration::from_millis(10));
+        }
+        let paused = tracing::info_span!("paused");
+        paused.in_scope(|| thread::sleep(Duration::from_millis(10)));
+        thread::sleep(Duration::from_millis(10));
+        paused.in_scope(|| thread::sleep(Duration::from_millis(10)));
+
+        let closed = tracing::info_span!("closed");
+        closed.in_scope(|| {});
+        thread::sleep(Duration::from_millis(10));
+    });
+    let timings = capture.timings.lock().unwrap();
+    assert!(timings["outer"].busy >= Duration::from_millis(50));
+    assert!(timings["inner"].busy >= Duration::from_millis(10));
+    assert!(timings["inner"].busy < timings["outer"].busy);
+    assert!(timings["paused"].busy >= Duration::from_millis(20));
+    assert!(timings["paused"].idle >= Duration::from_millis(10));
+    // The time from the last exit to the close is idle.
+    assert!(timings["closed"].busy < Duration::from_millis(10));
+    assert!(timings["closed"].idle >= Duration::from_millis(10));
+}
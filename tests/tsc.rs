@@ -0,0 +1,56 @@
+#![cfg(all(feature = "tsc", any(target_arch = "x86", target_arch = "x86_64")))]
+
+use howlong::{clock::*, timer::*, Clock, Duration, Error};
+use std::sync::{Mutex, MutexGuard};
+use std::thread;
+
+// The calibration is global, so that the tests measuring with it must not run concurrently
+// with the one recalibrating it.
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn lock() -> MutexGuard<'static, ()> {
+    LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[test]
+fn test_tsc_clock() {
+    let _lock = lock();
+    if !TscClock::is_available() {
+        assert!(matches!(TscClock::try_now(), Err(Error::Unsupported(_))));
+        return;
+    }
+    let frequency = TscClock::frequency().unwrap();
+    assert!(frequency > 0);
+    assert!(TscClock::resolution().unwrap() <= Duration::from_micros(1));
+
+    let fifty_millis = Duration::from_millis(50);
+    let start = TscClock::now();
+    let steady_start = HighResolutionClock::now();
+    thread::sleep(fifty_millis);
+    let elapsed = TscClock::now() - start;
+    let steady_elapsed = HighResolutionClock::now() - steady_start;
+    assert!(elapsed >= fifty_millis);
+    let diff = elapsed.as_nanos().abs_diff(steady_elapsed.as_nanos());
+    assert!(diff * 100 <= steady_elapsed.as_nanos() * 3, "{:?}", diff);
+}
+
+#[test]
+fn test_tsc_recalibrate() {
+    let _lock = lock();
+    if !TscClock::is_available() {
+        assert!(matches!(
+            TscClock::recalibrate(),
+            Err(Error::Unsupported(_))
+        ));
+        return;
+    }
+    let before = TscClock::now();
+    let first = TscClock::recalibrate().unwrap();
+    let second = TscClock::recalibrate().unwrap();
+    assert!(first.abs_diff(second) * 100 <= first * 3);
+    assert!(TscClock::now() >= before);
+
+    let timer = TscTimer::new();
+    thread::sleep(Duration::from_millis(10));
+    assert!(timer.elapsed() >= Duration::from_millis(10));
+}
@@ -0,0 +1,618 @@
+use howlong::{
+    Cycles, Duration, Error, HostDuration, ProcessDuration, ProcessTimePoint, ThreadDuration,
+    TimePoint, TimePointSub,
+};
+
+#[test]
+fn test_process_duration_format() {
+    let d = ProcessDuration::new(
+        Duration::from_millis(2000),
+        Duration::from_millis(1500),
+        Duration::from_micros(250),
+    );
+    assert_eq!(d.format("%w"), "2.000000");
+    assert_eq!(d.format("%u"), "1.500000");
+    assert_eq!(d.format("%s"), "0.000250");
+    assert_eq!(d.format("%t"), "1.500250");
+    assert_eq!(d.format("%p"), "75.0");
+    assert_eq!(d.format("%p%%"), "75.0%");
+    assert_eq!(d.format("%x %"), "%x %");
+    assert_eq!(d.format("wall: %ws"), "wall: 2.000000s");
+
+    let zero_real = ProcessDuration::new(Duration::ZERO, Duration::from_millis(6), Duration::ZERO);
+    assert_eq!(zero_real.format("%p%%"), "--%");
+    assert_eq!(ProcessDuration::ZERO.format("(%p%)"), "(--%)");
+}
+
+#[test]
+fn test_thread_duration_format() {
+    let d = ThreadDuration {
+        user: Duration::from_millis(5700),
+        system: Duration::from_millis(10),
+    };
+    assert_eq!(d.cpu_time(), Duration::from_millis(5710));
+    assert_eq!(format!("{}", d), "5.7s user + 10ms system = 5.71s CPU");
+}
+
+#[test]
+fn test_host_duration_utilization() {
+    let d = HostDuration {
+        user: Duration::from_millis(300),
+        system: Duration::from_millis(100),
+        idle: Duration::from_millis(600),
+    };
+    assert_eq!(d.checked_utilization(), Some(0.4));
+    assert_eq!(
+        format!("{}", d),
+        "300ms user + 100ms system + 600ms idle = 400ms CPU (40.0%)"
+    );
+
+    let zero = HostDuration::default();
+    assert!(zero.utilization().is_nan());
+    assert_eq!(zero.checked_utilization(), None);
+    assert_eq!(
+        format!("{}", zero),
+        "0ns user + 0ns system + 0ns idle = 0ns CPU (--%)"
+    );
+}
+
+#[test]
+fn test_cycles() {
+    let mut c = Cycles(100) + Cycles(20);
+    assert_eq!(c, Cycles(120));
+    c -= Cycles(30);
+    assert_eq!(c, Cycles(90));
+    assert_eq!(format!("{}", c), "90 cycles");
+}
+
+#[test]
+fn test_time_point_duration_since() {
+    // The system clock set backwards by a second in between.
+    let earlier = TimePoint::from(Duration::from_secs(1_000));
+    let later = TimePoint::from(Duration::from_secs(999));
+
+    assert_eq!(later.checked_duration_since(earlier), None);
+    assert_eq!(later.saturating_duration_since(earlier), Duration::ZERO);
+    assert_eq!(
+        later.signed_duration_since(earlier),
+        (true, Duration::from_secs(1))
+    );
+
+    assert_eq!(
+        earlier.checked_duration_since(later),
+        Some(Duration::from_secs(1))
+    );
+    assert_eq!(
+        earlier.saturating_duration_since(later),
+        Duration::from_secs(1)
+    );
+    assert_eq!(
+        earlier.signed_duration_since(later),
+        (false, Duration::from_secs(1))
+    );
+    assert_eq!(
+        earlier.signed_duration_since(earlier),
+        (false, Duration::ZERO)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_time_point_sub_backwards() {
+    let earlier = TimePoint::from(Duration::from_secs(1_000));
+    let later = TimePoint::from(Duration::from_secs(999));
+    let _ = later - earlier;
+}
+
+#[test]
+fn test_time_point_add_duration() {
+    let secs = Duration::from_secs;
+    let point = TimePoint::from(secs(2));
+    assert_eq!(point + secs(3), TimePoint::from(secs(5)));
+    assert_eq!(point - secs(2), TimePoint::from(Duration::ZERO));
+    assert_eq!(point.checked_add(secs(1)), Some(TimePoint::from(secs(3))));
+    assert_eq!(
+        point.checked_sub_duration(secs(1)),
+        Some(TimePoint::from(secs(1)))
+    );
+    assert_eq!(point.as_nanos(), 2_000_000_000);
+
+    // Overflow and underflow.
+    assert_eq!(
+        TimePoint::from(Duration::MAX).checked_add(Duration::from_nanos(1)),
+        None
+    );
+    assert_eq!(point.checked_sub_duration(secs(3)), None);
+    assert_eq!(
+        TimePoint::from(Duration::MAX).as_nanos(),
+        Duration::MAX.as_nanos()
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_time_point_add_duration_overflow() {
+    let _ = TimePoint::from(Duration::MAX) + Duration::from_nanos(1);
+}
+
+#[test]
+fn test_process_time_point_add_duration() {
+    let ms = Duration::from_millis;
+    let point = ProcessTimePoint::from(ProcessDuration::new(ms(10), ms(6), ms(2)));
+    let d = ProcessDuration::new(ms(5), ms(3), ms(1));
+    let later = ProcessTimePoint::from(ProcessDuration::new(ms(15), ms(9), ms(3)));
+    assert_eq!(point + d, later);
+    assert_eq!(later - d, point);
+    assert_eq!(point.checked_add(d), Some(later));
+    assert_eq!(later.checked_sub_duration(d), Some(point));
+
+    let mut p = point;
+    p += d;
+    assert_eq!(p, later);
+    p -= d;
+    assert_eq!(p, point);
+
+    // Any component out of range fails the whole operation.
+    let big = ProcessDuration::new(ms(0), ms(0), ms(3));
+    assert_eq!(point.checked_sub_duration(big), None);
+    let huge = ProcessDuration::new(ms(0), Duration::MAX, ms(0));
+    assert_eq!(point.checked_add(huge), None);
+}
+
+/// A xorshift generator of arbitrary durations for the round-trip tests.
+struct Durations(u64);
+
+impl Iterator for Durations {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        Some(Duration::new(self.0 >> 1, (self.0 % 1_000_000_000) as u32))
+    }
+}
+
+#[test]
+fn test_process_duration_round_trip() {
+    let edges = [Duration::ZERO, Duration::from_nanos(1), Duration::MAX];
+    let mut durations = edges
+        .iter()
+        .copied()
+        .chain(Durations(0x9e37_79b9_7f4a_7c15).take(3000));
+    while let (Some(real), Some(user), Some(system)) =
+        (durations.next(), durations.next(), durations.next())
+    {
+        let d = ProcessDuration { real, user, system };
+        let t = ProcessTimePoint::from(d);
+        assert_eq!(ProcessDuration::from(t), d);
+        assert_eq!(t.into_duration(), d);
+        assert_eq!(ProcessTimePoint::from(t.into_duration()), t);
+    }
+}
+
+#[test]
+fn test_process_time_point_into_duration() {
+    let ms = Duration::from_millis;
+    let t = ProcessTimePoint::from(ProcessDuration::new(ms(10), ms(6), ms(2)));
+    assert_eq!(
+        t.into_duration(),
+        ProcessDuration::new(ms(10), ms(6), ms(2))
+    );
+}
+
+#[test]
+fn test_process_time_point_new() {
+    let ms = Duration::from_millis;
+    let t = ProcessTimePoint::new(ms(10), ms(6), ms(2));
+    assert_eq!(t.real(), ms(10));
+    assert_eq!(t.user(), ms(6));
+    assert_eq!(t.system(), ms(2));
+    assert_eq!(t.cpu_time(), ms(8));
+    assert_eq!(
+        t,
+        ProcessTimePoint::from(ProcessDuration::new(ms(10), ms(6), ms(2)))
+    );
+    assert_eq!(t.to_string(), "10ms wall, 6ms user + 2ms system = 8ms CPU");
+}
+
+#[test]
+fn test_process_duration_eq_hash() {
+    use std::collections::HashMap;
+
+    let ms = Duration::from_millis;
+    let d = ProcessDuration::new(ms(10), ms(6), ms(2));
+    assert_ne!(d, ProcessDuration { system: ms(3), ..d });
+    assert_ne!(d, ProcessDuration::ZERO);
+    assert_eq!(
+        ProcessDuration::ZERO,
+        ProcessDuration::new(Duration::ZERO, Duration::ZERO, Duration::ZERO)
+    );
+
+    let mut cache = HashMap::new();
+    cache.insert(d, "first");
+    cache.insert(ProcessDuration::ZERO, "zero");
+    assert_eq!(cache.insert(d, "second"), Some("first"));
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache[&ProcessDuration::ZERO], "zero");
+}
+
+#[test]
+fn test_process_duration_ordering() {
+    use core::cmp::Ordering;
+
+    let ms = Duration::from_millis;
+    let zero = ProcessDuration::ZERO;
+    let slow = ProcessDuration::new(ms(20), ms(1), ms(1));
+    let busy = ProcessDuration::new(ms(10), ms(6), ms(2));
+    assert_eq!(slow.cmp_by_real(&busy), Ordering::Greater);
+    assert_eq!(slow.cmp_by_cpu(&busy), Ordering::Less);
+    assert_eq!(zero.cmp_by_real(&busy), Ordering::Less);
+    assert_eq!(zero.cmp_by_cpu(&zero), Ordering::Equal);
+    assert_eq!(slow.max_by_real(busy), slow);
+    assert_eq!(slow.max_by_cpu(busy), busy);
+    assert_eq!(zero.max_by_real(zero), zero);
+
+    // The ties are broken by the other times.
+    let swapped = ProcessDuration {
+        user: ms(2),
+        system: ms(6),
+        ..busy
+    };
+    assert_eq!(busy.cmp_by_real(&swapped), Ordering::Greater);
+    assert_eq!(busy.cmp_by_cpu(&swapped), Ordering::Greater);
+
+    let mut durations = vec![busy, zero, slow];
+    durations.sort_by(ProcessDuration::cmp_by_real);
+    assert_eq!(durations, [zero, busy, slow]);
+    durations.sort_by(ProcessDuration::cmp_by_cpu);
+    assert_eq!(durations, [zero, slow, busy]);
+}
+
+#[test]
+fn test_process_duration_from_str_round_trip() {
+    let grid = [
+        Duration::ZERO,
+        Duration::from_nanos(1),
+        Duration::from_nanos(999),
+        Duration::from_micros(1),
+        Duration::from_nanos(1_500),
+        Duration::from_nanos(999_999),
+        Duration::from_millis(1),
+        Duration::from_nanos(123_456_789),
+        Duration::from_secs(1),
+        Duration::from_millis(5_710),
+        Duration::new(59, 999_999_999),
+        Duration::from_secs(3 * 3600),
+        Duration::new(25 * 3600, 1),
+    ];
+    for &real in &grid {
+        for &user in &grid {
+            for &system in &grid {
+                let d = ProcessDuration { real, user, system };
+                let s = d.to_string();
+                assert_eq!(s.parse::<ProcessDuration>().unwrap(), d, "{}", s);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_process_duration_from_str() {
+    let ms = Duration::from_millis;
+    let d: ProcessDuration = "5.71s wall, 5.7s user + 0ns system = 5.7s CPU (99.8%)"
+        .parse()
+        .unwrap();
+    assert_eq!(d, ProcessDuration::new(ms(5710), ms(5700), Duration::ZERO));
+    let d: ProcessDuration = "2ms wall, 1.5us user + 500ns system = 2µs CPU (0.1%)"
+        .parse()
+        .unwrap();
+    assert_eq!(d.user, Duration::from_nanos(1500));
+    let d: ProcessDuration = "0ns wall, 0ns user + 0ns system = 0ns CPU (--%)"
+        .parse()
+        .unwrap();
+    assert_eq!(d, ProcessDuration::ZERO);
+
+    for s in [
+        "",
+        "5.71s wall",
+        "5.71s wall, 5.7s user + 0ns system = 5.7s CPU (99.8%",
+        "5.71s wall, 5.7s user + 0ns system = 5.7s CPU (99.8%) ",
+        "5.71 wall, 5.7s user + 0ns system = 5.7s CPU (99.8%)",
+        "5.71h wall, 5.7s user + 0ns system = 5.7s CPU (99.8%)",
+        "5.s wall, 5.7s user + 0ns system = 5.7s CPU (99.8%)",
+        ".5s wall, 5.7s user + 0ns system = 5.7s CPU (99.8%)",
+        "-5s wall, 5.7s user + 0ns system = 5.7s CPU (99.8%)",
+        "5.71s wall, 5.7s user + 1.5ns system = 5.7s CPU (99.8%)",
+        "5.71s wall, 5.7s user + 1.0000001ms system = 5.7s CPU (99.8%)",
+        "5.71s wall, 5.7s user + 0ns system = 5.8s CPU (99.8%)",
+        "5.71s wall, 5.7s user + 0ns system = 5.7s CPU (99.9%)",
+        "0ns wall, 0ns user + 0ns system = 0ns CPU (NaN%)",
+        "0ns wall, 1ms user + 0ns system = 1ms CPU (inf%)",
+        "1ms wall, 1ms user + 0ns system = 1ms CPU (--%)",
+        "99999999999999999999s wall, 5.7s user + 0ns system = 5.7s CPU (0.0%)",
+    ]
+    .iter()
+    {
+        assert!(
+            matches!(s.parse::<ProcessDuration>(), Err(Error::ParseError(_))),
+            "{}",
+            s
+        );
+    }
+}
+
+#[test]
+fn test_process_duration_sum() {
+    let ms = Duration::from_millis;
+    let results = vec![
+        ProcessDuration::new(ms(10), ms(6), ms(2)),
+        ProcessDuration::new(ms(20), ms(15), ms(4)),
+        ProcessDuration::new(ms(31), ms(20), ms(1)),
+    ];
+    let total = ProcessDuration::new(ms(61), ms(41), ms(7));
+    assert_eq!(results.iter().sum::<ProcessDuration>(), total);
+    assert_eq!(results.clone().into_iter().sum::<ProcessDuration>(), total);
+    assert_eq!(
+        results[..0].iter().sum::<ProcessDuration>(),
+        ProcessDuration::ZERO
+    );
+}
+
+#[test]
+fn test_process_duration_mean() {
+    let ms = Duration::from_millis;
+    let results = [
+        ProcessDuration::new(ms(10), ms(6), ms(2)),
+        ProcessDuration::new(ms(20), ms(15), ms(4)),
+        ProcessDuration::new(ms(31), ms(20), ms(1)),
+    ];
+    // 61ms / 3, 41ms / 3, and 7ms / 3, with the remainders truncated.
+    let mean = ProcessDuration::new(
+        Duration::from_nanos(20_333_333),
+        Duration::from_nanos(13_666_666),
+        Duration::from_nanos(2_333_333),
+    );
+    assert_eq!(ProcessDuration::mean(results), Some(mean));
+    assert_eq!(ProcessDuration::mean(results.iter()), Some(mean));
+    assert_eq!(ProcessDuration::mean(&results[..1]), Some(results[0]));
+    assert_eq!(ProcessDuration::mean(&results[..0]), None);
+    assert_eq!(
+        ProcessDuration::mean([ProcessDuration::ZERO]),
+        Some(ProcessDuration::ZERO)
+    );
+
+    // The sums do not overflow.
+    let max = ProcessDuration::new(Duration::MAX, Duration::MAX, Duration::MAX);
+    assert_eq!(ProcessDuration::mean([max, max]), Some(max));
+}
+
+#[test]
+fn test_process_time_point_checked_saturating_sub() {
+    let ms = Duration::from_millis;
+    let earlier = ProcessTimePoint::new(ms(100), ms(60), ms(20));
+    let later = ProcessTimePoint::new(ms(110), ms(66), ms(22));
+    let d = ProcessDuration::new(ms(10), ms(6), ms(2));
+    assert_eq!(later.checked_sub(earlier), Some(d));
+    assert_eq!(later.saturating_sub(earlier), d);
+    assert_eq!(later.checked_sub(later), Some(ProcessDuration::ZERO));
+
+    // Only one of the times regresses, e.g. at a tick boundary of a coarser CPU clock.
+    let regressed = [
+        ProcessTimePoint::new(ms(99), ms(66), ms(22)),
+        ProcessTimePoint::new(ms(110), ms(59), ms(22)),
+        ProcessTimePoint::new(ms(110), ms(66), ms(19)),
+    ];
+    let clamped = [
+        ProcessDuration { real: ms(0), ..d },
+        ProcessDuration { user: ms(0), ..d },
+        ProcessDuration { system: ms(0), ..d },
+    ];
+    for (&point, &expected) in regressed.iter().zip(clamped.iter()) {
+        assert_eq!(point.checked_sub(earlier), None);
+        assert_eq!(point.saturating_sub(earlier), expected);
+        assert_eq!(TimePointSub::checked_sub(point, earlier), None);
+        assert_eq!(TimePointSub::saturating_sub(point, earlier), expected);
+    }
+}
+
+#[test]
+fn test_process_duration_checked_saturating_sub() {
+    let ms = Duration::from_millis;
+    let a = ProcessDuration::new(ms(10), ms(6), ms(2));
+    let b = ProcessDuration::new(ms(4), ms(7), ms(1));
+    assert_eq!(a.checked_sub(a), Some(ProcessDuration::ZERO));
+    assert_eq!(a.checked_sub(b), None);
+    assert_eq!(
+        a.saturating_sub(b),
+        ProcessDuration::new(ms(6), ms(0), ms(1))
+    );
+    assert_eq!(b.saturating_sub(a).user, ms(1));
+    assert_eq!(
+        ProcessDuration::ZERO.saturating_sub(a),
+        ProcessDuration::ZERO
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_process_time_point_sub_regressed() {
+    let ms = Duration::from_millis;
+    let _ = ProcessTimePoint::new(ms(110), ms(59), ms(22))
+        - ProcessTimePoint::new(ms(100), ms(60), ms(20));
+}
+
+#[test]
+fn test_process_duration_constructors() {
+    let ms = Duration::from_millis;
+    let x = ProcessDuration::new(ms(10), ms(6), ms(2));
+    assert_eq!(x.real, ms(10));
+    assert_eq!(x.user, ms(6));
+    assert_eq!(x.system, ms(2));
+    assert_eq!(ProcessDuration::from_secs_f64(0.01, 0.006, 0.002), x);
+    assert_eq!(
+        ProcessTimePoint::from_secs_f64(0.01, 0.006, 0.002),
+        ProcessTimePoint::new(ms(10), ms(6), ms(2))
+    );
+
+    assert_eq!(ProcessDuration::ZERO, ProcessDuration::default());
+    assert!(ProcessDuration::ZERO.is_zero());
+    assert!(!x.is_zero());
+    assert!(!ProcessDuration::new(ms(0), ms(0), Duration::from_nanos(1)).is_zero());
+    assert_eq!(ProcessDuration::ZERO + x, x);
+    assert_eq!(x + ProcessDuration::ZERO, x);
+    assert_eq!(x - x, ProcessDuration::ZERO);
+    assert!((x - x).is_zero());
+    assert_eq!(x - ProcessDuration::ZERO, x);
+
+    const CONST: ProcessDuration =
+        ProcessDuration::new(Duration::from_secs(1), Duration::ZERO, Duration::ZERO);
+    const CONST_POINT: ProcessTimePoint =
+        ProcessTimePoint::new(Duration::ZERO, Duration::ZERO, Duration::ZERO);
+    assert_eq!(CONST.real, Duration::from_secs(1));
+    assert_eq!(ProcessDuration::from(CONST_POINT), ProcessDuration::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn test_process_duration_from_secs_f64_negative() {
+    let _ = ProcessDuration::from_secs_f64(1.0, -1.0, 0.0);
+}
+
+#[test]
+fn test_process_duration_cpu_usage() {
+    let ms = Duration::from_millis;
+    let normal = ProcessDuration::new(ms(10), ms(6), ms(2));
+    assert_eq!(normal.cpu_usage(), 0.8);
+    assert_eq!(normal.checked_cpu_usage(), Some(0.8));
+    assert_eq!(
+        normal.to_string(),
+        "10ms wall, 6ms user + 2ms system = 8ms CPU (80.0%)"
+    );
+
+    let zero_real = ProcessDuration::new(ms(0), ms(6), ms(2));
+    assert_eq!(zero_real.cpu_usage(), f64::INFINITY);
+    assert_eq!(zero_real.checked_cpu_usage(), None);
+    assert_eq!(
+        zero_real.to_string(),
+        "0ns wall, 6ms user + 2ms system = 8ms CPU (--%)"
+    );
+
+    assert!(ProcessDuration::ZERO.cpu_usage().is_nan());
+    assert_eq!(ProcessDuration::ZERO.checked_cpu_usage(), None);
+    assert_eq!(
+        ProcessDuration::ZERO.to_string(),
+        "0ns wall, 0ns user + 0ns system = 0ns CPU (--%)"
+    );
+
+    let zero_cpu = ProcessDuration::new(ms(10), ms(0), ms(0));
+    assert_eq!(zero_cpu.checked_cpu_usage(), Some(0.0));
+    assert_eq!(
+        zero_cpu.to_string(),
+        "10ms wall, 0ns user + 0ns system = 0ns CPU (0.0%)"
+    );
+
+    for d in [normal, zero_real, ProcessDuration::ZERO, zero_cpu].iter() {
+        assert_eq!(d.to_string().parse::<ProcessDuration>().unwrap(), *d);
+    }
+}
+
+#[test]
+fn test_process_duration_idle_time() {
+    let ms = Duration::from_millis;
+    let io_bound = ProcessDuration::new(ms(100), ms(6), ms(4));
+    assert_eq!(io_bound.idle_time(), ms(90));
+    assert!(!io_bound.is_cpu_bound(0.5));
+    assert!(io_bound.is_cpu_bound(0.05));
+
+    // The CPU time of a multithreaded process can be longer than the real time.
+    let parallel = ProcessDuration::new(ms(100), ms(350), ms(20));
+    assert_eq!(parallel.idle_time(), Duration::ZERO);
+    assert!(parallel.is_cpu_bound(1.0));
+
+    assert_eq!(ProcessDuration::ZERO.idle_time(), Duration::ZERO);
+    assert!(!ProcessDuration::ZERO.is_cpu_bound(0.0));
+    assert!(!ProcessDuration::new(ms(0), ms(5), ms(0)).is_cpu_bound(0.9));
+}
+
+#[test]
+fn test_process_duration_display_format_spec() {
+    let d = ProcessDuration::new(
+        Duration::from_millis(5705),
+        Duration::from_millis(5700),
+        Duration::from_micros(1500),
+    );
+    let cases = [
+        (
+            format!("{}", d),
+            "5.705s wall, 5.7s user + 1.5ms system = 5.7015s CPU (99.9%)",
+        ),
+        (
+            format!("{:.3}", d),
+            "5.705s wall, 5.700s user + 0.002s system = 5.702s CPU (99.9%)",
+        ),
+        (
+            format!("{:.0}", d),
+            "6s wall, 6s user + 0s system = 6s CPU (99.9%)",
+        ),
+        (
+            format!("{:>61}", d),
+            "  5.705s wall, 5.7s user + 1.5ms system = 5.7015s CPU (99.9%)",
+        ),
+        (
+            format!("{:61}|", d),
+            "5.705s wall, 5.7s user + 1.5ms system = 5.7015s CPU (99.9%)  |",
+        ),
+        (
+            format!("{:*^62}", d),
+            "*5.705s wall, 5.7s user + 1.5ms system = 5.7015s CPU (99.9%)**",
+        ),
+        (
+            format!("{:>10}", d),
+            "5.705s wall, 5.7s user + 1.5ms system = 5.7015s CPU (99.9%)",
+        ),
+        (
+            format!("{:-<64.2}|", d),
+            "5.71s wall, 5.70s user + 0.00s system = 5.70s CPU (99.9%)-------|",
+        ),
+        (
+            format!("{:#}", d),
+            "wall:   5.705s\nuser:   5.7s\nsystem: 1.5ms\nCPU:    5.7015s (99.9%)",
+        ),
+        (
+            format!("{:#.2}", d),
+            "wall:   5.71s\nuser:   5.70s\nsystem: 0.00s\nCPU:    5.70s (99.9%)",
+        ),
+        (
+            format!("{:>#16}", d),
+            "  wall:   5.705s\n    user:   5.7s\n   system: 1.5ms\nCPU:    5.7015s (99.9%)",
+        ),
+        (
+            format!("{:.1}", ProcessDuration::ZERO),
+            "0.0s wall, 0.0s user + 0.0s system = 0.0s CPU (--%)",
+        ),
+    ];
+    for (actual, expected) in cases.iter() {
+        assert_eq!(actual, expected);
+    }
+
+    // The default form still round-trips.
+    assert_eq!(d.to_string().parse::<ProcessDuration>().unwrap(), d);
+}
+
+#[test]
+fn test_process_duration_cpu_usage_per_core() {
+    let ms = Duration::from_millis;
+    let d = ProcessDuration::new(ms(100), ms(350), ms(50));
+    assert_eq!(d.cpu_usage(), 4.0);
+    assert_eq!(d.cpu_usage_per_core(1), 4.0);
+    assert_eq!(d.cpu_usage_per_core(4), 1.0);
+    assert_eq!(d.cpu_usage_per_core(8), 0.5);
+    assert!(ProcessDuration::ZERO.cpu_usage_per_core(4).is_nan());
+}
+
+#[test]
+#[should_panic]
+fn test_process_duration_cpu_usage_per_core_zero() {
+    let _ = ProcessDuration::ZERO.cpu_usage_per_core(0);
+}
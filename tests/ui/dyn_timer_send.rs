@@ -0,0 +1,6 @@
+use howlong::DynTimer;
+
+fn main() {
+    let timer = DynTimer::new(<dyn howlong::DynClock>::by_name("thread").unwrap());
+    std::thread::spawn(move || timer.elapsed());
+}
@@ -0,0 +1,7 @@
+use howlong::{clock::*, Stamped};
+
+fn main() {
+    let start = Stamped::<SystemClock>::now();
+    let end = Stamped::<ProcessRealCPUClock>::now();
+    let _ = end - start;
+}
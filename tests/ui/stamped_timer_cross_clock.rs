@@ -0,0 +1,5 @@
+use howlong::{clock::*, Stamped, StampedTimer};
+
+fn main() {
+    let _ = StampedTimer::<SystemClock>::with_start(Stamped::<ProcessRealCPUClock>::now());
+}
@@ -0,0 +1,6 @@
+use howlong::ThreadCPUTimer;
+
+fn main() {
+    let timer = ThreadCPUTimer::new();
+    std::thread::spawn(move || timer.elapsed());
+}
@@ -0,0 +1,6 @@
+use howlong::ThreadTimer;
+
+fn main() {
+    let timer = ThreadTimer::new();
+    std::thread::spawn(move || timer.elapsed());
+}
@@ -0,0 +1,8 @@
+use howlong::ThreadTimer;
+
+fn main() {
+    let timer = ThreadTimer::new();
+    std::thread::scope(|s| {
+        s.spawn(|| timer.elapsed());
+    });
+}
@@ -0,0 +1,50 @@
+//! These tests only run on the platforms without a supported backend, or if the fallback is
+//! forced by `RUSTFLAGS="--cfg howlong_unsupported"`.
+#![cfg(howlong_unsupported)]
+
+use howlong::{clock::*, timer::*, Clock, Error};
+
+#[test]
+fn test_is_supported() {
+    const _: () = assert!(!howlong::is_supported());
+}
+
+#[test]
+fn test_unsupported_clocks() {
+    assert!(matches!(
+        SystemClock::try_now(),
+        Err(Error::Unsupported("SystemClock"))
+    ));
+    assert!(matches!(
+        HighResolutionClock::try_now(),
+        Err(Error::Unsupported(_))
+    ));
+    assert!(matches!(
+        ProcessCPUClock::try_now(),
+        Err(Error::Unsupported("ProcessCPUClock"))
+    ));
+    assert!(matches!(
+        ThreadClock::try_now(),
+        Err(Error::Unsupported("ThreadClock"))
+    ));
+    assert!(matches!(UptimeClock::uptime(), Err(Error::Unsupported(_))));
+    assert!(matches!(
+        ProcessUptimeClock::uptime(),
+        Err(Error::Unsupported("ProcessUptimeClock"))
+    ));
+    assert!(ProcessCPUClock::for_pid(std::process::id()).is_err());
+    assert!(!SystemClock::is_available());
+    assert!(!ThreadClock::is_available());
+}
+
+#[test]
+#[should_panic(expected = "Unsupported(\"SystemClock\")")]
+fn test_unsupported_now() {
+    SystemClock::now();
+}
+
+#[test]
+#[should_panic]
+fn test_unsupported_timer() {
+    SystemTimer::new();
+}
@@ -1,9 +1,15 @@
-#[inline(never)]
-pub(crate) fn black_box<T>(input: T) -> T {
-    unsafe {
-        let ret = core::ptr::read_volatile(&input);
-        core::mem::forget(input);
-        ret
+#![allow(dead_code)]
+
+use howlong::{Clock, Duration, TimePoint};
+
+pub(crate) use howlong::bench::black_box;
+
+/// Keep the thread busy until `duration` has elapsed on the clock `C`, e.g. `ThreadClock` to
+/// consume that much CPU time, or `HighResolutionClock` to run for that much real time.
+pub(crate) fn busy_loop<C: Clock<Output = TimePoint>>(duration: Duration) {
+    let start = C::now();
+    while C::now() - start < duration {
+        black_box((0..1000u64).sum::<u64>());
     }
 }
 
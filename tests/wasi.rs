@@ -0,0 +1,46 @@
+#![cfg(target_os = "wasi")]
+
+use howlong::*;
+
+fn spin() -> u64 {
+    let mut sum = 0u64;
+    for i in 0..10_000_000u64 {
+        sum = bench::black_box(sum.wrapping_add(i));
+    }
+    sum
+}
+
+#[test]
+fn test_wasi_clocks() {
+    let start = SteadyClock::now();
+    spin();
+    assert!(SteadyClock::now() > start);
+    assert!(Duration::from(SystemClock::now()) > Duration::from_secs(0));
+    assert!(SteadyClock::resolution().unwrap() > Duration::from_nanos(0));
+}
+
+#[test]
+fn test_wasi_cpu_clocks() {
+    match ProcessCPUClock::total() {
+        Ok(mut last) => {
+            for _ in 0..5 {
+                spin();
+                let total = ProcessCPUClock::total().unwrap();
+                assert!(total >= last);
+                last = total;
+            }
+            assert!(last > Duration::from_nanos(0));
+        }
+        // The runtime does not implement `CLOCK_PROCESS_CPUTIME_ID`.
+        Err(Error::Unsupported(_)) => {}
+        Err(err) => panic!("{}", err),
+    }
+    match ThreadClock::try_now() {
+        Ok(start) => {
+            spin();
+            assert!(ThreadClock::now() >= start);
+        }
+        Err(Error::Unsupported(_)) => {}
+        Err(err) => panic!("{}", err),
+    }
+}
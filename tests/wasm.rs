@@ -0,0 +1,42 @@
+#![cfg(all(target_arch = "wasm32", target_os = "unknown", feature = "wasm"))]
+
+use howlong::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn busy_loop() -> u64 {
+    let mut sum = 0u64;
+    for i in 0..1_000_000u64 {
+        sum = bench::black_box(sum.wrapping_add(i));
+    }
+    sum
+}
+
+#[wasm_bindgen_test]
+fn test_steady_timer() {
+    let timer = SteadyTimer::new();
+    let mut last = timer.elapsed();
+    for _ in 0..10 {
+        busy_loop();
+        let elapsed = timer.elapsed();
+        assert!(elapsed >= last);
+        last = elapsed;
+    }
+    assert!(last > Duration::from_nanos(0));
+}
+
+#[wasm_bindgen_test]
+fn test_clocks() {
+    let start = SystemClock::now();
+    busy_loop();
+    assert!(SystemClock::now() >= start);
+    let start = ProcessRealCPUClock::now();
+    busy_loop();
+    assert!(ProcessRealCPUClock::now() > start);
+    assert!(matches!(
+        ProcessCPUClock::try_now(),
+        Err(Error::Unsupported(_))
+    ));
+    assert!(matches!(ThreadClock::try_now(), Err(Error::Unsupported(_))));
+}